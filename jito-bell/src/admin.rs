@@ -0,0 +1,400 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::{
+    extract::State,
+    http::{header::AUTHORIZATION, HeaderMap, StatusCode},
+    routing::{get, post, put},
+    Json, Router,
+};
+use log::info;
+use serde::{Deserialize, Serialize};
+use tokio::{
+    net::TcpListener,
+    sync::{mpsc, oneshot},
+};
+
+use crate::{
+    config::JitoBellConfig,
+    error::JitoBellError,
+    supervisor::{self, Supervisor},
+    suppression::Suppressions,
+    threshold_config::ThresholdConfig,
+};
+
+/// Capacity of the admin command channel
+const ADMIN_COMMAND_CAPACITY: usize = 16;
+
+/// Value of redacted settings (notifier credentials, the admin token, the leader lease Redis URL)
+const REDACTED: &str = "<redacted>";
+
+/// Command applied by the handler between subscription updates
+#[derive(Debug)]
+pub enum AdminCommand {
+    /// Current configuration, credentials redacted
+    GetConfig {
+        reply: oneshot::Sender<serde_json::Value>,
+    },
+
+    /// Replace the thresholds of an address
+    SetThresholds {
+        update: ThresholdUpdate,
+        reply: oneshot::Sender<Result<(), String>>,
+    },
+}
+
+/// Body of PUT /admin/thresholds
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ThresholdUpdate {
+    /// Program key under `programs` (e.g. "spl_stake_pool")
+    pub program: String,
+
+    /// Instruction key (e.g. "deposit_sol")
+    pub instruction: String,
+
+    /// Address under `stake_pools`, `lsts`, `vrts` or `accounts` of the instruction
+    pub address: String,
+
+    /// New thresholds
+    pub thresholds: Vec<ThresholdConfig>,
+}
+
+/// Body of POST /admin/mute and POST /admin/unmute
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MuteRequest {
+    /// Program key under `programs`
+    pub program: String,
+
+    /// Instruction key
+    pub instruction: String,
+
+    /// Mute duration in seconds, until unmuted when missing
+    #[serde(default)]
+    pub duration_secs: Option<u64>,
+}
+
+/// Shared state of the admin API
+#[derive(Clone)]
+struct AdminState {
+    /// Bearer token required by every request
+    token: Arc<String>,
+
+    /// Commands to the handler
+    commands: mpsc::Sender<AdminCommand>,
+
+    /// Suppression list, instruction mutes
+    suppressions: Arc<Suppressions>,
+}
+
+/// Bind the admin API and serve it in the background
+///
+/// - Every request requires `Authorization: Bearer <admin.token>`
+/// - Returns the receiver of the commands the handler applies
+/// - The server is supervised, and binds `bind_address` again when restarted
+pub async fn serve(
+    bind_address: &str,
+    token: &str,
+    suppressions: Arc<Suppressions>,
+    supervisor: &Supervisor,
+) -> Result<mpsc::Receiver<AdminCommand>, JitoBellError> {
+    let listener = TcpListener::bind(bind_address).await?;
+    info!("Admin API listening on {bind_address}");
+
+    let (commands, receiver) = mpsc::channel(ADMIN_COMMAND_CAPACITY);
+    let state = AdminState {
+        token: Arc::new(token.to_string()),
+        commands,
+        suppressions,
+    };
+
+    let router = Router::new()
+        .route("/admin/config", get(get_config))
+        .route("/admin/thresholds", put(set_thresholds))
+        .route("/admin/mute", post(mute))
+        .route("/admin/unmute", post(unmute))
+        .route("/admin/mutes", get(list_mutes))
+        .with_state(state);
+
+    supervisor.spawn(
+        "admin",
+        supervisor::serve_task(bind_address.to_string(), listener, router),
+    );
+
+    Ok(receiver)
+}
+
+/// Reject requests without the bearer token
+fn authorize(state: &AdminState, headers: &HeaderMap) -> Result<(), (StatusCode, String)> {
+    let token = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .unwrap_or_default();
+
+    if constant_time_eq(token.as_bytes(), state.token.as_bytes()) {
+        Ok(())
+    } else {
+        Err((StatusCode::UNAUTHORIZED, "Invalid admin token".to_string()))
+    }
+}
+
+/// Compare secrets without leaking the position of the first difference
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// Send a command to the handler and wait for its reply
+async fn request<T>(
+    state: &AdminState,
+    command: impl FnOnce(oneshot::Sender<T>) -> AdminCommand,
+) -> Result<T, (StatusCode, String)> {
+    let unavailable = || {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Handler is not running".to_string(),
+        )
+    };
+
+    let (reply, response) = oneshot::channel();
+    state
+        .commands
+        .send(command(reply))
+        .await
+        .map_err(|_| unavailable())?;
+
+    response.await.map_err(|_| unavailable())
+}
+
+/// GET /admin/config
+async fn get_config(
+    State(state): State<AdminState>,
+    headers: HeaderMap,
+) -> Result<Json<serde_json::Value>, (StatusCode, String)> {
+    authorize(&state, &headers)?;
+
+    request(&state, |reply| AdminCommand::GetConfig { reply })
+        .await
+        .map(Json)
+}
+
+/// PUT /admin/thresholds
+///
+/// - Applies from the next transaction, until the configuration file changes or a restart
+async fn set_thresholds(
+    State(state): State<AdminState>,
+    headers: HeaderMap,
+    Json(update): Json<ThresholdUpdate>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    authorize(&state, &headers)?;
+
+    request(&state, |reply| AdminCommand::SetThresholds {
+        update,
+        reply,
+    })
+    .await?
+    .map(|()| StatusCode::NO_CONTENT)
+    .map_err(|e| (StatusCode::NOT_FOUND, e))
+}
+
+/// POST /admin/mute
+async fn mute(
+    State(state): State<AdminState>,
+    headers: HeaderMap,
+    Json(mute_request): Json<MuteRequest>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    authorize(&state, &headers)?;
+
+    state.suppressions.mute_instruction(
+        &mute_request.program,
+        &mute_request.instruction,
+        mute_request.duration_secs.map(Duration::from_secs),
+    );
+    info!(
+        "Muted instruction {}.{} from the admin API",
+        mute_request.program, mute_request.instruction
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /admin/unmute
+async fn unmute(
+    State(state): State<AdminState>,
+    headers: HeaderMap,
+    Json(mute_request): Json<MuteRequest>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    authorize(&state, &headers)?;
+
+    state
+        .suppressions
+        .unmute_instruction(&mute_request.program, &mute_request.instruction);
+    info!(
+        "Unmuted instruction {}.{} from the admin API",
+        mute_request.program, mute_request.instruction
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /admin/mutes
+///
+/// - Muted instructions as "program.instruction"
+async fn list_mutes(
+    State(state): State<AdminState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<String>>, (StatusCode, String)> {
+    authorize(&state, &headers)?;
+
+    Ok(Json(state.suppressions.muted_instructions()))
+}
+
+/// Configuration as JSON, notifier settings, the admin token and the leader lease Redis URL
+/// redacted
+pub fn redacted_config(config: &JitoBellConfig) -> serde_json::Value {
+    let mut value = serde_json::to_value(config).unwrap_or_default();
+
+    if let Some(notifications) = value
+        .get_mut("notifications")
+        .and_then(serde_json::Value::as_object_mut)
+    {
+        for (_, notifier) in notifications.iter_mut() {
+            if !notifier.is_null() {
+                *notifier = serde_json::Value::from(REDACTED);
+            }
+        }
+    }
+    if let Some(token) = value.pointer_mut("/admin/token") {
+        *token = serde_json::Value::from(REDACTED);
+    }
+    if let Some(redis_url) = value
+        .pointer_mut("/leader_election/redis_url")
+        .filter(|redis_url| !redis_url.is_null())
+    {
+        *redis_url = serde_json::Value::from(REDACTED);
+    }
+
+    value
+}
+
+/// Replace the thresholds of `update.address` in the instruction's `stake_pools`, `lsts`,
+/// `vrts` or `accounts`
+pub fn apply_threshold_update(
+    config: &mut JitoBellConfig,
+    update: ThresholdUpdate,
+) -> Result<(), String> {
+    let instruction = config
+        .programs
+        .get_mut(&update.program)
+        .ok_or_else(|| format!("Unknown program {}", update.program))?
+        .instructions
+        .get_mut(&update.instruction)
+        .ok_or_else(|| {
+            format!(
+                "Unknown instruction {} of {}",
+                update.instruction, update.program
+            )
+        })?;
+
+    let alert_config = [
+        instruction.stake_pools.as_mut(),
+        instruction.lsts.as_mut(),
+        instruction.vrts.as_mut(),
+        instruction.accounts.as_mut(),
+    ]
+    .into_iter()
+    .flatten()
+    .find_map(|alert_configs| alert_configs.get_mut(&update.address))
+    .ok_or_else(|| {
+        format!(
+            "Address {} is not configured for {}.{}",
+            update.address, update.program, update.instruction
+        )
+    })?;
+
+    alert_config.thresholds = update.thresholds;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        admin::{apply_threshold_update, constant_time_eq, redacted_config, ThresholdUpdate},
+        config::{ConfigFormat, JitoBellConfig},
+        threshold_config::ThresholdConfig,
+    };
+
+    const CONFIG: &str = r#"
+programs:
+  spl_stake_pool:
+    program_id: "SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy"
+    instructions:
+      deposit_sol:
+        lsts:
+          "J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn":
+            thresholds:
+              - value: 100.0
+                notification:
+                  description: "Deposit"
+                  destinations: ["discord"]
+notifications:
+  discord:
+    webhook_url: "https://discord.com/api/webhooks/1/token"
+explorer_url: "https://explorer.solana.com"
+message_templates:
+  default: "{{description}}"
+admin:
+  bind_address: "127.0.0.1:8081"
+  token: "secret"
+"#;
+
+    fn config() -> JitoBellConfig {
+        JitoBellConfig::parse(CONFIG, ConfigFormat::Yaml).unwrap()
+    }
+
+    #[test]
+    fn test_redacted_config() {
+        let value = redacted_config(&config());
+
+        assert_eq!(value["notifications"]["discord"], "<redacted>");
+        assert_eq!(value["admin"]["token"], "<redacted>");
+        assert!(!value.to_string().contains("webhooks/1/token"));
+        assert!(value["programs"]["spl_stake_pool"].is_object());
+    }
+
+    #[test]
+    fn test_apply_threshold_update() {
+        let mut config = config();
+        let update = |address: &str| ThresholdUpdate {
+            program: "spl_stake_pool".to_string(),
+            instruction: "deposit_sol".to_string(),
+            address: address.to_string(),
+            thresholds: vec![ThresholdConfig {
+                value: 5000.0,
+                median_multiple: None,
+                notification: Default::default(),
+            }],
+        };
+
+        apply_threshold_update(
+            &mut config,
+            update("J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn"),
+        )
+        .unwrap();
+        let thresholds = &config.programs["spl_stake_pool"].instructions["deposit_sol"]
+            .lsts
+            .as_ref()
+            .unwrap()["J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn"]
+            .thresholds;
+        assert_eq!(thresholds.len(), 1);
+        assert_eq!(thresholds[0].value, 5000.0);
+
+        assert!(apply_threshold_update(&mut config, update("unknown")).is_err());
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secreT"));
+        assert!(!constant_time_eq(b"secret", b"secret2"));
+    }
+}
@@ -0,0 +1,212 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Bytes,
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode},
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::Utc;
+use log::info;
+use serde::Deserialize;
+use tokio::net::TcpListener;
+
+use crate::{
+    error::JitoBellError,
+    health::{Health, HealthCheck, HealthConfig},
+    notifier::discord_bot,
+    recent::RecentEvents,
+    schema::SchemaVersion,
+    store::{AuditFilter, AuditRecord, EventStore, ReceiptFilter, ReceiptRecord},
+    supervisor::{self, Supervisor},
+    suppression::Suppressions,
+};
+
+/// Default number of events returned by GET /recent
+const DEFAULT_RECENT_LIMIT: usize = 20;
+
+/// Shared state of the REST API
+#[derive(Clone)]
+pub struct ApiState {
+    /// Event store
+    pub store: Option<Arc<EventStore>>,
+
+    /// Recent events
+    pub recent: Arc<RecentEvents>,
+
+    /// Suppression list updated by Discord interactions
+    pub suppressions: Arc<Suppressions>,
+
+    /// Discord application public key (hex)
+    pub discord_public_key: Option<String>,
+
+    /// Stream liveness and circuit states
+    pub health: Arc<Health>,
+
+    /// Thresholds of the health checks
+    pub health_config: HealthConfig,
+}
+
+/// Query parameters of GET /recent
+#[derive(Debug, Deserialize)]
+pub struct RecentQuery {
+    /// Maximum number of events
+    pub limit: Option<usize>,
+
+    /// Event schema version
+    #[serde(default)]
+    pub schema_version: SchemaVersion,
+}
+
+/// Bind the REST API and serve it in the background
+///
+/// - The server is supervised, and binds `bind_address` again when restarted
+pub async fn serve(
+    bind_address: &str,
+    state: ApiState,
+    supervisor: &Supervisor,
+) -> Result<(), JitoBellError> {
+    let listener = TcpListener::bind(bind_address).await?;
+    info!("API server listening on {bind_address}");
+
+    let router = Router::new()
+        .route("/audit", get(list_audit))
+        .route("/healthz", get(healthz))
+        .route("/readyz", get(readyz))
+        .route("/receipts", get(list_receipts))
+        .route("/recent", get(list_recent))
+        .route("/discord/interactions", post(discord_interaction))
+        .with_state(state);
+
+    supervisor.spawn(
+        "api",
+        supervisor::serve_task(bind_address.to_string(), listener, router),
+    );
+
+    Ok(())
+}
+
+/// GET /audit
+///
+/// - Query parameters: destination, signature, since (RFC 3339), limit
+async fn list_audit(
+    State(state): State<ApiState>,
+    Query(filter): Query<AuditFilter>,
+) -> Result<Json<Vec<AuditRecord>>, (StatusCode, String)> {
+    let store = state.store.ok_or((
+        StatusCode::SERVICE_UNAVAILABLE,
+        "Event store is not configured".to_string(),
+    ))?;
+
+    store
+        .list_notifications(&filter)
+        .map(Json)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// GET /healthz
+///
+/// - 503 when the subscription is wedged (no update within `health.max_update_age_secs`)
+async fn healthz(State(state): State<ApiState>) -> (StatusCode, Json<HealthCheck>) {
+    let check = state
+        .health
+        .check(&state.health_config, Utc::now().timestamp_millis());
+    (health_status(check.live), Json(check))
+}
+
+/// GET /readyz
+///
+/// - 503 before the first update, when the subscription is wedged, or while a destination
+///   circuit is open (unless `health.ready_with_open_circuits`)
+async fn readyz(State(state): State<ApiState>) -> (StatusCode, Json<HealthCheck>) {
+    let check = state
+        .health
+        .check(&state.health_config, Utc::now().timestamp_millis());
+    (health_status(check.ready), Json(check))
+}
+
+/// Status code of a health check
+fn health_status(ok: bool) -> StatusCode {
+    if ok {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    }
+}
+
+/// GET /receipts
+///
+/// - Query parameters: destination, undelivered (true/false), since, until (RFC 3339), limit
+/// - Newest first
+async fn list_receipts(
+    State(state): State<ApiState>,
+    Query(filter): Query<ReceiptFilter>,
+) -> Result<Json<Vec<ReceiptRecord>>, (StatusCode, String)> {
+    let store = state.store.ok_or((
+        StatusCode::SERVICE_UNAVAILABLE,
+        "Event store is not configured".to_string(),
+    ))?;
+
+    store
+        .list_receipts(&filter)
+        .map(Json)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// GET /recent
+///
+/// - Query parameters: limit, schema_version
+/// - Newest first
+async fn list_recent(
+    State(state): State<ApiState>,
+    Query(query): Query<RecentQuery>,
+) -> Result<Json<Vec<serde_json::Value>>, (StatusCode, String)> {
+    state
+        .recent
+        .list(query.limit.unwrap_or(DEFAULT_RECENT_LIMIT))
+        .iter()
+        .map(|event| event.to_payload(query.schema_version))
+        .collect::<Result<Vec<_>, _>>()
+        .map(Json)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// POST /discord/interactions
+///
+/// - Verify the request signature with the application public key
+/// - Handle "Acknowledge" and "Mute 1h" buttons
+async fn discord_interaction(
+    State(state): State<ApiState>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<serde_json::Value>, (StatusCode, String)> {
+    let public_key = state.discord_public_key.ok_or((
+        StatusCode::SERVICE_UNAVAILABLE,
+        "Discord bot is not configured".to_string(),
+    ))?;
+
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+    };
+    let signature = header("X-Signature-Ed25519");
+    let timestamp = header("X-Signature-Timestamp");
+
+    if !discord_bot::verify_signature(&public_key, signature, timestamp, &body) {
+        return Err((
+            StatusCode::UNAUTHORIZED,
+            "Invalid request signature".to_string(),
+        ));
+    }
+
+    let interaction: serde_json::Value =
+        serde_json::from_slice(&body).map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+
+    Ok(Json(discord_bot::handle_interaction(
+        &interaction,
+        &state.suppressions,
+    )))
+}
@@ -1,7 +1,23 @@
-use std::{env, io::Write, path::PathBuf};
+use std::{
+    env,
+    io::Write,
+    path::{Path, PathBuf},
+};
 
-use clap::{Parser, ValueEnum};
-use jito_bell::{multi_writer::MultiWriter, subscribe_option::SubscribeOption, JitoBellHandler};
+use anyhow::anyhow;
+use chrono::{DateTime, Utc};
+use clap::{Parser, Subcommand, ValueEnum};
+use jito_bell::{
+    config::JitoBellConfig,
+    config_validation,
+    examples::ExampleConfig,
+    logging::{self, LogFormat},
+    multi_writer::MultiWriter,
+    pipeline_trace,
+    store::{AuditFilter, EventStore, ReceiptFilter},
+    subscribe_option::SubscribeOption,
+    JitoBellHandler,
+};
 use log::info;
 use solana_sdk::commitment_config::CommitmentConfig;
 use yellowstone_grpc_proto::geyser::CommitmentLevel;
@@ -9,9 +25,12 @@ use yellowstone_grpc_proto::geyser::CommitmentLevel;
 #[derive(Debug, Clone, Parser)]
 #[clap(author, version, about)]
 struct Args {
+    #[clap(subcommand)]
+    command: Option<Command>,
+
     #[clap(short, long, env = "ENDPOINT")]
     /// Service endpoint
-    endpoint: String,
+    endpoint: Option<String>,
 
     #[clap(long, env = "X_TOKEN")]
     x_token: Option<String>,
@@ -44,8 +63,93 @@ struct Args {
     #[clap(long, env)]
     account_required: Vec<String>,
 
-    #[clap(long, env = "CONFIG_FILE")]
-    config_file: PathBuf,
+    /// Config file, YAML or TOML/JSON by its `.toml`/`.json` extension
+    #[clap(long, env = "CONFIG_FILE", global = true)]
+    config_file: Option<PathBuf>,
+
+    /// Print a built-in example config and exit
+    #[clap(long)]
+    print_example_config: Option<ExampleConfig>,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum Command {
+    /// Show notifications recorded in the audit trail
+    Audit {
+        /// Filter by destination (telegram, slack, discord, ...)
+        #[clap(long)]
+        destination: Option<String>,
+
+        /// Filter by transaction signature
+        #[clap(long)]
+        signature: Option<String>,
+
+        /// Only show notifications sent at or after this time (RFC 3339)
+        #[clap(long)]
+        since: Option<DateTime<Utc>>,
+
+        /// Maximum number of notifications to show
+        #[clap(long, default_value_t = 50)]
+        limit: u32,
+    },
+
+    /// Check the config at --config-file
+    Config {
+        #[clap(subcommand)]
+        command: ConfigCommand,
+    },
+
+    /// Interactively create a starter config at --config-file
+    Init {
+        /// Overwrite an existing config
+        #[clap(long)]
+        force: bool,
+
+        /// Write a built-in example config instead of asking questions
+        #[clap(long)]
+        template: Option<ExampleConfig>,
+    },
+
+    /// Show delivery receipts of notifications, newest first
+    Receipts {
+        /// Filter by destination (telegram, slack, discord, ...)
+        #[clap(long)]
+        destination: Option<String>,
+
+        /// Only show notifications not delivered (pending, retrying or dead-lettered)
+        #[clap(long)]
+        undelivered: bool,
+
+        /// Only show notifications dispatched at or after this time (RFC 3339)
+        #[clap(long)]
+        since: Option<DateTime<Utc>>,
+
+        /// Only show notifications dispatched before this time (RFC 3339)
+        #[clap(long)]
+        until: Option<DateTime<Utc>>,
+
+        /// Maximum number of notifications to show
+        #[clap(long, default_value_t = 50)]
+        limit: u32,
+    },
+
+    /// Show recent events of a running instance (one JSON object per line, newest first)
+    Recent {
+        /// API URL of the running instance, defaults to http://<api.bind_address>
+        #[clap(long)]
+        api_url: Option<String>,
+
+        /// Maximum number of events to show
+        #[clap(long, default_value_t = 20)]
+        limit: usize,
+    },
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum ConfigCommand {
+    /// Report every problem of the config (schema, program and instruction keys, destinations
+    /// and template variables) with its line
+    Validate,
 }
 
 #[derive(Debug, Clone, Copy, Default, ValueEnum)]
@@ -66,10 +170,182 @@ impl From<ArgsCommitment> for CommitmentLevel {
     }
 }
 
+/// Print notifications recorded in the audit trail
+fn print_audit(config_file: &Path, filter: AuditFilter) -> anyhow::Result<()> {
+    let config = JitoBellConfig::load(config_file)?;
+    let store_config = config
+        .store
+        .ok_or_else(|| anyhow!("Event store is not configured"))?;
+    let store = EventStore::open(&store_config.path)?;
+
+    for record in store.list_notifications(&filter)? {
+        println!(
+            "{} [{}] {} {}",
+            record.created_at, record.destination, record.status, record.transaction_signature
+        );
+        println!("    {}", record.rendered);
+    }
+
+    Ok(())
+}
+
+/// Print the delivery receipts matching `filter`
+fn print_receipts(config_file: &Path, filter: ReceiptFilter) -> anyhow::Result<()> {
+    let config = JitoBellConfig::load(config_file)?;
+    let store_config = config
+        .store
+        .ok_or_else(|| anyhow!("Event store is not configured"))?;
+    let store = EventStore::open(&store_config.path)?;
+
+    for record in store.list_receipts(&filter)? {
+        println!(
+            "#{} {} [{}] {} ({} attempts) {}",
+            record.id,
+            record.created_at,
+            record.destination,
+            record.status,
+            record.attempts,
+            record.transaction_signature
+        );
+        println!("    {}", record.description);
+        if let Some(last_error) = record.last_error {
+            println!("    last error: {last_error}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Print the problems of a config file, fail if there is any
+fn validate_config(config_file: &Path) -> anyhow::Result<()> {
+    let config_str = std::fs::read_to_string(config_file)?;
+    let problems = config_validation::validate(config_file);
+
+    for problem in problems.iter() {
+        match problem.line {
+            Some(line) => {
+                println!("{}:{line}: {problem}", config_file.display());
+                if let Some(text) = config_str.lines().nth(line - 1) {
+                    println!("    {}", text.trim());
+                }
+            }
+            None => println!("{}: {problem}", config_file.display()),
+        }
+    }
+
+    if problems.is_empty() {
+        println!("{} is valid", config_file.display());
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "{} problem(s) found in {}",
+            problems.len(),
+            config_file.display()
+        ))
+    }
+}
+
+/// Print recent events fetched from a running instance
+async fn print_recent(
+    config_file: &Path,
+    api_url: Option<String>,
+    limit: usize,
+) -> anyhow::Result<()> {
+    let api_url = match api_url {
+        Some(api_url) => api_url,
+        None => {
+            let config = JitoBellConfig::load(config_file)?;
+            let api_config = config
+                .api
+                .ok_or_else(|| anyhow!("REST API is not configured, pass --api-url"))?;
+            format!("http://{}", api_config.bind_address)
+        }
+    };
+
+    let url = format!("{}/recent", api_url.trim_end_matches('/'));
+    let events: Vec<serde_json::Value> = reqwest::Client::new()
+        .get(&url)
+        .query(&[("limit", limit)])
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    for event in events {
+        println!("{event}");
+    }
+
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     dotenvy::dotenv().ok();
 
+    let args = Args::parse();
+
+    if let Some(example) = args.print_example_config {
+        print!("{}", example.to_yaml()?);
+        return Ok(());
+    }
+
+    let config_file = args
+        .config_file
+        .clone()
+        .ok_or_else(|| anyhow!("--config-file (CONFIG_FILE) is required"))?;
+
+    if let Some(command) = args.command {
+        match command {
+            Command::Audit {
+                destination,
+                signature,
+                since,
+                limit,
+            } => {
+                let filter = AuditFilter {
+                    destination,
+                    signature,
+                    since,
+                    limit: Some(limit),
+                };
+                print_audit(&config_file, filter)?;
+            }
+            Command::Config {
+                command: ConfigCommand::Validate,
+            } => validate_config(&config_file)?,
+            Command::Init { force, template } => match template {
+                Some(example) => jito_bell::init::write_template(&config_file, example, force)?,
+                None => jito_bell::init::run(&config_file, force).await?,
+            },
+            Command::Receipts {
+                destination,
+                undelivered,
+                since,
+                until,
+                limit,
+            } => {
+                let filter = ReceiptFilter {
+                    destination,
+                    undelivered,
+                    since,
+                    until,
+                    limit: Some(limit),
+                };
+                print_receipts(&config_file, filter)?;
+            }
+            Command::Recent { api_url, limit } => {
+                print_recent(&config_file, api_url, limit).await?;
+            }
+        }
+
+        return Ok(());
+    }
+
+    let endpoint = args
+        .endpoint
+        .ok_or_else(|| anyhow!("--endpoint (ENDPOINT) is required"))?;
+
     let log_path =
         env::var("LOG_FILE_PATH").unwrap_or_else(|_| "/var/log/jito-bell/app.log".to_string());
 
@@ -77,34 +353,61 @@ async fn main() -> anyhow::Result<()> {
         std::fs::create_dir_all(dir)?;
     }
 
-    env::set_var(
-        env_logger::DEFAULT_FILTER_ENV,
-        env::var_os(env_logger::DEFAULT_FILTER_ENV).unwrap_or_else(|| "info".into()),
-    );
+    // Keep stdout machine-readable when it is used as a notification sink
+    let config = JitoBellConfig::load(&config_file)?;
+    let http_client = config.http_timeouts.shared_client()?;
+    let mut multi_writer = MultiWriter::new();
+    if config.notifications.stdout.is_some() {
+        multi_writer = multi_writer.with_stderr();
+    }
 
-    let env = env_logger::Env::default();
-    env_logger::Builder::from_env(env)
-        .format(|buf, record| {
-            writeln!(
-                buf,
-                "{} [{}] {}: {}",
-                chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
-                record.level(),
-                record.target(),
-                record.args()
-            )
-        })
-        .write_style(env_logger::WriteStyle::Always)
-        .target(env_logger::Target::Pipe(Box::new(MultiWriter::new())))
-        .init();
+    let log_format = match env::var("LOG_FORMAT") {
+        Ok(log_format) => log_format.parse()?,
+        Err(_) => config.logging.format,
+    };
+    match log_format {
+        LogFormat::Text => {
+            env::set_var(
+                env_logger::DEFAULT_FILTER_ENV,
+                env::var_os(env_logger::DEFAULT_FILTER_ENV).unwrap_or_else(|| "info".into()),
+            );
 
-    let args = Args::parse();
+            let env = env_logger::Env::default();
+            env_logger::Builder::from_env(env)
+                .format(|buf, record| {
+                    writeln!(
+                        buf,
+                        "{} [{}] {}: {}",
+                        chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
+                        record.level(),
+                        record.target(),
+                        record.args()
+                    )
+                })
+                .write_style(env_logger::WriteStyle::Always)
+                .target(env_logger::Target::Pipe(Box::new(multi_writer)))
+                .init();
+
+            if let Some(tracing_config) = &config.tracing {
+                pipeline_trace::init(tracing_config, http_client.clone())?;
+            }
+        }
+        LogFormat::Json => {
+            let filter = logging::env_filter(env::var("RUST_LOG").ok().as_deref());
+            logging::init_json(
+                multi_writer,
+                filter,
+                config.tracing.as_ref(),
+                http_client.clone(),
+            )?;
+        }
+    }
 
-    info!("Starting Jito Bell with endpoint: {}", args.endpoint);
+    info!("Starting Jito Bell with endpoint: {}", endpoint);
 
     let commitment: CommitmentLevel = args.commitment.unwrap_or_default().into();
     let subscribe_option = SubscribeOption::new(
-        args.endpoint.clone(),
+        endpoint.clone(),
         args.x_token,
         commitment,
         args.vote,
@@ -119,12 +422,17 @@ async fn main() -> anyhow::Result<()> {
 
     let commitment = CommitmentConfig::confirmed();
     let mut handler =
-        JitoBellHandler::new(args.endpoint.clone(), commitment, args.config_file).await?;
+        JitoBellHandler::new(endpoint.clone(), commitment, config_file, http_client).await?;
 
     info!("Jito Bell Config:\n{}", handler.config);
 
+    handler.check_destinations().await?;
+    handler.start_api_server().await?;
+    handler.start_admin_server().await?;
+
     info!("Starting heartbeat...");
     handler.heart_beat(&subscribe_option).await?;
+    info!("Jito Bell stopped");
 
     Ok(())
 }
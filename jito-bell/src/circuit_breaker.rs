@@ -0,0 +1,215 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::notification_info::NotificationInfo;
+
+/// Circuit breaker of notification destinations
+///
+/// - After `failure_threshold` consecutive failures, sends to the destination are skipped for
+///   `open_secs`, then a single probe is let through: success closes the circuit, failure opens
+///   it again
+/// - 0 disables the breaker
+/// - Openings and closings are alerted once each with `notification`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures opening the circuit
+    #[serde(default = "default_failure_threshold")]
+    pub failure_threshold: u32,
+
+    /// Seconds the circuit stays open before a probe
+    #[serde(default = "default_open_secs")]
+    pub open_secs: u64,
+
+    /// Alert sent when a circuit opens or closes
+    pub notification: Option<NotificationInfo>,
+}
+
+fn default_failure_threshold() -> u32 {
+    5
+}
+
+fn default_open_secs() -> u64 {
+    60
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: default_failure_threshold(),
+            open_secs: default_open_secs(),
+            notification: None,
+        }
+    }
+}
+
+/// State of a destination's circuit
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CircuitState {
+    /// Sends go through, counting consecutive failures
+    Closed { failures: u32 },
+
+    /// Sends are skipped until the time
+    Open { until: Instant },
+
+    /// A probe is in flight, other sends are skipped
+    HalfOpen,
+}
+
+/// Change of a circuit reported by [`CircuitBreakers::record`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitTransition {
+    /// The circuit opened
+    Opened,
+
+    /// The circuit closed after a successful probe
+    Closed,
+}
+
+/// Circuits per destination
+#[derive(Debug, Default)]
+pub struct CircuitBreakers {
+    circuits: HashMap<String, CircuitState>,
+}
+
+impl CircuitBreakers {
+    /// Whether a send to `destination` goes through, letting one probe through once the open
+    /// period elapsed
+    pub fn allow(&mut self, destination: &str, now: Instant) -> bool {
+        let Some(state) = self.circuits.get_mut(destination) else {
+            return true;
+        };
+
+        match *state {
+            CircuitState::Closed { .. } => true,
+            CircuitState::Open { until } if now >= until => {
+                *state = CircuitState::HalfOpen;
+                true
+            }
+            CircuitState::Open { .. } | CircuitState::HalfOpen => false,
+        }
+    }
+
+    /// Whether sends to `destination` are skipped, without letting a probe through
+    pub fn is_open(&self, destination: &str, now: Instant) -> bool {
+        match self.circuits.get(destination) {
+            Some(CircuitState::Open { until }) => now < *until,
+            Some(CircuitState::HalfOpen) => true,
+            _ => false,
+        }
+    }
+
+    /// Record the result of a send
+    pub fn record(
+        &mut self,
+        destination: &str,
+        success: bool,
+        config: &CircuitBreakerConfig,
+        now: Instant,
+    ) -> Option<CircuitTransition> {
+        if config.failure_threshold == 0 {
+            self.circuits.remove(destination);
+            return None;
+        }
+
+        let state = self
+            .circuits
+            .entry(destination.to_string())
+            .or_insert(CircuitState::Closed { failures: 0 });
+        let open = CircuitState::Open {
+            until: now + Duration::from_secs(config.open_secs),
+        };
+
+        match (*state, success) {
+            (CircuitState::HalfOpen, true) => {
+                *state = CircuitState::Closed { failures: 0 };
+                Some(CircuitTransition::Closed)
+            }
+            (_, true) => {
+                *state = CircuitState::Closed { failures: 0 };
+                None
+            }
+            (CircuitState::Closed { failures }, false)
+                if failures + 1 < config.failure_threshold =>
+            {
+                *state = CircuitState::Closed {
+                    failures: failures + 1,
+                };
+                None
+            }
+            (CircuitState::Open { .. }, false) => None,
+            (_, false) => {
+                *state = open;
+                Some(CircuitTransition::Opened)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::circuit_breaker::{CircuitBreakerConfig, CircuitBreakers, CircuitTransition};
+
+    #[test]
+    fn test_circuit_breaker() {
+        let config = CircuitBreakerConfig {
+            failure_threshold: 3,
+            open_secs: 60,
+            ..Default::default()
+        };
+        let now = Instant::now();
+        let mut breakers = CircuitBreakers::default();
+
+        assert!(breakers.allow("webhook", now));
+        assert_eq!(breakers.record("webhook", false, &config, now), None);
+        assert_eq!(breakers.record("webhook", false, &config, now), None);
+        assert_eq!(
+            breakers.record("webhook", false, &config, now),
+            Some(CircuitTransition::Opened)
+        );
+        assert!(!breakers.allow("webhook", now));
+        assert!(breakers.is_open("webhook", now));
+        assert!(breakers.allow("slack", now));
+
+        // A single probe once the open period elapsed, failing opens the circuit again
+        let later = now + Duration::from_secs(60);
+        assert!(!breakers.is_open("webhook", later));
+        assert!(breakers.allow("webhook", later));
+        assert!(!breakers.allow("webhook", later));
+        assert_eq!(
+            breakers.record("webhook", false, &config, later),
+            Some(CircuitTransition::Opened)
+        );
+        assert!(!breakers.allow("webhook", later));
+
+        // A successful probe closes it
+        let much_later = later + Duration::from_secs(60);
+        assert!(breakers.allow("webhook", much_later));
+        assert_eq!(
+            breakers.record("webhook", true, &config, much_later),
+            Some(CircuitTransition::Closed)
+        );
+        assert!(breakers.allow("webhook", much_later));
+    }
+
+    #[test]
+    fn test_success_resets_failures() {
+        let config = CircuitBreakerConfig {
+            failure_threshold: 2,
+            open_secs: 60,
+            ..Default::default()
+        };
+        let now = Instant::now();
+        let mut breakers = CircuitBreakers::default();
+
+        breakers.record("webhook", false, &config, now);
+        breakers.record("webhook", true, &config, now);
+        assert_eq!(breakers.record("webhook", false, &config, now), None);
+        assert!(breakers.allow("webhook", now));
+    }
+}
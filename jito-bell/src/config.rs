@@ -1,10 +1,41 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::SystemTime,
+};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
 
-use crate::{notification_config::NotificationConfig, program::Program};
+use crate::{
+    circuit_breaker::CircuitBreakerConfig,
+    config_include::{self, IncludedConfig},
+    dead_letter::DeadLetterConfig,
+    env_substitution::substitute_env,
+    error::JitoBellError,
+    failover::FailoverConfig,
+    health::HealthConfig,
+    http_timeout::HttpTimeouts,
+    keepalive::KeepaliveConfig,
+    logging::LoggingConfig,
+    notification_config::NotificationConfig,
+    notification_info::{Destination, NotificationInfo},
+    parser::anchor_idl::AnchorIdlParser,
+    pipeline_trace::TracingConfig,
+    program::Program,
+    retry::{ReconnectPolicy, RetryPolicy},
+    schedule::ScheduleConfig,
+    secrets::SecretResolver,
+    slot_gap::SlotGapConfig,
+    startup_check::StartupCheckConfig,
+    supervisor::SupervisorConfig,
+    timezone::Timezone,
+    update_queue::UpdateQueueConfig,
+    DEFAULT_ENRICHMENT_DEADLINE_MS, DEFAULT_RECENT_CAPACITY,
+};
 
-#[derive(Deserialize)]
+#[derive(Default, Deserialize, Serialize)]
 pub struct JitoBellConfig {
     /// Programs Configuration
     pub programs: HashMap<String, Program>,
@@ -17,6 +48,471 @@ pub struct JitoBellConfig {
 
     /// Message Templates
     pub message_templates: HashMap<String, String>,
+
+    /// Message templates keyed by locale (e.g. "ja"), then by destination or "default"
+    #[serde(default)]
+    pub localized_templates: HashMap<String, HashMap<String, String>>,
+
+    /// Locale of each destination (e.g. `telegram: ja`), English templates and numbers otherwise
+    #[serde(default)]
+    pub destination_locales: HashMap<String, String>,
+
+    /// Timezone of human-readable timestamps (`{{local_time}}`, Discord and Slack messages)
+    #[serde(default)]
+    pub timezone: Timezone,
+
+    /// Delivery schedules keyed by destination (e.g. quiet hours)
+    #[serde(default)]
+    pub schedules: HashMap<String, ScheduleConfig>,
+
+    /// Display names of pools keyed by address, rendered as `{{pool_name}}`
+    #[serde(default)]
+    pub pool_names: HashMap<String, String>,
+
+    /// Symbols and decimals of mints keyed by address, used instead of the on-chain lookup
+    #[serde(default)]
+    pub mints: HashMap<String, MintConfig>,
+
+    /// Labels of accounts keyed by pubkey (e.g. "Binance hot wallet"), rendered instead of the
+    /// pubkey wherever an account appears in templates
+    #[serde(default)]
+    pub account_labels: HashMap<String, String>,
+
+    /// Retry policy of notifier HTTP calls
+    #[serde(default)]
+    pub retry: RetryPolicy,
+
+    /// Timeouts of notifier HTTP calls
+    #[serde(default)]
+    pub http_timeouts: HttpTimeouts,
+
+    /// Circuit breaker of notification destinations
+    #[serde(default)]
+    pub circuit_breaker: CircuitBreakerConfig,
+
+    /// Keepalive of the Geyser connection
+    #[serde(default)]
+    pub keepalive: KeepaliveConfig,
+
+    /// Queue of Geyser updates between the stream reader and the dispatcher
+    #[serde(default)]
+    pub update_queue: UpdateQueueConfig,
+
+    /// Reconnection policy of the Geyser subscription
+    #[serde(default)]
+    pub reconnect: ReconnectPolicy,
+
+    /// Restart of the background tasks
+    #[serde(default)]
+    pub supervisor: SupervisorConfig,
+
+    /// Detection of slot gaps and stalls of the Geyser stream
+    pub slot_gap: Option<SlotGapConfig>,
+
+    /// Thresholds of GET /healthz and GET /readyz
+    #[serde(default)]
+    pub health: HealthConfig,
+
+    /// Tracing of the event pipeline, stage latencies reported as datapoints
+    pub tracing: Option<TracingConfig>,
+
+    /// Format of the log lines
+    #[serde(default)]
+    pub logging: LoggingConfig,
+
+    /// Event store configuration
+    pub store: Option<StoreConfig>,
+
+    /// Dead-letter handling of notifications that could not be delivered
+    #[serde(default)]
+    pub dead_letter: DeadLetterConfig,
+
+    /// Startup check of the configured destinations
+    pub startup_check: Option<StartupCheckConfig>,
+
+    /// REST API configuration
+    pub api: Option<ApiConfig>,
+
+    /// Admin API configuration
+    pub admin: Option<AdminConfig>,
+
+    /// Ordered delivery configuration
+    pub ordered_delivery: Option<OrderedDeliveryConfig>,
+
+    /// RPC enrichment configuration
+    pub enrichment: Option<EnrichmentConfig>,
+
+    /// Horizontal sharding configuration
+    pub sharding: Option<ShardingConfig>,
+
+    /// Failover across Geyser endpoints
+    pub failover: Option<FailoverConfig>,
+
+    /// Leader election configuration (active/passive HA)
+    pub leader_election: Option<LeaderElectionConfig>,
+
+    /// Recent events buffer configuration
+    pub recent: Option<RecentConfig>,
+
+    /// Memory caps of in-process queues and caches
+    pub limits: Option<LimitsConfig>,
+
+    /// Scheduled validator list diff reports
+    #[serde(default)]
+    pub validator_list_reports: Vec<ValidatorListReportConfig>,
+
+    /// Files the configuration was read from, the config file and its includes
+    #[serde(skip)]
+    pub files: Vec<PathBuf>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct StoreConfig {
+    /// Path to the SQLite database
+    pub path: PathBuf,
+
+    /// Keep notifications in an outbox until sent, retrying failed ones after recovery
+    #[serde(default)]
+    pub outbox: bool,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ApiConfig {
+    /// Bind address (e.g. "0.0.0.0:8080")
+    pub bind_address: String,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct AdminConfig {
+    /// Bind address (e.g. "127.0.0.1:8081")
+    pub bind_address: String,
+
+    /// Bearer token required by every request (e.g. "${JITO_BELL_ADMIN_TOKEN}")
+    pub token: String,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct OrderedDeliveryConfig {
+    /// Number of slots to hold notifications before releasing them in order
+    #[serde(default = "default_hold_slots")]
+    pub hold_slots: u64,
+}
+
+fn default_hold_slots() -> u64 {
+    2
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct EnrichmentConfig {
+    /// Latency budget of the RPC and price lookups per event in milliseconds
+    #[serde(default = "default_enrichment_deadline_ms")]
+    pub deadline_ms: u64,
+}
+
+fn default_enrichment_deadline_ms() -> u64 {
+    DEFAULT_ENRICHMENT_DEADLINE_MS
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MintConfig {
+    /// Symbol of the mint's amounts (e.g. "JitoSOL")
+    pub symbol: String,
+
+    /// Decimals of the mint
+    pub decimals: u8,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ShardingConfig {
+    /// Index of this instance (0-based)
+    pub index: u64,
+
+    /// Number of instances
+    pub count: u64,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct LeaderElectionConfig {
+    /// Lease file on storage shared by all replicas
+    pub lease_path: Option<PathBuf>,
+
+    /// Redis holding the lease instead of a file, e.g. "redis://redis:6379"
+    pub redis_url: Option<String>,
+
+    /// Lease key in Redis
+    #[serde(default = "default_redis_lease_key")]
+    pub redis_key: String,
+
+    /// Identity of this instance, defaults to "<hostname>-<pid>"
+    pub instance_id: Option<String>,
+
+    /// Lease duration in seconds
+    #[serde(default = "default_lease_duration_secs")]
+    pub lease_duration_secs: u64,
+
+    /// Lease renewal interval in seconds
+    #[serde(default = "default_renew_interval_secs")]
+    pub renew_interval_secs: u64,
+}
+
+fn default_lease_duration_secs() -> u64 {
+    15
+}
+
+fn default_renew_interval_secs() -> u64 {
+    5
+}
+
+fn default_redis_lease_key() -> String {
+    "jito-bell:leader".to_string()
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct RecentConfig {
+    /// Number of recent events kept in memory
+    #[serde(default = "default_recent_capacity")]
+    pub capacity: usize,
+}
+
+fn default_recent_capacity() -> usize {
+    DEFAULT_RECENT_CAPACITY
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct LimitsConfig {
+    /// Notifications held by ordered delivery, further ones are delivered right away
+    #[serde(default = "default_max_pending_notifications")]
+    pub max_pending_notifications: usize,
+
+    /// Transactions remembered for duplicate detection, the oldest are forgotten first
+    #[serde(default = "default_max_dedup_entries")]
+    pub max_dedup_entries: usize,
+
+    /// Medians cached by dynamic thresholds
+    #[serde(default = "default_max_median_cache_entries")]
+    pub max_median_cache_entries: usize,
+}
+
+impl Default for LimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_pending_notifications: default_max_pending_notifications(),
+            max_dedup_entries: default_max_dedup_entries(),
+            max_median_cache_entries: default_max_median_cache_entries(),
+        }
+    }
+}
+
+fn default_max_pending_notifications() -> usize {
+    10_000
+}
+
+fn default_max_dedup_entries() -> usize {
+    100_000
+}
+
+fn default_max_median_cache_entries() -> usize {
+    10_000
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ValidatorListReportConfig {
+    /// Stake pool address
+    pub stake_pool: String,
+
+    /// Report interval in seconds
+    #[serde(default = "default_report_interval_secs")]
+    pub interval_secs: u64,
+
+    /// Ignore per-validator stake changes smaller than this (SOL)
+    #[serde(default)]
+    pub min_stake_delta: f64,
+
+    /// Notification
+    pub notification: NotificationInfo,
+}
+
+fn default_report_interval_secs() -> u64 {
+    3600
+}
+
+/// Format of the configuration file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Yaml,
+    Toml,
+    Json,
+}
+
+impl ConfigFormat {
+    /// Format from the file extension, `.toml` and `.json` files and YAML otherwise
+    pub fn from_path(config_path: &Path) -> Self {
+        match config_path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_ascii_lowercase())
+            .as_deref()
+        {
+            Some("toml") => Self::Toml,
+            Some("json") => Self::Json,
+            _ => Self::Yaml,
+        }
+    }
+
+    /// Parse a document of this format into a value tree
+    pub fn parse_value(&self, config_str: &str) -> Result<serde_json::Value, JitoBellError> {
+        match self {
+            ConfigFormat::Yaml => Ok(serde_yaml::from_str(config_str)?),
+            ConfigFormat::Toml => toml::from_str(config_str)
+                .map_err(|e| JitoBellError::Config(format!("Invalid TOML config: {e}"))),
+            ConfigFormat::Json => serde_json::from_str(config_str)
+                .map_err(|e| JitoBellError::Config(format!("Invalid JSON config: {e}"))),
+        }
+    }
+
+    /// Convert a YAML document to this format
+    pub fn convert_yaml(&self, yaml: &str) -> Result<String, JitoBellError> {
+        let value: serde_yaml::Value = serde_yaml::from_str(yaml)?;
+
+        match self {
+            ConfigFormat::Yaml => Ok(yaml.to_string()),
+            ConfigFormat::Toml => toml::to_string_pretty(&value)
+                .map_err(|e| JitoBellError::Config(format!("Invalid TOML config: {e}"))),
+            ConfigFormat::Json => serde_json::to_string_pretty(&value)
+                .map_err(|e| JitoBellError::Config(format!("Invalid JSON config: {e}"))),
+        }
+    }
+}
+
+impl JitoBellConfig {
+    /// Load configuration from a YAML, TOML or JSON file, chosen by its extension
+    ///
+    /// - Files listed under `include` are merged first, see [`config_include::read_with_includes`]
+    /// - `${VAR}` placeholders in string values are resolved from the environment
+    pub fn load(config_path: &Path) -> Result<Self, JitoBellError> {
+        let included = Self::read(config_path)?;
+
+        Self::from_included(included)
+    }
+
+    /// Load configuration like [`JitoBellConfig::load`], then fetch the secret references
+    /// (`aws-sm://`, `vault://`) under `notifications` with `http_client`
+    pub async fn load_with_secrets(
+        config_path: &Path,
+        http_client: &reqwest::Client,
+    ) -> Result<Self, JitoBellError> {
+        let mut included = Self::read(config_path)?;
+        if let Some(notifications) = included.value.get_mut("notifications") {
+            SecretResolver::new(http_client)
+                .resolve(notifications)
+                .await?;
+        }
+
+        Self::from_included(included)
+    }
+
+    /// Parse configuration in the given format, without resolving `include`
+    ///
+    /// - `${VAR}` placeholders in string values are resolved from the environment
+    pub fn parse(config_str: &str, format: ConfigFormat) -> Result<Self, JitoBellError> {
+        let mut value = format.parse_value(config_str)?;
+        substitute_env(&mut value)?;
+
+        Self::from_value(value)
+    }
+
+    /// Read a config file and its includes, resolving environment placeholders
+    fn read(config_path: &Path) -> Result<IncludedConfig, JitoBellError> {
+        let mut included = config_include::read_with_includes(config_path)?;
+        substitute_env(&mut included.value)?;
+
+        Ok(included)
+    }
+
+    fn from_included(included: IncludedConfig) -> Result<Self, JitoBellError> {
+        let mut config = Self::from_value(included.value)?;
+        config.files = included.files;
+
+        Ok(config)
+    }
+
+    fn from_value(value: serde_json::Value) -> Result<Self, JitoBellError> {
+        serde_json::from_value(value)
+            .map_err(|e| JitoBellError::Config(format!("Invalid config: {e}")))
+    }
+
+    /// Latest modification time of the files the configuration was read from
+    pub fn modified(&self) -> std::io::Result<SystemTime> {
+        self.files
+            .iter()
+            .map(|file| std::fs::metadata(file).and_then(|metadata| metadata.modified()))
+            .try_fold(SystemTime::UNIX_EPOCH, |latest, modified| {
+                Ok(latest.max(modified?))
+            })
+    }
+
+    /// Additional program IDs parsed as SPL stake pools (`programs.spl_stake_pool.additional_program_ids`)
+    pub fn stake_pool_program_ids(&self) -> Result<Vec<Pubkey>, JitoBellError> {
+        self.programs
+            .get("spl_stake_pool")
+            .map(|program| program.additional_program_ids.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|program_id| {
+                Pubkey::from_str(program_id).map_err(|e| {
+                    JitoBellError::Config(format!(
+                        "Invalid stake pool program ID {program_id}: {e}"
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// Whether any instruction notifies on failed transactions
+    pub fn notifies_failed(&self) -> bool {
+        self.programs
+            .values()
+            .flat_map(|program| program.instructions.values())
+            .any(|instruction| instruction.notify_failed)
+    }
+
+    /// Stake pools watched for fee changes (`programs.spl_stake_pool.instructions.set_fee.stake_pools`)
+    pub fn set_fee_stake_pools(&self) -> Result<Vec<Pubkey>, JitoBellError> {
+        self.programs
+            .get("spl_stake_pool")
+            .and_then(|program| program.instructions.get("set_fee"))
+            .and_then(|instruction| instruction.stake_pools.as_ref())
+            .map(|stake_pools| stake_pools.keys().collect::<Vec<_>>())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|stake_pool| {
+                Pubkey::from_str(stake_pool).map_err(|e| {
+                    JitoBellError::Config(format!("Invalid stake pool {stake_pool}: {e}"))
+                })
+            })
+            .collect()
+    }
+
+    /// Parsers of the programs configured with an Anchor IDL (`programs.<name>.idl`)
+    pub fn anchor_idl_parsers(&self) -> Result<Vec<AnchorIdlParser>, JitoBellError> {
+        self.programs
+            .iter()
+            .filter_map(|(name, program)| program.idl.as_ref().map(|idl| (name, program, idl)))
+            .map(|(name, program, idl)| {
+                let program_id = Pubkey::from_str(&program.program_id).map_err(|e| {
+                    JitoBellError::Config(format!(
+                        "Invalid program ID {} of {name}: {e}",
+                        program.program_id
+                    ))
+                })?;
+                let idl_str = std::fs::read_to_string(idl).map_err(JitoBellError::Io)?;
+
+                AnchorIdlParser::from_json(name, program_id, &idl_str).map_err(|e| {
+                    JitoBellError::Config(format!("Invalid IDL {} of {name}: {e}", idl.display()))
+                })
+            })
+            .collect()
+    }
 }
 
 impl std::fmt::Display for JitoBellConfig {
@@ -43,7 +539,13 @@ impl std::fmt::Display for JitoBellConfig {
                                 threshold.notification.description
                             )?;
 
-                            let destinations = threshold.notification.destinations.join(",");
+                            let destinations: Vec<_> = threshold
+                                .notification
+                                .destinations
+                                .iter()
+                                .map(Destination::name)
+                                .collect();
+                            let destinations = destinations.join(",");
                             writeln!(f, "               Destinations: {}", destinations)?;
                         }
                     }
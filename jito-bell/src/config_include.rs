@@ -0,0 +1,209 @@
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use crate::{config::ConfigFormat, error::JitoBellError};
+
+/// Key listing the files a config file is merged over
+const INCLUDE_KEY: &str = "include";
+
+/// Config value tree read from a file and its includes
+pub struct IncludedConfig {
+    /// Merged value tree
+    pub value: Value,
+
+    /// Files read, the config file first
+    pub files: Vec<PathBuf>,
+}
+
+/// Read a config file, merging the files listed under `include`
+///
+/// - Paths are relative to the including file, includes may include other files
+/// - Included files are merged in order, a later file overrides an earlier one
+/// - The including file overrides every file it includes
+/// - Mappings are merged key by key, any other value (lists included) is replaced
+pub fn read_with_includes(config_path: &Path) -> Result<IncludedConfig, JitoBellError> {
+    read_with(config_path, &|path: &Path| std::fs::read_to_string(path))
+}
+
+/// Read a config file and its includes with `read`
+pub fn read_with(
+    config_path: &Path,
+    read: &impl Fn(&Path) -> std::io::Result<String>,
+) -> Result<IncludedConfig, JitoBellError> {
+    let mut files = Vec::new();
+    let value = read_file(config_path, read, &mut Vec::new(), &mut files)?;
+
+    Ok(IncludedConfig { value, files })
+}
+
+fn read_file(
+    path: &Path,
+    read: &impl Fn(&Path) -> std::io::Result<String>,
+    stack: &mut Vec<PathBuf>,
+    files: &mut Vec<PathBuf>,
+) -> Result<Value, JitoBellError> {
+    let key = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if stack.contains(&key) {
+        let cycle: Vec<_> = stack
+            .iter()
+            .chain(std::iter::once(&key))
+            .map(|path| path.display().to_string())
+            .collect();
+        return Err(JitoBellError::Config(format!(
+            "Config include cycle: {}",
+            cycle.join(" -> ")
+        )));
+    }
+
+    let config_str = read(path).map_err(|e| {
+        JitoBellError::Config(format!("Failed to read config {}: {e}", path.display()))
+    })?;
+    files.push(path.to_path_buf());
+
+    let mut value = ConfigFormat::from_path(path).parse_value(&config_str)?;
+    let includes = take_includes(&mut value, path)?;
+
+    stack.push(key);
+    let mut merged = Value::Object(Map::new());
+    let dir = path.parent().unwrap_or(Path::new(""));
+    for include in includes {
+        let included = read_file(&dir.join(include), read, stack, files)?;
+        merge(&mut merged, included);
+    }
+    stack.pop();
+
+    merge(&mut merged, value);
+
+    Ok(merged)
+}
+
+/// Remove the `include` list of a config file
+fn take_includes(value: &mut Value, path: &Path) -> Result<Vec<String>, JitoBellError> {
+    let Some(include) = value
+        .as_object_mut()
+        .and_then(|object| object.remove(INCLUDE_KEY))
+    else {
+        return Ok(Vec::new());
+    };
+
+    match include {
+        Value::String(include) => Ok(vec![include]),
+        Value::Array(includes) => includes
+            .into_iter()
+            .map(|include| match include {
+                Value::String(include) => Ok(include),
+                _ => Err(JitoBellError::Config(format!(
+                    "`include` of {} must list file paths",
+                    path.display()
+                ))),
+            })
+            .collect(),
+        Value::Null => Ok(Vec::new()),
+        _ => Err(JitoBellError::Config(format!(
+            "`include` of {} must list file paths",
+            path.display()
+        ))),
+    }
+}
+
+/// Merge `overlay` over `base`, mappings key by key
+pub fn merge(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Object(base), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(base) => merge(base, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, path::Path};
+
+    use serde_json::json;
+
+    use crate::config_include::{merge, read_with};
+
+    fn files(files: &[(&str, &str)]) -> impl Fn(&Path) -> std::io::Result<String> {
+        let files: HashMap<String, String> = files
+            .iter()
+            .map(|(path, content)| (path.to_string(), content.to_string()))
+            .collect();
+
+        move |path: &Path| {
+            files
+                .get(path.to_str().unwrap())
+                .cloned()
+                .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::NotFound))
+        }
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut base = json!({
+            "explorer_url": "https://explorer.solana.com",
+            "notifications": { "discord": { "webhook_url": "a" } },
+            "destinations": ["discord"],
+        });
+        merge(
+            &mut base,
+            json!({
+                "notifications": { "telegram": { "chat_id": "-100" } },
+                "destinations": ["telegram"],
+            }),
+        );
+
+        assert_eq!(base["explorer_url"], "https://explorer.solana.com");
+        assert_eq!(base["notifications"]["discord"]["webhook_url"], "a");
+        assert_eq!(base["notifications"]["telegram"]["chat_id"], "-100");
+        assert_eq!(base["destinations"], json!(["telegram"]));
+    }
+
+    #[test]
+    fn test_read_with_includes() {
+        let read = files(&[
+            (
+                "config/prod.yaml",
+                "include: [base.yaml, secrets.json]\nexplorer_url: prod\n",
+            ),
+            (
+                "config/base.yaml",
+                "explorer_url: base\nmessage_templates:\n  default: \"{{description}}\"\n",
+            ),
+            (
+                "config/secrets.json",
+                r#"{ "explorer_url": "secrets", "notifications": { "discord": { "webhook_url": "a" } } }"#,
+            ),
+        ]);
+
+        let included = read_with(Path::new("config/prod.yaml"), &read).unwrap();
+        assert_eq!(included.value["explorer_url"], "prod");
+        assert_eq!(
+            included.value["message_templates"]["default"],
+            "{{description}}"
+        );
+        assert_eq!(
+            included.value["notifications"]["discord"]["webhook_url"],
+            "a"
+        );
+        assert!(included.value.get("include").is_none());
+        assert_eq!(included.files.len(), 3);
+    }
+
+    #[test]
+    fn test_include_cycle() {
+        let read = files(&[
+            ("a.yaml", "include: b.yaml\n"),
+            ("b.yaml", "include: a.yaml\n"),
+        ]);
+        assert!(read_with(Path::new("a.yaml"), &read).is_err());
+    }
+}
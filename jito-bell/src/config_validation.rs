@@ -0,0 +1,626 @@
+use std::{path::Path, str::FromStr};
+
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    config::JitoBellConfig,
+    error::JitoBellError,
+    instruction::{AlertConfig, Instruction},
+    notification_event::NotificationEvent,
+    notification_info::NotificationInfo,
+    parser::JitoBellProgram,
+};
+
+/// Problem found while validating a configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigProblem {
+    /// Line of the problem (1-based), when it could be located
+    pub line: Option<usize>,
+
+    /// Location in the config (e.g. `programs.spl_stake_pool.instructions.deposit_sol`)
+    pub path: String,
+
+    /// Description of the problem
+    pub message: String,
+}
+
+impl std::fmt::Display for ConfigProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+/// Lines of the config file, to locate problems
+struct Lines<'a> {
+    lines: Vec<&'a str>,
+}
+
+impl<'a> Lines<'a> {
+    fn new(config_str: &'a str) -> Self {
+        Self {
+            lines: config_str.lines().collect(),
+        }
+    }
+
+    /// First line (1-based) at or after `from` containing `token` as a whole word
+    fn find(&self, token: &str, from: Option<usize>) -> Option<usize> {
+        let from = from.unwrap_or(1);
+        self.lines
+            .iter()
+            .enumerate()
+            .skip(from.saturating_sub(1))
+            .find(|(_, line)| contains_token(line, token))
+            .map(|(index, _)| index + 1)
+    }
+}
+
+/// Whether `token` occurs in `line`, not as part of a longer identifier
+fn contains_token(line: &str, token: &str) -> bool {
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+
+    line.match_indices(token).any(|(start, _)| {
+        let before = line[..start].chars().next_back();
+        let after = line[start + token.len()..].chars().next();
+        !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+    })
+}
+
+/// Template variables (`{{name}}`) used in a template
+fn template_variables(template: &str) -> Vec<&str> {
+    let mut variables = Vec::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        variables.push(&rest[start + 2..start + 2 + end]);
+        rest = &rest[start + 2 + end + 2..];
+    }
+
+    variables
+}
+
+/// Validator of a parsed configuration, collecting every problem
+struct Validator<'a> {
+    config: &'a JitoBellConfig,
+    lines: Lines<'a>,
+    problems: Vec<ConfigProblem>,
+}
+
+impl Validator<'_> {
+    fn problem(&mut self, line: Option<usize>, path: String, message: String) {
+        self.problems.push(ConfigProblem {
+            line,
+            path,
+            message,
+        });
+    }
+
+    fn validate_template(&mut self, template: &str, path: &str, scope: Option<usize>) {
+        for variable in template_variables(template) {
+            if !NotificationEvent::TEMPLATE_VARIABLES.contains(&variable) {
+                let line = self.lines.find(&format!("{{{{{variable}}}}}"), scope);
+                self.problem(
+                    line.or(scope),
+                    path.to_string(),
+                    format!(
+                        "unknown template variable {{{{{variable}}}}}, expected one of: {}",
+                        NotificationEvent::TEMPLATE_VARIABLES.join(", ")
+                    ),
+                );
+            }
+        }
+    }
+
+    fn validate_notification(
+        &mut self,
+        notification: &NotificationInfo,
+        path: &str,
+        scope: Option<usize>,
+    ) {
+        for destination in notification.destinations.iter() {
+            let line = self.lines.find(destination.name(), scope).or(scope);
+            match self.config.notifications.is_configured(destination.name()) {
+                Some(true) => {}
+                Some(false) => self.problem(
+                    line,
+                    path.to_string(),
+                    format!("destination {destination} is not configured under `notifications`"),
+                ),
+                None => self.problem(
+                    line,
+                    path.to_string(),
+                    format!("unknown destination {destination}"),
+                ),
+            }
+
+            if let Some(template) = destination.template() {
+                self.validate_template(template, path, line);
+            }
+        }
+
+        if let Some(template) = &notification.template {
+            self.validate_template(template, path, scope);
+        }
+    }
+
+    fn validate_alert_config(
+        &mut self,
+        alert_config: &AlertConfig,
+        path: &str,
+        scope: Option<usize>,
+    ) {
+        for (index, threshold) in alert_config.thresholds.iter().enumerate() {
+            let path = format!("{path}.thresholds[{index}]");
+            self.validate_notification(&threshold.notification, &path, scope);
+        }
+        for (index, threshold) in alert_config.usd_thresholds.iter().enumerate() {
+            let path = format!("{path}.usd_thresholds[{index}]");
+            self.validate_notification(&threshold.notification, &path, scope);
+        }
+        for (index, threshold) in alert_config.lamport_thresholds.iter().enumerate() {
+            let path = format!("{path}.lamport_thresholds[{index}]");
+            self.validate_notification(&threshold.notification, &path, scope);
+        }
+    }
+
+    fn validate_instruction(
+        &mut self,
+        instruction: &Instruction,
+        path: &str,
+        scope: Option<usize>,
+    ) {
+        let alert_configs = [
+            ("stake_pools", &instruction.stake_pools),
+            ("lsts", &instruction.lsts),
+            ("vrts", &instruction.vrts),
+            ("accounts", &instruction.accounts),
+        ];
+        for (key, alert_configs) in alert_configs {
+            for (address, alert_config) in alert_configs.iter().flatten() {
+                let scope = self.lines.find(address, scope).or(scope);
+                self.validate_alert_config(alert_config, &format!("{path}.{key}.{address}"), scope);
+            }
+        }
+
+        for (stake_pool, _) in instruction.stake_pools.iter().flatten() {
+            if Pubkey::from_str(stake_pool).is_err() {
+                let line = self.lines.find(stake_pool, scope).or(scope);
+                self.problem(
+                    line,
+                    format!("{path}.stake_pools.{stake_pool}"),
+                    format!("invalid stake pool {stake_pool}"),
+                );
+            }
+        }
+
+        for (index, threshold) in instruction.lamport_thresholds.iter().enumerate() {
+            let path = format!("{path}.lamport_thresholds[{index}]");
+            self.validate_notification(&threshold.notification, &path, scope);
+        }
+        for (index, threshold) in instruction.priority_fee_thresholds.iter().enumerate() {
+            let path = format!("{path}.priority_fee_thresholds[{index}]");
+            self.validate_notification(&threshold.notification, &path, scope);
+        }
+        for (index, notification) in instruction.notifications.iter().enumerate() {
+            let path = format!("{path}.notifications[{index}]");
+            self.validate_notification(notification, &path, scope);
+        }
+    }
+
+    fn validate_programs(&mut self) {
+        let config = self.config;
+        let idl_instructions = match config.anchor_idl_parsers() {
+            Ok(parsers) => parsers
+                .into_iter()
+                .map(|parser| {
+                    let names: Vec<String> =
+                        parser.instruction_names().map(str::to_string).collect();
+                    (parser.name, names)
+                })
+                .collect(),
+            Err(e) => {
+                self.problem(None, "programs".to_string(), e.to_string());
+                Vec::new()
+            }
+        };
+
+        let mut programs: Vec<_> = config.programs.iter().collect();
+        programs.sort_by_key(|(name, _)| name.as_str());
+
+        for (name, program) in programs {
+            let path = format!("programs.{name}");
+            let program_line = self.lines.find(name, None);
+
+            if Pubkey::from_str(&program.program_id).is_err() {
+                let line = self.lines.find(&program.program_id, program_line);
+                self.problem(
+                    line.or(program_line),
+                    format!("{path}.program_id"),
+                    format!("invalid program ID {}", program.program_id),
+                );
+            }
+
+            let known_instructions: Option<Vec<String>> = match &program.idl {
+                Some(_) => idl_instructions
+                    .iter()
+                    .find(|(idl_name, _)| idl_name == name)
+                    .map(|(_, names)| names.clone()),
+                None => match JitoBellProgram::instruction_names(name) {
+                    Some(names) => Some(names.iter().map(|name| name.to_string()).collect()),
+                    None => {
+                        self.problem(
+                            program_line,
+                            path.clone(),
+                            "unknown program, not a built-in parser and no `idl` is set"
+                                .to_string(),
+                        );
+                        None
+                    }
+                },
+            };
+
+            let mut instructions: Vec<_> = program.instructions.iter().collect();
+            instructions.sort_by_key(|(name, _)| name.as_str());
+
+            for (instruction_name, instruction) in instructions {
+                let path = format!("{path}.instructions.{instruction_name}");
+                let instruction_line = self
+                    .lines
+                    .find(instruction_name, program_line)
+                    .or(program_line);
+
+                if let Some(known_instructions) = &known_instructions {
+                    if !known_instructions.contains(instruction_name) {
+                        self.problem(
+                            instruction_line,
+                            path.clone(),
+                            format!(
+                                "unknown instruction, expected one of: {}",
+                                known_instructions.join(", ")
+                            ),
+                        );
+                    }
+                }
+
+                self.validate_instruction(instruction, &path, instruction_line);
+            }
+        }
+
+        if let Err(e) = config.stake_pool_program_ids() {
+            let line = self.lines.find("additional_program_ids", None);
+            self.problem(
+                line,
+                "programs.spl_stake_pool.additional_program_ids".to_string(),
+                e.to_string(),
+            );
+        }
+    }
+
+    fn validate_templates(&mut self) {
+        let config = self.config;
+        let templates_line = self.lines.find("message_templates", None);
+
+        if !config.message_templates.contains_key("default") {
+            self.problem(
+                templates_line,
+                "message_templates".to_string(),
+                "missing the `default` template".to_string(),
+            );
+        }
+
+        let mut templates: Vec<_> = config.message_templates.iter().collect();
+        templates.sort_by_key(|(name, _)| name.as_str());
+
+        for (name, template) in templates {
+            let path = format!("message_templates.{name}");
+            let line = self.lines.find(name, templates_line).or(templates_line);
+
+            if name != "default" && config.notifications.is_configured(name).is_none() {
+                self.problem(
+                    line,
+                    path.clone(),
+                    format!("unknown destination {name}, the template is never used"),
+                );
+            }
+            self.validate_template(template, &path, line);
+        }
+
+        let localized_line = self.lines.find("localized_templates", None);
+        let mut locales: Vec<_> = config.localized_templates.iter().collect();
+        locales.sort_by_key(|(locale, _)| locale.as_str());
+
+        for (locale, templates) in locales {
+            let locale_line = self.lines.find(locale, localized_line).or(localized_line);
+            let mut templates: Vec<_> = templates.iter().collect();
+            templates.sort_by_key(|(name, _)| name.as_str());
+
+            for (name, template) in templates {
+                let path = format!("localized_templates.{locale}.{name}");
+                let line = self.lines.find(name, locale_line).or(locale_line);
+
+                if name != "default" && config.notifications.is_configured(name).is_none() {
+                    self.problem(
+                        line,
+                        path.clone(),
+                        format!("unknown destination {name}, the template is never used"),
+                    );
+                }
+                self.validate_template(template, &path, line);
+            }
+        }
+
+        let notifications_line = self.lines.find("notifications", None);
+        if let Some(zulip) = &config.notifications.zulip {
+            self.validate_template(
+                &zulip.topic,
+                "notifications.zulip.topic",
+                notifications_line,
+            );
+        }
+        if let Some(webhook) = &config.notifications.webhook {
+            for (key, template) in webhook.query.iter().chain(webhook.headers.iter()) {
+                let path = format!("notifications.webhook.{key}");
+                self.validate_template(template, &path, notifications_line);
+            }
+        }
+    }
+
+    fn validate_schedules(&mut self) {
+        let config = self.config;
+        let schedules_line = self.lines.find("schedules", None);
+
+        let mut destinations: Vec<_> = config.schedules.keys().collect();
+        destinations.sort();
+
+        for destination in destinations {
+            let line = self.lines.find(destination, schedules_line);
+            if config.notifications.is_configured(destination).is_none() {
+                self.problem(
+                    line.or(schedules_line),
+                    format!("schedules.{destination}"),
+                    format!("unknown destination {destination}, the schedule is never used"),
+                );
+            }
+            let rate_limit = config.schedules[destination].rate_limit;
+            if rate_limit.is_some_and(|rate_limit| rate_limit.messages == 0) {
+                let rate_limit_line = self.lines.find("rate_limit", line);
+                self.problem(
+                    rate_limit_line.or(line),
+                    format!("schedules.{destination}.rate_limit.messages"),
+                    "must be at least 1, no message would ever be sent".to_string(),
+                );
+            }
+        }
+    }
+
+    fn validate_account_labels(&mut self) {
+        let config = self.config;
+        let labels_line = self.lines.find("account_labels", None);
+
+        let mut accounts: Vec<_> = config.account_labels.keys().collect();
+        accounts.sort();
+
+        for account in accounts {
+            if Pubkey::from_str(account).is_err() {
+                let line = self.lines.find(account, labels_line);
+                self.problem(
+                    line.or(labels_line),
+                    format!("account_labels.{account}"),
+                    format!("invalid account {account}, the label is never used"),
+                );
+            }
+        }
+    }
+
+    fn validate_mints(&mut self) {
+        let config = self.config;
+        let mints_line = self.lines.find("mints", None);
+
+        let mut mints: Vec<_> = config.mints.keys().collect();
+        mints.sort();
+
+        for mint in mints {
+            if Pubkey::from_str(mint).is_err() {
+                let line = self.lines.find(mint, mints_line);
+                self.problem(
+                    line.or(mints_line),
+                    format!("mints.{mint}"),
+                    format!("invalid mint {mint}, its symbol and decimals are never used"),
+                );
+            }
+        }
+    }
+
+    fn validate_reports(&mut self) {
+        let config = self.config;
+        let reports_line = self.lines.find("validator_list_reports", None);
+
+        for (index, report) in config.validator_list_reports.iter().enumerate() {
+            let path = format!("validator_list_reports[{index}]");
+            let line = self.lines.find(&report.stake_pool, reports_line);
+
+            if Pubkey::from_str(&report.stake_pool).is_err() {
+                self.problem(
+                    line.or(reports_line),
+                    format!("{path}.stake_pool"),
+                    format!("invalid stake pool {}", report.stake_pool),
+                );
+            }
+            self.validate_notification(&report.notification, &path, line.or(reports_line));
+        }
+    }
+}
+
+/// Validate a config file and its includes, reporting every problem instead of failing at runtime
+///
+/// - The config parses (schema, types, includes and environment placeholders)
+/// - Program and instruction keys match a built-in parser or the program's IDL
+/// - Destinations of every rule are known and configured under `notifications`
+/// - Templates only use known variables
+///
+/// Lines are located in the config file itself, problems of included files have no line
+pub fn validate(config_path: &Path) -> Vec<ConfigProblem> {
+    let config_str = std::fs::read_to_string(config_path).unwrap_or_default();
+
+    validate_config(&config_str, JitoBellConfig::load(config_path))
+}
+
+/// Validate a loaded configuration, locating problems in `config_str`
+fn validate_config(
+    config_str: &str,
+    config: Result<JitoBellConfig, JitoBellError>,
+) -> Vec<ConfigProblem> {
+    let config = match config {
+        Ok(config) => config,
+        Err(e) => {
+            return vec![ConfigProblem {
+                line: None,
+                path: String::new(),
+                message: e.to_string(),
+            }]
+        }
+    };
+
+    let mut validator = Validator {
+        config: &config,
+        lines: Lines::new(config_str),
+        problems: Vec::new(),
+    };
+    validator.validate_programs();
+    validator.validate_templates();
+    validator.validate_schedules();
+    validator.validate_account_labels();
+    validator.validate_mints();
+    validator.validate_reports();
+
+    validator.problems
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        config::{ConfigFormat, JitoBellConfig},
+        config_validation::{contains_token, template_variables, validate_config, ConfigProblem},
+    };
+
+    fn validate(config_str: &str) -> Vec<ConfigProblem> {
+        validate_config(
+            config_str,
+            JitoBellConfig::parse(config_str, ConfigFormat::Yaml),
+        )
+    }
+
+    const CONFIG: &str = r#"
+programs:
+  spl_stake_pool:
+    program_id: "SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy"
+    instructions:
+      deposit_sl:
+        lsts:
+          "J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn":
+            thresholds:
+              - value: 100.0
+                notification:
+                  description: "Deposit"
+                  destinations: ["discord", "telegram", "pager"]
+                  template: "{{description}} {{amont}}"
+notifications:
+  discord:
+    webhook_url: "https://discord.com/api/webhooks/1/token"
+explorer_url: "https://explorer.solana.com"
+message_templates:
+  default: "{{description}} - {{amount}} {{currency_unit}}"
+"#;
+
+    #[test]
+    fn test_validate() {
+        let problems = validate(CONFIG);
+        let messages: Vec<_> = problems
+            .iter()
+            .map(|problem| (problem.line, problem.message.as_str()))
+            .collect();
+
+        assert_eq!(problems.len(), 4, "{problems:?}");
+        assert_eq!(messages[0].0, Some(6));
+        assert!(messages[0].1.starts_with("unknown instruction"));
+        assert_eq!(
+            messages[1],
+            (
+                Some(13),
+                "destination telegram is not configured under `notifications`"
+            )
+        );
+        assert_eq!(messages[2], (Some(13), "unknown destination pager"));
+        assert_eq!(messages[3].0, Some(14));
+        assert!(messages[3]
+            .1
+            .starts_with("unknown template variable {{amont}}"));
+    }
+
+    #[test]
+    fn test_validate_parse_error() {
+        let problems = validate("programs: [");
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].line, None);
+    }
+
+    #[test]
+    fn test_missing_default_template() {
+        let config = CONFIG.replace("  default:", "  slack:");
+        let problems = validate(&config);
+        assert!(problems
+            .iter()
+            .any(|problem| problem.message == "missing the `default` template"));
+    }
+
+    #[test]
+    fn test_invalid_account_label() {
+        let config = format!(
+            "{CONFIG}account_labels:\n  Jito4APyf642JPZPx3hGc6WWJ8zPKtRbRs4P815Awbb: \"JitoSOL\"\n  binance: \"Binance hot wallet\"\n"
+        );
+        let problems = validate(&config);
+        let problem = problems
+            .iter()
+            .find(|problem| problem.path.starts_with("account_labels"))
+            .unwrap();
+        assert_eq!(problem.path, "account_labels.binance");
+        assert_eq!(problem.line, Some(23));
+    }
+
+    #[test]
+    fn test_zero_rate_limit() {
+        let config =
+            format!("{CONFIG}schedules:\n  discord:\n    rate_limit:\n      messages: 0\n");
+        let problems = validate(&config);
+        let problem = problems
+            .iter()
+            .find(|problem| problem.path.starts_with("schedules"))
+            .unwrap();
+        assert_eq!(problem.path, "schedules.discord.rate_limit.messages");
+        assert_eq!(problem.line, Some(23));
+    }
+
+    #[test]
+    fn test_contains_token() {
+        assert!(contains_token("      deposit_sol:", "deposit_sol"));
+        assert!(!contains_token(
+            "      deposit_sol_with_slippage:",
+            "deposit_sol"
+        ));
+        assert!(contains_token(r#"["discord", "slack"]"#, "slack"));
+    }
+
+    #[test]
+    fn test_template_variables() {
+        assert_eq!(
+            template_variables("{{description}} - {{ amount }} {{unterminated"),
+            vec!["description", " amount "]
+        );
+    }
+}
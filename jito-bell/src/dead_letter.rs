@@ -0,0 +1,157 @@
+use std::{
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use chrono::{SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{error::JitoBellError, notification_info::NotificationInfo, outbox::OutboxEntry};
+
+/// Dead-letter handling of notifications that could not be delivered
+///
+/// - Without the outbox, a notification is dead once the HTTP retries are exhausted
+/// - With the outbox, once it failed `max_outbox_attempts` times
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DeadLetterConfig {
+    /// JSON lines file dead notifications are appended to
+    pub path: Option<PathBuf>,
+
+    /// Failed attempts of an outbox notification before it is dead
+    #[serde(default = "default_max_outbox_attempts")]
+    pub max_outbox_attempts: u32,
+
+    /// Ops alert sent for each dead notification, to its destinations but the failed one
+    pub notification: Option<NotificationInfo>,
+}
+
+fn default_max_outbox_attempts() -> u32 {
+    10
+}
+
+impl Default for DeadLetterConfig {
+    fn default() -> Self {
+        Self {
+            path: None,
+            max_outbox_attempts: default_max_outbox_attempts(),
+            notification: None,
+        }
+    }
+}
+
+/// Notification that could not be delivered
+#[derive(Debug, Clone, Serialize)]
+pub struct DeadLetter {
+    /// Time the notification was given up (RFC 3339)
+    pub created_at: String,
+
+    /// Destination (telegram, slack, discord, ...)
+    pub destination: String,
+
+    /// Error of the last attempt
+    pub error: String,
+
+    /// Failed delivery attempts
+    pub attempts: u32,
+
+    /// Notification and event
+    pub entry: OutboxEntry,
+}
+
+impl DeadLetter {
+    /// Dead letter given up now
+    pub fn new(destination: &str, error: String, attempts: u32, entry: OutboxEntry) -> Self {
+        Self {
+            created_at: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            destination: destination.to_string(),
+            error,
+            attempts,
+            entry,
+        }
+    }
+
+    /// Summary of the ops alert
+    pub fn summary(&self, description: &str) -> String {
+        format!(
+            "{description}: {} notification {} ({}) failed, attempts: {}, error: {}",
+            self.destination,
+            self.entry.event.transaction_signature,
+            self.entry.event.description,
+            self.attempts,
+            self.error
+        )
+    }
+}
+
+/// Append a dead letter to a JSON lines file
+pub fn append(path: &Path, dead_letter: &DeadLetter) -> Result<(), JitoBellError> {
+    let mut line = serde_json::to_string(dead_letter)
+        .map_err(|e| JitoBellError::Notification(e.to_string()))?;
+    line.push('\n');
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(line.as_bytes())?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        dead_letter::{append, DeadLetter},
+        notification_event::NotificationEvent,
+        notification_info::NotificationInfo,
+        outbox::OutboxEntry,
+    };
+
+    fn dead_letter() -> DeadLetter {
+        let notification = NotificationInfo::default();
+        let event = NotificationEvent::scheduled(
+            &notification,
+            "Large withdrawal".to_string(),
+            "pool",
+            1500.0,
+            "SOL",
+        );
+
+        DeadLetter::new(
+            "telegram",
+            "Notification error: 502".to_string(),
+            3,
+            OutboxEntry::new(&notification, &event),
+        )
+    }
+
+    #[test]
+    fn test_summary() {
+        let summary = dead_letter().summary("Undelivered alert");
+        assert!(summary.starts_with("Undelivered alert: telegram notification"));
+        assert!(summary
+            .ends_with("(Large withdrawal) failed, attempts: 3, error: Notification error: 502"));
+    }
+
+    #[test]
+    fn test_append() {
+        let path = std::env::temp_dir().join(format!(
+            "jito-bell-dead-letter-{}.jsonl",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        append(&path, &dead_letter()).unwrap();
+        append(&path, &dead_letter()).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<serde_json::Value> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["destination"], "telegram");
+        assert_eq!(lines[0]["attempts"], 3);
+        assert_eq!(lines[0]["entry"]["event"]["amount"], 1500.0);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
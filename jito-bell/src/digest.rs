@@ -0,0 +1,271 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{notification_event::NotificationEvent, notification_info::NotificationInfo};
+
+/// Digest mode of an instruction
+///
+/// - Events are aggregated over `window_secs` from the first one and sent as one summary (count,
+///   total volume, largest transaction) instead of one message per transaction
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DigestConfig {
+    /// Aggregation window in seconds
+    #[serde(default = "default_window_secs")]
+    pub window_secs: u64,
+
+    /// Description and destinations of the summary, those of the first event's notification
+    /// when missing
+    pub notification: Option<NotificationInfo>,
+}
+
+fn default_window_secs() -> u64 {
+    900
+}
+
+impl DigestConfig {
+    /// Aggregation window
+    pub fn window(&self) -> Duration {
+        Duration::from_secs(self.window_secs)
+    }
+}
+
+/// Events of an instruction aggregated in the current window
+#[derive(Debug)]
+struct DigestWindow {
+    /// Time of the first event
+    started: Instant,
+
+    /// Notification of the summary
+    notification: NotificationInfo,
+
+    /// Number of events
+    count: usize,
+
+    /// Sum of the amounts
+    total: f64,
+
+    /// Event with the largest amount
+    largest: NotificationEvent,
+}
+
+/// Summary of an instruction's events over a window
+#[derive(Debug)]
+pub struct InstructionDigest {
+    /// Program key under `programs`
+    pub program: String,
+
+    /// Instruction key
+    pub instruction: String,
+
+    /// Notification of the summary
+    pub notification: NotificationInfo,
+
+    /// Number of events
+    pub count: usize,
+
+    /// Sum of the amounts
+    pub total: f64,
+
+    /// Event with the largest amount
+    pub largest: NotificationEvent,
+
+    /// Length of the window
+    pub window: Duration,
+}
+
+impl InstructionDigest {
+    /// Summary text
+    pub fn render(&self) -> String {
+        let unit = &self.largest.currency_unit;
+
+        format!(
+            "{} ({}.{}, last {} min)\nEvents: {}\nTotal volume: {:.2} {unit}\nLargest: {:.2} {unit} {}",
+            self.notification.description,
+            self.program,
+            self.instruction,
+            self.window.as_secs().div_ceil(60),
+            self.count,
+            self.total,
+            self.largest.amount,
+            self.largest.explorer_url
+        )
+        .trim_end()
+        .to_string()
+    }
+}
+
+/// Instruction digests in progress, keyed by program, instruction and currency unit
+#[derive(Debug, Default)]
+pub struct InstructionDigests {
+    windows: HashMap<(String, String, String), DigestWindow>,
+}
+
+impl InstructionDigests {
+    /// Add an event to the window of its instruction, opening one if needed
+    pub fn add(
+        &mut self,
+        program: &str,
+        instruction: &str,
+        config: &DigestConfig,
+        notification: &NotificationInfo,
+        event: &NotificationEvent,
+        now: Instant,
+    ) {
+        let key = (
+            program.to_string(),
+            instruction.to_string(),
+            event.currency_unit.clone(),
+        );
+        let window = self.windows.entry(key).or_insert_with(|| DigestWindow {
+            started: now,
+            notification: config
+                .notification
+                .clone()
+                .unwrap_or_else(|| notification.clone()),
+            count: 0,
+            total: 0.0,
+            largest: event.clone(),
+        });
+
+        window.count += 1;
+        window.total += event.amount;
+        if event.amount > window.largest.amount {
+            window.largest = event.clone();
+        }
+    }
+
+    /// Take the digests whose window ended
+    ///
+    /// - Instructions no longer in digest mode (`window_of` is None) are due immediately
+    pub fn take_due(
+        &mut self,
+        window_of: impl Fn(&str, &str) -> Option<Duration>,
+        now: Instant,
+    ) -> Vec<InstructionDigest> {
+        let due: Vec<_> = self
+            .windows
+            .iter()
+            .filter_map(|(key, window)| {
+                let (program, instruction, _) = key;
+                let length = window_of(program, instruction).unwrap_or_default();
+                (now.saturating_duration_since(window.started) >= length)
+                    .then(|| (key.clone(), length))
+            })
+            .collect();
+
+        due.into_iter()
+            .filter_map(|(key, length)| {
+                let window = self.windows.remove(&key)?;
+                let (program, instruction, _) = key;
+                Some(InstructionDigest {
+                    program,
+                    instruction,
+                    notification: window.notification,
+                    count: window.count,
+                    total: window.total,
+                    largest: window.largest,
+                    window: length,
+                })
+            })
+            .collect()
+    }
+
+    /// Whether no window is open
+    pub fn is_empty(&self) -> bool {
+        self.windows.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::{
+        digest::{DigestConfig, InstructionDigests},
+        notification_event::NotificationEvent,
+        notification_info::NotificationInfo,
+    };
+
+    fn event(amount: f64, unit: &str) -> NotificationEvent {
+        let mut event = NotificationEvent::scheduled(
+            &NotificationInfo::default(),
+            "Deposit".to_string(),
+            "pool",
+            amount,
+            unit,
+        );
+        event.explorer_url = format!("https://solscan.io/tx/{amount}");
+
+        event
+    }
+
+    #[test]
+    fn test_digests() {
+        let config = DigestConfig {
+            window_secs: 900,
+            notification: None,
+        };
+        let notification = NotificationInfo {
+            description: "Deposits".to_string(),
+            ..Default::default()
+        };
+        let now = Instant::now();
+        let mut digests = InstructionDigests::default();
+
+        for (amount, unit) in [
+            (100.0, "SOL"),
+            (2500.0, "SOL"),
+            (400.0, "SOL"),
+            (5.0, "JitoSOL"),
+        ] {
+            digests.add(
+                "spl_stake_pool",
+                "deposit_sol",
+                &config,
+                &notification,
+                &event(amount, unit),
+                now,
+            );
+        }
+
+        let window_of = |_: &str, _: &str| Some(config.window());
+        assert!(digests.take_due(window_of, now).is_empty());
+
+        let mut due = digests.take_due(window_of, now + Duration::from_secs(900));
+        due.sort_by_key(|digest| digest.count);
+        assert_eq!(due.len(), 2);
+        assert!(digests.is_empty());
+
+        let digest = &due[1];
+        assert_eq!(digest.count, 3);
+        assert_eq!(digest.total, 3000.0);
+        assert_eq!(
+            digest.render(),
+            "Deposits (spl_stake_pool.deposit_sol, last 15 min)\nEvents: 3\nTotal volume: 3000.00 SOL\nLargest: 2500.00 SOL https://solscan.io/tx/2500"
+        );
+    }
+
+    #[test]
+    fn test_digest_mode_removed() {
+        let config = DigestConfig {
+            window_secs: 900,
+            notification: None,
+        };
+        let now = Instant::now();
+        let mut digests = InstructionDigests::default();
+        digests.add(
+            "spl_stake_pool",
+            "deposit_sol",
+            &config,
+            &NotificationInfo::default(),
+            &event(100.0, "SOL"),
+            now,
+        );
+
+        assert_eq!(digests.take_due(|_, _| None, now).len(), 1);
+    }
+}
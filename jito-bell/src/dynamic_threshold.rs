@@ -0,0 +1,156 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use chrono::Utc;
+use log::error;
+
+use crate::{store::EventStore, threshold_config::ThresholdConfig};
+
+/// Trailing window of observed amounts used for the median
+const MEDIAN_WINDOW_DAYS: i64 = 7;
+
+/// How long a computed median is reused before querying the store again
+const MEDIAN_REFRESH_INTERVAL: Duration = Duration::from_secs(600);
+
+/// Cached median of an instruction
+struct CachedMedian {
+    /// Median amount, None if nothing was observed in the window
+    median: Option<f64>,
+
+    /// Time the median was computed
+    refreshed_at: Instant,
+}
+
+/// Dynamic Thresholds
+///
+/// - Record every evaluated amount in the event store
+/// - Resolve `median_multiple` thresholds against the trailing 7-day median per instruction
+/// - At most `max_cache_entries` medians are cached
+pub struct DynamicThresholds {
+    /// Event Store
+    store: Arc<EventStore>,
+
+    /// Medians keyed by (instruction, address)
+    medians: HashMap<(String, String), CachedMedian>,
+
+    /// Maximum number of cached medians
+    max_cache_entries: usize,
+
+    /// Cached medians evicted before they expired
+    evicted: u64,
+}
+
+impl DynamicThresholds {
+    /// Initialize Dynamic Thresholds
+    pub fn new(store: Arc<EventStore>, max_cache_entries: usize) -> Self {
+        Self {
+            store,
+            medians: HashMap::new(),
+            max_cache_entries,
+            evicted: 0,
+        }
+    }
+
+    /// Number of cached medians
+    pub fn cache_len(&self) -> usize {
+        self.medians.len()
+    }
+
+    /// Cached medians evicted before they expired
+    pub fn evicted(&self) -> u64 {
+        self.evicted
+    }
+
+    /// Record an observed amount
+    pub fn observe(&self, instruction: &str, address: &str, amount: f64) {
+        if let Err(e) = self.store.record_amount(instruction, address, amount) {
+            error!("Failed to record observed amount: {e}");
+        }
+    }
+
+    /// Trailing median of an instruction, refreshed every `MEDIAN_REFRESH_INTERVAL`
+    pub fn median(&mut self, instruction: &str, address: &str) -> Option<f64> {
+        let key = (instruction.to_string(), address.to_string());
+
+        if let Some(cached) = self.medians.get(&key) {
+            if cached.refreshed_at.elapsed() < MEDIAN_REFRESH_INTERVAL {
+                return cached.median;
+            }
+        }
+
+        let since = Utc::now() - chrono::Duration::days(MEDIAN_WINDOW_DAYS);
+        let median = match self.store.median_amount(instruction, address, since) {
+            Ok(median) => median,
+            Err(e) => {
+                error!("Failed to compute median amount: {e}");
+                None
+            }
+        };
+
+        if self.medians.len() >= self.max_cache_entries {
+            self.medians
+                .retain(|_, cached| cached.refreshed_at.elapsed() < MEDIAN_REFRESH_INTERVAL);
+        }
+        if self.medians.len() >= self.max_cache_entries {
+            self.evicted += self.medians.len() as u64;
+            self.medians.clear();
+        }
+
+        self.medians.insert(
+            key,
+            CachedMedian {
+                median,
+                refreshed_at: Instant::now(),
+            },
+        );
+
+        median
+    }
+}
+
+/// Effective threshold value
+///
+/// - `median_multiple` x median when both are available
+/// - Fall back to the static `value` otherwise (e.g. empty history)
+pub fn effective_value(threshold: &ThresholdConfig, median: Option<f64>) -> f64 {
+    match (threshold.median_multiple, median) {
+        (Some(multiple), Some(median)) => multiple * median,
+        _ => threshold.value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        dynamic_threshold::effective_value, notification_info::NotificationInfo,
+        threshold_config::ThresholdConfig,
+    };
+
+    fn threshold(value: f64, median_multiple: Option<f64>) -> ThresholdConfig {
+        ThresholdConfig {
+            value,
+            median_multiple,
+            notification: NotificationInfo {
+                description: "test".to_string(),
+                destinations: vec![],
+                template: None,
+                severity: None,
+                delivery: Default::default(),
+                threshold_ratio: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_effective_value() {
+        assert_eq!(effective_value(&threshold(100.0, None), Some(10.0)), 100.0);
+        assert_eq!(
+            effective_value(&threshold(100.0, Some(5.0)), Some(10.0)),
+            50.0
+        );
+        assert_eq!(effective_value(&threshold(100.0, Some(5.0)), None), 100.0);
+    }
+}
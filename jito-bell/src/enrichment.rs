@@ -0,0 +1,175 @@
+use std::{collections::HashMap, time::Duration};
+
+use borsh::BorshDeserialize;
+use defillama_rs::{
+    models::{Chain, Token},
+    DefiLlamaClient,
+};
+use log::warn;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use spl_token_2022::{extension::StateWithExtensions, state::Mint};
+
+use crate::{config::MintConfig, DEFAULT_VRT_SYMBOL};
+
+/// Default decimals when the mint account is unavailable
+const DEFAULT_DECIMALS: u8 = 9;
+
+/// Enrichment of a VRT event
+#[derive(Debug, Clone)]
+pub struct VrtEnrichment {
+    /// 10^decimals of the VRT mint
+    pub divisor: f64,
+
+    /// VRT symbol from token metadata
+    pub symbol: String,
+
+    /// USD price, None if not requested or unavailable
+    pub usd_price: Option<f64>,
+}
+
+impl From<&MintConfig> for VrtEnrichment {
+    fn from(mint: &MintConfig) -> Self {
+        Self {
+            divisor: 10_f64.powi(mint.decimals as i32),
+            symbol: mint.symbol.clone(),
+            usd_price: None,
+        }
+    }
+}
+
+impl Default for VrtEnrichment {
+    fn default() -> Self {
+        Self {
+            divisor: 10_f64.powi(DEFAULT_DECIMALS as i32),
+            symbol: DEFAULT_VRT_SYMBOL.to_string(),
+            usd_price: None,
+        }
+    }
+}
+
+/// Enrichment Scheduler
+///
+/// - Batch account lookups into a single `getMultipleAccounts` call
+/// - Run account and price lookups concurrently within a deadline, falling back to un-enriched
+///   defaults when the budget is exceeded
+/// - Mints of the registry (`mints`) skip the account lookup
+pub struct EnrichmentScheduler {
+    /// Latency budget per event
+    deadline: Duration,
+
+    /// Symbols and decimals of registered mints
+    mints: HashMap<Pubkey, MintConfig>,
+}
+
+impl EnrichmentScheduler {
+    /// Initialize Enrichment Scheduler
+    pub fn new(deadline: Duration, mints: HashMap<Pubkey, MintConfig>) -> Self {
+        Self { deadline, mints }
+    }
+
+    /// Decimals and symbol of a registered mint
+    pub fn registered_mint(&self, mint: &Pubkey) -> Option<VrtEnrichment> {
+        self.mints.get(mint).map(VrtEnrichment::from)
+    }
+
+    /// Enrich VRT event with mint decimals, symbol and optionally USD price
+    #[tracing::instrument(name = "enrich", skip_all, fields(mint = %vrt, with_price = with_price))]
+    pub async fn enrich_vrt(
+        &self,
+        rpc_client: &RpcClient,
+        vrt: &Pubkey,
+        with_price: bool,
+    ) -> VrtEnrichment {
+        if let Some(mut enrichment) = self.registered_mint(vrt) {
+            if with_price {
+                enrichment.usd_price = self.usd_price(vrt).await;
+            }
+            return enrichment;
+        }
+
+        let meta_pubkey =
+            jito_vault_sdk::inline_mpl_token_metadata::pda::find_metadata_account(vrt).0;
+
+        let keys = [*vrt, meta_pubkey];
+        let accounts = rpc_client.get_multiple_accounts(&keys);
+        let price = async {
+            if with_price {
+                fetch_usd_price(vrt).await
+            } else {
+                None
+            }
+        };
+
+        let (accounts, usd_price) = match tokio::time::timeout(self.deadline, async {
+            tokio::join!(accounts, price)
+        })
+        .await
+        {
+            Ok(result) => result,
+            Err(_) => {
+                warn!(
+                    "Enrichment of {vrt} exceeded {}ms budget, sending un-enriched alert",
+                    self.deadline.as_millis()
+                );
+                return VrtEnrichment::default();
+            }
+        };
+
+        let mut enrichment = VrtEnrichment {
+            usd_price,
+            ..VrtEnrichment::default()
+        };
+
+        match accounts {
+            Ok(accounts) => {
+                if let Some(Some(mint_acc)) = accounts.first() {
+                    // Also decodes Token-2022 mints, whose extensions follow the base mint
+                    if let Ok(mint) = StateWithExtensions::<Mint>::unpack(&mint_acc.data) {
+                        enrichment.divisor = 10_f64.powi(mint.base.decimals as i32);
+                    }
+                }
+
+                if let Some(Some(meta_acc)) = accounts.get(1) {
+                    if let Ok(meta) = jito_vault_client::log::metadata::Metadata::deserialize(
+                        &mut meta_acc.data.as_slice(),
+                    ) {
+                        enrichment.symbol = meta.symbol;
+                    }
+                }
+            }
+            Err(e) => warn!("Failed to fetch accounts for {vrt}: {e}"),
+        }
+
+        enrichment
+    }
+
+    /// USD price of a mint, None if unavailable within the deadline
+    #[tracing::instrument(name = "enrich", skip_all, fields(mint = %mint))]
+    pub async fn usd_price(&self, mint: &Pubkey) -> Option<f64> {
+        match tokio::time::timeout(self.deadline, fetch_usd_price(mint)).await {
+            Ok(usd_price) => usd_price,
+            Err(_) => {
+                warn!(
+                    "USD price of {mint} exceeded {}ms budget",
+                    self.deadline.as_millis()
+                );
+                None
+            }
+        }
+    }
+}
+
+/// Fetch USD price from DefiLlama
+async fn fetch_usd_price(mint: &Pubkey) -> Option<f64> {
+    let client = DefiLlamaClient::new();
+    let token = Token::new(Chain::Solana, mint.to_string());
+
+    match client.get_price(&token).await {
+        Ok(prices) => prices.coins.values().last().map(|coin| coin.price),
+        Err(e) => {
+            warn!("Failed to fetch USD price of {mint}: {e}");
+            None
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use serde_json::Value;
+
+use crate::error::JitoBellError;
+
+/// Replace `${VAR}` placeholders in every string value with environment variables
+///
+/// - `${VAR:-default}` falls back to `default` when `VAR` is not set
+/// - `$${` is written as a literal `${`
+/// - A variable that is not set and has no default is an error
+pub fn substitute_env(value: &mut Value) -> Result<(), JitoBellError> {
+    substitute_with(value, &|name| std::env::var(name).ok())
+}
+
+/// Replace placeholders in every string value, resolving variables with `lookup`
+pub fn substitute_with(
+    value: &mut Value,
+    lookup: &impl Fn(&str) -> Option<String>,
+) -> Result<(), JitoBellError> {
+    match value {
+        Value::String(s) => {
+            if s.contains('$') {
+                *s = substitute_str(s, lookup)?;
+            }
+        }
+        Value::Array(values) => {
+            for value in values.iter_mut() {
+                substitute_with(value, lookup)?;
+            }
+        }
+        Value::Object(map) => {
+            for value in map.values_mut() {
+                substitute_with(value, lookup)?;
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+
+    Ok(())
+}
+
+/// Replace placeholders in a single string
+fn substitute_str(
+    s: &str,
+    lookup: &impl Fn(&str) -> Option<String>,
+) -> Result<String, JitoBellError> {
+    let mut substituted = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find('$') {
+        substituted.push_str(&rest[..start]);
+        let after = &rest[start..];
+
+        if let Some(escaped) = after.strip_prefix("$${") {
+            substituted.push_str("${");
+            rest = escaped;
+        } else if let Some(placeholder) = after.strip_prefix("${") {
+            let end = placeholder.find('}').ok_or_else(|| {
+                JitoBellError::Config(format!("Unterminated placeholder in config value: {s}"))
+            })?;
+            let (name, default) = match placeholder[..end].split_once(":-") {
+                Some((name, default)) => (name, Some(default)),
+                None => (&placeholder[..end], None),
+            };
+
+            match lookup(name).or_else(|| default.map(str::to_string)) {
+                Some(resolved) => substituted.push_str(&resolved),
+                None => {
+                    return Err(JitoBellError::Config(format!(
+                        "Environment variable {name} referenced in config is not set"
+                    )))
+                }
+            }
+            rest = &placeholder[end + 1..];
+        } else {
+            substituted.push('$');
+            rest = &after[1..];
+        }
+    }
+    substituted.push_str(rest);
+
+    Ok(substituted)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::env_substitution::substitute_with;
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "TELEGRAM_BOT_TOKEN" => Some("123:abc".to_string()),
+            "CHAT_ID" => Some("-100".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_substitute() {
+        let mut value = json!({
+            "telegram": { "bot_token": "${TELEGRAM_BOT_TOKEN}", "chat_id": "id ${CHAT_ID}" },
+            "destinations": ["${MISSING:-discord}", "slack"],
+            "price": "$5 and $${LITERAL}",
+            "threshold": 100,
+        });
+        substitute_with(&mut value, &lookup).unwrap();
+
+        assert_eq!(value["telegram"]["bot_token"], "123:abc");
+        assert_eq!(value["telegram"]["chat_id"], "id -100");
+        assert_eq!(value["destinations"][0], "discord");
+        assert_eq!(value["price"], "$5 and ${LITERAL}");
+        assert_eq!(value["threshold"], 100);
+    }
+
+    #[test]
+    fn test_substitute_missing() {
+        let mut value = json!({ "webhook_url": "${MISSING}" });
+        assert!(substitute_with(&mut value, &lookup).is_err());
+
+        let mut value = json!({ "webhook_url": "${TELEGRAM_BOT_TOKEN" });
+        assert!(substitute_with(&mut value, &lookup).is_err());
+    }
+}
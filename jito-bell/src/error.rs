@@ -1,3 +1,4 @@
+use solana_rpc_client_api::client_error::Error as ClientError;
 use spl_stake_pool::solana_program;
 use thiserror::Error;
 use yellowstone_grpc_client::{GeyserGrpcBuilderError, GeyserGrpcClientError};
@@ -23,7 +24,10 @@ pub enum JitoBellError {
     GeyserGrpcBuilder(#[from] GeyserGrpcBuilderError),
 
     #[error("Geyser client error: {0}")]
-    GeyserGrpcClient(#[from] GeyserGrpcClientError),
+    GeyserGrpcClient(Box<GeyserGrpcClientError>),
+
+    #[error("Geyser compatibility error: {0}")]
+    GeyserCompatibility(String),
 
     #[error("Subscription Error: {0}")]
     Subscription(String),
@@ -32,10 +36,13 @@ pub enum JitoBellError {
     SolanaProgram(#[from] solana_program::program_error::ProgramError),
 
     #[error("Solana RPC Client error: {0}")]
-    SolanaRpcClient(#[from] solana_rpc_client_api::client_error::Error),
+    SolanaRpcClient(Box<ClientError>),
 
     #[error("Defillama error: {0}")]
     DefiLlama(#[from] defillama_rs::DefillamaError),
+
+    #[error("Store error: {0}")]
+    Store(#[from] rusqlite::Error),
 }
 
 // For serde_yaml errors
@@ -44,3 +51,16 @@ impl From<serde_yaml::Error> for JitoBellError {
         JitoBellError::Config(err.to_string())
     }
 }
+
+// Boxed, the errors are large
+impl From<GeyserGrpcClientError> for JitoBellError {
+    fn from(err: GeyserGrpcClientError) -> Self {
+        JitoBellError::GeyserGrpcClient(Box::new(err))
+    }
+}
+
+impl From<ClientError> for JitoBellError {
+    fn from(err: ClientError) -> Self {
+        JitoBellError::SolanaRpcClient(Box::new(err))
+    }
+}
@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+
+use clap::ValueEnum;
+use serde_yaml::{Mapping, Value};
+
+use crate::{
+    config::{ApiConfig, JitoBellConfig, StoreConfig},
+    error::JitoBellError,
+    instruction::{AlertConfig, Instruction},
+    notification_config::{
+        DiscordConfig, NotificationConfig, SlackConfig, TelegramConfig, TwitterConfig,
+    },
+    notification_info::{Destination, NotificationInfo},
+    program::Program,
+    threshold_config::ThresholdConfig,
+};
+
+/// SPL Stake Pool program ID
+const SPL_STAKE_POOL_PROGRAM_ID: &str = "SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy";
+
+/// Jito Vault program ID
+const JITO_VAULT_PROGRAM_ID: &str = "Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8";
+
+/// Jito Steward program ID
+const JITO_STEWARD_PROGRAM_ID: &str = "Stewardf95sJbmtcZsyagb2dg4Mo8eVQho8gpECvLx8";
+
+/// Jito stake pool
+const JITO_STAKE_POOL: &str = "Jito4APyf642JPZPx3hGc6WWJ8zPKtRbRs4P815Awbb";
+
+/// JitoSOL mint
+const JITOSOL_MINT: &str = "J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn";
+
+/// VRT mints watched by the restaking example
+const VRT_MINTS: [&str; 2] = [
+    "CXSLcb8gFEw2zhFwaQEZ2xCRpoN2dxafMNm7HuciDfDq",
+    "CkXLPfDG3cDawtUvnztq99HdGoQWhJceBZxqKYL2TUrg",
+];
+
+/// Built-in example configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExampleConfig {
+    /// JitoSOL deposits, withdrawals and validator stake changes to Slack
+    JitosolOnly,
+
+    /// JitoSOL, Jito restaking vaults and the steward, with the event store and REST API
+    FullRestaking,
+
+    /// Large JitoSOL flows only, to public Discord, Telegram and Twitter channels
+    CommunityPublic,
+}
+
+impl ExampleConfig {
+    /// Build the example from the config types
+    pub fn build(&self) -> JitoBellConfig {
+        match self {
+            ExampleConfig::JitosolOnly => JitoBellConfig {
+                programs: HashMap::from([(
+                    "spl_stake_pool".to_string(),
+                    jitosol_program(&[
+                        (1000.0, "JitoSOL", &["slack"]),
+                        (10000.0, "Whale JitoSOL", &["slack"]),
+                    ]),
+                )]),
+                notifications: NotificationConfig {
+                    slack: Some(slack_config()),
+                    ..Default::default()
+                },
+                ..base_config()
+            },
+            ExampleConfig::FullRestaking => JitoBellConfig {
+                programs: HashMap::from([
+                    (
+                        "spl_stake_pool".to_string(),
+                        jitosol_program(&[
+                            (1000.0, "JitoSOL", &["slack"]),
+                            (10000.0, "Whale JitoSOL", &["slack", "telegram"]),
+                        ]),
+                    ),
+                    ("jito_vault".to_string(), vault_program()),
+                    ("jito_steward".to_string(), steward_program()),
+                ]),
+                notifications: NotificationConfig {
+                    slack: Some(slack_config()),
+                    telegram: Some(telegram_config()),
+                    ..Default::default()
+                },
+                store: Some(StoreConfig {
+                    path: "/var/lib/jito-bell/jito-bell.db".into(),
+                    outbox: false,
+                }),
+                api: Some(ApiConfig {
+                    bind_address: "127.0.0.1:8080".to_string(),
+                }),
+                ..base_config()
+            },
+            ExampleConfig::CommunityPublic => JitoBellConfig {
+                programs: HashMap::from([(
+                    "spl_stake_pool".to_string(),
+                    jitosol_program(&[(
+                        10000.0,
+                        "Whale JitoSOL",
+                        &["discord", "telegram", "twitter"],
+                    )]),
+                )]),
+                notifications: NotificationConfig {
+                    discord: Some(DiscordConfig {
+                        webhook_url: "https://discord.com/api/webhooks/<id>/<token>".to_string(),
+                    }),
+                    telegram: Some(telegram_config()),
+                    twitter: Some(TwitterConfig {
+                        twitter_bearer_token: String::new(),
+                        twitter_api_key: String::new(),
+                        twitter_api_secret: String::new(),
+                        twitter_access_token: String::new(),
+                        twitter_access_token_secret: String::new(),
+                        min_amount: None,
+                    }),
+                    ..Default::default()
+                },
+                ..base_config()
+            },
+        }
+    }
+
+    /// Render the example as YAML
+    ///
+    /// - Unset options and empty lists are omitted, keys are sorted for stable output
+    pub fn to_yaml(&self) -> Result<String, JitoBellError> {
+        let value = serde_yaml::to_value(self.build())?;
+        let value = prune(value).unwrap_or(Value::Mapping(Mapping::new()));
+
+        Ok(serde_yaml::to_string(&value)?)
+    }
+}
+
+/// Explorer url and message templates shared by the examples
+fn base_config() -> JitoBellConfig {
+    JitoBellConfig {
+        explorer_url: "https://solscan.io".to_string(),
+        message_templates: HashMap::from([
+            (
+                "default".to_string(),
+                "{{description}} - Amount: {{amount}} {{currency_unit}} - Tx: https://explorer.solana.com/tx/{{tx_hash}}".to_string(),
+            ),
+            (
+                "slack".to_string(),
+                "<!here> {{description}} - Amount: {{amount}} {{currency_unit}} - <https://explorer.solana.com/tx/{{tx_hash}}|View Transaction>".to_string(),
+            ),
+            (
+                "discord".to_string(),
+                "{{description}} - Amount: {{amount}} {{currency_unit}} - [View Transaction](https://explorer.solana.com/tx/{{tx_hash}})".to_string(),
+            ),
+        ]),
+        ..Default::default()
+    }
+}
+
+fn slack_config() -> SlackConfig {
+    SlackConfig {
+        webhook_url: Some("https://hooks.slack.com/services/<id>".to_string()),
+        bot_token: None,
+        channel: "#jito-bell".to_string(),
+        follow_up_finalized: false,
+    }
+}
+
+fn telegram_config() -> TelegramConfig {
+    TelegramConfig {
+        bot_token: String::new(),
+        chat_id: String::new(),
+        message_thread_id: None,
+        parse_mode: None,
+        disable_web_page_preview: false,
+        disable_notification: false,
+    }
+}
+
+fn notification(description: String, destinations: &[&str]) -> NotificationInfo {
+    NotificationInfo {
+        description,
+        destinations: destinations
+            .iter()
+            .map(|destination| Destination::from(*destination))
+            .collect(),
+        ..Default::default()
+    }
+}
+
+/// Alert with one threshold per tier `(value, subject, destinations)`
+fn alert(tiers: &[(f64, &str, &[&str])], action: &str) -> AlertConfig {
+    AlertConfig {
+        thresholds: tiers
+            .iter()
+            .map(|(value, subject, destinations)| ThresholdConfig {
+                value: *value,
+                median_multiple: None,
+                notification: notification(format!("{subject} {action} detected"), destinations),
+            })
+            .collect(),
+        usd_thresholds: vec![],
+        lamport_thresholds: vec![],
+    }
+}
+
+/// SPL Stake Pool program watching JitoSOL
+fn jitosol_program(tiers: &[(f64, &str, &[&str])]) -> Program {
+    let lst = |action: &str| Instruction {
+        lsts: Some(HashMap::from([(
+            JITOSOL_MINT.to_string(),
+            alert(tiers, action),
+        )])),
+        stake_pools: Some(HashMap::from([(
+            JITO_STAKE_POOL.to_string(),
+            AlertConfig::default(),
+        )])),
+        ..Default::default()
+    };
+    let stake_pool = |action: &str| Instruction {
+        stake_pools: Some(HashMap::from([(
+            JITO_STAKE_POOL.to_string(),
+            alert(tiers, action),
+        )])),
+        ..Default::default()
+    };
+
+    Program {
+        program_id: SPL_STAKE_POOL_PROGRAM_ID.to_string(),
+        additional_program_ids: Vec::new(),
+        idl: None,
+        instructions: HashMap::from([
+            ("deposit_sol".to_string(), lst("SOL deposit")),
+            ("withdraw_sol".to_string(), lst("SOL withdrawal")),
+            ("deposit_stake".to_string(), lst("stake deposit")),
+            ("withdraw_stake".to_string(), lst("stake withdrawal")),
+            (
+                "increase_validator_stake".to_string(),
+                stake_pool("validator stake increase"),
+            ),
+            (
+                "decrease_validator_stake_with_reserve".to_string(),
+                stake_pool("validator stake decrease"),
+            ),
+        ]),
+    }
+}
+
+/// Jito Vault program watching VRT mints and withdrawals
+fn vault_program() -> Program {
+    let tiers: &[(f64, &str, &[&str])] = &[
+        (1000.0, "VRT", &["slack"]),
+        (10000.0, "Whale VRT", &["slack", "telegram"]),
+    ];
+    let vrts = |action: &str| Instruction {
+        vrts: Some(
+            VRT_MINTS
+                .iter()
+                .map(|mint| (mint.to_string(), alert(tiers, action)))
+                .collect(),
+        ),
+        ..Default::default()
+    };
+
+    Program {
+        program_id: JITO_VAULT_PROGRAM_ID.to_string(),
+        additional_program_ids: Vec::new(),
+        idl: None,
+        instructions: HashMap::from([
+            ("mint_to".to_string(), vrts("mint")),
+            ("enqueue_withdrawal".to_string(), vrts("withdrawal")),
+        ]),
+    }
+}
+
+/// Jito Steward program notifying every rebalance
+fn steward_program() -> Program {
+    Program {
+        program_id: JITO_STEWARD_PROGRAM_ID.to_string(),
+        additional_program_ids: Vec::new(),
+        idl: None,
+        instructions: HashMap::from([(
+            "rebalance".to_string(),
+            Instruction {
+                notifications: vec![notification(
+                    "Steward rebalanced stake".to_string(),
+                    &["slack"],
+                )],
+                ..Default::default()
+            },
+        )]),
+    }
+}
+
+/// Drop nulls and empty lists/maps, sort mapping keys
+fn prune(value: Value) -> Option<Value> {
+    match value {
+        Value::Null => None,
+        Value::Sequence(sequence) => {
+            let sequence: Vec<Value> = sequence.into_iter().filter_map(prune).collect();
+            (!sequence.is_empty()).then_some(Value::Sequence(sequence))
+        }
+        Value::Mapping(mapping) => {
+            let mut entries: Vec<(Value, Value)> = mapping
+                .into_iter()
+                .filter_map(|(key, value)| prune(value).map(|value| (key, value)))
+                .collect();
+            entries.sort_by_key(|(key, _)| key.as_str().unwrap_or_default().to_string());
+
+            (!entries.is_empty()).then(|| Value::Mapping(entries.into_iter().collect()))
+        }
+        value => Some(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::ValueEnum;
+
+    use crate::{config::JitoBellConfig, examples::ExampleConfig};
+
+    #[test]
+    fn test_examples_round_trip() {
+        for example in ExampleConfig::value_variants() {
+            let yaml = example.to_yaml().unwrap();
+            let config: JitoBellConfig = serde_yaml::from_str(&yaml).unwrap();
+
+            assert!(!config.programs.is_empty());
+            assert_eq!(yaml, ExampleConfig::to_yaml(example).unwrap());
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use serde::{Deserialize, Serialize};
+
+use crate::notification_info::NotificationInfo;
+
+/// Failover across Geyser endpoints
+///
+/// - `--endpoint` is tried first, then `endpoints` by ascending priority, then `--endpoint`
+///   again
+/// - The endpoint switches after `after_failures` consecutive failed subscriptions
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FailoverConfig {
+    /// Fallback endpoints
+    pub endpoints: Vec<GeyserEndpointConfig>,
+
+    /// Consecutive failed subscriptions before switching to the next endpoint
+    #[serde(default = "default_after_failures")]
+    pub after_failures: u32,
+
+    /// Alert sent when the endpoint switches
+    pub notification: Option<NotificationInfo>,
+}
+
+fn default_after_failures() -> u32 {
+    3
+}
+
+/// Yellowstone gRPC endpoint
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GeyserEndpointConfig {
+    /// Endpoint URL
+    pub endpoint: String,
+
+    /// X-Token
+    pub x_token: Option<String>,
+
+    /// Lower priorities are tried first
+    #[serde(default)]
+    pub priority: u32,
+}
+
+/// Endpoint switch reported by [`EndpointRotation::record_failure`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failover {
+    /// Endpoint switched from
+    pub from: String,
+
+    /// Endpoint switched to
+    pub to: String,
+}
+
+/// Endpoints in failover order and the one in use
+#[derive(Debug)]
+pub struct EndpointRotation {
+    /// Endpoints, the primary first
+    endpoints: Vec<GeyserEndpointConfig>,
+
+    /// Index of the endpoint in use
+    current: usize,
+
+    /// Consecutive failed subscriptions of the endpoint in use
+    failures: u32,
+
+    /// Failures before switching
+    after_failures: u32,
+}
+
+impl EndpointRotation {
+    /// Rotation starting at the primary endpoint
+    pub fn new(primary: GeyserEndpointConfig, failover: Option<&FailoverConfig>) -> Self {
+        let mut endpoints = vec![primary];
+        let mut after_failures = u32::MAX;
+        if let Some(failover) = failover {
+            let mut fallbacks = failover.endpoints.clone();
+            fallbacks.sort_by_key(|endpoint| endpoint.priority);
+            endpoints.extend(fallbacks);
+            after_failures = failover.after_failures.max(1);
+        }
+
+        Self {
+            endpoints,
+            current: 0,
+            failures: 0,
+            after_failures,
+        }
+    }
+
+    /// Endpoint in use
+    pub fn current(&self) -> &GeyserEndpointConfig {
+        &self.endpoints[self.current]
+    }
+
+    /// Record a subscription that delivered updates
+    pub fn record_success(&mut self) {
+        self.failures = 0;
+    }
+
+    /// Record a failed subscription, switching to the next endpoint after `after_failures`
+    pub fn record_failure(&mut self) -> Option<Failover> {
+        self.failures = self.failures.saturating_add(1);
+        if self.failures < self.after_failures {
+            return None;
+        }
+
+        self.switch()
+    }
+
+    /// Switch to the next endpoint, None without fallback endpoints
+    pub fn switch(&mut self) -> Option<Failover> {
+        if self.endpoints.len() < 2 {
+            return None;
+        }
+
+        let from = self.current().endpoint.clone();
+        self.current = (self.current + 1) % self.endpoints.len();
+        self.failures = 0;
+
+        Some(Failover {
+            from,
+            to: self.current().endpoint.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::failover::{EndpointRotation, Failover, FailoverConfig, GeyserEndpointConfig};
+
+    fn endpoint(endpoint: &str, priority: u32) -> GeyserEndpointConfig {
+        GeyserEndpointConfig {
+            endpoint: endpoint.to_string(),
+            x_token: None,
+            priority,
+        }
+    }
+
+    #[test]
+    fn test_rotation() {
+        let failover = FailoverConfig {
+            endpoints: vec![endpoint("https://c", 2), endpoint("https://b", 1)],
+            after_failures: 2,
+            notification: None,
+        };
+        let mut rotation = EndpointRotation::new(endpoint("https://a", 0), Some(&failover));
+        assert_eq!(rotation.current().endpoint, "https://a");
+
+        assert_eq!(rotation.record_failure(), None);
+        rotation.record_success();
+        assert_eq!(rotation.record_failure(), None);
+        assert_eq!(
+            rotation.record_failure(),
+            Some(Failover {
+                from: "https://a".to_string(),
+                to: "https://b".to_string(),
+            })
+        );
+
+        rotation.record_failure();
+        rotation.record_failure();
+        assert_eq!(rotation.current().endpoint, "https://c");
+        rotation.record_failure();
+        rotation.record_failure();
+        assert_eq!(rotation.current().endpoint, "https://a");
+    }
+
+    #[test]
+    fn test_rotation_without_failover() {
+        let mut rotation = EndpointRotation::new(endpoint("https://a", 0), None);
+        for _ in 0..10 {
+            assert_eq!(rotation.record_failure(), None);
+        }
+        assert_eq!(rotation.current().endpoint, "https://a");
+    }
+}
@@ -0,0 +1,149 @@
+use log::{debug, warn};
+use serde_json::Value;
+use yellowstone_grpc_proto::prelude::SubscribeUpdate;
+
+use crate::error::JitoBellError;
+
+/// Yellowstone proto major version this build decodes
+pub const SUPPORTED_PROTO_MAJOR: u64 = 2;
+
+/// Number of undecodable updates, before any decodable one, treated as an incompatible stream
+const MAX_UNKNOWN_UPDATES_AT_STARTUP: u64 = 10;
+
+/// Compatibility table shown in mismatch errors
+pub const COMPATIBILITY_TABLE: &str = "\
+Yellowstone gRPC proto | jito-bell
+-----------------------+--------------
+1.x                    | unsupported
+2.x                    | supported
+3.x and later          | unsupported";
+
+/// Check the `GetVersion` response of the Geyser plugin
+///
+/// - Return the proto version reported by the plugin
+/// - Unparseable responses (e.g. behind a proxy) are logged and accepted
+pub fn check_version(version: &str) -> Result<Option<String>, JitoBellError> {
+    let proto = match parse_proto_version(version) {
+        Some(proto) => proto,
+        None => {
+            warn!("Could not determine Yellowstone proto version from: {version}");
+            return Ok(None);
+        }
+    };
+
+    let major = proto
+        .split('.')
+        .next()
+        .and_then(|major| major.parse::<u64>().ok());
+
+    match major {
+        Some(SUPPORTED_PROTO_MAJOR) => Ok(Some(proto)),
+        _ => Err(JitoBellError::GeyserCompatibility(format!(
+            "Geyser endpoint speaks Yellowstone proto {proto}, this build supports {SUPPORTED_PROTO_MAJOR}.x\n{COMPATIBILITY_TABLE}"
+        ))),
+    }
+}
+
+/// Extract the proto version from the JSON version string
+///
+/// - `{"version": {"proto": "2.0.0", ...}, "extra": {...}}`
+/// - `{"proto": "2.0.0", ...}`
+fn parse_proto_version(version: &str) -> Option<String> {
+    let value: Value = serde_json::from_str(version).ok()?;
+
+    value
+        .get("version")
+        .and_then(|version| version.get("proto"))
+        .or_else(|| value.get("proto"))
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+/// Update Monitor
+///
+/// - Detect updates whose variant is unknown to this build (decoded as an empty oneof)
+/// - Fail when the stream starts with nothing but undecodable updates
+#[derive(Debug, Default)]
+pub struct UpdateMonitor {
+    /// Number of decoded updates
+    decoded: u64,
+
+    /// Number of undecodable updates
+    unknown: u64,
+}
+
+impl UpdateMonitor {
+    /// Observe an update
+    pub fn observe(&mut self, update: &SubscribeUpdate) -> Result<(), JitoBellError> {
+        if update.update_oneof.is_some() {
+            self.decoded += 1;
+            return Ok(());
+        }
+
+        self.unknown += 1;
+        if self.unknown == 1 {
+            warn!(
+                "Received Geyser update with an unknown variant (filters: {:?}), the endpoint may be newer than this build",
+                update.filters
+            );
+        } else {
+            debug!("Unknown Geyser updates: {}", self.unknown);
+        }
+
+        if self.decoded == 0 && self.unknown >= MAX_UNKNOWN_UPDATES_AT_STARTUP {
+            return Err(JitoBellError::GeyserCompatibility(format!(
+                "First {} Geyser updates could not be decoded\n{COMPATIBILITY_TABLE}",
+                self.unknown
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use yellowstone_grpc_proto::prelude::{
+        subscribe_update::UpdateOneof, SubscribeUpdate, SubscribeUpdatePong,
+    };
+
+    use crate::geyser_compat::{check_version, UpdateMonitor, MAX_UNKNOWN_UPDATES_AT_STARTUP};
+
+    #[test]
+    fn test_check_version() {
+        let supported = r#"{"version":{"package":"yellowstone-grpc-geyser","version":"2.0.0","proto":"2.0.0"},"extra":{"hostname":"node"}}"#;
+        assert_eq!(check_version(supported).unwrap(), Some("2.0.0".to_string()));
+
+        let flat = r#"{"package":"yellowstone-grpc-geyser","proto":"2.1.0"}"#;
+        assert_eq!(check_version(flat).unwrap(), Some("2.1.0".to_string()));
+
+        let old = r#"{"version":{"proto":"1.12.0"}}"#;
+        assert!(check_version(old).is_err());
+
+        let new = r#"{"version":{"proto":"3.0.0"}}"#;
+        assert!(check_version(new).is_err());
+
+        assert_eq!(check_version("not json").unwrap(), None);
+    }
+
+    #[test]
+    fn test_update_monitor() {
+        let unknown = SubscribeUpdate::default();
+        let pong = SubscribeUpdate {
+            update_oneof: Some(UpdateOneof::Pong(SubscribeUpdatePong { id: 1 })),
+            ..Default::default()
+        };
+
+        let mut monitor = UpdateMonitor::default();
+        for _ in 1..MAX_UNKNOWN_UPDATES_AT_STARTUP {
+            assert!(monitor.observe(&unknown).is_ok());
+        }
+        assert!(monitor.observe(&unknown).is_err());
+
+        let mut monitor = UpdateMonitor::default();
+        assert!(monitor.observe(&pong).is_ok());
+        for _ in 0..MAX_UNKNOWN_UPDATES_AT_STARTUP {
+            assert!(monitor.observe(&unknown).is_ok());
+        }
+    }
+}
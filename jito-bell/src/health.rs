@@ -0,0 +1,196 @@
+use std::{
+    collections::BTreeSet,
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Mutex, PoisonError,
+    },
+};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// Health and readiness served at GET /healthz and GET /readyz
+///
+/// - Live while the dispatcher handled a slot-bearing update within `max_update_age_secs`
+///   (or since startup, before the first update), so a wedged subscription is restarted
+/// - Ready when live after the first update, and with no destination circuit open unless
+///   `ready_with_open_circuits`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct HealthConfig {
+    /// Maximum age of the last update in seconds
+    #[serde(default = "default_max_update_age_secs")]
+    pub max_update_age_secs: u64,
+
+    /// Stay ready while destination circuits are open
+    #[serde(default)]
+    pub ready_with_open_circuits: bool,
+}
+
+fn default_max_update_age_secs() -> u64 {
+    60
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            max_update_age_secs: default_max_update_age_secs(),
+            ready_with_open_circuits: false,
+        }
+    }
+}
+
+/// Health state shared by the handler and the REST API
+#[derive(Debug)]
+pub struct Health {
+    /// Start time (unix milliseconds)
+    started_at: i64,
+
+    /// Time of the last update (unix milliseconds), 0 before the first one
+    last_update_at: AtomicI64,
+
+    /// Destinations whose circuit is open
+    open_circuits: Mutex<BTreeSet<String>>,
+}
+
+/// Result of a health check
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HealthCheck {
+    /// Whether the subscription is not wedged
+    pub live: bool,
+
+    /// Whether the instance is ready to deliver notifications
+    pub ready: bool,
+
+    /// Seconds since the last update, None before the first one
+    pub last_update_age_secs: Option<f64>,
+
+    /// Destinations whose circuit is open
+    pub open_circuits: Vec<String>,
+}
+
+impl Default for Health {
+    fn default() -> Self {
+        Self {
+            started_at: Utc::now().timestamp_millis(),
+            last_update_at: AtomicI64::new(0),
+            open_circuits: Mutex::new(BTreeSet::new()),
+        }
+    }
+}
+
+impl Health {
+    /// Record an update handled by the dispatcher
+    pub fn record_update(&self) {
+        self.last_update_at
+            .store(Utc::now().timestamp_millis(), Ordering::Relaxed);
+    }
+
+    /// Record the circuit state of a destination
+    pub fn set_circuit_open(&self, destination: &str, open: bool) {
+        let mut open_circuits = self
+            .open_circuits
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if open {
+            open_circuits.insert(destination.to_string());
+        } else {
+            open_circuits.remove(destination);
+        }
+    }
+
+    /// Check health at `now` (unix milliseconds)
+    pub fn check(&self, config: &HealthConfig, now: i64) -> HealthCheck {
+        let last_update_at = self.last_update_at.load(Ordering::Relaxed);
+        let open_circuits: Vec<String> = self
+            .open_circuits
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .cloned()
+            .collect();
+
+        let max_age_ms = config.max_update_age_secs.saturating_mul(1_000) as i64;
+        let (live, last_update_age_secs) = if last_update_at == 0 {
+            (now - self.started_at <= max_age_ms, None)
+        } else {
+            let age_ms = now - last_update_at;
+            (age_ms <= max_age_ms, Some(age_ms as f64 / 1_000.0))
+        };
+        let ready = live
+            && last_update_age_secs.is_some()
+            && (config.ready_with_open_circuits || open_circuits.is_empty());
+
+        HealthCheck {
+            live,
+            ready,
+            last_update_age_secs,
+            open_circuits,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use crate::health::{Health, HealthConfig};
+
+    #[test]
+    fn test_startup_grace() {
+        let health = Health::default();
+        let config = HealthConfig::default();
+
+        let check = health.check(&config, health.started_at + 30_000);
+        assert!(check.live);
+        assert!(!check.ready);
+        assert_eq!(check.last_update_age_secs, None);
+
+        let check = health.check(&config, health.started_at + 61_000);
+        assert!(!check.live);
+    }
+
+    #[test]
+    fn test_update_age() {
+        let health = Health::default();
+        let config = HealthConfig::default();
+        health
+            .last_update_at
+            .store(health.started_at + 10_000, Ordering::Relaxed);
+
+        let check = health.check(&config, health.started_at + 15_000);
+        assert!(check.live);
+        assert!(check.ready);
+        assert_eq!(check.last_update_age_secs, Some(5.0));
+
+        let check = health.check(&config, health.started_at + 75_000);
+        assert!(!check.live);
+        assert!(!check.ready);
+    }
+
+    #[test]
+    fn test_open_circuits() {
+        let health = Health::default();
+        health
+            .last_update_at
+            .store(health.started_at, Ordering::Relaxed);
+        health.set_circuit_open("slack", true);
+
+        let check = health.check(&HealthConfig::default(), health.started_at);
+        assert!(check.live);
+        assert!(!check.ready);
+        assert_eq!(check.open_circuits, vec!["slack".to_string()]);
+
+        let config = HealthConfig {
+            ready_with_open_circuits: true,
+            ..Default::default()
+        };
+        assert!(health.check(&config, health.started_at).ready);
+
+        health.set_circuit_open("slack", false);
+        assert!(
+            health
+                .check(&HealthConfig::default(), health.started_at)
+                .ready
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::JitoBellError;
+
+/// Idle connections kept per host by the shared client
+const POOL_MAX_IDLE_PER_HOST: usize = 16;
+
+/// Idle connections of the shared client are closed after this long
+const POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// Timeouts of notifier HTTP calls
+///
+/// - Each attempt of a retried call (`retry`) has its own timeouts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct HttpTimeouts {
+    /// Timeout of establishing a connection in milliseconds
+    #[serde(default = "default_connect_ms")]
+    pub connect_ms: u64,
+
+    /// Timeout of a whole request in milliseconds, from connecting to reading the response body
+    #[serde(default = "default_request_ms")]
+    pub request_ms: u64,
+}
+
+fn default_connect_ms() -> u64 {
+    5_000
+}
+
+fn default_request_ms() -> u64 {
+    10_000
+}
+
+impl Default for HttpTimeouts {
+    fn default() -> Self {
+        Self {
+            connect_ms: default_connect_ms(),
+            request_ms: default_request_ms(),
+        }
+    }
+}
+
+impl HttpTimeouts {
+    /// Client builder with the timeouts
+    pub fn client_builder(&self) -> reqwest::ClientBuilder {
+        reqwest::Client::builder()
+            .connect_timeout(Duration::from_millis(self.connect_ms))
+            .timeout(Duration::from_millis(self.request_ms))
+    }
+
+    /// Client with the timeouts shared by the notifiers, keeping connections alive between
+    /// notifications so they don't each pay for a TLS handshake
+    pub fn shared_client(&self) -> Result<reqwest::Client, JitoBellError> {
+        Ok(self
+            .client_builder()
+            .pool_max_idle_per_host(POOL_MAX_IDLE_PER_HOST)
+            .pool_idle_timeout(POOL_IDLE_TIMEOUT)
+            .build()?)
+    }
+}
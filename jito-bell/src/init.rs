@@ -0,0 +1,492 @@
+use std::{
+    fs,
+    io::{self, BufRead, Write},
+    path::Path,
+};
+
+use serde_json::{json, Map, Value};
+
+use crate::{
+    config::{ConfigFormat, JitoBellConfig},
+    error::JitoBellError,
+    examples::ExampleConfig,
+    http_timeout::HttpTimeouts,
+};
+
+/// SPL Stake Pool program ID
+const SPL_STAKE_POOL_PROGRAM_ID: &str = "SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy";
+
+/// Jito stake pool, suggested as the first pool to watch
+const DEFAULT_STAKE_POOL: &str = "Jito4APyf642JPZPx3hGc6WWJ8zPKtRbRs4P815Awbb";
+
+/// JitoSOL mint
+const DEFAULT_POOL_MINT: &str = "J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn";
+
+/// Message sent to verify each channel
+const TEST_MESSAGE: &str = "jito-bell test message: this channel is configured";
+
+/// Destination configured by the wizard
+#[derive(Debug, Clone, PartialEq)]
+pub enum InitDestination {
+    Slack {
+        webhook_url: String,
+        channel: String,
+    },
+    Discord {
+        webhook_url: String,
+    },
+    Telegram {
+        bot_token: String,
+        chat_id: String,
+    },
+}
+
+impl InitDestination {
+    /// Destination name used in `destinations`
+    pub fn name(&self) -> &'static str {
+        match self {
+            InitDestination::Slack { .. } => "slack",
+            InitDestination::Discord { .. } => "discord",
+            InitDestination::Telegram { .. } => "telegram",
+        }
+    }
+
+    /// `notifications` entry
+    fn config(&self) -> Value {
+        match self {
+            InitDestination::Slack {
+                webhook_url,
+                channel,
+            } => json!({ "webhook_url": webhook_url, "channel": channel }),
+            InitDestination::Discord { webhook_url } => json!({ "webhook_url": webhook_url }),
+            InitDestination::Telegram { bot_token, chat_id } => {
+                json!({ "bot_token": bot_token, "chat_id": chat_id })
+            }
+        }
+    }
+
+    /// Send a test message with `client`
+    async fn test_send(&self, client: &reqwest::Client) -> Result<(), JitoBellError> {
+        let response = match self {
+            InitDestination::Slack {
+                webhook_url,
+                channel,
+            } => {
+                client
+                    .post(webhook_url)
+                    .json(&json!({ "text": TEST_MESSAGE, "channel": channel }))
+                    .send()
+                    .await?
+            }
+            InitDestination::Discord { webhook_url } => {
+                client
+                    .post(webhook_url)
+                    .json(&json!({ "content": TEST_MESSAGE }))
+                    .send()
+                    .await?
+            }
+            InitDestination::Telegram { bot_token, chat_id } => {
+                client
+                    .post(format!(
+                        "https://api.telegram.org/bot{bot_token}/sendMessage"
+                    ))
+                    .form(&[("chat_id", chat_id.as_str()), ("text", TEST_MESSAGE)])
+                    .send()
+                    .await?
+            }
+        };
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(JitoBellError::Notification(format!(
+                "{} responded with {}",
+                self.name(),
+                response.status()
+            )))
+        }
+    }
+}
+
+/// Stake pool watched by the wizard
+#[derive(Debug, Clone, PartialEq)]
+pub struct InitPool {
+    /// Stake pool address
+    pub stake_pool: String,
+
+    /// Pool mint (LST)
+    pub pool_mint: String,
+
+    /// Threshold in SOL
+    pub threshold: f64,
+}
+
+/// Answers collected by the wizard
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InitAnswers {
+    /// Destinations
+    pub destinations: Vec<InitDestination>,
+
+    /// Watched stake pools
+    pub pools: Vec<InitPool>,
+}
+
+/// Build the starter config from the answers
+///
+/// - Deposits and withdrawals are watched on the pool mint, validator stake changes on the pool
+pub fn build_config(answers: &InitAnswers) -> Value {
+    let destinations: Vec<&str> = answers
+        .destinations
+        .iter()
+        .map(InitDestination::name)
+        .collect();
+
+    let rule = |description: String, threshold: f64| {
+        json!({
+            "thresholds": [{
+                "value": threshold,
+                "notification": {
+                    "description": description,
+                    "destinations": destinations,
+                }
+            }]
+        })
+    };
+
+    let mut instructions = Map::new();
+    for (instruction, key, description) in [
+        ("deposit_sol", "lsts", "SOL deposit"),
+        ("withdraw_sol", "lsts", "SOL withdrawal"),
+        ("deposit_stake", "lsts", "Stake deposit"),
+        ("withdraw_stake", "lsts", "Stake withdrawal"),
+        (
+            "increase_validator_stake",
+            "stake_pools",
+            "Increase validator stake",
+        ),
+        (
+            "decrease_validator_stake_with_reserve",
+            "stake_pools",
+            "Decrease validator stake",
+        ),
+    ] {
+        let alerts: Map<String, Value> = answers
+            .pools
+            .iter()
+            .map(|pool| {
+                let address = if key == "lsts" {
+                    &pool.pool_mint
+                } else {
+                    &pool.stake_pool
+                };
+                (
+                    address.clone(),
+                    rule(format!("{description} detected"), pool.threshold),
+                )
+            })
+            .collect();
+
+        let mut instruction_config = Map::new();
+        instruction_config.insert(key.to_string(), Value::Object(alerts));
+        instructions.insert(instruction.to_string(), Value::Object(instruction_config));
+    }
+
+    let notifications: Map<String, Value> = answers
+        .destinations
+        .iter()
+        .map(|destination| (destination.name().to_string(), destination.config()))
+        .collect();
+
+    json!({
+        "programs": {
+            "spl_stake_pool": {
+                "program_id": SPL_STAKE_POOL_PROGRAM_ID,
+                "instructions": instructions,
+            }
+        },
+        "notifications": notifications,
+        "explorer_url": "https://solscan.io",
+        "message_templates": {
+            "default": "{{description}} - Amount: {{amount}} {{currency_unit}} - Tx: https://explorer.solana.com/tx/{{tx_hash}}",
+            "slack": "<!here> {{description}} - Amount: {{amount}} {{currency_unit}} - <https://explorer.solana.com/tx/{{tx_hash}}|View Transaction>",
+            "discord": "@here {{description}} - Amount: {{amount}} {{currency_unit}} - [View Transaction](https://explorer.solana.com/tx/{{tx_hash}})",
+        }
+    })
+}
+
+/// Interactive prompts over any reader/writer
+struct Prompter<R, W> {
+    input: R,
+    output: W,
+}
+
+impl<R: BufRead, W: Write> Prompter<R, W> {
+    /// Ask a question, an empty answer selects the default
+    fn ask(&mut self, question: &str, default: Option<&str>) -> io::Result<String> {
+        loop {
+            match default {
+                Some(default) => write!(self.output, "{question} [{default}]: ")?,
+                None => write!(self.output, "{question}: ")?,
+            }
+            self.output.flush()?;
+
+            let mut line = String::new();
+            if self.input.read_line(&mut line)? == 0 {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "Input closed"));
+            }
+
+            let answer = line.trim();
+            match (answer.is_empty(), default) {
+                (false, _) => return Ok(answer.to_string()),
+                (true, Some(default)) => return Ok(default.to_string()),
+                (true, None) => writeln!(self.output, "A value is required")?,
+            }
+        }
+    }
+
+    /// Ask a yes/no question
+    fn confirm(&mut self, question: &str, default: bool) -> io::Result<bool> {
+        let answer = self.ask(question, Some(if default { "Y/n" } else { "y/N" }))?;
+
+        Ok(match answer.to_lowercase().as_str() {
+            "y" | "yes" => true,
+            "n" | "no" => false,
+            _ => default,
+        })
+    }
+
+    /// Ask for a number
+    fn ask_f64(&mut self, question: &str, default: f64) -> io::Result<f64> {
+        loop {
+            let answer = self.ask(question, Some(&default.to_string()))?;
+            match answer.parse() {
+                Ok(value) => return Ok(value),
+                Err(_) => writeln!(self.output, "Enter a number")?,
+            }
+        }
+    }
+}
+
+/// Run `jito-bell init`
+///
+/// - Ask for the endpoint, channels (with live test sends) and pools to watch
+/// - Write the config to `config_path` and, optionally, a `.env` next to it
+pub async fn run(config_path: &Path, force: bool) -> Result<(), JitoBellError> {
+    check_overwrite(config_path, force)?;
+
+    let stdin = io::stdin();
+    let mut prompter = Prompter {
+        input: stdin.lock(),
+        output: io::stdout(),
+    };
+
+    writeln!(prompter.output, "jito-bell setup\n")?;
+
+    let endpoint = prompter.ask("Yellowstone gRPC / RPC endpoint", None)?;
+    let x_token = prompter.ask("x-token (leave empty if not required)", Some(""))?;
+
+    let mut answers = InitAnswers::default();
+
+    writeln!(prompter.output, "\nNotification channels")?;
+    if prompter.confirm("Configure Slack?", false)? {
+        let destination = InitDestination::Slack {
+            webhook_url: prompter.ask("  Slack webhook URL", None)?,
+            channel: prompter.ask("  Slack channel", Some("#jito-bell"))?,
+        };
+        answers.destinations.push(destination);
+    }
+    if prompter.confirm("Configure Discord?", false)? {
+        let destination = InitDestination::Discord {
+            webhook_url: prompter.ask("  Discord webhook URL", None)?,
+        };
+        answers.destinations.push(destination);
+    }
+    if prompter.confirm("Configure Telegram?", false)? {
+        let destination = InitDestination::Telegram {
+            bot_token: prompter.ask("  Telegram bot token", None)?,
+            chat_id: prompter.ask("  Telegram chat ID", None)?,
+        };
+        answers.destinations.push(destination);
+    }
+
+    let client = HttpTimeouts::default().shared_client()?;
+    for destination in answers.destinations.iter() {
+        let question = format!("Send a test message to {}?", destination.name());
+        if prompter.confirm(&question, true)? {
+            match destination.test_send(&client).await {
+                Ok(()) => writeln!(prompter.output, "  ok")?,
+                Err(e) => writeln!(
+                    prompter.output,
+                    "  failed: {e} (the config is written anyway, fix it before running)"
+                )?,
+            }
+        }
+    }
+
+    writeln!(prompter.output, "\nStake pools to watch")?;
+    loop {
+        let first = answers.pools.is_empty();
+        let stake_pool =
+            prompter.ask("  Stake pool address", first.then_some(DEFAULT_STAKE_POOL))?;
+        let pool_mint = prompter.ask("  Pool mint (LST)", first.then_some(DEFAULT_POOL_MINT))?;
+        let threshold = prompter.ask_f64("  Alert threshold (SOL)", 1000.0)?;
+        answers.pools.push(InitPool {
+            stake_pool,
+            pool_mint,
+            threshold,
+        });
+
+        if !prompter.confirm("Watch another pool?", false)? {
+            break;
+        }
+    }
+
+    let config = serde_yaml::to_string(&build_config(&answers))?;
+
+    write_config(config_path, &config)?;
+    writeln!(prompter.output, "\nWrote {}", config_path.display())?;
+
+    if prompter.confirm("Write ENDPOINT, X_TOKEN and CONFIG_FILE to .env?", true)? {
+        let mut env = format!(
+            "ENDPOINT={endpoint}\nCONFIG_FILE={}\n",
+            config_path.display()
+        );
+        if !x_token.is_empty() {
+            env.push_str(&format!("X_TOKEN={x_token}\n"));
+        }
+        fs::write(".env", env)?;
+        writeln!(prompter.output, "Wrote .env")?;
+    }
+
+    writeln!(prompter.output, "\nStart with: jito-bell")?;
+
+    Ok(())
+}
+
+/// Run `jito-bell init --template`, writing a built-in example instead of asking questions
+pub fn write_template(
+    config_path: &Path,
+    example: ExampleConfig,
+    force: bool,
+) -> Result<(), JitoBellError> {
+    check_overwrite(config_path, force)?;
+    write_config(config_path, &example.to_yaml()?)?;
+    println!(
+        "Wrote {}, fill in the channel credentials before running",
+        config_path.display()
+    );
+
+    Ok(())
+}
+
+fn check_overwrite(config_path: &Path, force: bool) -> Result<(), JitoBellError> {
+    if config_path.exists() && !force {
+        return Err(JitoBellError::Config(format!(
+            "{} already exists, pass --force to overwrite it",
+            config_path.display()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Write the YAML config, converted to TOML or JSON by the extension of `config_path`
+fn write_config(config_path: &Path, config: &str) -> Result<(), JitoBellError> {
+    let format = ConfigFormat::from_path(config_path);
+    let config = format.convert_yaml(config)?;
+
+    // Never write a config the service would reject
+    JitoBellConfig::parse(&config, format)?;
+
+    if let Some(parent) = config_path.parent() {
+        if !parent.as_os_str().is_empty() {
+            fs::create_dir_all(parent)?;
+        }
+    }
+    fs::write(config_path, config)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, path::Path};
+
+    use crate::{
+        config::{ConfigFormat, JitoBellConfig},
+        init::{build_config, InitAnswers, InitDestination, InitPool, Prompter},
+        notification_info::Destination,
+    };
+
+    fn answers() -> InitAnswers {
+        InitAnswers {
+            destinations: vec![
+                InitDestination::Discord {
+                    webhook_url: "https://discord.com/api/webhooks/1/token".to_string(),
+                },
+                InitDestination::Telegram {
+                    bot_token: "token".to_string(),
+                    chat_id: "-100".to_string(),
+                },
+            ],
+            pools: vec![InitPool {
+                stake_pool: "Jito4APyf642JPZPx3hGc6WWJ8zPKtRbRs4P815Awbb".to_string(),
+                pool_mint: "J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn".to_string(),
+                threshold: 500.0,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_build_config_is_valid() {
+        let yaml = serde_yaml::to_string(&build_config(&answers())).unwrap();
+        let config: JitoBellConfig = serde_yaml::from_str(&yaml).unwrap();
+
+        let instructions = &config.programs["spl_stake_pool"].instructions;
+        let deposit_sol = instructions["deposit_sol"].lsts.as_ref().unwrap();
+        let alert = &deposit_sol["J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn"];
+        assert_eq!(alert.thresholds[0].value, 500.0);
+        assert_eq!(
+            alert.thresholds[0].notification.destinations,
+            vec![Destination::from("discord"), Destination::from("telegram")]
+        );
+        assert!(config.notifications.telegram.is_some());
+        assert!(config.notifications.slack.is_none());
+    }
+
+    #[test]
+    fn test_config_formats() {
+        let yaml = serde_yaml::to_string(&build_config(&answers())).unwrap();
+
+        for format in [ConfigFormat::Toml, ConfigFormat::Json] {
+            let config_str = format.convert_yaml(&yaml).unwrap();
+            let config = JitoBellConfig::parse(&config_str, format).unwrap();
+
+            let instructions = &config.programs["spl_stake_pool"].instructions;
+            assert!(instructions["deposit_sol"].lsts.is_some());
+            assert!(config.notifications.telegram.is_some());
+        }
+
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("jito_bell_config.TOML")),
+            ConfigFormat::Toml
+        );
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("jito_bell_config.yml")),
+            ConfigFormat::Yaml
+        );
+    }
+
+    #[test]
+    fn test_prompter_defaults() {
+        let mut prompter = Prompter {
+            input: Cursor::new("\nvalue\n\nabc\n2.5\n"),
+            output: Vec::new(),
+        };
+
+        assert_eq!(prompter.ask("q", Some("default")).unwrap(), "default");
+        assert_eq!(prompter.ask("q", None).unwrap(), "value");
+        assert!(prompter.confirm("q", true).unwrap());
+        assert_eq!(prompter.ask_f64("q", 1.0).unwrap(), 2.5);
+    }
+}
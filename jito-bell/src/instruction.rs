@@ -1,10 +1,15 @@
 use std::collections::HashMap;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
 
-use crate::threshold_config::{ThresholdConfig, UsdThresholdConfig};
+use crate::{
+    digest::DigestConfig,
+    notification_info::NotificationInfo,
+    threshold_config::{LamportThresholdConfig, ThresholdConfig, UsdThresholdConfig},
+};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct AlertConfig {
     /// Thresholds (replaces the single threshold)
     #[serde(default)]
@@ -13,11 +18,30 @@ pub struct AlertConfig {
     /// Thresholds (replaces the single threshold)
     #[serde(default)]
     pub usd_thresholds: Vec<UsdThresholdConfig>,
+
+    /// Thresholds in lamports
+    #[serde(default)]
+    pub lamport_thresholds: Vec<LamportThresholdConfig>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+/// Argument of an IDL-decoded instruction compared against thresholds
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IdlAmountConfig {
+    /// Argument name, as written in the IDL
+    pub arg: String,
+
+    /// Decimals of the raw argument value
+    #[serde(default)]
+    pub decimals: u8,
+
+    /// Currency unit of the amount
+    #[serde(default)]
+    pub unit: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct Instruction {
-    /// Stake Pool
+    /// Stake Pool, also the only pools SPL stake pool instructions apply to when set
     pub stake_pools: Option<HashMap<String, AlertConfig>>,
 
     /// Pool mint token (LST)
@@ -25,4 +49,40 @@ pub struct Instruction {
 
     /// Vault receipt token (VRT)
     pub vrts: Option<HashMap<String, AlertConfig>>,
+
+    /// Watched accounts (e.g. the pool reserve or fee accounts)
+    pub accounts: Option<HashMap<String, AlertConfig>>,
+
+    /// Thresholds in lamports, independent of any address (e.g. tips paid by a transaction)
+    #[serde(default)]
+    pub lamport_thresholds: Vec<LamportThresholdConfig>,
+
+    /// Thresholds on the priority fee (lamports) of the transaction carrying the instruction
+    #[serde(default)]
+    pub priority_fee_thresholds: Vec<LamportThresholdConfig>,
+
+    /// Amount of an instruction decoded with an Anchor IDL
+    pub idl_amount: Option<IdlAmountConfig>,
+
+    /// Notifications sent whenever the instruction is seen (instructions without an amount)
+    #[serde(default)]
+    pub notifications: Vec<NotificationInfo>,
+
+    /// Also notify on failed transactions (`meta.err`), which are otherwise ignored
+    #[serde(default)]
+    pub notify_failed: bool,
+
+    /// Send one summary per window instead of one message per transaction
+    pub digest: Option<DigestConfig>,
+}
+
+impl Instruction {
+    /// Whether the instruction applies to a stake pool, one of `stake_pools` when set
+    pub fn applies_to_stake_pool(&self, stake_pool: Option<&Pubkey>) -> bool {
+        let Some(stake_pools) = &self.stake_pools else {
+            return true;
+        };
+
+        stake_pool.is_some_and(|stake_pool| stake_pools.contains_key(&stake_pool.to_string()))
+    }
 }
@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use yellowstone_grpc_proto::prelude::{SubscribeRequest, SubscribeRequestPing};
+
+/// Keepalive of the Geyser connection
+///
+/// - Pings of the endpoint are answered, and the subscription pings every `ping_interval_secs`
+///   so proxies and load balancers don't drop a quiet stream
+/// - HTTP/2 keepalive pings every `http2_interval_secs` and fail the connection when one is not
+///   acknowledged within `timeout_secs`, so a dead connection is reconnected instead of waiting
+///   forever
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct KeepaliveConfig {
+    /// Interval of subscription pings, 0 only answers the endpoint's pings
+    #[serde(default = "default_ping_interval_secs")]
+    pub ping_interval_secs: u64,
+
+    /// Interval of HTTP/2 keepalive pings
+    #[serde(default = "default_http2_interval_secs")]
+    pub http2_interval_secs: u64,
+
+    /// Timeout of HTTP/2 keepalive pings
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+
+    /// TCP keepalive interval
+    #[serde(default = "default_tcp_secs")]
+    pub tcp_secs: u64,
+}
+
+fn default_ping_interval_secs() -> u64 {
+    15
+}
+
+fn default_http2_interval_secs() -> u64 {
+    10
+}
+
+fn default_timeout_secs() -> u64 {
+    20
+}
+
+fn default_tcp_secs() -> u64 {
+    15
+}
+
+impl Default for KeepaliveConfig {
+    fn default() -> Self {
+        Self {
+            ping_interval_secs: default_ping_interval_secs(),
+            http2_interval_secs: default_http2_interval_secs(),
+            timeout_secs: default_timeout_secs(),
+            tcp_secs: default_tcp_secs(),
+        }
+    }
+}
+
+impl KeepaliveConfig {
+    /// Interval of subscription pings, None when disabled
+    pub fn ping_interval(&self) -> Option<Duration> {
+        (self.ping_interval_secs > 0).then(|| Duration::from_secs(self.ping_interval_secs))
+    }
+}
+
+/// Subscription request carrying only a ping, leaving the filters unchanged
+pub fn ping_request(id: i32) -> SubscribeRequest {
+    SubscribeRequest {
+        ping: Some(SubscribeRequestPing { id }),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::keepalive::{ping_request, KeepaliveConfig};
+
+    #[test]
+    fn test_ping_interval() {
+        assert_eq!(
+            KeepaliveConfig::default().ping_interval(),
+            Some(Duration::from_secs(15))
+        );
+
+        let config = KeepaliveConfig {
+            ping_interval_secs: 0,
+            ..Default::default()
+        };
+        assert_eq!(config.ping_interval(), None);
+    }
+
+    #[test]
+    fn test_ping_request() {
+        let request = ping_request(7);
+        assert_eq!(request.ping.map(|ping| ping.id), Some(7));
+        assert!(request.transactions.is_empty());
+        assert!(request.slots.is_empty());
+    }
+}
@@ -0,0 +1,323 @@
+use std::{
+    fs::{self, OpenOptions},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use chrono::Utc;
+use log::{error, info, warn};
+use redis::{aio::MultiplexedConnection, Client, Script};
+use serde::{Deserialize, Serialize};
+
+use crate::{config::LeaderElectionConfig, error::JitoBellError};
+
+/// Take the lease when free, extend it when held by this instance
+///
+/// - KEYS[1]: lease key, ARGV[1]: instance ID, ARGV[2]: lease duration in milliseconds
+/// - Returns 1 if this instance holds the lease afterwards
+const REDIS_ACQUIRE_SCRIPT: &str = r"
+local holder = redis.call('GET', KEYS[1])
+if holder == false then
+    redis.call('SET', KEYS[1], ARGV[1], 'PX', ARGV[2])
+    return 1
+elseif holder == ARGV[1] then
+    redis.call('PEXPIRE', KEYS[1], ARGV[2])
+    return 1
+end
+return 0
+";
+
+/// Lease stored on shared storage
+#[derive(Debug, Serialize, Deserialize)]
+struct Lease {
+    /// Instance holding the lease
+    holder: String,
+
+    /// Expiration time (unix milliseconds)
+    expires_at: i64,
+}
+
+/// Lease file on shared storage
+struct LeaseFile {
+    /// Lease file path
+    path: PathBuf,
+
+    /// Identity of this instance
+    instance_id: String,
+
+    /// Lease duration
+    duration: Duration,
+}
+
+impl LeaseFile {
+    /// Acquire or renew the lease
+    ///
+    /// - Return true if this instance holds the lease afterwards
+    /// - The read-modify-write is done under an exclusive lock of `<path>.lock`, so two replicas
+    ///   seeing an expired lease can't both take it
+    fn try_acquire(&self) -> Result<bool, JitoBellError> {
+        let mut lock_path = self.path.clone().into_os_string();
+        lock_path.push(".lock");
+        let lock = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(lock_path)?;
+        // Released when `lock` is dropped, or by the OS if the process dies
+        lock.lock()?;
+
+        let now = Utc::now().timestamp_millis();
+
+        if let Some(lease) = self.read()? {
+            if lease.holder != self.instance_id && lease.expires_at > now {
+                return Ok(false);
+            }
+        }
+
+        let lease = Lease {
+            holder: self.instance_id.clone(),
+            expires_at: now + self.duration.as_millis() as i64,
+        };
+        self.write(&lease)?;
+
+        Ok(true)
+    }
+
+    /// Read current lease, None if missing or unreadable
+    fn read(&self) -> Result<Option<Lease>, JitoBellError> {
+        match fs::read_to_string(&self.path) {
+            Ok(content) => Ok(serde_json::from_str(&content).ok()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Write lease atomically via a temporary file and rename
+    fn write(&self, lease: &Lease) -> Result<(), JitoBellError> {
+        let content = serde_json::to_string(lease)
+            .map_err(|e| JitoBellError::Config(format!("Failed to serialize lease: {e}")))?;
+
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(format!(".{}.tmp", self.instance_id));
+        let tmp_path = Path::new(&tmp_path);
+
+        fs::write(tmp_path, content)?;
+        fs::rename(tmp_path, &self.path)?;
+
+        Ok(())
+    }
+}
+
+/// Lease key in Redis
+struct RedisLease {
+    /// Redis client
+    client: Client,
+
+    /// Multiplexed connection, cheap to clone, opened again after an error
+    conn: Option<MultiplexedConnection>,
+
+    /// Lease key
+    key: String,
+
+    /// Identity of this instance
+    instance_id: String,
+
+    /// Lease duration
+    duration: Duration,
+}
+
+impl RedisLease {
+    /// Acquire or renew the lease atomically
+    ///
+    /// - Return true if this instance holds the lease afterwards
+    async fn try_acquire(&mut self) -> Result<bool, JitoBellError> {
+        let mut conn = match &self.conn {
+            Some(conn) => conn.clone(),
+            None => {
+                let conn = self
+                    .client
+                    .get_multiplexed_async_connection()
+                    .await
+                    .map_err(|e| {
+                        JitoBellError::Config(format!("Failed to connect to Redis: {e}"))
+                    })?;
+                self.conn = Some(conn.clone());
+                conn
+            }
+        };
+
+        let acquired: Result<i64, _> = Script::new(REDIS_ACQUIRE_SCRIPT)
+            .key(&self.key)
+            .arg(&self.instance_id)
+            .arg(self.duration.as_millis() as u64)
+            .invoke_async(&mut conn)
+            .await;
+
+        match acquired {
+            Ok(acquired) => Ok(acquired == 1),
+            Err(e) => {
+                self.conn = None;
+                Err(JitoBellError::Config(format!("Redis lease error: {e}")))
+            }
+        }
+    }
+}
+
+/// Lease competed for by the replicas
+enum LeaseStore {
+    /// Lease file on shared storage
+    File(LeaseFile),
+
+    /// Lease key in Redis
+    Redis(RedisLease),
+}
+
+impl LeaseStore {
+    /// Open the lease configured in `leader_election`
+    fn new(config: &LeaderElectionConfig, instance_id: String) -> Result<Self, JitoBellError> {
+        let duration = Duration::from_secs(config.lease_duration_secs);
+
+        match (&config.lease_path, &config.redis_url) {
+            (Some(path), None) => Ok(Self::File(LeaseFile {
+                path: path.clone(),
+                instance_id,
+                duration,
+            })),
+            (None, Some(redis_url)) => Ok(Self::Redis(RedisLease {
+                client: Client::open(redis_url.as_str())
+                    .map_err(|e| JitoBellError::Config(format!("Invalid Redis URL: {e}")))?,
+                conn: None,
+                key: config.redis_key.clone(),
+                instance_id,
+                duration,
+            })),
+            _ => Err(JitoBellError::Config(
+                "leader_election requires exactly one of lease_path and redis_url".to_string(),
+            )),
+        }
+    }
+
+    /// Acquire or renew the lease
+    ///
+    /// - Return true if this instance holds the lease afterwards
+    async fn try_acquire(&mut self) -> Result<bool, JitoBellError> {
+        match self {
+            Self::File(lease_file) => lease_file.try_acquire(),
+            Self::Redis(redis_lease) => redis_lease.try_acquire().await,
+        }
+    }
+}
+
+/// Leader Election
+///
+/// - Replicas compete for a lease, a file on shared storage or a Redis key, only the holder
+///   dispatches notifications
+/// - The lease is renewed in the background, a replica that fails to renew steps down before
+///   the lease expires for others
+/// - Leadership ends locally when the lease expires without a successful renewal, counted from
+///   before the renewal request, so a stalled replica doesn't send alongside the new leader
+pub struct LeaderElection {
+    /// End of the lease held by this instance (unix milliseconds), 0 when standing by
+    leader_until: Arc<AtomicI64>,
+}
+
+impl LeaderElection {
+    /// Initialize Leader Election and spawn the renewal task
+    pub fn new(config: &LeaderElectionConfig) -> Result<Self, JitoBellError> {
+        if config.renew_interval_secs >= config.lease_duration_secs {
+            return Err(JitoBellError::Config(
+                "leader_election.renew_interval_secs must be shorter than lease_duration_secs"
+                    .to_string(),
+            ));
+        }
+
+        let instance_id = config.instance_id.clone().unwrap_or_else(|| {
+            let hostname = std::env::var("HOSTNAME").unwrap_or_else(|_| "jito-bell".to_string());
+            format!("{hostname}-{}", std::process::id())
+        });
+
+        let mut lease = LeaseStore::new(config, instance_id.clone())?;
+        let duration_ms = Duration::from_secs(config.lease_duration_secs).as_millis() as i64;
+        let renew_interval = Duration::from_secs(config.renew_interval_secs);
+
+        let leader_until = Arc::new(AtomicI64::new(0));
+        let task_leader_until = leader_until.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(renew_interval);
+            let mut was_leader = false;
+            loop {
+                interval.tick().await;
+
+                let requested_at = Utc::now().timestamp_millis();
+                let acquired = match lease.try_acquire().await {
+                    Ok(acquired) => acquired,
+                    Err(e) => {
+                        error!("Failed to renew leader lease: {e}");
+                        false
+                    }
+                };
+                let until = if acquired {
+                    requested_at + duration_ms
+                } else {
+                    0
+                };
+                task_leader_until.store(until, Ordering::SeqCst);
+
+                match (was_leader, acquired) {
+                    (false, true) => info!("Became leader as {instance_id}"),
+                    (true, false) => warn!("Lost leadership, standing by"),
+                    _ => {}
+                }
+                was_leader = acquired;
+            }
+        });
+
+        Ok(Self { leader_until })
+    }
+
+    /// Whether this instance should dispatch notifications
+    pub fn is_leader(&self) -> bool {
+        Utc::now().timestamp_millis() < self.leader_until.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::leader::LeaseFile;
+
+    fn lease_file(path: &std::path::Path, instance_id: &str, duration: Duration) -> LeaseFile {
+        LeaseFile {
+            path: path.to_path_buf(),
+            instance_id: instance_id.to_string(),
+            duration,
+        }
+    }
+
+    #[test]
+    fn test_lease_file() {
+        let path =
+            std::env::temp_dir().join(format!("jito-bell-lease-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let a = lease_file(&path, "a", Duration::from_secs(60));
+        let b = lease_file(&path, "b", Duration::from_secs(60));
+        assert!(a.try_acquire().unwrap());
+        assert!(!b.try_acquire().unwrap());
+        assert!(a.try_acquire().unwrap());
+
+        let expired = lease_file(&path, "a", Duration::ZERO);
+        assert!(expired.try_acquire().unwrap());
+        assert!(b.try_acquire().unwrap());
+        assert!(!a.try_acquire().unwrap());
+
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(path.with_extension("json.lock"));
+    }
+}
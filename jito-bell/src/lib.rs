@@ -1,65 +1,374 @@
-use std::{collections::HashMap, path::PathBuf, str::FromStr};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap, HashSet},
+    panic::AssertUnwindSafe,
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime},
+};
 
+use admin::AdminCommand;
+use api::ApiState;
 use borsh::BorshDeserialize;
-use defillama_rs::{
-    models::{Chain, Token},
-    DefiLlamaClient,
-};
+use circuit_breaker::{CircuitBreakers, CircuitTransition};
+use dead_letter::DeadLetter;
+use digest::{DigestConfig, InstructionDigests};
+use dynamic_threshold::DynamicThresholds;
+use enrichment::{EnrichmentScheduler, VrtEnrichment};
 use error::JitoBellError;
-use futures::{sink::SinkExt, stream::StreamExt};
+use failover::{EndpointRotation, Failover, GeyserEndpointConfig};
+use futures::{future::join_all, sink::SinkExt, FutureExt};
+use geyser_compat::UpdateMonitor;
+use health::Health;
 use instruction::Instruction;
-use jito_vault_client::accounts::Vault;
-use log::{debug, error};
+use jito_vault_client::accounts::{Vault, VaultStakerWithdrawalTicket};
+use leader::LeaderElection;
+use log::{debug, error, info, warn};
 use maplit::hashmap;
 use metrics::EpochMetrics;
+use notification_config::SyslogSeverity;
+use notification_event::{NotificationEvent, SolFlow};
+use notification_info::{
+    Destination, DiscordDeliveryOptions, NotificationInfo, Severity, SlackDeliveryOptions,
+    SyslogDeliveryOptions, TelegramChat, TelegramDeliveryOptions, WebhookDeliveryOptions,
+};
+use notifier::{
+    discord_bot,
+    file::{FileNotifier, TransactionRecord},
+    irc::IrcNotifier,
+    mqtt::MqttNotifier,
+    nostr::NostrNotifier,
+    push::PushNotifier,
+    redis::RedisNotifier,
+    slack_bot::{self, SlackBotClient},
+    stdout::StdoutNotifier,
+    syslog::SyslogNotifier,
+    twitter,
+    webhook::WebhookNotifier,
+    websocket::WebSocketBroadcaster,
+    xmpp::XmppNotifier,
+};
+use ordered_delivery::{OrderedDelivery, PendingNotification, PushOutcome};
+use outbox::OutboxEntry;
 use parser::{
-    stake_pool::SplStakePoolProgram, token_2022::SplToken2022Program, vault::JitoVaultProgram,
-    JitoBellProgram, JitoTransactionParser,
+    anchor_idl::{AnchorIdlParser, AnchorIdlProgram},
+    debridge::DebridgeDlnSourceProgram,
+    governance::SplGovernanceProgram,
+    jupiter::JupiterProgram,
+    kamino::KaminoLendProgram,
+    marginfi::MarginfiProgram,
+    marinade::MarinadeProgram,
+    orca::OrcaWhirlpoolProgram,
+    raydium::RaydiumCpmmProgram,
+    single_pool::SplSinglePoolProgram,
+    squads::SquadsMultisigProgram,
+    stake_pool::SplStakePoolProgram,
+    steward::JitoStewardProgram,
+    system::SystemProgram,
+    tip_distribution::JitoTipDistributionProgram,
+    tip_payment::JitoTipPaymentProgram,
+    tip_router::JitoTipRouterProgram,
+    token_2022::SplToken2022Program,
+    vault::JitoVaultProgram,
+    vote::VoteProgram,
+    wormhole::WormholeTokenBridgeProgram,
+    JitoBellProgram, JitoTransactionParser, LiquidityLeg,
 };
+use rate_limit::RateLimiters;
+use receipt::ReceiptStatus;
+use recent::{RecentEvent, RecentEvents};
+use schedule::{HeldAlerts, QuietHoursDigests, TimeOfDay};
+use sharding::Shard;
+use shutdown::ShutdownSignal;
+use slot_gap::SlotMonitor;
 use solana_metrics::datapoint_info;
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{
-    clock::DEFAULT_SLOTS_PER_EPOCH, commitment_config::CommitmentConfig, program_pack::Pack,
+    clock::DEFAULT_SLOTS_PER_EPOCH,
+    commitment_config::CommitmentConfig,
+    native_token::{sol_to_lamports, LAMPORTS_PER_SOL},
     pubkey::Pubkey,
 };
-use spl_token::state::Mint;
+use stake_pool_fee::StakePoolFees;
+use store::EventStore;
 use subscribe_option::SubscribeOption;
-use threshold_config::ThresholdConfig;
+use supervisor::{Supervisor, TaskRestart};
+use suppression::Suppressions;
+use threshold_config::{LamportThresholdConfig, ThresholdConfig};
+use token_extensions::Token2022Mints;
+use tokio::sync::{
+    mpsc::{Receiver, UnboundedReceiver},
+    Mutex,
+};
+use tracing::Instrument;
 use twitterust::{TwitterClient, TwitterCredentials};
+use update_queue::StreamItem;
+use validator_list::{PoolValidators, ValidatorListMonitor};
 use yellowstone_grpc_client::GeyserGrpcClient;
 use yellowstone_grpc_proto::{
-    geyser::SubscribeRequestFilterSlots,
+    geyser::{SubscribeRequestFilterBlocksMeta, SubscribeRequestFilterSlots},
     prelude::{
         subscribe_update::UpdateOneof, SubscribeRequest, SubscribeRequestFilterTransactions,
+        SubscribeUpdate, SubscribeUpdateTransaction,
     },
     tonic::transport::ClientTlsConfig,
 };
 
 use crate::config::JitoBellConfig;
 
+pub mod admin;
+pub mod api;
+pub mod circuit_breaker;
 pub mod config;
+pub mod config_include;
+pub mod config_validation;
+pub mod dead_letter;
+pub mod digest;
+pub mod dynamic_threshold;
+pub mod enrichment;
+pub mod env_substitution;
 mod error;
+pub mod examples;
+pub mod failover;
+pub mod geyser_compat;
+pub mod health;
+pub mod http_timeout;
+pub mod init;
 pub mod instruction;
+pub mod keepalive;
+pub mod leader;
+pub mod locale;
+pub mod logging;
 mod metrics;
 pub mod multi_writer;
 pub mod notification_config;
+pub mod notification_event;
 pub mod notification_info;
+pub mod notifier;
+pub mod ordered_delivery;
+pub mod otlp;
+pub mod outbox;
 pub mod parser;
+pub mod pipeline_trace;
 pub mod program;
+pub mod rate_limit;
+pub mod receipt;
+pub mod recent;
+pub mod retry;
+pub mod schedule;
+pub mod schema;
+pub mod secrets;
+pub mod sharding;
+pub mod shutdown;
+pub mod slot_gap;
+pub mod stake_pool_fee;
+pub mod startup_check;
+pub mod store;
 pub mod subscribe_option;
+pub mod supervisor;
+pub mod suppression;
 pub mod threshold_config;
+pub mod timezone;
+pub mod token_extensions;
+pub mod update_queue;
+pub mod validator_list;
 
 pub const DEFAULT_VRT_SYMBOL: &str = "VRT";
 
+/// Default number of recent events kept in memory
+pub const DEFAULT_RECENT_CAPACITY: usize = 100;
+
+/// Default latency budget of RPC enrichment per event
+pub const DEFAULT_ENRICHMENT_DEADLINE_MS: u64 = 1_500;
+
+/// Time to live of cached stake pool validator lists
+const POOL_VALIDATORS_TTL_SECS: u64 = 600;
+
+/// Interval to check whether scheduled reports are due
+const REPORT_CHECK_INTERVAL_SECS: u64 = 60;
+
+/// Interval to check the configuration file for changes
+const CONFIG_RELOAD_CHECK_INTERVAL_SECS: u64 = 5;
+
+/// Interval to check whether rate limited destinations can send their digest
+const RATE_LIMIT_CHECK_INTERVAL_SECS: u64 = 1;
+
+/// Interval to check whether instruction digest windows ended
+const DIGEST_CHECK_INTERVAL_SECS: u64 = 5;
+
+/// Interval to check the Geyser stream for stalls
+const STALL_CHECK_INTERVAL_SECS: u64 = 5;
+
+/// Interval to report the depth of the update queue
+const UPDATE_QUEUE_REPORT_INTERVAL_SECS: u64 = 10;
+
+/// Interval to retry notifications left in the outbox
+const OUTBOX_RETRY_INTERVAL_SECS: u64 = 30;
+
+/// Notifications retried from the outbox per interval
+const OUTBOX_RETRY_BATCH: u32 = 100;
+
+/// Time given to in-flight notifications on shutdown
+const SHUTDOWN_DRAIN_TIMEOUT_SECS: u64 = 25;
+
+/// Reason of alerts kept across a restart, held for quiet hours
+const HELD_FOR_QUIET_HOURS: &str = "quiet_hours";
+
+/// Reason of alerts kept across a restart, held by a rate limit
+const HELD_BY_RATE_LIMIT: &str = "rate_limit";
+
+/// Block times kept for `{{block_time}}`, the most recent slots
+const MAX_BLOCK_TIMES: usize = 512;
+
+/// Discord message flag: do not include any embeds when serializing this message
+const DISCORD_SUPPRESS_EMBEDS_FLAG: u64 = 1 << 2;
+
+/// Discord message flag: do not trigger push and desktop notifications
+const DISCORD_SUPPRESS_NOTIFICATIONS_FLAG: u64 = 1 << 12;
+
+/// How a Geyser subscription ended without error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StreamEnd {
+    /// The stream ended, reconnect
+    Ended,
+
+    /// SIGINT or SIGTERM was received
+    Shutdown,
+}
+
 pub struct JitoBellHandler {
     /// Configuration for Notification
     pub config: JitoBellConfig,
 
+    /// Path of the configuration file, reloaded when it changes
+    config_path: PathBuf,
+
+    /// Latest modification time of the loaded configuration file and its includes
+    config_modified: Option<SystemTime>,
+
     /// RPC Client
     pub rpc_client: RpcClient,
 
+    /// HTTP client shared by the notifiers
+    http_client: reqwest::Client,
+
     /// Epoch Metrics
     epoch_metrics: EpochMetrics,
+
+    /// MQTT Notifier
+    mqtt_notifier: Option<MqttNotifier>,
+
+    /// XMPP Notifier
+    xmpp_notifier: Option<XmppNotifier>,
+
+    /// IRC Notifier
+    irc_notifier: Option<IrcNotifier>,
+
+    /// Redis Notifier
+    redis_notifier: Option<RedisNotifier>,
+
+    /// Mobile Push Notifier
+    push_notifier: Option<PushNotifier>,
+
+    /// Nostr Notifier
+    nostr_notifier: Option<NostrNotifier>,
+
+    /// Webhook Notifier
+    webhook_notifier: Option<WebhookNotifier>,
+
+    /// Shard of this instance
+    shard: Option<Shard>,
+
+    /// Leader election, only the leader dispatches notifications
+    leader_election: Option<LeaderElection>,
+
+    /// Enrichment Scheduler
+    enrichment_scheduler: EnrichmentScheduler,
+
+    /// Syslog Notifier
+    syslog_notifier: Option<SyslogNotifier>,
+
+    /// File Notifier
+    file_notifier: Option<FileNotifier>,
+
+    /// Stdout Notifier
+    stdout_notifier: Option<StdoutNotifier>,
+
+    /// WebSocket Broadcaster
+    websocket_broadcaster: Option<WebSocketBroadcaster>,
+
+    /// Event Store
+    store: Option<Arc<EventStore>>,
+
+    /// Dynamic Thresholds, requires the event store
+    dynamic_thresholds: Option<DynamicThresholds>,
+
+    /// Recent Events
+    recent: Arc<RecentEvents>,
+
+    /// Suppression list, pools muted from Discord
+    suppressions: Arc<Suppressions>,
+
+    /// Validator List Monitor for scheduled diff reports
+    validator_list_monitor: ValidatorListMonitor,
+
+    /// Ordered Delivery buffer
+    ordered_delivery: Option<OrderedDelivery>,
+
+    /// Programs parsed as SPL stake pools besides the canonical one
+    stake_pool_program_ids: Vec<Pubkey>,
+
+    /// Programs parsed with their Anchor IDL
+    idl_parsers: Vec<AnchorIdlParser>,
+
+    /// Validator lists of stake pools watched for commission changes
+    pool_validators: PoolValidators,
+
+    /// Last known fees of stake pools watched for fee changes
+    stake_pool_fees: StakePoolFees,
+
+    /// Realm of each governance seen executing a proposal
+    governance_realms: HashMap<Pubkey, Pubkey>,
+
+    /// Transfer extensions of Token-2022 mints seen in transfers
+    token_2022_mints: Token2022Mints,
+
+    /// Block time (unix timestamp) of recent slots, from block meta updates
+    block_times: BTreeMap<u64, i64>,
+
+    /// Program and instruction name being handled, set on the events it dispatches
+    current_instruction: Option<(String, String)>,
+
+    /// Alerts held during quiet hours, sent as a digest when the window ends
+    quiet_hours_digests: QuietHoursDigests,
+
+    /// Rate limiters per destination, alerts beyond the limit are sent as a digest
+    rate_limiters: RateLimiters,
+
+    /// Events of instructions in digest mode, aggregated until their window ends
+    instruction_digests: InstructionDigests,
+
+    /// Circuit breakers per destination
+    circuit_breakers: CircuitBreakers,
+
+    /// Circuit openings and closings not alerted yet
+    circuit_transitions: Vec<(String, CircuitTransition)>,
+
+    /// Latest slot and update time of the Geyser stream, kept across reconnections
+    slot_monitor: SlotMonitor,
+
+    /// Stream liveness and circuit states served at GET /healthz and GET /readyz
+    health: Arc<Health>,
+
+    /// Supervisor of the API servers
+    supervisor: Supervisor,
+
+    /// Restarts of supervised tasks, kept across a panic of the subscription
+    task_restarts: Arc<Mutex<UnboundedReceiver<TaskRestart>>>,
+
+    /// Commands from the admin API, kept across a panic of the subscription
+    admin_commands: Option<Arc<Mutex<Receiver<AdminCommand>>>>,
 }
 
 impl JitoBellHandler {
@@ -68,20 +377,237 @@ impl JitoBellHandler {
         endpoint: String,
         commitment: CommitmentConfig,
         config_path: PathBuf,
+        http_client: reqwest::Client,
     ) -> Result<Self, JitoBellError> {
-        let config_str = std::fs::read_to_string(&config_path).map_err(JitoBellError::Io)?;
-
-        let config: JitoBellConfig = serde_yaml::from_str(&config_str)?;
+        let config = JitoBellConfig::load_with_secrets(&config_path, &http_client).await?;
+        let config_modified = config.modified().ok();
         let rpc_client = RpcClient::new_with_commitment(endpoint.to_string(), commitment);
+        let (supervisor, task_restarts) = Supervisor::new(config.supervisor.restart);
 
         let epoch = rpc_client.get_epoch_info().await?;
         let epoch_metrics = EpochMetrics::new(epoch.epoch);
 
-        Ok(Self {
+        let mqtt_notifier = match &config.notifications.mqtt {
+            Some(mqtt_config) => Some(MqttNotifier::new(mqtt_config)?),
+            None => None,
+        };
+
+        let xmpp_notifier = match &config.notifications.xmpp {
+            Some(xmpp_config) => Some(XmppNotifier::new(xmpp_config)?),
+            None => None,
+        };
+
+        let irc_notifier = config.notifications.irc.as_ref().map(IrcNotifier::new);
+
+        let redis_notifier = match &config.notifications.redis {
+            Some(redis_config) => Some(RedisNotifier::new(redis_config).await?),
+            None => None,
+        };
+
+        let push_notifier = match &config.notifications.push {
+            Some(push_config) => Some(PushNotifier::new(
+                push_config,
+                config.retry,
+                http_client.clone(),
+                config.http_timeouts,
+            )?),
+            None => None,
+        };
+
+        let nostr_notifier = match &config.notifications.nostr {
+            Some(nostr_config) => Some(NostrNotifier::new(nostr_config)?),
+            None => None,
+        };
+
+        let webhook_notifier = config.notifications.webhook.as_ref().map(|webhook_config| {
+            WebhookNotifier::new(webhook_config, config.retry, http_client.clone())
+        });
+
+        let shard = match &config.sharding {
+            Some(sharding_config) => Some(Shard::new(sharding_config)?),
+            None => None,
+        };
+
+        let leader_election = match &config.leader_election {
+            Some(leader_election_config) => Some(LeaderElection::new(leader_election_config)?),
+            None => None,
+        };
+
+        let mints = config
+            .mints
+            .iter()
+            .filter_map(|(mint, mint_config)| {
+                Pubkey::from_str(mint)
+                    .ok()
+                    .map(|mint| (mint, mint_config.clone()))
+            })
+            .collect();
+        let enrichment_scheduler = EnrichmentScheduler::new(
+            Duration::from_millis(
+                config
+                    .enrichment
+                    .as_ref()
+                    .map_or(DEFAULT_ENRICHMENT_DEADLINE_MS, |enrichment_config| {
+                        enrichment_config.deadline_ms
+                    }),
+            ),
+            mints,
+        );
+
+        let syslog_notifier = match &config.notifications.syslog {
+            Some(syslog_config) => Some(SyslogNotifier::new(syslog_config).await?),
+            None => None,
+        };
+
+        let file_notifier = match &config.notifications.file {
+            Some(file_config) => Some(FileNotifier::new(file_config)?),
+            None => None,
+        };
+
+        let stdout_notifier = config
+            .notifications
+            .stdout
+            .as_ref()
+            .map(StdoutNotifier::new);
+
+        let websocket_broadcaster = match &config.notifications.websocket {
+            Some(websocket_config) => Some(WebSocketBroadcaster::new(websocket_config).await?),
+            None => None,
+        };
+
+        let store = match &config.store {
+            Some(store_config) => Some(Arc::new(EventStore::open(&store_config.path)?)),
+            None => None,
+        };
+
+        let limits = config.limits.clone().unwrap_or_default();
+
+        let dynamic_thresholds = store
+            .clone()
+            .map(|store| DynamicThresholds::new(store, limits.max_median_cache_entries));
+
+        let recent = Arc::new(RecentEvents::new(
+            config
+                .recent
+                .as_ref()
+                .map_or(DEFAULT_RECENT_CAPACITY, |recent_config| {
+                    recent_config.capacity
+                }),
+        ));
+
+        let validator_list_monitor = ValidatorListMonitor::new(&config.validator_list_reports)?;
+
+        let ordered_delivery = config
+            .ordered_delivery
+            .as_ref()
+            .map(|ordered_delivery_config| {
+                OrderedDelivery::new(
+                    ordered_delivery_config.hold_slots,
+                    limits.max_pending_notifications,
+                    limits.max_dedup_entries,
+                )
+            });
+
+        let stake_pool_program_ids = config.stake_pool_program_ids()?;
+        let idl_parsers = config.anchor_idl_parsers()?;
+
+        // Remember current fees so that immediate fee changes can report the old fee
+        let mut stake_pool_fees = StakePoolFees::default();
+        for stake_pool in config.set_fee_stake_pools()? {
+            if let Err(e) = stake_pool_fees.refresh(&rpc_client, &stake_pool).await {
+                warn!("Failed to fetch fees of stake pool {stake_pool}: {e}");
+            }
+        }
+
+        let mut handler = Self {
             config,
+            config_path,
+            config_modified,
             rpc_client,
+            http_client,
             epoch_metrics,
-        })
+            mqtt_notifier,
+            xmpp_notifier,
+            irc_notifier,
+            redis_notifier,
+            push_notifier,
+            nostr_notifier,
+            webhook_notifier,
+            shard,
+            leader_election,
+            enrichment_scheduler,
+            syslog_notifier,
+            file_notifier,
+            stdout_notifier,
+            websocket_broadcaster,
+            store,
+            dynamic_thresholds,
+            recent,
+            suppressions: Arc::new(Suppressions::default()),
+            validator_list_monitor,
+            ordered_delivery,
+            stake_pool_program_ids,
+            idl_parsers,
+            pool_validators: PoolValidators::new(Duration::from_secs(POOL_VALIDATORS_TTL_SECS)),
+            stake_pool_fees,
+            governance_realms: HashMap::new(),
+            token_2022_mints: Token2022Mints::default(),
+            block_times: BTreeMap::new(),
+            current_instruction: None,
+            quiet_hours_digests: QuietHoursDigests::default(),
+            rate_limiters: RateLimiters::default(),
+            instruction_digests: InstructionDigests::default(),
+            circuit_breakers: CircuitBreakers::default(),
+            circuit_transitions: Vec::new(),
+            slot_monitor: SlotMonitor::default(),
+            health: Arc::new(Health::default()),
+            supervisor,
+            task_restarts: Arc::new(Mutex::new(task_restarts)),
+            admin_commands: None,
+        };
+        handler.restore_held_alerts();
+
+        Ok(handler)
+    }
+
+    /// Start REST API server if configured
+    pub async fn start_api_server(&self) -> Result<(), JitoBellError> {
+        if let Some(api_config) = &self.config.api {
+            let state = ApiState {
+                store: self.store.clone(),
+                recent: self.recent.clone(),
+                suppressions: self.suppressions.clone(),
+                discord_public_key: self
+                    .config
+                    .notifications
+                    .discord_bot
+                    .as_ref()
+                    .and_then(|discord_bot_config| discord_bot_config.public_key.clone()),
+                health: self.health.clone(),
+                health_config: self.config.health,
+            };
+            api::serve(&api_config.bind_address, state, &self.supervisor).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Start admin API server if configured
+    ///
+    /// - Threshold changes and instruction mutes apply without restarting the subscription
+    pub async fn start_admin_server(&mut self) -> Result<(), JitoBellError> {
+        if let Some(admin_config) = &self.config.admin {
+            let admin_commands = admin::serve(
+                &admin_config.bind_address,
+                &admin_config.token,
+                self.suppressions.clone(),
+                &self.supervisor,
+            )
+            .await?;
+            self.admin_commands = Some(Arc::new(Mutex::new(admin_commands)));
+        }
+
+        Ok(())
     }
 
     /// Sort thresholds
@@ -95,53 +621,387 @@ impl JitoBellHandler {
         });
     }
 
-    /// Get divisor
+    /// Resolve thresholds of an instruction
     ///
-    /// - Fetch Mint account to get decimals value, if fails return default 9
-    pub async fn divisor(&self, vrt: &Pubkey) -> f64 {
-        let decimals = match self.rpc_client.get_account(vrt).await {
-            Ok(mint_acc) => match Mint::unpack(&mint_acc.data) {
-                Ok(acc) => acc.decimals,
-                Err(_) => 9,
-            },
-            Err(_e) => 9,
+    /// - Record the observed amount for dynamic thresholds
+    /// - Replace `median_multiple` thresholds with their effective value
+    /// - Record the amount relative to each threshold, for destination threshold multipliers
+    /// - Sort values from high to low
+    fn resolve_thresholds(
+        &mut self,
+        instruction: &str,
+        address: &str,
+        amount: f64,
+        thresholds: &[ThresholdConfig],
+    ) -> Vec<ThresholdConfig> {
+        let mut thresholds = thresholds.to_vec();
+
+        if let Some(dynamic_thresholds) = &mut self.dynamic_thresholds {
+            dynamic_thresholds.observe(instruction, address, amount);
+
+            if thresholds
+                .iter()
+                .any(|threshold| threshold.median_multiple.is_some())
+            {
+                let median = dynamic_thresholds.median(instruction, address);
+                for threshold in thresholds.iter_mut() {
+                    threshold.value = dynamic_threshold::effective_value(threshold, median);
+                }
+            }
+        }
+
+        for threshold in thresholds.iter_mut() {
+            threshold.notification.threshold_ratio = Some(amount / threshold.value);
+        }
+
+        self.sort_thresholds(&mut thresholds);
+        thresholds
+    }
+
+    /// Start heart beating
+    ///
+    /// - The subscription is re-established with exponential backoff when the stream fails or
+    ///   ends (`reconnect`)
+    /// - With `failover`, the next endpoint is used after repeated failures and the switch is
+    ///   alerted
+    /// - Incompatible endpoints fail immediately unless there is another endpoint, other failures
+    ///   once `reconnect.max_attempts` consecutive attempts failed
+    /// - A panic of the subscription or of dispatching an update is reconnected the same way, and
+    ///   alerted like a supervised task restart (`supervisor`)
+    /// - Returns Ok on SIGINT or SIGTERM once the in-flight notifications are drained
+    pub async fn heart_beat(
+        &mut self,
+        subscribe_option: &SubscribeOption,
+    ) -> Result<(), JitoBellError> {
+        let primary = GeyserEndpointConfig {
+            endpoint: subscribe_option.endpoint.clone(),
+            x_token: subscribe_option.x_token.clone(),
+            priority: 0,
+        };
+        let mut rotation = EndpointRotation::new(primary, self.config.failover.as_ref());
+        let mut shutdown = ShutdownSignal::listen()?;
+
+        let mut attempt = 0;
+        loop {
+            let endpoint = rotation.current().clone();
+            let mut received_updates = false;
+            let subscription = AssertUnwindSafe(self.subscribe(
+                subscribe_option,
+                &endpoint,
+                &mut shutdown,
+                &mut received_updates,
+            ))
+            .catch_unwind()
+            .await;
+            let (result, panic) = match subscription {
+                Ok(result) => (result, None),
+                Err(payload) => {
+                    let panic = supervisor::panic_message(&*payload);
+                    let error =
+                        JitoBellError::Subscription(format!("Subscription panicked: {panic}"));
+                    (Err(error), Some(panic))
+                }
+            };
+            if matches!(result, Ok(StreamEnd::Shutdown)) {
+                self.drain().await;
+                return Ok(());
+            }
+
+            if received_updates {
+                attempt = 0;
+                rotation.record_success();
+            }
+            attempt += 1;
+
+            let failover = if matches!(result, Err(JitoBellError::GeyserCompatibility(_))) {
+                match rotation.switch() {
+                    Some(failover) => Some(failover),
+                    None => return result.map(|_| ()),
+                }
+            } else {
+                rotation.record_failure()
+            };
+            if let Some(failover) = failover {
+                self.send_failover_alert(&failover).await;
+            }
+
+            let reconnect = self.config.reconnect;
+            if reconnect.gives_up(attempt) {
+                return Err(result.err().unwrap_or_else(|| {
+                    JitoBellError::Subscription("Geyser stream ended".to_string())
+                }));
+            }
+
+            let delay = reconnect.delay(attempt);
+            match result {
+                Ok(_) => warn!(
+                    "Geyser stream ended, reconnecting in {}ms (attempt {attempt})",
+                    delay.as_millis()
+                ),
+                Err(e) => error!(
+                    "Geyser subscription failed: {e}, reconnecting in {}ms (attempt {attempt})",
+                    delay.as_millis()
+                ),
+            }
+            datapoint_info!("jito-bell-reconnect", ("attempt", attempt, i64));
+            if let Some(panic) = panic {
+                let restart = TaskRestart {
+                    task: "subscription",
+                    reason: format!("panicked: {panic}"),
+                    attempt,
+                    delay,
+                };
+                self.send_restart_alert(&restart).await;
+            }
+            tokio::select! {
+                _ = tokio::time::sleep(delay) => {}
+                signal = shutdown.recv() => {
+                    info!("Received {signal} while reconnecting, shutting down");
+                    self.drain().await;
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Deliver the notifications in flight before exiting
+    ///
+    /// - Held ordered notifications are released, open instruction digests and the due quiet
+    ///   hours and rate limit digests sent, and the outbox retried once, within
+    ///   `SHUTDOWN_DRAIN_TIMEOUT_SECS`
+    /// - Notifications not delivered in time stay in the outbox, if enabled, for the next run
+    /// - Alerts still held for quiet hours and rate limits are kept in the store, if enabled, and
+    ///   held again on the next start, otherwise dropped
+    async fn drain(&mut self) {
+        let drained =
+            tokio::time::timeout(Duration::from_secs(SHUTDOWN_DRAIN_TIMEOUT_SECS), async {
+                self.release_ordered_notifications(u64::MAX).await;
+                self.send_instruction_digests(true).await;
+                self.send_quiet_hours_digests().await;
+                self.send_rate_limit_digests().await;
+                self.retry_outbox().await;
+            })
+            .await;
+
+        match drained {
+            Ok(()) => info!("Drained in-flight notifications"),
+            Err(_) => warn!(
+                "Timed out draining in-flight notifications after {SHUTDOWN_DRAIN_TIMEOUT_SECS}s"
+            ),
+        }
+
+        self.keep_held_alerts();
+    }
+
+    /// Keep the alerts still held for quiet hours and rate limits in the store for the next run
+    ///
+    /// - Dropped without a store
+    fn keep_held_alerts(&mut self) {
+        let held = self.quiet_hours_digests.len() + self.rate_limiters.len();
+        if held == 0 {
+            return;
+        }
+        let Some(store) = &self.store else {
+            warn!("Dropping {held} alerts held for quiet hours and rate limits, no store to keep them");
+            return;
+        };
+
+        let quiet_hours = self
+            .quiet_hours_digests
+            .take_all()
+            .into_iter()
+            .map(|(destination, alerts)| (HELD_FOR_QUIET_HOURS, destination, alerts));
+        let rate_limits = self
+            .rate_limiters
+            .take_all()
+            .into_iter()
+            .map(|(destination, alerts)| (HELD_BY_RATE_LIMIT, destination, alerts));
+
+        let mut dropped = 0;
+        for (reason, destination, alerts) in quiet_hours.chain(rate_limits) {
+            let kept = serde_json::to_string(&alerts)
+                .map_err(|e| JitoBellError::Notification(e.to_string()))
+                .and_then(|payload| store.keep_held_alerts(reason, &destination, &payload));
+            if let Err(e) = kept {
+                error!("Failed to keep alerts held for {destination}: {e}");
+                dropped += alerts.count();
+            }
+        }
+
+        if dropped > 0 {
+            warn!("Dropping {dropped} alerts held for quiet hours and rate limits, not kept");
+        }
+        info!(
+            "Kept {} alerts held for quiet hours and rate limits for the next run",
+            held - dropped
+        );
+    }
+
+    /// Hold the alerts kept by the previous run again, their digests are sent once due
+    fn restore_held_alerts(&mut self) {
+        let Some(store) = &self.store else {
+            return;
         };
 
-        10_f64.powi(decimals as i32)
+        let records = match store.take_held_alerts() {
+            Ok(records) => records,
+            Err(e) => {
+                error!("Failed to load alerts held by the previous run: {e}");
+                return;
+            }
+        };
+
+        for record in records {
+            let alerts: HeldAlerts = match serde_json::from_str(&record.payload) {
+                Ok(alerts) => alerts,
+                Err(e) => {
+                    error!(
+                        "Failed to parse alerts held for {} by the previous run: {e}",
+                        record.destination
+                    );
+                    continue;
+                }
+            };
+            match record.reason.as_str() {
+                HELD_FOR_QUIET_HOURS => self
+                    .quiet_hours_digests
+                    .restore(&record.destination, alerts),
+                HELD_BY_RATE_LIMIT => self.rate_limiters.restore(&record.destination, alerts),
+                reason => error!("Unknown reason of held alerts: {reason}"),
+            }
+        }
+
+        let held = self.quiet_hours_digests.len() + self.rate_limiters.len();
+        if held > 0 {
+            info!("Holding {held} alerts kept by the previous run");
+        }
     }
 
-    /// Get VRT Symbol
+    /// Check the configured destinations (`startup_check`)
     ///
-    /// - Fetch Metadata account to get symbol value, if fails return default "VRT"
-    pub async fn vrt_symbol(&self, vrt: &Pubkey) -> String {
-        let meta_pubkey =
-            jito_vault_sdk::inline_mpl_token_metadata::pda::find_metadata_account(vrt).0;
-        let symbol = match self.rpc_client.get_account(&meta_pubkey).await {
-            Ok(meta_acc) => {
-                match jito_vault_client::log::metadata::Metadata::deserialize(
-                    &mut meta_acc.data.as_slice(),
-                ) {
-                    Ok(meta) => meta.symbol,
-                    Err(_e) => DEFAULT_VRT_SYMBOL.to_string(),
+    /// - Credentials and reachability are probed where the destination has an API for it, and
+    ///   with `test_message` a test notification is sent through the normal delivery path
+    /// - Failures are logged, and fail startup with `fail_fast`
+    pub async fn check_destinations(&mut self) -> Result<(), JitoBellError> {
+        let Some(startup_check) = self.config.startup_check.clone() else {
+            return Ok(());
+        };
+
+        let mut failed = Vec::new();
+        for destination in self.config.notifications.configured() {
+            let mut result =
+                startup_check::probe(&self.http_client, &self.config.notifications, destination)
+                    .await;
+            if startup_check.test_message && result.is_ok() {
+                let notification = NotificationInfo {
+                    description: "Jito Bell startup check".to_string(),
+                    ..Default::default()
+                };
+                let mut event = NotificationEvent::scheduled(
+                    &notification,
+                    notification.description.clone(),
+                    "",
+                    0.0,
+                    "",
+                );
+                event.timezone = self.config.timezone;
+                result = self
+                    .send_to_destination(destination, &notification, &event)
+                    .await
+                    .map(|()| Some("test notification sent".to_string()));
+            }
+
+            match result {
+                Ok(Some(checked)) => info!("Destination {destination} is OK: {checked}"),
+                Ok(None) => info!("Destination {destination} has no startup check"),
+                Err(e) => {
+                    error!("Destination {destination} failed the startup check: {e}");
+                    failed.push(destination);
                 }
             }
-            Err(_e) => DEFAULT_VRT_SYMBOL.to_string(),
+        }
+
+        if startup_check.fail_fast && !failed.is_empty() {
+            return Err(JitoBellError::Config(format!(
+                "Destinations failed the startup check: {}",
+                failed.join(", ")
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Report a switch of the Geyser endpoint
+    ///
+    /// - Logged and reported in the `jito-bell-failover` datapoint, alerted with
+    ///   `failover.notification`
+    async fn send_failover_alert(&mut self, failover: &Failover) {
+        warn!(
+            "Failing over Geyser endpoint from {} to {}",
+            failover.from, failover.to
+        );
+        datapoint_info!(
+            "jito-bell-failover",
+            ("from", failover.from, String),
+            ("to", failover.to, String)
+        );
+
+        let Some(notification) = self
+            .config
+            .failover
+            .as_ref()
+            .and_then(|failover| failover.notification.clone())
+        else {
+            return;
         };
 
-        symbol
+        let description = format!(
+            "{}: {} -> {}",
+            notification.description, failover.from, failover.to
+        );
+        let mut event = NotificationEvent::scheduled(&notification, description, "", 0.0, "");
+        event.timezone = self.config.timezone;
+
+        if let Err(e) = self.deliver_notification(&notification, &event).await {
+            error!("Failed to send failover alert: {e}");
+        }
     }
 
-    /// Start heart beating
-    pub async fn heart_beat(
+    /// Subscribe to the Geyser endpoint and handle updates until the stream fails or ends, or
+    /// shutdown is requested
+    ///
+    /// - The stream is read by its own task into the update queue (`update_queue`), so slow
+    ///   destinations don't stall stream consumption
+    /// - An update being handled is finished before the shutdown signal is checked
+    /// - `received_updates` is set once the stream delivers an update
+    async fn subscribe(
         &mut self,
         subscribe_option: &SubscribeOption,
-    ) -> Result<(), JitoBellError> {
-        let mut client = GeyserGrpcClient::build_from_shared(subscribe_option.endpoint.clone())?
-            .x_token(subscribe_option.x_token.clone())?
+        endpoint: &GeyserEndpointConfig,
+        shutdown: &mut ShutdownSignal,
+        received_updates: &mut bool,
+    ) -> Result<StreamEnd, JitoBellError> {
+        let keepalive = self.config.keepalive;
+        let mut client = GeyserGrpcClient::build_from_shared(endpoint.endpoint.clone())?
+            .x_token(endpoint.x_token.clone())?
             .tls_config(ClientTlsConfig::new().with_native_roots())?
+            .http2_keep_alive_interval(Duration::from_secs(keepalive.http2_interval_secs))
+            .keep_alive_timeout(Duration::from_secs(keepalive.timeout_secs))
+            .keep_alive_while_idle(true)
+            .tcp_keepalive(Some(Duration::from_secs(keepalive.tcp_secs)))
             .connect()
             .await?;
-        let (mut subscribe_tx, mut stream) = client.subscribe().await?;
+
+        let version = client.get_version().await?;
+        if let Some(proto) = geyser_compat::check_version(&version.version)? {
+            info!("Geyser endpoint speaks Yellowstone proto {proto}");
+        }
+
+        if subscribe_option.failed == Some(false) && self.config.notifies_failed() {
+            warn!("`notify_failed` is set but failed transactions are filtered out by the subscription");
+        }
+
+        let (mut subscribe_tx, stream) = client.subscribe().await?;
 
         let subscribe_request = SubscribeRequest {
             slots: hashmap! { "".to_owned() => SubscribeRequestFilterSlots {
@@ -159,7 +1019,7 @@ impl JitoBellHandler {
             transactions_status: HashMap::new(),
             entry: HashMap::new(),
             blocks: HashMap::new(),
-            blocks_meta: HashMap::new(),
+            blocks_meta: hashmap! { "".to_owned() => SubscribeRequestFilterBlocksMeta {} },
             commitment: Some(subscribe_option.commitment as i32),
             accounts_data_slice: vec![],
             ping: None,
@@ -171,97 +1031,737 @@ impl JitoBellHandler {
             )));
         }
 
-        while let Some(message) = stream.next().await {
-            match message {
-                Ok(msg) => match msg.update_oneof {
-                    Some(UpdateOneof::Slot(update_slot)) => {
-                        let current_epoch = update_slot.slot / DEFAULT_SLOTS_PER_EPOCH;
-                        if current_epoch != self.epoch_metrics.epoch {
-                            datapoint_info!(
-                                "jito-bell-stats",
-                                ("epoch", self.epoch_metrics.epoch, i64),
-                                ("transaction", self.epoch_metrics.tx, i64),
-                                (
-                                    "success_notification",
-                                    self.epoch_metrics.notification.success,
-                                    i64
-                                ),
-                                (
-                                    "fail_notification",
-                                    self.epoch_metrics.notification.fail,
-                                    i64
-                                ),
-                            );
-                            self.epoch_metrics = EpochMetrics::new(current_epoch);
-                        }
-                    }
-                    Some(UpdateOneof::Transaction(transaction)) => {
-                        let parser = JitoTransactionParser::new(transaction);
-                        self.epoch_metrics.increment_tx_count();
+        let (update_tx, mut updates) = update_queue::channel(&self.config.update_queue);
+        let reader = tokio::spawn(update_queue::read_stream(
+            subscribe_tx,
+            stream,
+            keepalive,
+            update_tx,
+        ));
 
-                        debug!("Instruction: {:?}", parser.programs);
+        let mut report_interval =
+            tokio::time::interval(Duration::from_secs(REPORT_CHECK_INTERVAL_SECS));
+        let mut config_reload_interval =
+            tokio::time::interval(Duration::from_secs(CONFIG_RELOAD_CHECK_INTERVAL_SECS));
+        let mut rate_limit_interval =
+            tokio::time::interval(Duration::from_secs(RATE_LIMIT_CHECK_INTERVAL_SECS));
+        let mut digest_interval =
+            tokio::time::interval(Duration::from_secs(DIGEST_CHECK_INTERVAL_SECS));
+        let mut outbox_interval =
+            tokio::time::interval(Duration::from_secs(OUTBOX_RETRY_INTERVAL_SECS));
+        let mut stall_interval =
+            tokio::time::interval(Duration::from_secs(STALL_CHECK_INTERVAL_SECS));
+        let mut update_queue_interval =
+            tokio::time::interval(Duration::from_secs(UPDATE_QUEUE_REPORT_INTERVAL_SECS));
+        let mut update_monitor = UpdateMonitor::default();
+        let admin_commands = self.admin_commands.clone();
+        let task_restarts = self.task_restarts.clone();
 
-                        if let Err(e) = self.send_notification(&parser).await {
-                            error!("Error: {e}");
+        let result = loop {
+            tokio::select! {
+                item = updates.recv() => match item {
+                    Some(StreamItem::Update(update)) => {
+                        *received_updates = true;
+                        if let Err(e) = update_monitor.observe(&update) {
+                            break Err(e);
                         }
+                        if let Some(slot) = slot_gap::update_slot(&update) {
+                            self.health.record_update();
+                            self.observe_slot(slot).await;
+                        }
+                        self.handle_update(*update).await
+                    }
+                    Some(StreamItem::Failed(error)) => {
+                        break Err(JitoBellError::Subscription(error));
                     }
-                    _ => continue,
+                    None => break Ok(StreamEnd::Ended),
                 },
-                Err(error) => {
-                    error!("Stream error: {error:?}");
-                    break;
+                signal = shutdown.recv() => {
+                    info!("Received {signal}, stopping the subscription");
+                    break Ok(StreamEnd::Shutdown);
+                }
+                _ = report_interval.tick() => {
+                    self.run_validator_list_reports().await;
+                    self.send_quiet_hours_digests().await;
+                }
+                _ = config_reload_interval.tick() => self.reload_config().await,
+                _ = rate_limit_interval.tick() => self.send_rate_limit_digests().await,
+                _ = digest_interval.tick() => self.send_instruction_digests(false).await,
+                _ = outbox_interval.tick() => self.retry_outbox().await,
+                _ = stall_interval.tick() => self.check_stall().await,
+                _ = update_queue_interval.tick() => {
+                    datapoint_info!(
+                        "jito-bell-update-queue",
+                        ("depth", updates.len(), i64),
+                        ("capacity", updates.capacity(), i64),
+                        ("dropped", updates.dropped(), i64)
+                    );
+                }
+                Some(command) = async {
+                    match admin_commands.as_ref() {
+                        Some(admin_commands) => admin_commands.lock().await.recv().await,
+                        None => std::future::pending().await,
+                    }
+                } => self.handle_admin_command(command),
+                Some(restart) = async { task_restarts.lock().await.recv().await } => {
+                    self.send_restart_alert(&restart).await;
                 }
             }
+        };
+
+        reader.abort();
+        if matches!(result, Ok(StreamEnd::Shutdown)) && !updates.is_empty() {
+            warn!("Discarding {} queued Geyser updates", updates.len());
         }
 
-        Ok(())
+        result
     }
 
-    /// Send notification
-    pub async fn send_notification(
-        &mut self,
-        parser: &JitoTransactionParser,
-    ) -> Result<(), JitoBellError> {
-        for program in &parser.programs {
-            let program_str = program.to_string();
+    /// Check the slot of an update for a gap (`slot_gap`)
+    ///
+    /// - Gaps are logged, reported in the `jito-bell-slot-gap` datapoint and alerted with
+    ///   `slot_gap.notification`, including those across a reconnection
+    async fn observe_slot(&mut self, slot: u64) {
+        let Some(config) = &self.config.slot_gap else {
+            return;
+        };
+        let Some(gap) = self.slot_monitor.observe(slot, config, Instant::now()) else {
+            return;
+        };
 
-            match program {
-                JitoBellProgram::SplToken2022(_) => {
-                    debug!("Token 2022");
-                }
-                JitoBellProgram::SplStakePool(spl_stake_program) => {
-                    debug!("SPL Stake Pool");
+        warn!(
+            "Geyser stream skipped {} slots between {} and {}, transactions may have been missed",
+            gap.missed(),
+            gap.from,
+            gap.to
+        );
+        datapoint_info!(
+            "jito-bell-slot-gap",
+            ("from", gap.from, i64),
+            ("to", gap.to, i64),
+            ("missed", gap.missed(), i64)
+        );
+        self.send_slot_gap_alert(format!(
+            "{} slots missed between {} and {}",
+            gap.missed(),
+            gap.from,
+            gap.to
+        ))
+        .await;
+    }
 
-                    let spl_program_str = spl_stake_program.to_string();
+    /// Check the Geyser stream for a stall (`slot_gap`)
+    ///
+    /// - Stalls are logged, reported in the `jito-bell-stall` datapoint and alerted with
+    ///   `slot_gap.notification`, once per stall
+    async fn check_stall(&mut self) {
+        let Some(config) = &self.config.slot_gap else {
+            return;
+        };
+        let Some(elapsed) = self.slot_monitor.check_stall(config, Instant::now()) else {
+            return;
+        };
 
-                    let instruction_opt =
-                        self.config
-                            .programs
-                            .get(&program_str)
-                            .and_then(|program_config| {
-                                program_config.instructions.get(&spl_program_str).cloned()
-                            });
+        let last_slot = self
+            .slot_monitor
+            .last_slot()
+            .map_or_else(|| "none".to_string(), |slot| slot.to_string());
+        warn!(
+            "No Geyser update for {}s since slot {last_slot}",
+            elapsed.as_secs()
+        );
+        datapoint_info!("jito-bell-stall", ("elapsed_secs", elapsed.as_secs(), i64));
+        self.send_slot_gap_alert(format!(
+            "no update for {}s since slot {last_slot}",
+            elapsed.as_secs()
+        ))
+        .await;
+    }
 
-                    if let Some(instruction) = instruction_opt {
-                        self.handle_spl_stake_pool_program(parser, spl_stake_program, &instruction)
-                            .await?;
-                    }
-                }
+    /// Alert a gap or stall with `slot_gap.notification`
+    async fn send_slot_gap_alert(&mut self, detail: String) {
+        let Some(notification) = self
+            .config
+            .slot_gap
+            .as_ref()
+            .and_then(|slot_gap| slot_gap.notification.clone())
+        else {
+            return;
+        };
+
+        let description = format!("{}: {detail}", notification.description);
+        let mut event = NotificationEvent::scheduled(&notification, description, "", 0.0, "");
+        event.timezone = self.config.timezone;
+
+        if let Err(e) = self.deliver_notification(&notification, &event).await {
+            error!("Failed to send slot gap alert: {e}");
+        }
+    }
+
+    /// Alert the restart of a supervised task (`supervisor`)
+    ///
+    /// - Restarts are reported in the `jito-bell-task-restart` datapoint and alerted with
+    ///   `supervisor.notification`
+    async fn send_restart_alert(&mut self, restart: &TaskRestart) {
+        datapoint_info!(
+            "jito-bell-task-restart",
+            ("task", restart.task, String),
+            ("attempt", restart.attempt, i64),
+            ("delay_ms", restart.delay.as_millis() as i64, i64)
+        );
+
+        let Some(notification) = self.config.supervisor.notification.clone() else {
+            return;
+        };
+
+        let description = format!(
+            "{}: {} {}, restarting in {}s (attempt {})",
+            notification.description,
+            restart.task,
+            restart.reason,
+            restart.delay.as_secs(),
+            restart.attempt
+        );
+        let mut event = NotificationEvent::scheduled(&notification, description, "", 0.0, "");
+        event.timezone = self.config.timezone;
+
+        if let Err(e) = self.deliver_notification(&notification, &event).await {
+            error!("Failed to send task restart alert: {e}");
+        }
+    }
+
+    /// Apply a command of the admin API
+    ///
+    /// - Threshold changes last until the configuration file changes or a restart
+    fn handle_admin_command(&mut self, command: AdminCommand) {
+        match command {
+            AdminCommand::GetConfig { reply } => {
+                let _ = reply.send(admin::redacted_config(&self.config));
+            }
+            AdminCommand::SetThresholds { update, reply } => {
+                let target = format!(
+                    "{}.{} {}",
+                    update.program, update.instruction, update.address
+                );
+                let result = admin::apply_threshold_update(&mut self.config, update);
+                match &result {
+                    Ok(()) => info!("Updated thresholds of {target} from the admin API"),
+                    Err(e) => warn!("Failed to update thresholds of {target}: {e}"),
+                }
+                let _ = reply.send(result);
+            }
+        }
+    }
+
+    /// Reload the configuration file if it changed since it was loaded
+    ///
+    /// - Programs, thresholds, templates and destinations apply from the next update, the
+    ///   subscription is kept
+    /// - Notifier connections, sharding, leader election and the other startup settings need a
+    ///   restart
+    /// - Secret references are fetched again
+    /// - An invalid file is reported and the current configuration is kept
+    async fn reload_config(&mut self) {
+        let modified = match self.config.modified() {
+            Ok(modified) => modified,
+            Err(e) => {
+                warn!(
+                    "Failed to check configuration {}: {e}",
+                    self.config_path.display()
+                );
+                return;
+            }
+        };
+        if self.config_modified == Some(modified) {
+            return;
+        }
+        self.config_modified = Some(modified);
+
+        let reloaded = JitoBellConfig::load_with_secrets(&self.config_path, &self.http_client)
+            .await
+            .and_then(|config| {
+                let stake_pool_program_ids = config.stake_pool_program_ids()?;
+                let idl_parsers = config.anchor_idl_parsers()?;
+                Ok((config, stake_pool_program_ids, idl_parsers))
+            });
+
+        match reloaded {
+            Ok((config, stake_pool_program_ids, idl_parsers)) => {
+                self.config = config;
+                self.stake_pool_program_ids = stake_pool_program_ids;
+                self.idl_parsers = idl_parsers;
+                info!("Reloaded configuration {}", self.config_path.display());
+            }
+            Err(e) => error!(
+                "Failed to reload configuration {}, keeping the current one: {e}",
+                self.config_path.display()
+            ),
+        }
+    }
+
+    /// Handle subscription update
+    async fn handle_update(&mut self, update: SubscribeUpdate) {
+        match update.update_oneof {
+            Some(UpdateOneof::Slot(update_slot)) => {
+                let current_epoch = update_slot.slot / DEFAULT_SLOTS_PER_EPOCH;
+                if current_epoch != self.epoch_metrics.epoch {
+                    datapoint_info!(
+                        "jito-bell-stats",
+                        ("epoch", self.epoch_metrics.epoch, i64),
+                        ("transaction", self.epoch_metrics.tx, i64),
+                        (
+                            "success_notification",
+                            self.epoch_metrics.notification.success(),
+                            i64
+                        ),
+                        (
+                            "fail_notification",
+                            self.epoch_metrics.notification.fail(),
+                            i64
+                        ),
+                    );
+                    for (program, metrics) in self.epoch_metrics.programs.iter() {
+                        datapoint_info!(
+                            "jito-bell-parser-coverage",
+                            "program" => *program,
+                            ("epoch", self.epoch_metrics.epoch, i64),
+                            ("transaction", metrics.tx, i64),
+                            ("outer_decoded", metrics.coverage.outer_decoded, i64),
+                            ("outer_skipped", metrics.coverage.outer_skipped, i64),
+                            ("inner_decoded", metrics.coverage.inner_decoded, i64),
+                            ("inner_skipped", metrics.coverage.inner_skipped, i64),
+                        );
+                    }
+                    self.report_limits();
+                    self.epoch_metrics = EpochMetrics::new(current_epoch);
+                }
+
+                self.release_ordered_notifications(update_slot.slot).await;
+            }
+            Some(UpdateOneof::BlockMeta(block_meta)) => {
+                if let Some(block_time) = block_meta.block_time {
+                    self.block_times
+                        .insert(block_meta.slot, block_time.timestamp);
+                    while self.block_times.len() > MAX_BLOCK_TIMES {
+                        self.block_times.pop_first();
+                    }
+                }
+            }
+            Some(UpdateOneof::Transaction(transaction)) => {
+                if !self.owns_transaction(&transaction) {
+                    return;
+                }
+
+                let span = tracing::info_span!(
+                    "transaction",
+                    slot = transaction.slot,
+                    signature = tracing::field::Empty
+                );
+                async {
+                    let parser = tracing::info_span!("parse").in_scope(|| {
+                        JitoTransactionParser::new(
+                            transaction,
+                            &self.stake_pool_program_ids,
+                            &self.idl_parsers,
+                        )
+                    });
+                    tracing::Span::current()
+                        .record("signature", parser.transaction_signature.as_str());
+                    self.epoch_metrics.increment_tx_count();
+                    self.epoch_metrics.record_parser_coverage(&parser.coverage);
+
+                    debug!("Instruction: {:?}", parser.programs);
+
+                    if !parser.failed {
+                        self.record_transaction(&parser);
+                    }
+
+                    let matched = tracing::info_span!(
+                        "match",
+                        programs = parser.programs.len(),
+                        program = tracing::field::Empty,
+                        instruction = tracing::field::Empty
+                    );
+                    if let Err(e) = self.send_notification(&parser).instrument(matched).await {
+                        error!("Error: {e}");
+                    }
+                }
+                .instrument(span)
+                .await
+            }
+            _ => {}
+        }
+    }
+
+    /// Run validator list diff reports that are due
+    ///
+    /// - Only the first shard runs reports, so sharded instances do not post duplicates
+    async fn run_validator_list_reports(&mut self) {
+        if self.shard.is_some_and(|shard| !shard.is_primary()) {
+            return;
+        }
+
+        for index in self.validator_list_monitor.due_reports() {
+            let report_config = &self.config.validator_list_reports[index];
+            let notification = report_config.notification.clone();
+            let stake_pool = report_config.stake_pool.clone();
+            let min_delta = sol_to_lamports(report_config.min_stake_delta);
+
+            let diff = match self
+                .validator_list_monitor
+                .run_report(&self.rpc_client, index, min_delta)
+                .await
+            {
+                Ok(Some(diff)) => diff,
+                Ok(None) => {
+                    debug!("Took initial validator list snapshot of {stake_pool}");
+                    continue;
+                }
+                Err(e) => {
+                    error!("Failed to snapshot validator list of {stake_pool}: {e}");
+                    continue;
+                }
+            };
+
+            if diff.is_empty() {
+                debug!("No validator list changes in {stake_pool}");
+                continue;
+            }
+
+            let mut event = NotificationEvent::scheduled(
+                &notification,
+                diff.render(&notification.description),
+                &stake_pool,
+                diff.net_delta() as f64 / LAMPORTS_PER_SOL as f64,
+                "SOL",
+            );
+            event.timezone = self.config.timezone;
+            self.recent
+                .push(RecentEvent::Notification(Box::new(event.clone())));
+
+            if let Err(e) = self.deliver_notification(&notification, &event).await {
+                error!("Error: {e}");
+            }
+        }
+    }
+
+    /// Whether this instance's shard handles the transaction
+    fn owns_transaction(&self, transaction: &SubscribeUpdateTransaction) -> bool {
+        match (&self.shard, &transaction.transaction) {
+            (Some(shard), Some(tx)) => shard.owns(&tx.signature),
+            _ => true,
+        }
+    }
+
+    /// Config of an instruction, if its program is watched
+    fn instruction_config(&self, program: &str, instruction: &str) -> Option<Instruction> {
+        self.config
+            .programs
+            .get(program)
+            .and_then(|program_config| program_config.instructions.get(instruction).cloned())
+    }
+
+    /// Send notification
+    pub async fn send_notification(
+        &mut self,
+        parser: &JitoTransactionParser,
+    ) -> Result<(), JitoBellError> {
+        self.current_instruction = None;
+        self.handle_priority_fee(parser).await?;
+
+        for program in &parser.programs {
+            if !self.notifies(parser, program) {
+                continue;
+            }
+
+            let program_str = program.to_string();
+            let instruction_name = program.instruction_name();
+            tracing::Span::current()
+                .record("program", program_str.as_str())
+                .record("instruction", instruction_name.as_str());
+            self.current_instruction = Some((program_str.clone(), instruction_name));
+
+            match program {
+                JitoBellProgram::SplToken2022(spl_token_2022_program) => {
+                    debug!("Token 2022");
+
+                    let instruction_opt =
+                        self.instruction_config(&program_str, &spl_token_2022_program.to_string());
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_spl_token_2022_program(
+                            parser,
+                            spl_token_2022_program,
+                            &instruction,
+                        )
+                        .await?;
+                    }
+                }
+                JitoBellProgram::SplStakePool(spl_stake_program) => {
+                    debug!("SPL Stake Pool");
+
+                    let instruction_opt = self
+                        .instruction_config(&program_str, &spl_stake_program.to_string())
+                        .filter(|instruction| {
+                            instruction.applies_to_stake_pool(spl_stake_program.stake_pool())
+                        });
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_spl_stake_pool_program(parser, spl_stake_program, &instruction)
+                            .await?;
+                    }
+                }
+                JitoBellProgram::System(system_program) => {
+                    debug!("System");
+
+                    let instruction_opt =
+                        self.instruction_config(&program_str, &system_program.to_string());
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_system_program(parser, system_program, &instruction)
+                            .await?;
+                    }
+                }
+                JitoBellProgram::Marinade(marinade_program) => {
+                    debug!("Marinade");
+
+                    let instruction_opt =
+                        self.instruction_config(&program_str, &marinade_program.to_string());
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_marinade_program(parser, marinade_program, &instruction)
+                            .await?;
+                    }
+                }
+                JitoBellProgram::AnchorIdl(anchor_idl_program) => {
+                    debug!("Anchor IDL");
+
+                    let instruction_opt =
+                        self.instruction_config(&program_str, &anchor_idl_program.to_string());
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_anchor_idl_program(parser, anchor_idl_program, &instruction)
+                            .await?;
+                    }
+                }
+                JitoBellProgram::KaminoLend(kamino_lend_program) => {
+                    debug!("Kamino Lend");
+
+                    let instruction_opt =
+                        self.instruction_config(&program_str, &kamino_lend_program.to_string());
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_kamino_lend_program(parser, kamino_lend_program, &instruction)
+                            .await?;
+                    }
+                }
+                JitoBellProgram::Marginfi(marginfi_program) => {
+                    debug!("marginfi");
+
+                    let instruction_opt =
+                        self.instruction_config(&program_str, &marginfi_program.to_string());
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_marginfi_program(parser, marginfi_program, &instruction)
+                            .await?;
+                    }
+                }
+                JitoBellProgram::OrcaWhirlpool(orca_whirlpool_program) => {
+                    debug!("Orca Whirlpool");
+
+                    let instruction_opt =
+                        self.instruction_config(&program_str, &orca_whirlpool_program.to_string());
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_orca_whirlpool_program(
+                            parser,
+                            orca_whirlpool_program,
+                            &instruction,
+                        )
+                        .await?;
+                    }
+                }
+                JitoBellProgram::RaydiumCpmm(raydium_cpmm_program) => {
+                    debug!("Raydium CPMM");
+
+                    let instruction_opt =
+                        self.instruction_config(&program_str, &raydium_cpmm_program.to_string());
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_raydium_cpmm_program(
+                            parser,
+                            raydium_cpmm_program,
+                            &instruction,
+                        )
+                        .await?;
+                    }
+                }
+                JitoBellProgram::Vote(vote_program) => {
+                    debug!("Vote");
+
+                    let instruction_opt =
+                        self.instruction_config(&program_str, &vote_program.to_string());
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_vote_program(parser, vote_program, &instruction)
+                            .await?;
+                    }
+                }
+                JitoBellProgram::SplGovernance(spl_governance_program) => {
+                    debug!("SPL Governance");
+
+                    let instruction_opt =
+                        self.instruction_config(&program_str, &spl_governance_program.to_string());
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_spl_governance_program(
+                            parser,
+                            spl_governance_program,
+                            &instruction,
+                        )
+                        .await?;
+                    }
+                }
+                JitoBellProgram::SquadsMultisig(squads_multisig_program) => {
+                    debug!("Squads Multisig");
+
+                    let instruction_opt =
+                        self.instruction_config(&program_str, &squads_multisig_program.to_string());
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_squads_multisig_program(
+                            parser,
+                            squads_multisig_program,
+                            &instruction,
+                        )
+                        .await?;
+                    }
+                }
+                JitoBellProgram::WormholeTokenBridge(wormhole_token_bridge_program) => {
+                    debug!("Wormhole Token Bridge");
+
+                    let instruction_opt = self.instruction_config(
+                        &program_str,
+                        &wormhole_token_bridge_program.to_string(),
+                    );
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_wormhole_token_bridge_program(
+                            parser,
+                            wormhole_token_bridge_program,
+                            &instruction,
+                        )
+                        .await?;
+                    }
+                }
+                JitoBellProgram::DebridgeDlnSource(debridge_dln_source_program) => {
+                    debug!("deBridge DLN Source");
+
+                    let instruction_opt = self
+                        .instruction_config(&program_str, &debridge_dln_source_program.to_string());
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_debridge_dln_source_program(
+                            parser,
+                            debridge_dln_source_program,
+                            &instruction,
+                        )
+                        .await?;
+                    }
+                }
+                JitoBellProgram::SplSinglePool(spl_single_pool_program) => {
+                    debug!("SPL Single Pool");
+
+                    let instruction_opt =
+                        self.instruction_config(&program_str, &spl_single_pool_program.to_string());
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_spl_single_pool_program(
+                            parser,
+                            spl_single_pool_program,
+                            &instruction,
+                        )
+                        .await?;
+                    }
+                }
+                JitoBellProgram::Jupiter(jupiter_program) => {
+                    debug!("Jupiter");
+
+                    let instruction_opt =
+                        self.instruction_config(&program_str, &jupiter_program.to_string());
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_jupiter_program(parser, jupiter_program, &instruction)
+                            .await?;
+                    }
+                }
+                JitoBellProgram::JitoSteward(jito_steward_program) => {
+                    debug!("Jito Steward");
+
+                    let instruction_opt =
+                        self.instruction_config(&program_str, &jito_steward_program.to_string());
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_jito_steward_program(
+                            parser,
+                            jito_steward_program,
+                            &instruction,
+                        )
+                        .await?;
+                    }
+                }
+                JitoBellProgram::JitoTipDistribution(jito_tip_distribution_program) => {
+                    debug!("Jito Tip Distribution");
+
+                    let instruction_opt = self.instruction_config(
+                        &program_str,
+                        &jito_tip_distribution_program.to_string(),
+                    );
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_jito_tip_distribution_program(
+                            parser,
+                            jito_tip_distribution_program,
+                            &instruction,
+                        )
+                        .await?;
+                    }
+                }
+                JitoBellProgram::JitoTipPayment(jito_tip_payment_program) => {
+                    debug!("Jito Tip Payment");
+
+                    let instruction_opt = self
+                        .instruction_config(&program_str, &jito_tip_payment_program.to_string());
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_jito_tip_payment_program(
+                            parser,
+                            jito_tip_payment_program,
+                            &instruction,
+                        )
+                        .await?;
+                    }
+                }
+                JitoBellProgram::JitoTipRouter(jito_tip_router_program) => {
+                    debug!("Jito TipRouter");
+
+                    let instruction_opt =
+                        self.instruction_config(&program_str, &jito_tip_router_program.to_string());
+
+                    if let Some(instruction) = instruction_opt {
+                        self.handle_jito_tip_router_program(
+                            parser,
+                            jito_tip_router_program,
+                            &instruction,
+                        )
+                        .await?;
+                    }
+                }
                 JitoBellProgram::JitoVault(jito_vault_program) => {
                     debug!("Jito Vault");
 
-                    let jito_vault_program_str = jito_vault_program.to_string();
-
                     let instruction_opt =
-                        self.config
-                            .programs
-                            .get(&program_str)
-                            .and_then(|program_config| {
-                                program_config
-                                    .instructions
-                                    .get(&jito_vault_program_str)
-                                    .cloned()
-                            });
+                        self.instruction_config(&program_str, &jito_vault_program.to_string());
 
                     if let Some(instruction) = instruction_opt {
                         self.handle_jito_vault_program(parser, jito_vault_program, &instruction)
@@ -270,6 +1770,7 @@ impl JitoBellHandler {
                 }
             }
         }
+        self.current_instruction = None;
 
         Ok(())
     }
@@ -277,6 +1778,12 @@ impl JitoBellHandler {
     /// Handle SPL Stake Pool Program
     ///
     /// - Notify only once for the first matching threshold.
+    /// - Pool management instructions (e.g. `set_manager`, `set_fee`) notify every configured
+    ///   notification, limited to `stake_pools` when set. `{{validator}}` is the vote account
+    ///   when the instruction names one.
+    /// - `set_fee` fetches the stake pool to report the fee before and after the change.
+    /// - Pool tokens minted by DepositStake and burned by withdrawals use the decimals and symbol
+    ///   of the pool mint under `mints`.
     async fn handle_spl_stake_pool_program(
         &mut self,
         parser: &JitoTransactionParser,
@@ -302,19 +1809,23 @@ impl JitoBellHandler {
                 let _system_program_info = &ix.accounts[12];
                 let _stake_program_info = &ix.accounts[13];
 
-                if let Some(mut stake_pools) = instruction.stake_pools.clone() {
-                    if let Some(alert_config) =
-                        stake_pools.get_mut(&stake_pool_info.pubkey.to_string())
+                if let Some(stake_pools) = &instruction.stake_pools {
+                    if let Some(alert_config) = stake_pools.get(&stake_pool_info.pubkey.to_string())
                     {
-                        self.sort_thresholds(alert_config.thresholds.as_mut());
-                        for threshold in alert_config.thresholds.iter() {
+                        let thresholds = self.resolve_thresholds(
+                            &spl_stake_program.to_string(),
+                            &stake_pool_info.pubkey.to_string(),
+                            *amount,
+                            &alert_config.thresholds,
+                        );
+                        for threshold in thresholds.iter() {
                             if *amount > threshold.value {
                                 self.dispatch_platform_notifications(
-                                    &threshold.notification.destinations,
-                                    &threshold.notification.description,
+                                    parser,
+                                    &threshold.notification,
+                                    &stake_pool_info.pubkey.to_string(),
                                     *amount,
                                     "SOL",
-                                    &parser.transaction_signature,
                                 )
                                 .await?;
                                 break;
@@ -323,8 +1834,9 @@ impl JitoBellHandler {
                     }
                 }
             }
-            SplStakePoolProgram::DepositStake { ix } => {
-                let _stake_pool_info = &ix.accounts[0];
+            SplStakePoolProgram::DepositStake { ix }
+            | SplStakePoolProgram::DepositStakeWithSlippage { ix } => {
+                let stake_pool_info = &ix.accounts[0];
                 let _validator_list_info = &ix.accounts[1];
                 let _stake_deposit_authority_info = &ix.accounts[2];
                 let withdraw_authority_info = &ix.accounts[3];
@@ -336,40 +1848,57 @@ impl JitoBellHandler {
                 let _referrer_fee_info = &ix.accounts[9];
                 let pool_mint_info = &ix.accounts[10];
 
-                if let Some(mut lsts) = instruction.lsts.clone() {
-                    if let Some(alert_config) = lsts.get_mut(&pool_mint_info.pubkey.to_string()) {
+                if let Some(lsts) = &instruction.lsts {
+                    if let Some(alert_config) = lsts.get(&pool_mint_info.pubkey.to_string()) {
+                        let pool_token = self.pool_token(&pool_mint_info.pubkey);
                         for program in &parser.programs {
-                            if let JitoBellProgram::SplToken2022(program) = program {
-                                match program {
-                                    SplToken2022Program::MintTo { ix, amount } => {
-                                        let mint_info = &ix.accounts[0];
-                                        let destination_account_info = &ix.accounts[1];
-                                        let owner_info = &ix.accounts[2];
-
-                                        if mint_info.pubkey.eq(&pool_mint_info.pubkey)
-                                            && destination_account_info
-                                                .pubkey
-                                                .eq(&dest_user_pool_info.pubkey)
-                                            && owner_info.pubkey.eq(&withdraw_authority_info.pubkey)
-                                        {
-                                            self.sort_thresholds(alert_config.thresholds.as_mut());
-                                            for threshold in alert_config.thresholds.iter() {
-                                                if *amount as f64 > threshold.value {
-                                                    self.dispatch_platform_notifications(
-                                                        &threshold.notification.destinations,
-                                                        &threshold.notification.description,
-                                                        *amount as f64,
-                                                        "SOL",
-                                                        &parser.transaction_signature,
-                                                    )
-                                                    .await?;
-                                                    break;
-                                                }
-                                            }
+                            if let JitoBellProgram::SplToken2022(SplToken2022Program::MintTo {
+                                ix,
+                                amount,
+                            }) = program
+                            {
+                                let mint_info = &ix.accounts[0];
+                                let destination_account_info = &ix.accounts[1];
+                                let owner_info = &ix.accounts[2];
+
+                                if mint_info.pubkey.eq(&pool_mint_info.pubkey)
+                                    && destination_account_info
+                                        .pubkey
+                                        .eq(&dest_user_pool_info.pubkey)
+                                    && owner_info.pubkey.eq(&withdraw_authority_info.pubkey)
+                                {
+                                    let amount = *amount as f64 / pool_token.divisor;
+                                    let thresholds = self.resolve_thresholds(
+                                        &spl_stake_program.to_string(),
+                                        &pool_mint_info.pubkey.to_string(),
+                                        amount,
+                                        &alert_config.thresholds,
+                                    );
+                                    for threshold in thresholds.iter() {
+                                        if amount > threshold.value {
+                                            let mut event = NotificationEvent::new(
+                                                parser,
+                                                &threshold.notification,
+                                                &stake_pool_info.pubkey.to_string(),
+                                                amount,
+                                                &pool_token.symbol,
+                                                &self.config.explorer_url,
+                                            );
+                                            event.receiver =
+                                                Some(dest_user_pool_info.pubkey.to_string());
+                                            event.first_time_depositor = parser
+                                                .created_token_account(
+                                                    &dest_user_pool_info.pubkey,
+                                                    &pool_mint_info.pubkey,
+                                                );
 
+                                            self.dispatch_event(&threshold.notification, event)
+                                                .await?;
                                             break;
                                         }
                                     }
+
+                                    break;
                                 }
                             }
                         }
@@ -378,95 +1907,148 @@ impl JitoBellHandler {
             }
             SplStakePoolProgram::WithdrawStake {
                 ix,
-                minimum_lamports_out,
-            } => {
-                let _stake_pool_info = &ix.accounts[0];
+                minimum_lamports_out: amount,
+            }
+            | SplStakePoolProgram::WithdrawStakeWithSlippage { ix, amount } => {
+                let stake_pool_info = &ix.accounts[0];
                 let _validator_list_info = &ix.accounts[1];
                 let _withdraw_authority_info = &ix.accounts[2];
                 let _stake_split_from = &ix.accounts[3];
-                let _stake_split_to = &ix.accounts[4];
+                let stake_split_to = &ix.accounts[4];
                 let _user_stake_authority_info = &ix.accounts[5];
                 let _user_transfer_authority_info = &ix.accounts[6];
-                let _burn_from_pool_info = &ix.accounts[7];
+                let burn_from_pool_info = &ix.accounts[7];
                 let _manager_fee_info = &ix.accounts[8];
                 let pool_mint_info = &ix.accounts[9];
 
-                if let Some(mut lsts) = instruction.lsts.clone() {
-                    if let Some(alert_config) = lsts.get_mut(&pool_mint_info.pubkey.to_string()) {
-                        self.sort_thresholds(alert_config.thresholds.as_mut());
-                        for threshold in alert_config.thresholds.iter() {
-                            if *minimum_lamports_out >= threshold.value {
-                                self.dispatch_platform_notifications(
-                                    &threshold.notification.destinations,
-                                    &threshold.notification.description,
-                                    *minimum_lamports_out,
-                                    "SOL",
-                                    &parser.transaction_signature,
-                                )
-                                .await?;
-                                break;
-                            }
+                // Pool tokens actually burned (after the withdrawal fee) when the burn is seen
+                let pool_token = self.pool_token(&pool_mint_info.pubkey);
+                let amount = parser
+                    .burned_pool_tokens(&pool_mint_info.pubkey, &burn_from_pool_info.pubkey)
+                    .unwrap_or_else(|| sol_to_lamports(*amount))
+                    as f64
+                    / pool_token.divisor;
+
+                if let Some(lsts) = &instruction.lsts {
+                    if let Some(alert_config) = lsts.get(&pool_mint_info.pubkey.to_string()) {
+                        let thresholds = self.resolve_thresholds(
+                            &spl_stake_program.to_string(),
+                            &pool_mint_info.pubkey.to_string(),
+                            amount,
+                            &alert_config.thresholds,
+                        );
+                        if let Some(threshold) = ThresholdConfig::reached(&thresholds, amount) {
+                            let mut event = NotificationEvent::new(
+                                parser,
+                                &threshold.notification,
+                                &stake_pool_info.pubkey.to_string(),
+                                amount,
+                                &pool_token.symbol,
+                                &self.config.explorer_url,
+                            );
+                            event.sender = Some(burn_from_pool_info.pubkey.to_string());
+                            event.receiver = Some(stake_split_to.pubkey.to_string());
+
+                            self.dispatch_event(&threshold.notification, event).await?;
                         }
                     }
                 }
             }
-            SplStakePoolProgram::DepositSol { ix, amount } => {
-                let _stake_pool_info = &ix.accounts[0];
+            SplStakePoolProgram::DepositSol { ix, amount }
+            | SplStakePoolProgram::DepositSolWithSlippage { ix, amount } => {
+                let stake_pool_info = &ix.accounts[0];
                 let _withdraw_authority_info = &ix.accounts[1];
                 let _reserve_stake_account_info = &ix.accounts[2];
-                let _from_user_lamports_info = &ix.accounts[3];
-                let _dest_user_pool_info = &ix.accounts[4];
+                let from_user_lamports_info = &ix.accounts[3];
+                let dest_user_pool_info = &ix.accounts[4];
                 let _manager_fee_info = &ix.accounts[5];
                 let _referrer_fee_info = &ix.accounts[6];
                 let pool_mint_info = &ix.accounts[7];
 
-                if let Some(mut lsts) = instruction.lsts.clone() {
-                    if let Some(alert_config) = lsts.get_mut(&pool_mint_info.pubkey.to_string()) {
-                        self.sort_thresholds(alert_config.thresholds.as_mut());
-                        for threshold in alert_config.thresholds.iter() {
-                            if *amount >= threshold.value {
-                                self.dispatch_platform_notifications(
-                                    &threshold.notification.destinations,
-                                    &threshold.notification.description,
-                                    *amount,
-                                    "SOL",
-                                    &parser.transaction_signature,
-                                )
-                                .await?;
-                                break;
-                            }
+                if let Some(lsts) = &instruction.lsts {
+                    if let Some(alert_config) = lsts.get(&pool_mint_info.pubkey.to_string()) {
+                        let thresholds = self.resolve_thresholds(
+                            &spl_stake_program.to_string(),
+                            &pool_mint_info.pubkey.to_string(),
+                            *amount,
+                            &alert_config.thresholds,
+                        );
+                        if let Some(threshold) = ThresholdConfig::reached(&thresholds, *amount) {
+                            let mut event = NotificationEvent::new(
+                                parser,
+                                &threshold.notification,
+                                &stake_pool_info.pubkey.to_string(),
+                                *amount,
+                                "SOL",
+                                &self.config.explorer_url,
+                            );
+                            event.sender = Some(from_user_lamports_info.pubkey.to_string());
+                            event.receiver = Some(dest_user_pool_info.pubkey.to_string());
+                            event.first_time_depositor = parser.created_token_account(
+                                &dest_user_pool_info.pubkey,
+                                &pool_mint_info.pubkey,
+                            );
+                            event.sol_flow = Some(
+                                if parser.unwrapped_wsol_to(&from_user_lamports_info.pubkey) {
+                                    SolFlow::RecycledWsol
+                                } else {
+                                    SolFlow::FreshSol
+                                },
+                            );
+
+                            self.dispatch_event(&threshold.notification, event).await?;
                         }
                     }
                 }
             }
-            SplStakePoolProgram::WithdrawSol { ix, amount } => {
-                let _stake_pool_info = &ix.accounts[0];
+            SplStakePoolProgram::WithdrawSol { ix, amount }
+            | SplStakePoolProgram::WithdrawSolWithSlippage { ix, amount } => {
+                let stake_pool_info = &ix.accounts[0];
                 let _withdraw_authority_info = &ix.accounts[1];
                 let _user_transfer_authority_info = &ix.accounts[2];
-                let _burn_from_pool_info = &ix.accounts[3];
+                let burn_from_pool_info = &ix.accounts[3];
                 let _reserve_stake_info = &ix.accounts[4];
-                let _destination_lamports_info = &ix.accounts[5];
+                let destination_lamports_info = &ix.accounts[5];
                 let _manager_fee_info = &ix.accounts[6];
                 let pool_mint_info = &ix.accounts[7];
 
-                if let Some(mut lsts) = instruction.lsts.clone() {
-                    if let Some(alert_config) = lsts.get_mut(&pool_mint_info.pubkey.to_string()) {
-                        self.sort_thresholds(alert_config.thresholds.as_mut());
-                        for threshold in alert_config.thresholds.iter() {
-                            if *amount >= threshold.value {
-                                self.dispatch_platform_notifications(
-                                    &threshold.notification.destinations,
-                                    &threshold.notification.description,
-                                    *amount,
-                                    "SOL",
-                                    &parser.transaction_signature,
-                                )
-                                .await?;
-                                break;
-                            }
-                        }
-                    }
-                }
+                // Pool tokens actually burned (after the withdrawal fee) when the burn is seen
+                let pool_token = self.pool_token(&pool_mint_info.pubkey);
+                let amount = parser
+                    .burned_pool_tokens(&pool_mint_info.pubkey, &burn_from_pool_info.pubkey)
+                    .unwrap_or_else(|| sol_to_lamports(*amount))
+                    as f64
+                    / pool_token.divisor;
+
+                if let Some(lsts) = &instruction.lsts {
+                    if let Some(alert_config) = lsts.get(&pool_mint_info.pubkey.to_string()) {
+                        let thresholds = self.resolve_thresholds(
+                            &spl_stake_program.to_string(),
+                            &pool_mint_info.pubkey.to_string(),
+                            amount,
+                            &alert_config.thresholds,
+                        );
+                        if let Some(threshold) = ThresholdConfig::reached(&thresholds, amount) {
+                            let mut event = NotificationEvent::new(
+                                parser,
+                                &threshold.notification,
+                                &stake_pool_info.pubkey.to_string(),
+                                amount,
+                                &pool_token.symbol,
+                                &self.config.explorer_url,
+                            );
+                            event.sender = Some(burn_from_pool_info.pubkey.to_string());
+                            event.receiver = Some(destination_lamports_info.pubkey.to_string());
+                            event.sol_flow = Some(if parser.wrapped_wsol() {
+                                SolFlow::WrappedWsol
+                            } else {
+                                SolFlow::NativeSol
+                            });
+
+                            self.dispatch_event(&threshold.notification, event).await?;
+                        }
+                    }
+                }
             }
             SplStakePoolProgram::DecreaseValidatorStakeWithReserve { ix, amount } => {
                 let stake_pool_info = &ix.accounts[0];
@@ -481,19 +2063,23 @@ impl JitoBellHandler {
                 let _system_program_info = &ix.accounts[9];
                 let _stake_program_info = &ix.accounts[10];
 
-                if let Some(mut stake_pools) = instruction.stake_pools.clone() {
-                    if let Some(alert_config) =
-                        stake_pools.get_mut(&stake_pool_info.pubkey.to_string())
+                if let Some(stake_pools) = &instruction.stake_pools {
+                    if let Some(alert_config) = stake_pools.get(&stake_pool_info.pubkey.to_string())
                     {
-                        self.sort_thresholds(alert_config.thresholds.as_mut());
-                        for threshold in alert_config.thresholds.iter() {
+                        let thresholds = self.resolve_thresholds(
+                            &spl_stake_program.to_string(),
+                            &stake_pool_info.pubkey.to_string(),
+                            *amount,
+                            &alert_config.thresholds,
+                        );
+                        for threshold in thresholds.iter() {
                             if *amount > threshold.value {
                                 self.dispatch_platform_notifications(
-                                    &threshold.notification.destinations,
-                                    &threshold.notification.description,
+                                    parser,
+                                    &threshold.notification,
+                                    &stake_pool_info.pubkey.to_string(),
                                     *amount,
                                     "SOL",
-                                    &parser.transaction_signature,
                                 )
                                 .await?;
                                 break;
@@ -502,300 +2088,2819 @@ impl JitoBellHandler {
                     }
                 }
             }
-            SplStakePoolProgram::Initialize
-            | SplStakePoolProgram::AddValidatorToPool
-            | SplStakePoolProgram::RemoveValidatorFromPool
-            | SplStakePoolProgram::DecreaseValidatorStake
-            | SplStakePoolProgram::SetPreferredValidator
-            | SplStakePoolProgram::UpdateValidatorListBalance
-            | SplStakePoolProgram::UpdateStakePoolBalance
-            | SplStakePoolProgram::CleanupRemovedValidatorEntries
-            | SplStakePoolProgram::SetManager
-            | SplStakePoolProgram::SetFee
-            | SplStakePoolProgram::SetStaker
-            | SplStakePoolProgram::SetFundingAuthority
-            | SplStakePoolProgram::CreateTokenMetadata
-            | SplStakePoolProgram::UpdateTokenMetadata
-            | SplStakePoolProgram::IncreaseAdditionalValidatorStake
-            | SplStakePoolProgram::DecreaseAdditionalValidatorStake
-            | SplStakePoolProgram::Redelegate
-            | SplStakePoolProgram::DepositStakeWithSlippage
-            | SplStakePoolProgram::WithdrawStakeWithSlippage
-            | SplStakePoolProgram::DepositSolWithSlippage
-            | SplStakePoolProgram::WithdrawSolWithSlippage => {
-                unreachable!()
+            SplStakePoolProgram::IncreaseAdditionalValidatorStake { ix, amount }
+            | SplStakePoolProgram::DecreaseAdditionalValidatorStake { ix, amount }
+            | SplStakePoolProgram::DecreaseValidatorStake { ix, amount }
+            | SplStakePoolProgram::Redelegate { ix, amount } => {
+                let Some(stake_pool_info) = ix.accounts.first() else {
+                    return Ok(());
+                };
+                let vote_account_info = match spl_stake_program {
+                    SplStakePoolProgram::IncreaseAdditionalValidatorStake { .. } => {
+                        ix.accounts.get(8)
+                    }
+                    SplStakePoolProgram::Redelegate { .. } => ix.accounts.get(10),
+                    _ => None,
+                };
+                let stake_pool = stake_pool_info.pubkey.to_string();
+
+                if let Some(stake_pools) = &instruction.stake_pools {
+                    if let Some(alert_config) = stake_pools.get(&stake_pool) {
+                        let thresholds = self.resolve_thresholds(
+                            &spl_stake_program.to_string(),
+                            &stake_pool,
+                            *amount,
+                            &alert_config.thresholds,
+                        );
+                        if let Some(threshold) = thresholds
+                            .iter()
+                            .find(|threshold| *amount > threshold.value)
+                        {
+                            let mut event = NotificationEvent::new(
+                                parser,
+                                &threshold.notification,
+                                &stake_pool,
+                                *amount,
+                                "SOL",
+                                &self.config.explorer_url,
+                            );
+                            event.validator = vote_account_info.map(|info| info.pubkey.to_string());
+
+                            self.dispatch_event(&threshold.notification, event).await?;
+                        }
+                    }
+                }
+            }
+            SplStakePoolProgram::Initialize { ix }
+            | SplStakePoolProgram::AddValidatorToPool { ix }
+            | SplStakePoolProgram::RemoveValidatorFromPool { ix }
+            | SplStakePoolProgram::SetPreferredValidator { ix, .. }
+            | SplStakePoolProgram::UpdateValidatorListBalance { ix }
+            | SplStakePoolProgram::UpdateStakePoolBalance { ix }
+            | SplStakePoolProgram::CleanupRemovedValidatorEntries { ix }
+            | SplStakePoolProgram::SetManager { ix }
+            | SplStakePoolProgram::SetFee { ix, .. }
+            | SplStakePoolProgram::SetStaker { ix }
+            | SplStakePoolProgram::SetFundingAuthority { ix }
+            | SplStakePoolProgram::CreateTokenMetadata { ix }
+            | SplStakePoolProgram::UpdateTokenMetadata { ix } => {
+                let Some(stake_pool_info) = ix.accounts.first() else {
+                    return Ok(());
+                };
+                let stake_pool = stake_pool_info.pubkey.to_string();
+
+                let validator = match spl_stake_program {
+                    SplStakePoolProgram::AddValidatorToPool { .. } => {
+                        ix.accounts.get(6).map(|info| info.pubkey)
+                    }
+                    SplStakePoolProgram::SetPreferredValidator { vote_account, .. } => {
+                        *vote_account
+                    }
+                    _ => None,
+                };
+                let fee_change = match spl_stake_program {
+                    SplStakePoolProgram::SetFee { fee, .. } => Some(
+                        self.stake_pool_fees
+                            .change(&self.rpc_client, &stake_pool_info.pubkey, fee)
+                            .await,
+                    ),
+                    _ => None,
+                };
+
+                for notification in instruction.notifications.iter() {
+                    let mut event = NotificationEvent::new(
+                        parser,
+                        notification,
+                        &stake_pool,
+                        0.0,
+                        "",
+                        &self.config.explorer_url,
+                    );
+                    event.validator = validator.map(|vote_account| vote_account.to_string());
+                    event.fee_change = fee_change.clone();
+
+                    self.dispatch_event(notification, event).await?;
+                }
             }
         }
 
         Ok(())
     }
 
-    /// Handle Jito Vault Program
+    /// Handle Marinade Program
     ///
+    /// - Keyed by the mSOL mint under `lsts`, like stake pool deposits and withdrawals
     /// - Notify only once for the first matching threshold.
-    async fn handle_jito_vault_program(
+    async fn handle_marinade_program(
         &mut self,
         parser: &JitoTransactionParser,
-        jito_vault_program: &JitoVaultProgram,
+        marinade_program: &MarinadeProgram,
         instruction: &Instruction,
     ) -> Result<(), JitoBellError> {
-        debug!("Jito Vault Program: {}", jito_vault_program);
-
-        match jito_vault_program {
-            JitoVaultProgram::MintTo { ix, min_amount_out } => {
-                let _config_info = &ix.accounts[0];
-                let _vault_info = &ix.accounts[1];
-                let vrt_mint_info = &ix.accounts[2];
-                let _depositor_info = &ix.accounts[3];
-                let _depositor_token_account = &ix.accounts[4];
-                let _vault_token_account = &ix.accounts[5];
-                let _depositor_vrt_token_account = &ix.accounts[6];
-                let _vault_fee_token_account = &ix.accounts[7];
+        debug!("Marinade Program: {}", marinade_program);
 
-                if let Some(vrts) = instruction.vrts.clone() {
-                    if let Some((address, vrt_config)) =
-                        vrts.get_key_value(&vrt_mint_info.pubkey.to_string())
-                    {
-                        let vrt = Pubkey::from_str(address).unwrap();
-                        let divisor = self.divisor(&vrt).await;
-                        let symbol = self.vrt_symbol(&vrt).await;
+        let (ix, amount, unit) = match marinade_program {
+            MarinadeProgram::Deposit { ix, amount } => (ix, *amount, "SOL"),
+            MarinadeProgram::LiquidUnstake { ix, amount }
+            | MarinadeProgram::OrderUnstake { ix, amount } => (ix, *amount, "mSOL"),
+        };
+        let (Some(state_info), Some(msol_mint_info)) = (ix.accounts.first(), ix.accounts.get(1))
+        else {
+            return Ok(());
+        };
 
-                        let mut thresholds = vrt_config.thresholds.clone();
-                        self.sort_thresholds(&mut thresholds);
-                        for threshold in vrt_config.thresholds.iter() {
-                            let min_amount_out = *min_amount_out as f64 / divisor;
-                            if min_amount_out >= threshold.value {
-                                self.dispatch_platform_notifications(
-                                    &threshold.notification.destinations,
-                                    &threshold.notification.description,
-                                    min_amount_out,
-                                    &symbol,
-                                    &parser.transaction_signature,
-                                )
-                                .await?;
-                                break;
-                            }
-                        }
+        if let Some(lsts) = &instruction.lsts {
+            if let Some(alert_config) = lsts.get(&msol_mint_info.pubkey.to_string()) {
+                let thresholds = self.resolve_thresholds(
+                    &marinade_program.to_string(),
+                    &msol_mint_info.pubkey.to_string(),
+                    amount,
+                    &alert_config.thresholds,
+                );
+                for threshold in thresholds.iter() {
+                    if amount >= threshold.value {
+                        self.dispatch_platform_notifications(
+                            parser,
+                            &threshold.notification,
+                            &state_info.pubkey.to_string(),
+                            amount,
+                            unit,
+                        )
+                        .await?;
+                        break;
                     }
                 }
             }
-            JitoVaultProgram::EnqueueWithdrawal { ix, amount } => {
-                let _config_info = &ix.accounts[0];
-                let vault_info = &ix.accounts[1];
-                let _vault_staker_withdrawal_ticket_info = &ix.accounts[2];
-                let _vault_staker_withdrawal_ticket_token_account_info = &ix.accounts[3];
-                let _staker_info = &ix.accounts[4];
-                let _staker_vrt_token_account_info = &ix.accounts[5];
-                let _base_info = &ix.accounts[6];
+        }
 
-                let vault_acc = self.rpc_client.get_account(&vault_info.pubkey).await?;
-                let vault = Vault::deserialize(&mut vault_acc.data.as_slice())?;
+        Ok(())
+    }
 
-                // VRT amount
-                if let Some(ref vrts) = instruction.vrts {
-                    if let Some((address, vrt_config)) =
-                        vrts.get_key_value(&vault.vrt_mint.to_string())
-                    {
-                        let vrt = Pubkey::from_str(address).unwrap();
-                        let divisor = self.divisor(&vrt).await;
-                        let symbol = self.vrt_symbol(&vrt).await;
-
-                        let mut thresholds = vrt_config.thresholds.clone();
-                        self.sort_thresholds(&mut thresholds);
-                        for threshold in vrt_config.thresholds.iter() {
-                            let amount = *amount as f64 / divisor;
-                            if amount >= threshold.value {
-                                self.dispatch_platform_notifications(
-                                    &threshold.notification.destinations,
-                                    &threshold.notification.description,
-                                    amount,
-                                    &symbol,
-                                    &parser.transaction_signature,
-                                )
-                                .await?;
-                                break;
-                            }
-                        }
+    /// Handle a program decoded with its Anchor IDL
+    ///
+    /// - With `idl_amount`, the first named account listed under `accounts` selects the thresholds
+    /// - `notifications` are sent whenever the instruction is seen
+    /// - Notify only once for the first matching threshold.
+    async fn handle_anchor_idl_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        anchor_idl_program: &AnchorIdlProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!(
+            "Anchor IDL Program: {} {}",
+            anchor_idl_program.program, anchor_idl_program
+        );
 
-                        // USD amount
-                        if !vrt_config.usd_thresholds.is_empty() {
-                            let client = DefiLlamaClient::new();
-                            let vrt = Token::new(Chain::Solana, vrt.to_string());
-                            let prices = client.get_price(&vrt).await?;
+        let amount = instruction.idl_amount.as_ref().and_then(|idl_amount| {
+            let value = anchor_idl_program.arg(&idl_amount.arg)?;
+            let raw = value
+                .as_f64()
+                .or_else(|| value.as_str().and_then(|value| value.parse().ok()))?;
 
-                            if let Some(usd_price) = prices.coins.values().last() {
-                                let mut sorted_usd_thresholds = vrt_config.usd_thresholds.clone();
-                                sorted_usd_thresholds.sort_by(|a, b| {
-                                    b.value
-                                        .partial_cmp(&a.value)
-                                        .unwrap_or(std::cmp::Ordering::Equal)
-                                });
+            Some((
+                raw / 10_f64.powi(idl_amount.decimals as i32),
+                idl_amount.unit.as_str(),
+            ))
+        });
 
-                                for usd_threshold in sorted_usd_thresholds.iter() {
-                                    let amount = *amount as f64 / 1_000_000_000_f64;
-                                    let amount = (amount * usd_price.price) as u64;
+        if let (Some((amount, unit)), Some(accounts)) = (amount, &instruction.accounts) {
+            let alert = anchor_idl_program.accounts.iter().find_map(|(_, pubkey)| {
+                accounts
+                    .get(&pubkey.to_string())
+                    .map(|alert_config| (pubkey.to_string(), alert_config))
+            });
 
-                                    if amount >= usd_threshold.value {
-                                        self.dispatch_platform_notifications(
-                                            &usd_threshold.notification.destinations,
-                                            &usd_threshold.notification.description,
-                                            amount as f64,
-                                            "USD",
-                                            &parser.transaction_signature,
-                                        )
-                                        .await?;
-                                        break;
-                                    }
-                                }
-                            }
-                        }
+            if let Some((address, alert_config)) = alert {
+                let thresholds = self.resolve_thresholds(
+                    &anchor_idl_program.to_string(),
+                    &address,
+                    amount,
+                    &alert_config.thresholds,
+                );
+                for threshold in thresholds.iter() {
+                    if amount >= threshold.value {
+                        self.dispatch_platform_notifications(
+                            parser,
+                            &threshold.notification,
+                            &address,
+                            amount,
+                            unit,
+                        )
+                        .await?;
+                        break;
                     }
                 }
             }
-            JitoVaultProgram::InitializeConfig
-            | JitoVaultProgram::InitializeVault
-            | JitoVaultProgram::InitializeVaultWithMint
-            | JitoVaultProgram::InitializeVaultOperatorDelegation
-            | JitoVaultProgram::InitializeVaultNcnTicket
-            | JitoVaultProgram::InitializeVaultNcnSlasherOperatorTicket
-            | JitoVaultProgram::InitializeVaultNcnSlasherTicket
-            | JitoVaultProgram::WarmupVaultNcnTicket
-            | JitoVaultProgram::CooldownVaultNcnTicket
-            | JitoVaultProgram::WarmupVaultNcnSlasherTicket
-            | JitoVaultProgram::CooldownVaultNcnSlasherTicket
-            | JitoVaultProgram::ChangeWithdrawalTicketOwner
-            | JitoVaultProgram::BurnWithdrawalTicket
-            | JitoVaultProgram::SetDepositCapacity
-            | JitoVaultProgram::SetFees
-            | JitoVaultProgram::SetProgramFee
-            | JitoVaultProgram::SetProgramFeeWallet
-            | JitoVaultProgram::SetIsPaused
-            | JitoVaultProgram::DelegateTokenAccount
-            | JitoVaultProgram::SetAdmin
-            | JitoVaultProgram::SetSecondaryAdmin
-            | JitoVaultProgram::AddDelegation
-            | JitoVaultProgram::CooldownDelegation
-            | JitoVaultProgram::UpdateVaultBalance
-            | JitoVaultProgram::InitializeVaultUpdateStateTracker
-            | JitoVaultProgram::CrankVaultUpdateStateTracker
-            | JitoVaultProgram::CloseVaultUpdateStateTracker
-            | JitoVaultProgram::CreateTokenMetadata
-            | JitoVaultProgram::UpdateTokenMetadata
-            | JitoVaultProgram::SetConfigAdmin => {
-                unreachable!()
+        }
+
+        let (amount, unit) = amount.unwrap_or((0.0, ""));
+        let program_id = anchor_idl_program.ix.program_id.to_string();
+        for notification in instruction.notifications.iter() {
+            self.dispatch_platform_notifications(parser, notification, &program_id, amount, unit)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Handle Kamino Lend Program
+    ///
+    /// - Rules are keyed by the reserve liquidity mint under `lsts`, `{{pool}}` is the reserve
+    async fn handle_kamino_lend_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        kamino_lend_program: &KaminoLendProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!("Kamino Lend Program: {}", kamino_lend_program);
+
+        let (ix, amount) = match kamino_lend_program {
+            KaminoLendProgram::DepositCollateral { ix, amount }
+            | KaminoLendProgram::WithdrawCollateral { ix, amount } => (ix, *amount),
+        };
+        let (Some(reserve_info), Some(reserve_liquidity_mint_info)) =
+            (ix.accounts.get(4), ix.accounts.get(5))
+        else {
+            return Ok(());
+        };
+
+        self.dispatch_mint_thresholds(
+            parser,
+            &kamino_lend_program.to_string(),
+            instruction,
+            &reserve_liquidity_mint_info.pubkey,
+            &reserve_info.pubkey.to_string(),
+            amount,
+            false,
+        )
+        .await
+    }
+
+    /// Handle marginfi Program
+    ///
+    /// - Rules are keyed by the bank mint under `lsts`, `{{pool}}` is the bank
+    async fn handle_marginfi_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        marginfi_program: &MarginfiProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!("marginfi Program: {}", marginfi_program);
+
+        let (ix, amount) = match marginfi_program {
+            MarginfiProgram::Deposit { ix, amount } | MarginfiProgram::Withdraw { ix, amount } => {
+                (ix, *amount)
+            }
+        };
+        let (Some(bank_info), Some(user_token_account_info)) =
+            (ix.accounts.get(3), ix.accounts.get(4))
+        else {
+            return Ok(());
+        };
+        let Some(mint) = parser
+            .token_account_mints
+            .get(&user_token_account_info.pubkey)
+            .copied()
+        else {
+            return Ok(());
+        };
+
+        self.dispatch_mint_thresholds(
+            parser,
+            &marginfi_program.to_string(),
+            instruction,
+            &mint,
+            &bank_info.pubkey.to_string(),
+            amount,
+            false,
+        )
+        .await
+    }
+
+    /// Handle Vote Program
+    ///
+    /// - Commission updates of validators in the validator list of a pool under `stake_pools`,
+    ///   the amount is the new commission (%)
+    /// - Notify only once for the first matching threshold.
+    async fn handle_vote_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        vote_program: &VoteProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!("Vote Program: {}", vote_program);
+
+        let VoteProgram::UpdateCommission { ix, commission } = vote_program;
+        let Some(vote_account_info) = ix.accounts.first() else {
+            return Ok(());
+        };
+        let Some(stake_pools) = &instruction.stake_pools else {
+            return Ok(());
+        };
+        let commission = *commission as f64;
+
+        for (stake_pool, alert_config) in stake_pools.iter() {
+            let stake_pool_pubkey = Pubkey::from_str(stake_pool).map_err(|e| {
+                JitoBellError::Config(format!("Invalid stake pool {stake_pool}: {e}"))
+            })?;
+            if !self
+                .pool_validators
+                .contains(
+                    &self.rpc_client,
+                    &stake_pool_pubkey,
+                    &vote_account_info.pubkey,
+                )
+                .await?
+            {
+                continue;
+            }
+
+            let thresholds = self.resolve_thresholds(
+                &vote_program.to_string(),
+                stake_pool,
+                commission,
+                &alert_config.thresholds,
+            );
+            if let Some(threshold) = thresholds
+                .iter()
+                .find(|threshold| commission >= threshold.value)
+            {
+                let mut event = NotificationEvent::new(
+                    parser,
+                    &threshold.notification,
+                    stake_pool,
+                    commission,
+                    "%",
+                    &self.config.explorer_url,
+                );
+                event.validator = Some(vote_account_info.pubkey.to_string());
+
+                self.dispatch_event(&threshold.notification, event).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handle SPL Governance Program
+    ///
+    /// - `{{pool}}` is the realm, `{{proposal}}` the proposal account
+    /// - Limited to the realms or governances under `accounts` when set
+    async fn handle_spl_governance_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        spl_governance_program: &SplGovernanceProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!("SPL Governance Program: {}", spl_governance_program);
+
+        let governance = spl_governance_program.governance();
+        let realm = match spl_governance_program {
+            SplGovernanceProgram::CreateProposal { ix } => ix.accounts[0].pubkey,
+            SplGovernanceProgram::ExecuteTransaction { .. } => {
+                self.governance_realm(&governance).await?
             }
+        };
+
+        if let Some(accounts) = &instruction.accounts {
+            if !accounts.contains_key(&realm.to_string())
+                && !accounts.contains_key(&governance.to_string())
+            {
+                return Ok(());
+            }
+        }
+
+        self.dispatch_proposal_notifications(
+            parser,
+            &instruction.notifications,
+            &realm.to_string(),
+            &spl_governance_program.proposal().to_string(),
+        )
+        .await
+    }
+
+    /// Handle Squads v4 Multisig Program
+    ///
+    /// - `{{pool}}` is the multisig, `{{proposal}}` the proposal account
+    /// - Limited to the multisigs under `accounts` when set
+    async fn handle_squads_multisig_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        squads_multisig_program: &SquadsMultisigProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!("Squads Multisig Program: {}", squads_multisig_program);
+
+        let multisig = squads_multisig_program.multisig().to_string();
+        if let Some(accounts) = &instruction.accounts {
+            if !accounts.contains_key(&multisig) {
+                return Ok(());
+            }
+        }
+
+        self.dispatch_proposal_notifications(
+            parser,
+            &instruction.notifications,
+            &multisig,
+            &squads_multisig_program.proposal().to_string(),
+        )
+        .await
+    }
+
+    /// Dispatch every notification of a governance or multisig proposal
+    async fn dispatch_proposal_notifications(
+        &mut self,
+        parser: &JitoTransactionParser,
+        notifications: &[NotificationInfo],
+        pool: &str,
+        proposal: &str,
+    ) -> Result<(), JitoBellError> {
+        for notification in notifications.iter() {
+            let mut event = NotificationEvent::new(
+                parser,
+                notification,
+                pool,
+                0.0,
+                "",
+                &self.config.explorer_url,
+            );
+            event.proposal = Some(proposal.to_string());
+
+            self.dispatch_event(notification, event).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Realm of a governance account, the first field after the account type
+    async fn governance_realm(&mut self, governance: &Pubkey) -> Result<Pubkey, JitoBellError> {
+        if let Some(realm) = self.governance_realms.get(governance) {
+            return Ok(*realm);
         }
 
-        Ok(())
-    }
+        let governance_acc = self.rpc_client.get_account(governance).await?;
+        let realm = governance_acc
+            .data
+            .get(1..33)
+            .and_then(|realm| Pubkey::try_from(realm).ok())
+            .ok_or_else(|| {
+                JitoBellError::TransactionParse(format!("Invalid governance {governance}"))
+            })?;
+        self.governance_realms.insert(*governance, realm);
+
+        Ok(realm)
+    }
+
+    /// Handle Orca Whirlpool Program
+    ///
+    /// - `{{pool}}` is the whirlpool
+    async fn handle_orca_whirlpool_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        orca_whirlpool_program: &OrcaWhirlpoolProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!("Orca Whirlpool Program: {}", orca_whirlpool_program);
+
+        let (ix, legs) = match orca_whirlpool_program {
+            OrcaWhirlpoolProgram::IncreaseLiquidity { ix, legs }
+            | OrcaWhirlpoolProgram::DecreaseLiquidity { ix, legs } => (ix, legs),
+        };
+        let Some(whirlpool_info) = ix.accounts.first() else {
+            return Ok(());
+        };
+
+        self.dispatch_liquidity_thresholds(
+            parser,
+            &orca_whirlpool_program.to_string(),
+            instruction,
+            &whirlpool_info.pubkey.to_string(),
+            legs,
+        )
+        .await
+    }
+
+    /// Handle Raydium CPMM Program
+    ///
+    /// - `{{pool}}` is the pool state
+    async fn handle_raydium_cpmm_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        raydium_cpmm_program: &RaydiumCpmmProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!("Raydium CPMM Program: {}", raydium_cpmm_program);
+
+        let (ix, legs) = match raydium_cpmm_program {
+            RaydiumCpmmProgram::Deposit { ix, legs }
+            | RaydiumCpmmProgram::Withdraw { ix, legs } => (ix, legs),
+        };
+        let Some(pool_state_info) = ix.accounts.get(2) else {
+            return Ok(());
+        };
+
+        self.dispatch_liquidity_thresholds(
+            parser,
+            &raydium_cpmm_program.to_string(),
+            instruction,
+            &pool_state_info.pubkey.to_string(),
+            legs,
+        )
+        .await
+    }
+
+    /// Dispatch the thresholds of the first liquidity leg whose mint is under `lsts`
+    ///
+    /// - The amount is the vault balance change, or the instruction bound without balance records
+    async fn dispatch_liquidity_thresholds(
+        &mut self,
+        parser: &JitoTransactionParser,
+        instruction_name: &str,
+        instruction: &Instruction,
+        pool: &str,
+        legs: &[LiquidityLeg],
+    ) -> Result<(), JitoBellError> {
+        let Some(lsts) = &instruction.lsts else {
+            return Ok(());
+        };
+
+        let leg = legs.iter().find_map(|leg| {
+            leg.mint
+                .or_else(|| parser.token_account_mints.get(&leg.vault).copied())
+                .filter(|mint| lsts.contains_key(&mint.to_string()))
+                .map(|mint| (mint, leg))
+        });
+
+        if let Some((mint, leg)) = leg {
+            let amount = parser
+                .token_balance_change(&leg.vault)
+                .unwrap_or(leg.amount);
+
+            self.dispatch_mint_thresholds(
+                parser,
+                instruction_name,
+                instruction,
+                &mint,
+                pool,
+                amount,
+                false,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Decimals and symbol of stake pool token amounts
+    ///
+    /// - Pool mints not under `mints` keep 9 decimals and the "SOL" unit
+    fn pool_token(&self, pool_mint: &Pubkey) -> VrtEnrichment {
+        self.enrichment_scheduler
+            .registered_mint(pool_mint)
+            .unwrap_or_else(|| VrtEnrichment {
+                divisor: LAMPORTS_PER_SOL as f64,
+                symbol: "SOL".to_string(),
+                usd_price: None,
+            })
+    }
+
+    /// Dispatch the first matching threshold of the `lsts` rule of `mint`
+    ///
+    /// - `amount` is in base units of the mint
+    /// - `first_time_depositor`: the depositor's token account of `mint` was created by the transaction
+    #[allow(clippy::too_many_arguments)]
+    async fn dispatch_mint_thresholds(
+        &mut self,
+        parser: &JitoTransactionParser,
+        instruction_name: &str,
+        instruction: &Instruction,
+        mint: &Pubkey,
+        pool: &str,
+        amount: u64,
+        first_time_depositor: bool,
+    ) -> Result<(), JitoBellError> {
+        let mint_str = mint.to_string();
+        let Some(alert_config) = instruction
+            .lsts
+            .as_ref()
+            .and_then(|lsts| lsts.get(&mint_str))
+        else {
+            return Ok(());
+        };
+
+        let enrichment = self
+            .enrichment_scheduler
+            .enrich_vrt(&self.rpc_client, mint, false)
+            .await;
+        let amount = amount as f64 / enrichment.divisor;
+
+        let thresholds = self.resolve_thresholds(
+            instruction_name,
+            &mint_str,
+            amount,
+            &alert_config.thresholds,
+        );
+        for threshold in thresholds.iter() {
+            if amount >= threshold.value {
+                let mut event = NotificationEvent::new(
+                    parser,
+                    &threshold.notification,
+                    pool,
+                    amount,
+                    &enrichment.symbol,
+                    &self.config.explorer_url,
+                );
+                event.first_time_depositor = first_time_depositor;
+
+                self.dispatch_event(&threshold.notification, event).await?;
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handle Wormhole Token Bridge Program
+    ///
+    /// - Rules are keyed by the bridged mint under `lsts`, `{{pool}}` is the mint
+    async fn handle_wormhole_token_bridge_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        wormhole_token_bridge_program: &WormholeTokenBridgeProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!(
+            "Wormhole Token Bridge Program: {}",
+            wormhole_token_bridge_program
+        );
+
+        let WormholeTokenBridgeProgram::TransferNative {
+            ix,
+            amount,
+            target_chain,
+        } = wormhole_token_bridge_program;
+        let Some(mint_info) = ix.accounts.get(3) else {
+            return Ok(());
+        };
+        debug!(
+            "Bridge out of {} to Wormhole chain {target_chain}",
+            mint_info.pubkey
+        );
+
+        self.dispatch_mint_thresholds(
+            parser,
+            &wormhole_token_bridge_program.to_string(),
+            instruction,
+            &mint_info.pubkey,
+            &mint_info.pubkey.to_string(),
+            *amount,
+            false,
+        )
+        .await
+    }
+
+    /// Handle deBridge DLN Source Program
+    ///
+    /// - Rules are keyed by the given mint under `lsts`, `{{pool}}` is the mint
+    async fn handle_debridge_dln_source_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        debridge_dln_source_program: &DebridgeDlnSourceProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!(
+            "deBridge DLN Source Program: {}",
+            debridge_dln_source_program
+        );
+
+        let DebridgeDlnSourceProgram::CreateOrder { ix, amount } = debridge_dln_source_program;
+        let Some(token_mint_info) = ix.accounts.get(2) else {
+            return Ok(());
+        };
+
+        self.dispatch_mint_thresholds(
+            parser,
+            &debridge_dln_source_program.to_string(),
+            instruction,
+            &token_mint_info.pubkey,
+            &token_mint_info.pubkey.to_string(),
+            *amount,
+            false,
+        )
+        .await
+    }
+
+    /// Handle SPL Token 2022 Program
+    ///
+    /// - Transfers are keyed by the mint under `lsts`, `{{pool}}` is the mint
+    /// - Amounts are net of the fee withheld by the TransferFee extension
+    async fn handle_spl_token_2022_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        spl_token_2022_program: &SplToken2022Program,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!("SPL Token 2022 Program: {}", spl_token_2022_program);
+
+        let (mint, amount, fee, hook_program) =
+            match spl_token_2022_program {
+                SplToken2022Program::Transfer { ix, amount } => {
+                    let Some(mint) = ix.accounts.first().and_then(|source_info| {
+                        parser.token_account_mints.get(&source_info.pubkey)
+                    }) else {
+                        return Ok(());
+                    };
+                    // Mints with transfer fees reject unchecked transfers
+                    (*mint, *amount, Some(0), None)
+                }
+                SplToken2022Program::TransferChecked {
+                    ix,
+                    amount,
+                    hook_program,
+                    ..
+                } => {
+                    let Some(mint_info) = ix.accounts.get(1) else {
+                        return Ok(());
+                    };
+                    (mint_info.pubkey, *amount, None, *hook_program)
+                }
+                SplToken2022Program::TransferCheckedWithFee {
+                    ix,
+                    amount,
+                    fee,
+                    hook_program,
+                    ..
+                } => {
+                    let Some(mint_info) = ix.accounts.get(1) else {
+                        return Ok(());
+                    };
+                    (mint_info.pubkey, *amount, Some(*fee), *hook_program)
+                }
+                _ => return Ok(()),
+            };
+        if !instruction
+            .lsts
+            .as_ref()
+            .is_some_and(|lsts| lsts.contains_key(&mint.to_string()))
+        {
+            return Ok(());
+        }
+        if let Some(hook_program) = hook_program {
+            debug!("Transfer of {mint} invoked transfer hook {hook_program}");
+        }
+
+        let fee = match fee {
+            Some(fee) => fee,
+            None => match self
+                .token_2022_mints
+                .extensions(&self.rpc_client, &mint, parser.slot)
+                .await
+            {
+                Ok((epoch, extensions)) => {
+                    if hook_program.is_some() && hook_program != extensions.transfer_hook_program {
+                        warn!(
+                            "Transfer hook of {} is not the TransferHook program of {mint}",
+                            parser.transaction_signature
+                        );
+                    }
+                    extensions.transfer_fee(epoch, amount)
+                }
+                Err(e) => {
+                    warn!("Failed to fetch extensions of mint {mint}: {e}");
+                    0
+                }
+            },
+        };
+
+        self.dispatch_mint_thresholds(
+            parser,
+            &spl_token_2022_program.to_string(),
+            instruction,
+            &mint,
+            &mint.to_string(),
+            amount.saturating_sub(fee),
+            false,
+        )
+        .await
+    }
+
+    /// Handle SPL Single Pool Program
+    ///
+    /// - Rules are keyed by the pool mint under `lsts`, `{{pool}}` is the single-validator pool
+    /// - Deposits are reported with the pool tokens minted, withdrawals with the pool tokens burned
+    async fn handle_spl_single_pool_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        spl_single_pool_program: &SplSinglePoolProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!("SPL Single Pool Program: {}", spl_single_pool_program);
+
+        let (ix, token_amount) = match spl_single_pool_program {
+            SplSinglePoolProgram::DepositStake { ix } => (ix, None),
+            SplSinglePoolProgram::WithdrawStake { ix, token_amount } => (ix, Some(*token_amount)),
+        };
+        let Some(pool_info) = ix.accounts.first() else {
+            return Ok(());
+        };
+
+        // The user token account is the only token account of the instruction
+        let Some((user_token_account, mint)) = ix.accounts.iter().find_map(|meta| {
+            parser
+                .token_account_mints
+                .get(&meta.pubkey)
+                .map(|mint| (meta.pubkey, *mint))
+        }) else {
+            return Ok(());
+        };
+        let Some(amount) = parser
+            .token_balance_change(&user_token_account)
+            .or(token_amount)
+        else {
+            return Ok(());
+        };
+
+        let first_time_depositor = matches!(
+            spl_single_pool_program,
+            SplSinglePoolProgram::DepositStake { .. }
+        ) && parser.created_token_account(&user_token_account, &mint);
+
+        self.dispatch_mint_thresholds(
+            parser,
+            &spl_single_pool_program.to_string(),
+            instruction,
+            &mint,
+            &pool_info.pubkey.to_string(),
+            amount,
+            first_time_depositor,
+        )
+        .await
+    }
+
+    /// Handle Jupiter Program
+    ///
+    /// - Swaps into a configured mint are reported with the output amount, swaps out of it with the input amount
+    /// - Notify only once for the first matching threshold.
+    async fn handle_jupiter_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        jupiter_program: &JupiterProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!("Jupiter Program: {}", jupiter_program);
+
+        let JupiterProgram::Swap { swap, .. } = jupiter_program;
+        let Some(lsts) = &instruction.lsts else {
+            return Ok(());
+        };
+        let source_mint = swap.source_mint.or_else(|| {
+            parser
+                .token_account_mints
+                .get(&swap.source_token_account)
+                .copied()
+        });
+
+        let (mint, raw_amount) = if lsts.contains_key(&swap.destination_mint.to_string()) {
+            (swap.destination_mint, swap.out_amount)
+        } else if let Some(mint) = source_mint.filter(|mint| lsts.contains_key(&mint.to_string())) {
+            (mint, swap.in_amount)
+        } else {
+            return Ok(());
+        };
+
+        self.dispatch_mint_thresholds(
+            parser,
+            &jupiter_program.to_string(),
+            instruction,
+            &mint,
+            &mint.to_string(),
+            raw_amount,
+            false,
+        )
+        .await
+    }
+
+    /// Handle Jito Vault Program
+    ///
+    /// - Notify only once for the first matching threshold.
+    async fn handle_jito_vault_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        jito_vault_program: &JitoVaultProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!("Jito Vault Program: {}", jito_vault_program);
+
+        match jito_vault_program {
+            JitoVaultProgram::MintTo { ix, min_amount_out } => {
+                let _config_info = &ix.accounts[0];
+                let vault_info = &ix.accounts[1];
+                let vrt_mint_info = &ix.accounts[2];
+                let _depositor_info = &ix.accounts[3];
+                let _depositor_token_account = &ix.accounts[4];
+                let _vault_token_account = &ix.accounts[5];
+                let _depositor_vrt_token_account = &ix.accounts[6];
+                let _vault_fee_token_account = &ix.accounts[7];
+
+                if let Some(vrts) = instruction.vrts.clone() {
+                    if let Some((address, vrt_config)) =
+                        vrts.get_key_value(&vrt_mint_info.pubkey.to_string())
+                    {
+                        let vrt = Pubkey::from_str(address).unwrap();
+                        let enrichment = self
+                            .enrichment_scheduler
+                            .enrich_vrt(&self.rpc_client, &vrt, false)
+                            .await;
+
+                        let min_amount_out = *min_amount_out as f64 / enrichment.divisor;
+                        let thresholds = self.resolve_thresholds(
+                            &jito_vault_program.to_string(),
+                            address,
+                            min_amount_out,
+                            &vrt_config.thresholds,
+                        );
+                        for threshold in thresholds.iter() {
+                            if min_amount_out >= threshold.value {
+                                self.dispatch_platform_notifications(
+                                    parser,
+                                    &threshold.notification,
+                                    &vault_info.pubkey.to_string(),
+                                    min_amount_out,
+                                    &enrichment.symbol,
+                                )
+                                .await?;
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+            JitoVaultProgram::EnqueueWithdrawal { ix, amount } => {
+                let _config_info = &ix.accounts[0];
+                let vault_info = &ix.accounts[1];
+                let _vault_staker_withdrawal_ticket_info = &ix.accounts[2];
+                let _vault_staker_withdrawal_ticket_token_account_info = &ix.accounts[3];
+                let _staker_info = &ix.accounts[4];
+                let _staker_vrt_token_account_info = &ix.accounts[5];
+                let _base_info = &ix.accounts[6];
+
+                let vault_acc = self.rpc_client.get_account(&vault_info.pubkey).await?;
+                let vault = Vault::deserialize(&mut vault_acc.data.as_slice())?;
+
+                // VRT amount
+                if let Some(ref vrts) = instruction.vrts {
+                    if let Some((address, vrt_config)) =
+                        vrts.get_key_value(&vault.vrt_mint.to_string())
+                    {
+                        let vrt = Pubkey::from_str(address).unwrap();
+                        let with_price = !vrt_config.usd_thresholds.is_empty();
+                        let enrichment = self
+                            .enrichment_scheduler
+                            .enrich_vrt(&self.rpc_client, &vrt, with_price)
+                            .await;
+
+                        let vrt_amount = *amount as f64 / enrichment.divisor;
+                        let thresholds = self.resolve_thresholds(
+                            &jito_vault_program.to_string(),
+                            address,
+                            vrt_amount,
+                            &vrt_config.thresholds,
+                        );
+                        for threshold in thresholds.iter() {
+                            if vrt_amount >= threshold.value {
+                                self.dispatch_platform_notifications(
+                                    parser,
+                                    &threshold.notification,
+                                    &vault_info.pubkey.to_string(),
+                                    vrt_amount,
+                                    &enrichment.symbol,
+                                )
+                                .await?;
+                                break;
+                            }
+                        }
+
+                        // USD amount, skipped when the price is unavailable within the budget
+                        if with_price {
+                            if let Some(usd_price) = enrichment.usd_price {
+                                let mut sorted_usd_thresholds = vrt_config.usd_thresholds.clone();
+                                sorted_usd_thresholds.sort_by(|a, b| {
+                                    b.value
+                                        .partial_cmp(&a.value)
+                                        .unwrap_or(std::cmp::Ordering::Equal)
+                                });
+
+                                for usd_threshold in sorted_usd_thresholds.iter_mut() {
+                                    let amount = *amount as f64 / 1_000_000_000_f64;
+                                    let amount = (amount * usd_price) as u64;
+
+                                    if amount >= usd_threshold.value {
+                                        usd_threshold.notification.threshold_ratio =
+                                            Some(amount as f64 / usd_threshold.value as f64);
+                                        let mut event = NotificationEvent::new(
+                                            parser,
+                                            &usd_threshold.notification,
+                                            &vault_info.pubkey.to_string(),
+                                            amount as f64,
+                                            "USD",
+                                            &self.config.explorer_url,
+                                        );
+                                        event.amount_usd = Some(amount as f64);
+
+                                        self.dispatch_event(&usd_threshold.notification, event)
+                                            .await?;
+                                        break;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            JitoVaultProgram::ChangeWithdrawalTicketOwner { ix } => {
+                let _config_info = &ix.accounts[0];
+                let vault_info = &ix.accounts[1];
+                let vault_staker_withdrawal_ticket_info = &ix.accounts[2];
+                let _old_owner_info = &ix.accounts[3];
+                let _new_owner_info = &ix.accounts[4];
+
+                let vault_acc = self.rpc_client.get_account(&vault_info.pubkey).await?;
+                let vault = Vault::deserialize(&mut vault_acc.data.as_slice())?;
+
+                let ticket_acc = self
+                    .rpc_client
+                    .get_account(&vault_staker_withdrawal_ticket_info.pubkey)
+                    .await?;
+                let ticket =
+                    VaultStakerWithdrawalTicket::deserialize(&mut ticket_acc.data.as_slice())?;
+
+                self.dispatch_vault_thresholds(
+                    parser,
+                    jito_vault_program,
+                    instruction,
+                    &vault.vrt_mint,
+                    &vault_info.pubkey,
+                    ticket.vrt_amount,
+                    &vault.vrt_mint,
+                )
+                .await?;
+            }
+            JitoVaultProgram::BurnWithdrawalTicket { ix } => {
+                let vault_info = &ix.accounts[1];
+                let vrt_mint_info = &ix.accounts[3];
+                let vault_staker_withdrawal_ticket_token_account_info = &ix.accounts[7];
+
+                // The ticket token account holds the VRT being burned
+                let vrt_amount = parser
+                    .pre_token_balances
+                    .get(&vault_staker_withdrawal_ticket_token_account_info.pubkey)
+                    .copied()
+                    .unwrap_or_default();
+
+                self.dispatch_vault_thresholds(
+                    parser,
+                    jito_vault_program,
+                    instruction,
+                    &vrt_mint_info.pubkey,
+                    &vault_info.pubkey,
+                    vrt_amount,
+                    &vrt_mint_info.pubkey,
+                )
+                .await?;
+            }
+            JitoVaultProgram::AddDelegation { ix, amount }
+            | JitoVaultProgram::CooldownDelegation { ix, amount } => {
+                let _config_info = &ix.accounts[0];
+                let vault_info = &ix.accounts[1];
+                let _operator_info = &ix.accounts[2];
+                let _vault_operator_delegation_info = &ix.accounts[3];
+                let _admin_info = &ix.accounts[4];
+
+                let vault_acc = self.rpc_client.get_account(&vault_info.pubkey).await?;
+                let vault = Vault::deserialize(&mut vault_acc.data.as_slice())?;
+
+                // Delegated amounts are denominated in the supported token
+                self.dispatch_vault_thresholds(
+                    parser,
+                    jito_vault_program,
+                    instruction,
+                    &vault.vrt_mint,
+                    &vault_info.pubkey,
+                    *amount,
+                    &vault.supported_mint,
+                )
+                .await?;
+            }
+            JitoVaultProgram::InitializeConfig
+            | JitoVaultProgram::InitializeVault
+            | JitoVaultProgram::InitializeVaultWithMint
+            | JitoVaultProgram::InitializeVaultOperatorDelegation
+            | JitoVaultProgram::InitializeVaultNcnTicket
+            | JitoVaultProgram::InitializeVaultNcnSlasherOperatorTicket
+            | JitoVaultProgram::InitializeVaultNcnSlasherTicket
+            | JitoVaultProgram::WarmupVaultNcnTicket
+            | JitoVaultProgram::CooldownVaultNcnTicket
+            | JitoVaultProgram::WarmupVaultNcnSlasherTicket
+            | JitoVaultProgram::CooldownVaultNcnSlasherTicket
+            | JitoVaultProgram::SetDepositCapacity
+            | JitoVaultProgram::SetFees
+            | JitoVaultProgram::SetProgramFee
+            | JitoVaultProgram::SetProgramFeeWallet
+            | JitoVaultProgram::SetIsPaused
+            | JitoVaultProgram::DelegateTokenAccount
+            | JitoVaultProgram::SetAdmin
+            | JitoVaultProgram::SetSecondaryAdmin
+            | JitoVaultProgram::UpdateVaultBalance
+            | JitoVaultProgram::InitializeVaultUpdateStateTracker
+            | JitoVaultProgram::CrankVaultUpdateStateTracker
+            | JitoVaultProgram::CloseVaultUpdateStateTracker
+            | JitoVaultProgram::CreateTokenMetadata
+            | JitoVaultProgram::UpdateTokenMetadata
+            | JitoVaultProgram::SetConfigAdmin => {
+                unreachable!()
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Notify the first threshold of the VRT (keyed by `vrt_mint`) the amount reaches
+    ///
+    /// - `amount_mint` decides the decimals and symbol of the raw amount
+    #[allow(clippy::too_many_arguments)]
+    async fn dispatch_vault_thresholds(
+        &mut self,
+        parser: &JitoTransactionParser,
+        jito_vault_program: &JitoVaultProgram,
+        instruction: &Instruction,
+        vrt_mint: &Pubkey,
+        vault: &Pubkey,
+        amount: u64,
+        amount_mint: &Pubkey,
+    ) -> Result<(), JitoBellError> {
+        let Some(ref vrts) = instruction.vrts else {
+            return Ok(());
+        };
+        let Some((address, vrt_config)) = vrts.get_key_value(&vrt_mint.to_string()) else {
+            return Ok(());
+        };
+
+        let enrichment = self
+            .enrichment_scheduler
+            .enrich_vrt(&self.rpc_client, amount_mint, false)
+            .await;
+
+        let amount = amount as f64 / enrichment.divisor;
+        let thresholds = self.resolve_thresholds(
+            &jito_vault_program.to_string(),
+            address,
+            amount,
+            &vrt_config.thresholds,
+        );
+        for threshold in thresholds.iter() {
+            if amount >= threshold.value {
+                self.dispatch_platform_notifications(
+                    parser,
+                    &threshold.notification,
+                    &vault.to_string(),
+                    amount,
+                    &enrichment.symbol,
+                )
+                .await?;
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handle Jito Steward Program
+    ///
+    /// - Notify every configured notification, steward instructions carry no amount
+    async fn handle_jito_steward_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        jito_steward_program: &JitoStewardProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!("Jito Steward Program: {}", jito_steward_program);
+
+        let ix = jito_steward_program.ix();
+        let (pool, validator) = match jito_steward_program {
+            JitoStewardProgram::Rebalance { .. } => {
+                let _config_info = ix.accounts.first();
+                let stake_pool_info = ix.accounts.get(4);
+                let vote_account_info = ix.accounts.get(11);
+
+                (
+                    stake_pool_info.map(|info| info.pubkey.to_string()),
+                    vote_account_info.map(|info| info.pubkey.to_string()),
+                )
+            }
+            _ => {
+                let config_info = ix.accounts.first();
+
+                (config_info.map(|info| info.pubkey.to_string()), None)
+            }
+        };
+        let pool = pool.unwrap_or_default();
+
+        for notification in instruction.notifications.iter() {
+            let mut event = NotificationEvent::new(
+                parser,
+                notification,
+                &pool,
+                0.0,
+                "",
+                &self.config.explorer_url,
+            );
+            event.validator = validator.clone();
+
+            self.dispatch_event(notification, event).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Handle Jito Tip Payment Program
+    ///
+    /// - Tips: sum every tip of the transaction, notify once for the first matching lamport threshold
+    /// - Other instructions: notify every configured notification
+    async fn handle_jito_tip_payment_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        jito_tip_payment_program: &JitoTipPaymentProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!("Jito Tip Payment Program: {}", jito_tip_payment_program);
+
+        let ix = jito_tip_payment_program.ix();
+        let payer = ix
+            .accounts
+            .first()
+            .map(|info| info.pubkey.to_string())
+            .unwrap_or_default();
+
+        match jito_tip_payment_program {
+            JitoTipPaymentProgram::Tip { .. } => {
+                let tips: Vec<(&JitoTipPaymentProgram, u64)> = parser
+                    .programs
+                    .iter()
+                    .filter_map(|program| match program {
+                        JitoBellProgram::JitoTipPayment(
+                            tip @ JitoTipPaymentProgram::Tip { lamports, .. },
+                        ) => Some((tip, *lamports)),
+                        _ => None,
+                    })
+                    .collect();
+
+                // Only the first tip of the transaction notifies
+                if !tips
+                    .first()
+                    .is_some_and(|(tip, _)| std::ptr::eq(*tip, jito_tip_payment_program))
+                {
+                    return Ok(());
+                }
+                let lamports: u64 = tips.iter().map(|(_, lamports)| lamports).sum();
+
+                self.dispatch_lamport_thresholds(
+                    parser,
+                    &instruction.lamport_thresholds,
+                    &payer,
+                    lamports,
+                    None,
+                )
+                .await?;
+            }
+            JitoTipPaymentProgram::ClaimTips { .. }
+            | JitoTipPaymentProgram::ChangeTipReceiver { .. }
+            | JitoTipPaymentProgram::ChangeBlockBuilder { .. } => {
+                for notification in instruction.notifications.iter() {
+                    let event = NotificationEvent::new(
+                        parser,
+                        notification,
+                        &payer,
+                        0.0,
+                        "",
+                        &self.config.explorer_url,
+                    );
+
+                    self.dispatch_event(notification, event).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handle Jito Tip Distribution Program
+    ///
+    /// - Notify only once for the first matching lamport threshold
+    async fn handle_jito_tip_distribution_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        jito_tip_distribution_program: &JitoTipDistributionProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!(
+            "Jito Tip Distribution Program: {}",
+            jito_tip_distribution_program
+        );
+
+        match jito_tip_distribution_program {
+            JitoTipDistributionProgram::Claim { ix, amount } => {
+                let _config_info = &ix.accounts[0];
+                let tip_distribution_account_info = &ix.accounts[1];
+                let claimant_info = &ix.accounts[4];
+
+                self.dispatch_lamport_thresholds(
+                    parser,
+                    &instruction.lamport_thresholds,
+                    &tip_distribution_account_info.pubkey.to_string(),
+                    *amount,
+                    Some(claimant_info.pubkey.to_string()),
+                )
+                .await?;
+            }
+            JitoTipDistributionProgram::UploadMerkleRoot {
+                ix,
+                max_total_claim,
+                max_num_nodes: _,
+            } => {
+                let tip_distribution_account_info = &ix.accounts[1];
+
+                self.dispatch_lamport_thresholds(
+                    parser,
+                    &instruction.lamport_thresholds,
+                    &tip_distribution_account_info.pubkey.to_string(),
+                    *max_total_claim,
+                    None,
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handle System Program
+    ///
+    /// - Transfers from or to a watched account, notify once per account for the first matching lamport threshold
+    async fn handle_system_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        system_program: &SystemProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!("System Program: {}", system_program);
+
+        match system_program {
+            SystemProgram::Transfer { ix, lamports } => {
+                let from_info = &ix.accounts[0];
+                let to_info = &ix.accounts[1];
+
+                if let Some(accounts) = &instruction.accounts {
+                    for account_info in [from_info, to_info] {
+                        let account = account_info.pubkey.to_string();
+                        if let Some(alert_config) = accounts.get(&account) {
+                            self.dispatch_lamport_thresholds(
+                                parser,
+                                &alert_config.lamport_thresholds,
+                                &account,
+                                *lamports,
+                                None,
+                            )
+                            .await?;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Notify the first lamport threshold the amount reaches, amounts are shown in SOL
+    async fn dispatch_lamport_thresholds(
+        &mut self,
+        parser: &JitoTransactionParser,
+        lamport_thresholds: &[LamportThresholdConfig],
+        pool: &str,
+        lamports: u64,
+        claimant: Option<String>,
+    ) -> Result<(), JitoBellError> {
+        let mut lamport_thresholds = lamport_thresholds.to_vec();
+        lamport_thresholds.sort_by_key(|threshold| Reverse(threshold.value));
+
+        if let Some(threshold) = lamport_thresholds
+            .iter_mut()
+            .find(|threshold| lamports >= threshold.value)
+        {
+            threshold.notification.threshold_ratio = Some(lamports as f64 / threshold.value as f64);
+            let mut event = NotificationEvent::new(
+                parser,
+                &threshold.notification,
+                pool,
+                lamports as f64 / LAMPORTS_PER_SOL as f64,
+                "SOL",
+                &self.config.explorer_url,
+            );
+            event.claimant = claimant;
+
+            self.dispatch_event(&threshold.notification, event).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Handle the priority fee of a transaction carrying a watched instruction
+    ///
+    /// - Thresholds of the first watched instruction with `priority_fee_thresholds` apply, once per transaction
+    async fn handle_priority_fee(
+        &mut self,
+        parser: &JitoTransactionParser,
+    ) -> Result<(), JitoBellError> {
+        let Some(priority_fee) = parser.compute_budget.priority_fee() else {
+            return Ok(());
+        };
+
+        let priority_fee_thresholds = parser.programs.iter().find_map(|program| {
+            if !self.notifies(parser, program) {
+                return None;
+            }
+
+            self.config
+                .programs
+                .get(&program.to_string())
+                .and_then(|program_config| {
+                    program_config.instructions.get(&program.instruction_name())
+                })
+                .map(|instruction| instruction.priority_fee_thresholds.clone())
+                .filter(|thresholds| !thresholds.is_empty())
+                .map(|thresholds| (program, thresholds))
+        });
+
+        if let Some((program, priority_fee_thresholds)) = priority_fee_thresholds {
+            debug!("Priority fee: {priority_fee} lamports");
+            let instruction_name = program.instruction_name();
+            tracing::Span::current()
+                .record("program", program.to_string().as_str())
+                .record("instruction", instruction_name.as_str());
+            self.current_instruction = Some((program.to_string(), instruction_name));
+
+            let fee_payer = parser
+                .fee_payer
+                .map(|fee_payer| fee_payer.to_string())
+                .unwrap_or_default();
+            self.dispatch_lamport_thresholds(
+                parser,
+                &priority_fee_thresholds,
+                &fee_payer,
+                priority_fee,
+                None,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Whether the instruction may notify, failed transactions only with `notify_failed`
+    fn notifies(&self, parser: &JitoTransactionParser, program: &JitoBellProgram) -> bool {
+        !parser.failed
+            || self
+                .config
+                .programs
+                .get(&program.to_string())
+                .and_then(|program_config| {
+                    program_config.instructions.get(&program.instruction_name())
+                })
+                .is_some_and(|instruction| instruction.notify_failed)
+    }
+
+    /// Handle Jito TipRouter Program
+    ///
+    /// - Notify every configured notification, reward routing and votes carry no amount
+    async fn handle_jito_tip_router_program(
+        &mut self,
+        parser: &JitoTransactionParser,
+        jito_tip_router_program: &JitoTipRouterProgram,
+        instruction: &Instruction,
+    ) -> Result<(), JitoBellError> {
+        debug!(
+            "Jito TipRouter Program: {} (epoch {})",
+            jito_tip_router_program,
+            jito_tip_router_program.epoch()
+        );
+
+        let ix = jito_tip_router_program.ix();
+        let (ncn, operator) = match jito_tip_router_program {
+            JitoTipRouterProgram::CastVote { .. } => {
+                let _epoch_state_info = ix.accounts.first();
+                let ncn_info = ix.accounts.get(3);
+                let operator_info = ix.accounts.get(6);
+
+                (
+                    ncn_info.map(|info| info.pubkey.to_string()),
+                    operator_info.map(|info| info.pubkey.to_string()),
+                )
+            }
+            _ => {
+                let first_info = ix.accounts.first();
+
+                (first_info.map(|info| info.pubkey.to_string()), None)
+            }
+        };
+        let ncn = ncn.unwrap_or_default();
+
+        for notification in instruction.notifications.iter() {
+            let mut event = NotificationEvent::new(
+                parser,
+                notification,
+                &ncn,
+                0.0,
+                "",
+                &self.config.explorer_url,
+            );
+            event.validator = operator.clone();
+
+            self.dispatch_event(notification, event).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Dispatch platform notifications
+    ///
+    /// - Hold the notification for ordered delivery if enabled, otherwise deliver immediately
+    async fn dispatch_platform_notifications(
+        &mut self,
+        parser: &JitoTransactionParser,
+        notification: &NotificationInfo,
+        pool: &str,
+        amount: f64,
+        unit: &str,
+    ) -> Result<(), JitoBellError> {
+        let event = NotificationEvent::new(
+            parser,
+            notification,
+            pool,
+            amount,
+            unit,
+            &self.config.explorer_url,
+        );
+
+        self.dispatch_event(notification, event).await
+    }
+
+    /// Dispatch notification event
+    ///
+    /// - Hold the event for ordered delivery if enabled, otherwise deliver immediately
+    async fn dispatch_event(
+        &mut self,
+        notification: &NotificationInfo,
+        mut event: NotificationEvent,
+    ) -> Result<(), JitoBellError> {
+        if self.suppressions.is_muted(&event.pool)
+            || self
+                .current_instruction
+                .as_ref()
+                .is_some_and(|(program, instruction)| {
+                    self.suppressions.is_instruction_muted(program, instruction)
+                })
+        {
+            debug!("Skip muted notification: {}", event.transaction_signature);
+            return Ok(());
+        }
+
+        self.complete_event(notification, &mut event).await;
+
+        self.recent
+            .push(RecentEvent::Notification(Box::new(event.clone())));
+
+        if let Some((program, instruction)) = &self.current_instruction {
+            if let Some(digest) = self
+                .config
+                .programs
+                .get(program)
+                .and_then(|program| program.instructions.get(instruction))
+                .and_then(|instruction| instruction.digest.as_ref())
+            {
+                debug!(
+                    "Add notification to the {program}.{instruction} digest: {}",
+                    event.transaction_signature
+                );
+                self.instruction_digests.add(
+                    program,
+                    instruction,
+                    digest,
+                    notification,
+                    &event,
+                    Instant::now(),
+                );
+                return Ok(());
+            }
+        }
+
+        if let Some(ordered_delivery) = self.ordered_delivery.as_mut() {
+            let signature = event.transaction_signature.clone();
+            let pending = PendingNotification {
+                notification: notification.clone(),
+                event,
+            };
+            match ordered_delivery.push(pending) {
+                PushOutcome::Queued => return Ok(()),
+                PushOutcome::Duplicate => {
+                    debug!("Skip duplicate notification: {signature}");
+                    return Ok(());
+                }
+                PushOutcome::Overflow(pending) => {
+                    warn!("Ordered delivery queue is full, delivering immediately: {signature}");
+                    return self
+                        .deliver_notification(&pending.notification, &pending.event)
+                        .await;
+                }
+            }
+        }
+
+        self.deliver_notification(notification, &event).await
+    }
+
+    /// Fill the template variables not known where the event was built
+    ///
+    /// - `amount_usd` of SOL amounts is only looked up when a template uses it
+    async fn complete_event(&self, notification: &NotificationInfo, event: &mut NotificationEvent) {
+        event.timezone = self.config.timezone;
+        if event.pool_name.is_none() {
+            event.pool_name = self.config.pool_names.get(&event.pool).cloned();
+        }
+        if !self.config.account_labels.is_empty() {
+            event.account_labels = event
+                .accounts()
+                .filter_map(|account| self.config.account_labels.get_key_value(account))
+                .map(|(account, label)| (account.clone(), label.clone()))
+                .collect();
+        }
+        if let Some((program, instruction)) = &self.current_instruction {
+            event.program.get_or_insert_with(|| program.clone());
+            event.instruction.get_or_insert_with(|| instruction.clone());
+        }
+        if event.block_time.is_none() {
+            event.block_time = self.block_times.get(&event.slot).copied();
+        }
+
+        if event.amount_usd.is_none()
+            && event.currency_unit == "SOL"
+            && self.uses_template_variable(notification, "amount_usd")
+        {
+            if let Some(usd_price) = self
+                .enrichment_scheduler
+                .usd_price(&spl_token::native_mint::id())
+                .await
+            {
+                event.amount_usd = Some(event.amount * usd_price);
+            }
+        }
+    }
+
+    /// Whether a template the notification may be rendered with uses the variable
+    fn uses_template_variable(&self, notification: &NotificationInfo, variable: &str) -> bool {
+        let variable = format!("{{{{{variable}}}}}");
+
+        notification
+            .template
+            .as_deref()
+            .into_iter()
+            .chain(
+                notification
+                    .destinations
+                    .iter()
+                    .filter_map(Destination::template),
+            )
+            .chain(self.config.message_templates.values().map(String::as_str))
+            .chain(
+                self.config
+                    .localized_templates
+                    .values()
+                    .flat_map(|templates| templates.values().map(String::as_str)),
+            )
+            .any(|template| template.contains(&variable))
+    }
+
+    /// Report sizes of the bounded queues and caches, and how often their caps were hit
+    fn report_limits(&self) {
+        let (pending, dedup, overflowed, dedup_evicted) = match &self.ordered_delivery {
+            Some(ordered_delivery) => (
+                ordered_delivery.pending_len(),
+                ordered_delivery.dedup_len(),
+                ordered_delivery.overflowed(),
+                ordered_delivery.dedup_evicted(),
+            ),
+            None => (0, 0, 0, 0),
+        };
+        let (median_cache, median_cache_evicted) = match &self.dynamic_thresholds {
+            Some(dynamic_thresholds) => {
+                (dynamic_thresholds.cache_len(), dynamic_thresholds.evicted())
+            }
+            None => (0, 0),
+        };
+
+        datapoint_info!(
+            "jito-bell-limits",
+            ("epoch", self.epoch_metrics.epoch, i64),
+            ("pending_notifications", pending, i64),
+            ("dedup_entries", dedup, i64),
+            ("median_cache_entries", median_cache, i64),
+            ("ordered_delivery_overflowed", overflowed, i64),
+            ("dedup_evicted", dedup_evicted, i64),
+            ("median_cache_evicted", median_cache_evicted, i64),
+        );
+    }
+
+    /// Release held notifications in order once their hold window has passed
+    async fn release_ordered_notifications(&mut self, tip_slot: u64) {
+        let released = match self.ordered_delivery.as_mut() {
+            Some(ordered_delivery) => ordered_delivery.release(tip_slot),
+            None => return,
+        };
+
+        for mut pending in released {
+            if pending.event.block_time.is_none() {
+                pending.event.block_time = self.block_times.get(&pending.event.slot).copied();
+            }
+            if let Err(e) = self
+                .deliver_notification(&pending.notification, &pending.event)
+                .await
+            {
+                error!("Error: {e}");
+            }
+        }
+    }
+
+    /// Deliver notification to every destination
+    ///
+    /// - Return error only if ALL platforms failed, or handle as needed
+    #[tracing::instrument(
+        name = "dispatch",
+        skip_all,
+        fields(signature = %event.transaction_signature)
+    )]
+    async fn deliver_notification(
+        &mut self,
+        notification: &NotificationInfo,
+        event: &NotificationEvent,
+    ) -> Result<(), JitoBellError> {
+        if let Some(leader_election) = &self.leader_election {
+            if !leader_election.is_leader() {
+                debug!(
+                    "Standby, skip notification: {}",
+                    event.transaction_signature
+                );
+                return Ok(());
+            }
+        }
+
+        if let Some(websocket_broadcaster) = &self.websocket_broadcaster {
+            if let Err(e) = websocket_broadcaster.broadcast_notification(event) {
+                error!("Failed to broadcast notification: {e}");
+            }
+        }
+
+        // Destinations with a threshold multiplier the amount does not reach are skipped
+        let destinations: Vec<_> = notification
+            .destinations
+            .iter()
+            .filter(|destination| destination.accepts(notification.threshold_ratio))
+            .collect();
+        if destinations.is_empty() {
+            return Ok(());
+        }
+        let mut errors = Vec::new();
+        let time_of_day = TimeOfDay::at(chrono::Utc::now(), &self.config.timezone);
+
+        // Holds, rate limits and circuits are applied in order, then the destinations are sent
+        // to concurrently
+        let mut sends = Vec::new();
+        for destination in destinations.iter() {
+            let event = event.for_destination(destination);
+
+            let schedule = self.config.schedules.get(destination.name());
+            let quiet_hours = schedule.and_then(|schedule| schedule.quiet_hours.as_ref());
+            if quiet_hours.is_some_and(|quiet_hours| quiet_hours.holds(event.severity, time_of_day))
+            {
+                debug!(
+                    "Quiet hours of {destination}, hold notification: {}",
+                    event.transaction_signature
+                );
+                self.quiet_hours_digests.hold(destination.name(), &event);
+                continue;
+            }
+
+            let rate_limit = schedule.and_then(|schedule| schedule.rate_limit.as_ref());
+            if rate_limit.is_some_and(|rate_limit| {
+                !self
+                    .rate_limiters
+                    .admit(destination.name(), rate_limit, &event, Instant::now())
+            }) {
+                debug!(
+                    "Rate limit of {destination} reached, hold notification: {}",
+                    event.transaction_signature
+                );
+                continue;
+            }
+
+            let allowed = self
+                .circuit_breakers
+                .allow(destination.name(), Instant::now());
+            let receipt_id = self.open_receipt(destination.name(), &event);
+            let outbox_id =
+                self.enqueue_outbox(destination.name(), notification, &event, receipt_id);
+            sends.push((destination, event, outbox_id, receipt_id, allowed));
+        }
+
+        let handler = &*self;
+        let results = join_all(sends.iter().map(
+            |(destination, event, _, _, allowed)| async move {
+                if !allowed {
+                    return Err(handler.circuit_open_error(destination.name()));
+                }
+                handler
+                    .post_to_destination(destination.name(), notification, event)
+                    .await
+            },
+        ))
+        .await;
+
+        if self.config.tracing.is_some() {
+            if let Some(block_time) = event.block_time {
+                let since_block_time_ms =
+                    chrono::Utc::now().timestamp_millis() - block_time * 1_000;
+                datapoint_info!(
+                    "jito-bell-dispatch-latency",
+                    ("since_block_time_ms", since_block_time_ms, i64)
+                );
+            }
+        }
+
+        for ((destination, event, outbox_id, receipt_id, allowed), result) in
+            sends.into_iter().zip(results)
+        {
+            if allowed {
+                self.record_circuit(destination.name(), &result);
+            } else if outbox_id.is_none() {
+                // Skipped, the circuit opening was alerted once instead of every notification
+                self.settle_receipt(receipt_id, ReceiptStatus::Skipped, 0, &result);
+                datapoint_info!(
+                    "jito-bell-circuit-skip",
+                    ("destination", destination.name(), String)
+                );
+                continue;
+            }
+            self.settle_outbox(outbox_id, &result);
+            let status = ReceiptStatus::after_attempt(result.is_ok(), outbox_id.is_some());
+            self.settle_receipt(receipt_id, status, 1, &result);
+            if let (Err(e), None) = (&result, outbox_id) {
+                let mut entry = OutboxEntry::new(notification, &event);
+                entry.receipt_id = receipt_id;
+                self.dead_letter(destination.name(), entry, e.to_string(), 1)
+                    .await;
+            }
+
+            if let Err(e) = result {
+                error!("Failed to send to {}: {:?}", destination, e);
+                errors.push((destination.name(), e));
+            }
+        }
+
+        self.send_circuit_alerts().await;
+
+        if errors.len() == destinations.len() {
+            Err(JitoBellError::Notification(
+                "All platforms failed".to_string(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Outbox of the event store, when enabled
+    fn outbox(&self) -> Option<&EventStore> {
+        self.store
+            .as_deref()
+            .filter(|_| self.config.store.as_ref().is_some_and(|store| store.outbox))
+    }
+
+    /// Keep a notification in the outbox until it is sent
+    ///
+    /// - Returns the outbox ID, None when the outbox is disabled or failed
+    fn enqueue_outbox(
+        &self,
+        destination: &str,
+        notification: &NotificationInfo,
+        event: &NotificationEvent,
+        receipt_id: Option<i64>,
+    ) -> Option<i64> {
+        let outbox = self.outbox()?;
+        let mut entry = OutboxEntry::new(notification, event);
+        entry.receipt_id = receipt_id;
+
+        match serde_json::to_string(&entry)
+            .map_err(|e| JitoBellError::Notification(e.to_string()))
+            .and_then(|payload| outbox.enqueue_outbox(destination, &payload))
+        {
+            Ok(id) => Some(id),
+            Err(e) => {
+                error!("Failed to add notification to the outbox: {e}");
+                None
+            }
+        }
+    }
+
+    /// Remove a sent notification from the outbox, or record the failure to retry it later
+    fn settle_outbox(&self, outbox_id: Option<i64>, result: &Result<(), JitoBellError>) {
+        let (Some(outbox), Some(id)) = (self.outbox(), outbox_id) else {
+            return;
+        };
+
+        let settled = match result {
+            Ok(()) => outbox.complete_outbox(id),
+            Err(e) => outbox.fail_outbox(id, &e.to_string()),
+        };
+        if let Err(e) = settled {
+            error!("Failed to update outbox notification {id}: {e}");
+        }
+    }
+
+    /// Open the delivery receipt of a notification to a destination
+    ///
+    /// - Returns the receipt ID, None when the event store is not configured or failed
+    fn open_receipt(&self, destination: &str, event: &NotificationEvent) -> Option<i64> {
+        let store = self.store.as_ref()?;
+
+        match store.open_receipt(
+            destination,
+            &event.transaction_signature,
+            &event.description,
+        ) {
+            Ok(id) => Some(id),
+            Err(e) => {
+                error!("Failed to open delivery receipt for {destination}: {e}");
+                None
+            }
+        }
+    }
+
+    /// Record the status of a delivery receipt after a delivery attempt
+    fn settle_receipt(
+        &self,
+        receipt_id: Option<i64>,
+        status: ReceiptStatus,
+        attempts: u32,
+        result: &Result<(), JitoBellError>,
+    ) {
+        let (Some(store), Some(id)) = (&self.store, receipt_id) else {
+            return;
+        };
+
+        let error = result.as_ref().err().map(ToString::to_string);
+        if let Err(e) = store.update_receipt(id, status, attempts, error.as_deref()) {
+            error!("Failed to update delivery receipt {id}: {e}");
+        }
+    }
+
+    /// Retry the notifications left in the outbox, oldest first
+    ///
+    /// - A destination still failing, or whose circuit is open, is skipped until the next
+    ///   interval, keeping its order
+    /// - Includes notifications left by a previous run
+    async fn retry_outbox(&mut self) {
+        let Some(outbox) = self.outbox() else {
+            return;
+        };
+        if self
+            .leader_election
+            .as_ref()
+            .is_some_and(|leader_election| !leader_election.is_leader())
+        {
+            return;
+        }
+
+        let records = match outbox.pending_outbox(OUTBOX_RETRY_BATCH) {
+            Ok(records) => records,
+            Err(e) => {
+                error!("Failed to read the outbox: {e}");
+                return;
+            }
+        };
+
+        let mut failing = HashSet::new();
+        for record in records {
+            if failing.contains(&record.destination)
+                || self
+                    .circuit_breakers
+                    .is_open(&record.destination, Instant::now())
+            {
+                continue;
+            }
+
+            let entry: OutboxEntry = match serde_json::from_str(&record.payload) {
+                Ok(entry) => entry,
+                Err(e) => {
+                    error!("Dropping unreadable outbox notification {}: {e}", record.id);
+                    self.settle_outbox(Some(record.id), &Ok(()));
+                    continue;
+                }
+            };
+            let (notification, event) = entry.clone().into_parts();
+
+            debug!(
+                "Retrying outbox notification {} to {} (attempt {})",
+                record.id,
+                record.destination,
+                record.attempts + 1
+            );
+            let result = self
+                .send_to_destination(&record.destination, &notification, &event)
+                .await;
+            let attempts = record.attempts + 1;
+            let gives_up = attempts >= self.config.dead_letter.max_outbox_attempts;
+            let status = ReceiptStatus::after_attempt(result.is_ok(), !gives_up);
+            self.settle_receipt(entry.receipt_id, status, attempts, &result);
+            match result {
+                Err(e) if gives_up => {
+                    self.settle_outbox(Some(record.id), &Ok(()));
+                    self.dead_letter(&record.destination, entry, e.to_string(), attempts)
+                        .await;
+                    failing.insert(record.destination);
+                }
+                result => {
+                    if result.is_err() {
+                        failing.insert(record.destination);
+                    }
+                    self.settle_outbox(Some(record.id), &result);
+                }
+            }
+        }
+    }
+
+    /// Give up a notification that could not be delivered
+    ///
+    /// - Logged, reported in the `jito-bell-dead-letter` datapoint and recorded in the event
+    ///   store and `dead_letter.path`
+    /// - Alerted with `dead_letter.notification` to its destinations but the failed one, sent
+    ///   once without the outbox
+    async fn dead_letter(
+        &mut self,
+        destination: &str,
+        entry: OutboxEntry,
+        error: String,
+        attempts: u32,
+    ) {
+        let dead_letter = DeadLetter::new(destination, error, attempts, entry);
+        error!(
+            "Giving up {destination} notification {} after {attempts} attempts: {}",
+            dead_letter.entry.event.transaction_signature, dead_letter.error
+        );
+        datapoint_info!(
+            "jito-bell-dead-letter",
+            ("destination", destination, String),
+            ("attempts", attempts, i64)
+        );
+
+        if let Some(store) = &self.store {
+            let recorded = serde_json::to_string(&dead_letter.entry)
+                .map_err(|e| JitoBellError::Notification(e.to_string()))
+                .and_then(|payload| {
+                    store.record_dead_letter(destination, &payload, &dead_letter.error, attempts)
+                });
+            if let Err(e) = recorded {
+                error!("Failed to record dead letter: {e}");
+            }
+        }
+        if let Some(path) = &self.config.dead_letter.path {
+            if let Err(e) = dead_letter::append(path, &dead_letter) {
+                error!("Failed to append dead letter to {}: {e}", path.display());
+            }
+        }
+
+        let Some(notification) = self.config.dead_letter.notification.clone() else {
+            return;
+        };
+        let summary = dead_letter.summary(&notification.description);
+        let mut event = NotificationEvent::scheduled(&notification, summary, "", 0.0, "");
+        event.timezone = self.config.timezone;
+
+        for ops_destination in notification.destinations.iter() {
+            if ops_destination.name() == destination {
+                continue;
+            }
+            let event = event.for_destination(ops_destination);
+            if let Err(e) = self
+                .send_to_destination(ops_destination.name(), &notification, &event)
+                .await
+            {
+                error!("Failed to send dead letter alert to {ops_destination}: {e}");
+            }
+        }
+    }
+
+    /// Send a notification event to a single destination
+    ///
+    /// - Skipped while the destination's circuit is open
+    async fn send_to_destination(
+        &mut self,
+        destination: &str,
+        notification: &NotificationInfo,
+        event: &NotificationEvent,
+    ) -> Result<(), JitoBellError> {
+        if !self.circuit_breakers.allow(destination, Instant::now()) {
+            return Err(self.circuit_open_error(destination));
+        }
+
+        let result = self
+            .post_to_destination(destination, notification, event)
+            .await;
+        self.record_circuit(destination, &result);
+
+        result
+    }
+
+    /// Error of a notification skipped because the destination's circuit is open
+    fn circuit_open_error(&self, destination: &str) -> JitoBellError {
+        debug!("Circuit of {destination} is open, skip notification");
+        JitoBellError::Notification(format!("Circuit of {destination} is open"))
+    }
+
+    /// Record the result of a send in the destination's circuit
+    fn record_circuit(&mut self, destination: &str, result: &Result<(), JitoBellError>) {
+        let Some(transition) = self.circuit_breakers.record(
+            destination,
+            result.is_ok(),
+            &self.config.circuit_breaker,
+            Instant::now(),
+        ) else {
+            return;
+        };
+        match transition {
+            CircuitTransition::Opened => {
+                warn!(
+                    "Circuit of {destination} opened, skipping it for {}s",
+                    self.config.circuit_breaker.open_secs
+                );
+                datapoint_info!(
+                    "jito-bell-circuit-open",
+                    ("destination", destination, String)
+                );
+                self.health.set_circuit_open(destination, true);
+            }
+            CircuitTransition::Closed => {
+                info!("Circuit of {destination} closed");
+                self.health.set_circuit_open(destination, false);
+            }
+        }
+        self.circuit_transitions
+            .push((destination.to_string(), transition));
+    }
+
+    /// Alert the circuits that opened or closed (`circuit_breaker.notification`)
+    ///
+    /// - Sent straight to the alert destinations other than the circuit's own and those whose
+    ///   circuit is open, without recording their results in the circuits
+    async fn send_circuit_alerts(&mut self) {
+        if self.circuit_transitions.is_empty() {
+            return;
+        }
+        let transitions = std::mem::take(&mut self.circuit_transitions);
+        let Some(notification) = self.config.circuit_breaker.notification.clone() else {
+            return;
+        };
+
+        for (destination, transition) in transitions {
+            let state = match transition {
+                CircuitTransition::Opened => format!(
+                    "opened, skipping it for {}s",
+                    self.config.circuit_breaker.open_secs
+                ),
+                CircuitTransition::Closed => "closed".to_string(),
+            };
+            let description = format!(
+                "{}: circuit of {destination} {state}",
+                notification.description
+            );
+            let mut event = NotificationEvent::scheduled(&notification, description, "", 0.0, "");
+            event.timezone = self.config.timezone;
+
+            for ops_destination in notification.destinations.iter() {
+                if ops_destination.name() == destination
+                    || self
+                        .circuit_breakers
+                        .is_open(ops_destination.name(), Instant::now())
+                {
+                    continue;
+                }
+                let event = event.for_destination(ops_destination);
+                if let Err(e) = self
+                    .post_to_destination(ops_destination.name(), &notification, &event)
+                    .await
+                {
+                    error!("Failed to send circuit alert to {ops_destination}: {e}");
+                }
+            }
+        }
+    }
+
+    /// Send a notification event to a single destination, regardless of its circuit
+    ///
+    /// - Takes `&self` so destinations can be sent to concurrently
+    #[tracing::instrument(name = "send", skip_all, fields(destination = %destination))]
+    async fn post_to_destination(
+        &self,
+        destination: &str,
+        notification: &NotificationInfo,
+        event: &NotificationEvent,
+    ) -> Result<(), JitoBellError> {
+        match destination {
+            "telegram" => {
+                debug!("Will Send Telegram Notification");
+                self.send_telegram_message(event, &notification.delivery.telegram)
+                    .await
+            }
+            "slack" => {
+                debug!("Will Send Slack Notification");
+                self.send_slack_message(event, &notification.delivery.slack)
+                    .await
+            }
+            "discord" => {
+                debug!("Will Send Discord Notification");
+                self.send_discord_message(event, &notification.delivery.discord)
+                    .await
+            }
+            "discord_bot" => {
+                debug!("Will Send Discord Bot Notification");
+                self.send_discord_bot_message(event).await
+            }
+            "twitter" => {
+                debug!("Will Send Twitter Notification");
+                self.send_twitter_message(event).await
+            }
+            "signal" => {
+                debug!("Will Send Signal Notification");
+                self.send_signal_message(event).await
+            }
+            "zulip" => {
+                debug!("Will Send Zulip Notification");
+                self.send_zulip_message(event).await
+            }
+            "webex" => {
+                debug!("Will Send Webex Notification");
+                self.send_webex_message(event).await
+            }
+            "push" => {
+                debug!("Will Send Push Notification");
+                self.send_push_message(event).await
+            }
+            "nostr" => {
+                debug!("Will Send Nostr Notification");
+                self.send_nostr_message(event).await
+            }
+            "webhook" => {
+                debug!("Will Send Webhook Notification");
+                self.send_webhook_message(event, &notification.delivery.webhook)
+                    .await
+            }
+            "xmpp" => {
+                debug!("Will Send XMPP Notification");
+                self.send_xmpp_message(event).await
+            }
+            "irc" => {
+                debug!("Will Send IRC Notification");
+                self.send_irc_message(event).await
+            }
+            "mqtt" => {
+                debug!("Will Send MQTT Notification");
+                self.send_mqtt_message(event).await
+            }
+            "redis" => {
+                debug!("Will Send Redis Notification");
+                self.send_redis_message(event).await
+            }
+            "file" => {
+                debug!("Will Append File Notification");
+                self.send_file_message(event)
+            }
+            "stdout" => {
+                debug!("Will Print Stdout Notification");
+                self.send_stdout_message(event)
+            }
+            "syslog" => {
+                debug!("Will Send Syslog Notification");
+                self.send_syslog_message(event, &notification.delivery.syslog)
+                    .await
+            }
+            destination => {
+                error!("Unknown notification type: {destination}");
+                Err(JitoBellError::Notification(format!(
+                    "Invalid Notification Type: {destination}"
+                )))
+            }
+        }
+    }
+
+    /// Send the digest of the alerts held during quiet hours once a destination's window ends
+    async fn send_quiet_hours_digests(&mut self) {
+        if self.quiet_hours_digests.is_empty() {
+            return;
+        }
+
+        let time_of_day = TimeOfDay::at(chrono::Utc::now(), &self.config.timezone);
+        let schedules = &self.config.schedules;
+        let digests = self.quiet_hours_digests.take_due(|destination| {
+            schedules
+                .get(destination)
+                .and_then(|schedule| schedule.quiet_hours.as_ref())
+                .is_some_and(|quiet_hours| quiet_hours.is_quiet(time_of_day))
+        });
+
+        for (destination, digest) in digests {
+            self.send_digest(&destination, "Quiet hours digest", digest)
+                .await;
+        }
+        debug!(
+            "Alerts held for quiet hours: {}",
+            self.quiet_hours_digests.len()
+        );
+    }
+
+    /// Send the digest of the alerts held by a destination's rate limit once it allows a message
+    async fn send_rate_limit_digests(&mut self) {
+        if self.rate_limiters.is_empty() {
+            return;
+        }
+
+        let schedules = &self.config.schedules;
+        let digests = self.rate_limiters.take_due(
+            |destination| {
+                schedules
+                    .get(destination)
+                    .and_then(|schedule| schedule.rate_limit)
+            },
+            Instant::now(),
+        );
+
+        for (destination, digest) in digests {
+            self.send_digest(&destination, "Rate limit digest", digest)
+                .await;
+        }
+        debug!("Alerts held by rate limits: {}", self.rate_limiters.len());
+    }
+
+    /// Send the summary of instructions in digest mode whose window ended, or of every open
+    /// window with `flush`
+    async fn send_instruction_digests(&mut self, flush: bool) {
+        if self.instruction_digests.is_empty() {
+            return;
+        }
+
+        let programs = &self.config.programs;
+        let digests = self.instruction_digests.take_due(
+            |program, instruction| {
+                if flush {
+                    return None;
+                }
+                programs
+                    .get(program)
+                    .and_then(|program| program.instructions.get(instruction))
+                    .and_then(|instruction| instruction.digest.as_ref())
+                    .map(DigestConfig::window)
+            },
+            Instant::now(),
+        );
+
+        for digest in digests {
+            let notification = NotificationInfo {
+                template: Some("{{description}}".to_string()),
+                threshold_ratio: None,
+                ..digest.notification.clone()
+            };
+            let mut event = NotificationEvent::scheduled(
+                &notification,
+                digest.render(),
+                &digest.largest.pool,
+                digest.total,
+                &digest.largest.currency_unit,
+            );
+            event.program = Some(digest.program.clone());
+            event.instruction = Some(digest.instruction.clone());
+            event.transaction_signature = digest.largest.transaction_signature.clone();
+            event.explorer_url = digest.largest.explorer_url.clone();
+            event.timezone = self.config.timezone;
+
+            if let Err(e) = self.deliver_notification(&notification, &event).await {
+                error!(
+                    "Failed to send {}.{} digest: {e}",
+                    digest.program, digest.instruction
+                );
+            }
+        }
+    }
+
+    /// Deliver a digest of held alerts to a destination
+    async fn send_digest(&mut self, destination: &str, description: &str, digest: String) {
+        let notification = NotificationInfo {
+            description: description.to_string(),
+            destinations: vec![Destination::from(destination)],
+            template: Some("{{description}}".to_string()),
+            ..Default::default()
+        };
+        let mut event = NotificationEvent::scheduled(&notification, digest, "", 0.0, "");
+        event.timezone = self.config.timezone;
+
+        if let Err(e) = self.deliver_notification(&notification, &event).await {
+            error!(
+                "Failed to send {} to {destination}: {e}",
+                description.to_lowercase()
+            );
+        }
+    }
+
+    /// Record parsed transaction
+    ///
+    /// - Keep it in the recent events buffer
+    /// - Append it to the JSON lines file, stdout and WebSocket clients configured with
+    ///   `include_transactions`
+    fn record_transaction(&self, parser: &JitoTransactionParser) {
+        if parser.programs.is_empty() {
+            return;
+        }
+
+        let record = TransactionRecord::new(parser);
+        self.recent.push(RecentEvent::Transaction(record.clone()));
+
+        if let Some(file_notifier) = &self.file_notifier {
+            if file_notifier.include_transactions() {
+                if let Err(e) = file_notifier.append_transaction(&record) {
+                    error!("Failed to append transaction to file: {e}");
+                }
+            }
+        }
+
+        if let Some(stdout_notifier) = &self.stdout_notifier {
+            if stdout_notifier.include_transactions() {
+                if let Err(e) = stdout_notifier.print_transaction(&record) {
+                    error!("Failed to print transaction to stdout: {e}");
+                }
+            }
+        }
+
+        if let Some(websocket_broadcaster) = &self.websocket_broadcaster {
+            if websocket_broadcaster.include_transactions() {
+                if let Err(e) = websocket_broadcaster.broadcast_transaction(&record) {
+                    error!("Failed to broadcast transaction: {e}");
+                }
+            }
+        }
+    }
+
+    /// Record a delivery attempt in the audit trail
+    ///
+    /// - No-op when the event store is not configured
+    fn record_audit(&self, destination: &str, sig: &str, rendered: &str, status: &str) {
+        if let Some(store) = &self.store {
+            if let Err(e) = store.record_notification(destination, sig, rendered, status) {
+                error!("Failed to record audit trail for {destination}: {e}");
+            }
+        }
+    }
+
+    /// Render message template
+    ///
+    /// - Use the rule template, then the destination specific template, fall back to "default"
+    /// - With a locale, the templates of that locale come first and numbers use its separators
+    fn render_template(&self, destination: &str, event: &NotificationEvent) -> String {
+        self.render_template_escaped(destination, event, str::to_string)
+    }
+
+    /// Render the template of a destination, variable values escaped with `escape`
+    fn render_template_escaped(
+        &self,
+        destination: &str,
+        event: &NotificationEvent,
+        escape: impl Fn(&str) -> String,
+    ) -> String {
+        let locale = self.destination_locale(destination, event);
+        let template = event.template.as_ref().unwrap_or_else(|| {
+            self.configured_template(destination, locale)
+                .unwrap_or(self.config.message_templates.get("default").unwrap())
+        });
+
+        event.render_escaped(template, locale, escape)
+    }
+
+    /// Locale of a destination, the destination override then `destination_locales`
+    fn destination_locale<'a>(
+        &'a self,
+        destination: &str,
+        event: &'a NotificationEvent,
+    ) -> Option<&'a str> {
+        event.locale.as_deref().or_else(|| {
+            self.config
+                .destination_locales
+                .get(destination)
+                .map(String::as_str)
+        })
+    }
+
+    /// Configured template of a destination
+    ///
+    /// - `localized_templates` of the locale (destination specific, then "default"), then
+    ///   the destination specific `message_templates`
+    fn configured_template(&self, destination: &str, locale: Option<&str>) -> Option<&String> {
+        locale
+            .and_then(|locale| self.config.localized_templates.get(locale))
+            .and_then(|templates| {
+                templates
+                    .get(destination)
+                    .or_else(|| templates.get("default"))
+            })
+            .or_else(|| self.config.message_templates.get(destination))
+    }
+
+    /// Send message to Telegram
+    async fn send_telegram_message(
+        &self,
+        event: &NotificationEvent,
+        options: &TelegramDeliveryOptions,
+    ) -> Result<(), JitoBellError> {
+        if let Some(telegram_config) = &self.config.notifications.telegram {
+            let parse_mode = telegram_config.parse_mode;
+            let mut message = match parse_mode {
+                Some(parse_mode) => {
+                    self.render_template_escaped("telegram", event, |text| parse_mode.escape(text))
+                }
+                None => self.render_template("telegram", event),
+            };
+            if let Some(severity) = event.severity {
+                message = format!("{} {message}", severity.telegram_prefix());
+            }
+
+            let chats = if options.chats.is_empty() {
+                vec![TelegramChat {
+                    chat_id: telegram_config.chat_id.clone(),
+                    message_thread_id: options
+                        .message_thread_id
+                        .or(telegram_config.message_thread_id),
+                }]
+            } else {
+                options.chats.clone()
+            };
+
+            let url = format!(
+                "https://api.telegram.org/bot{}/sendMessage",
+                telegram_config.bot_token
+            );
+            let disable_notification =
+                (telegram_config.disable_notification || options.disable_notification).to_string();
+            let disable_web_page_preview = telegram_config.disable_web_page_preview.to_string();
+
+            // Send to every chat, report the last failure
+            let mut result = Ok(());
+            let retry = self.config.retry;
+            let client = &self.http_client;
+            for chat in chats {
+                let mut form = vec![
+                    ("chat_id", chat.chat_id),
+                    ("text", message.clone()),
+                    ("disable_notification", disable_notification.clone()),
+                    ("disable_web_page_preview", disable_web_page_preview.clone()),
+                ];
+                if let Some(parse_mode) = parse_mode {
+                    form.push(("parse_mode", parse_mode.as_str().to_string()));
+                }
+                if let Some(message_thread_id) = chat.message_thread_id {
+                    form.push(("message_thread_id", message_thread_id.to_string()));
+                }
+
+                let response = retry.send(client.post(&url).form(&form)).await;
+
+                match response {
+                    Ok(res) => {
+                        self.record_audit(
+                            "telegram",
+                            &event.transaction_signature,
+                            &message,
+                            &res.status().to_string(),
+                        );
+
+                        if res.status().is_success() {
+                            self.epoch_metrics.increment_success_notification_count();
+                        } else {
+                            self.epoch_metrics.increment_fail_notification_count();
+                            result = Err(JitoBellError::Notification(format!(
+                                "Failed to send Telegram message: {}",
+                                res.status(),
+                            )));
+                        }
+                    }
+                    Err(e) => {
+                        self.record_audit(
+                            "telegram",
+                            &event.transaction_signature,
+                            &message,
+                            &e.to_string(),
+                        );
+                        self.epoch_metrics.increment_fail_notification_count();
+                        result = Err(JitoBellError::Notification(format!(
+                            "Failed to send Telegram message: {}",
+                            e
+                        )));
+                    }
+                }
+            }
+
+            return result;
+        }
+
+        Ok(())
+    }
+
+    /// Send message to Signal via signal-cli REST API
+    async fn send_signal_message(&self, event: &NotificationEvent) -> Result<(), JitoBellError> {
+        if let Some(signal_config) = &self.config.notifications.signal {
+            let message = self.render_template("signal", event);
+
+            let url = format!("{}/v2/send", signal_config.api_url.trim_end_matches('/'));
+            let payload = serde_json::json!({
+                "message": message,
+                "number": signal_config.number,
+                "recipients": signal_config.recipients,
+            });
+
+            let retry = self.config.retry;
+            let client = &self.http_client;
+            let response = retry.send(client.post(&url).json(&payload)).await;
+
+            match response {
+                Ok(res) => {
+                    self.record_audit(
+                        "signal",
+                        &event.transaction_signature,
+                        &message,
+                        &res.status().to_string(),
+                    );
+
+                    if res.status().is_success() {
+                        self.epoch_metrics.increment_success_notification_count();
+                        return Ok(());
+                    } else {
+                        self.epoch_metrics.increment_fail_notification_count();
+                        return Err(JitoBellError::Notification(format!(
+                            "Failed to send Signal message: {}",
+                            res.status(),
+                        )));
+                    }
+                }
+                Err(e) => {
+                    self.record_audit(
+                        "signal",
+                        &event.transaction_signature,
+                        &message,
+                        &e.to_string(),
+                    );
+                    self.epoch_metrics.increment_fail_notification_count();
+                    return Err(JitoBellError::Notification(format!(
+                        "Failed to send Signal message: {}",
+                        e
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Send message to Zulip stream, topic rendered per event
+    async fn send_zulip_message(&self, event: &NotificationEvent) -> Result<(), JitoBellError> {
+        if let Some(zulip_config) = &self.config.notifications.zulip {
+            let message = self.render_template("zulip", event);
+            let topic = event.render(&zulip_config.topic);
+            let topic = if topic.is_empty() {
+                "jito-bell".to_string()
+            } else {
+                topic
+            };
+
+            let url = format!(
+                "{}/api/v1/messages",
+                zulip_config.site.trim_end_matches('/')
+            );
+
+            let retry = self.config.retry;
+            let client = &self.http_client;
+            let response = retry
+                .send(
+                    client
+                        .post(&url)
+                        .basic_auth(&zulip_config.bot_email, Some(&zulip_config.api_key))
+                        .form(&[
+                            ("type", "stream"),
+                            ("to", zulip_config.stream.as_str()),
+                            ("topic", topic.as_str()),
+                            ("content", message.as_str()),
+                        ]),
+                )
+                .await;
 
-    /// Dispatch platform notifications
-    ///
-    /// - Return error only if ALL platforms failed, or handle as needed
-    async fn dispatch_platform_notifications(
-        &mut self,
-        destinations: &[String],
-        description: &str,
-        amount: f64,
-        unit: &str,
-        transaction_signature: &str,
-    ) -> Result<(), JitoBellError> {
-        let mut errors = Vec::new();
+            match response {
+                Ok(res) => {
+                    self.record_audit(
+                        "zulip",
+                        &event.transaction_signature,
+                        &message,
+                        &res.status().to_string(),
+                    );
 
-        for destination in destinations {
-            let result = match destination.as_str() {
-                "telegram" => {
-                    debug!("Will Send Telegram Notification");
-                    self.send_telegram_message(description, amount, unit, transaction_signature)
-                        .await
-                }
-                "slack" => {
-                    debug!("Will Send Slack Notification");
-                    self.send_slack_message(description, amount, unit, transaction_signature)
-                        .await
-                }
-                "discord" => {
-                    debug!("Will Send Discord Notification");
-                    self.send_discord_message(description, amount, unit, transaction_signature)
-                        .await
-                }
-                "twitter" => {
-                    debug!("Will Send Twitter Notification");
-                    self.send_twitter_message(description, amount, unit, transaction_signature)
-                        .await
-                }
-                destination => {
-                    error!("Unknown notification type: {destination}");
-                    Err(JitoBellError::Notification(format!(
-                        "Invalid Notification Type: {destination}"
-                    )))
+                    if res.status().is_success() {
+                        self.epoch_metrics.increment_success_notification_count();
+                        return Ok(());
+                    } else {
+                        self.epoch_metrics.increment_fail_notification_count();
+                        return Err(JitoBellError::Notification(format!(
+                            "Failed to send Zulip message: {}",
+                            res.status(),
+                        )));
+                    }
+                }
+                Err(e) => {
+                    self.record_audit(
+                        "zulip",
+                        &event.transaction_signature,
+                        &message,
+                        &e.to_string(),
+                    );
+                    self.epoch_metrics.increment_fail_notification_count();
+                    return Err(JitoBellError::Notification(format!(
+                        "Failed to send Zulip message: {}",
+                        e
+                    )));
                 }
-            };
-
-            if let Err(e) = result {
-                error!("Failed to send to {}: {:?}", destination, e);
-                errors.push((destination.clone(), e));
             }
         }
 
-        if errors.len() == destinations.len() {
-            Err(JitoBellError::Notification(
-                "All platforms failed".to_string(),
-            ))
-        } else {
-            Ok(())
-        }
+        Ok(())
     }
 
-    /// Send message to Telegram
-    async fn send_telegram_message(
-        &mut self,
-        description: &str,
-        amount: f64,
-        unit: &str,
-        sig: &str,
-    ) -> Result<(), JitoBellError> {
-        if let Some(telegram_config) = &self.config.notifications.telegram {
-            let template = self
-                .config
-                .message_templates
-                .get("telegram")
-                .unwrap_or(self.config.message_templates.get("default").unwrap());
-            let message = template
-                .replace("{{description}}", description)
-                .replace("{{amount}}", &format!("{:.2}", amount))
-                .replace("{{currency_unit}}", unit)
-                .replace("{{tx_hash}}", sig);
-
-            let bot_token = &telegram_config.bot_token;
-            let chat_id = &telegram_config.chat_id;
-
-            let url = format!("https://api.telegram.org/bot{}/sendMessage", bot_token);
-
-            let client = reqwest::Client::new();
-            let response = client
-                .post(&url)
-                .form(&[("chat_id", chat_id), ("text", &message)])
-                .send()
+    /// Send markdown message to Webex room
+    async fn send_webex_message(&self, event: &NotificationEvent) -> Result<(), JitoBellError> {
+        if let Some(webex_config) = &self.config.notifications.webex {
+            let message = self.render_template("webex", event);
+
+            let payload = serde_json::json!({
+                "roomId": webex_config.room_id,
+                "markdown": message,
+            });
+
+            let retry = self.config.retry;
+            let client = &self.http_client;
+            let response = retry
+                .send(
+                    client
+                        .post("https://webexapis.com/v1/messages")
+                        .bearer_auth(&webex_config.bot_token)
+                        .json(&payload),
+                )
                 .await;
 
             match response {
                 Ok(res) => {
+                    self.record_audit(
+                        "webex",
+                        &event.transaction_signature,
+                        &message,
+                        &res.status().to_string(),
+                    );
+
                     if res.status().is_success() {
                         self.epoch_metrics.increment_success_notification_count();
                         return Ok(());
                     } else {
                         self.epoch_metrics.increment_fail_notification_count();
                         return Err(JitoBellError::Notification(format!(
-                            "Failed to send Telegram message: {}",
+                            "Failed to send Webex message: {}",
                             res.status(),
                         )));
                     }
                 }
                 Err(e) => {
+                    self.record_audit(
+                        "webex",
+                        &event.transaction_signature,
+                        &message,
+                        &e.to_string(),
+                    );
                     self.epoch_metrics.increment_fail_notification_count();
                     return Err(JitoBellError::Notification(format!(
-                        "Failed to send Telegram message: {}",
+                        "Failed to send Webex message: {}",
                         e
                     )));
                 }
@@ -805,48 +4910,248 @@ impl JitoBellHandler {
         Ok(())
     }
 
+    /// Push alert to mobile devices and topics via FCM / APNs
+    async fn send_push_message(&self, event: &NotificationEvent) -> Result<(), JitoBellError> {
+        if let Some(push_notifier) = &self.push_notifier {
+            let message = self.render_template("push", event);
+
+            match push_notifier.send(event, &message).await {
+                Ok(delivered) => {
+                    self.record_audit(
+                        "push",
+                        &event.transaction_signature,
+                        &message,
+                        &format!("delivered to {delivered}"),
+                    );
+                    self.epoch_metrics.increment_success_notification_count();
+                    return Ok(());
+                }
+                Err(e) => {
+                    self.record_audit(
+                        "push",
+                        &event.transaction_signature,
+                        &message,
+                        &e.to_string(),
+                    );
+                    self.epoch_metrics.increment_fail_notification_count();
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sign and publish note to Nostr relays
+    async fn send_nostr_message(&self, event: &NotificationEvent) -> Result<(), JitoBellError> {
+        if let Some(nostr_notifier) = &self.nostr_notifier {
+            let message = self.render_template("nostr", event);
+
+            match nostr_notifier.publish(&message).await {
+                Ok(accepted) => {
+                    self.record_audit(
+                        "nostr",
+                        &event.transaction_signature,
+                        &message,
+                        &format!("accepted by {accepted} relays"),
+                    );
+                    self.epoch_metrics.increment_success_notification_count();
+                    return Ok(());
+                }
+                Err(e) => {
+                    self.record_audit(
+                        "nostr",
+                        &event.transaction_signature,
+                        &message,
+                        &e.to_string(),
+                    );
+                    self.epoch_metrics.increment_fail_notification_count();
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// POST event to the generic webhook
+    async fn send_webhook_message(
+        &self,
+        event: &NotificationEvent,
+        delivery: &WebhookDeliveryOptions,
+    ) -> Result<(), JitoBellError> {
+        if let Some(webhook_notifier) = &self.webhook_notifier {
+            match webhook_notifier.send(event, delivery).await {
+                Ok(status) => {
+                    self.record_audit(
+                        "webhook",
+                        &event.transaction_signature,
+                        &event.description,
+                        &status.to_string(),
+                    );
+                    self.epoch_metrics.increment_success_notification_count();
+                    return Ok(());
+                }
+                Err(e) => {
+                    self.record_audit(
+                        "webhook",
+                        &event.transaction_signature,
+                        &event.description,
+                        &e.to_string(),
+                    );
+                    self.epoch_metrics.increment_fail_notification_count();
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Send message with interactive buttons to Discord channels as a bot
+    async fn send_discord_bot_message(
+        &self,
+        event: &NotificationEvent,
+    ) -> Result<(), JitoBellError> {
+        if let Some(discord_bot_config) = &self.config.notifications.discord_bot {
+            let payload = discord_bot::message_payload(event);
+            let rendered = payload.to_string();
+
+            // Send to every channel, report the last failure
+            let mut result = Ok(());
+            let retry = self.config.retry;
+            let client = &self.http_client;
+            for channel_id in discord_bot_config.channel_ids.iter() {
+                let url = format!(
+                    "{}/channels/{}/messages",
+                    discord_bot::DISCORD_API_URL,
+                    channel_id
+                );
+                let response = retry
+                    .send(
+                        client
+                            .post(&url)
+                            .header(
+                                "Authorization",
+                                format!("Bot {}", discord_bot_config.bot_token),
+                            )
+                            .json(&payload),
+                    )
+                    .await;
+
+                match response {
+                    Ok(res) => {
+                        self.record_audit(
+                            "discord_bot",
+                            &event.transaction_signature,
+                            &rendered,
+                            &res.status().to_string(),
+                        );
+
+                        if res.status().is_success() {
+                            self.epoch_metrics.increment_success_notification_count();
+                        } else {
+                            self.epoch_metrics.increment_fail_notification_count();
+                            result = Err(JitoBellError::Notification(format!(
+                                "Failed to send Discord bot message: {}",
+                                res.status(),
+                            )));
+                        }
+                    }
+                    Err(e) => {
+                        self.record_audit(
+                            "discord_bot",
+                            &event.transaction_signature,
+                            &rendered,
+                            &e.to_string(),
+                        );
+                        self.epoch_metrics.increment_fail_notification_count();
+                        result = Err(JitoBellError::Notification(format!(
+                            "Failed to send Discord bot message: {}",
+                            e
+                        )));
+                    }
+                }
+            }
+
+            return result;
+        }
+
+        Ok(())
+    }
+
     /// Send message to Discord
     async fn send_discord_message(
-        &mut self,
-        description: &str,
-        amount: f64,
-        unit: &str,
-        sig: &str,
+        &self,
+        event: &NotificationEvent,
+        options: &DiscordDeliveryOptions,
     ) -> Result<(), JitoBellError> {
         if let Some(discord_config) = &self.config.notifications.discord {
             let webhook_url = &discord_config.webhook_url;
 
-            let payload = serde_json::json!({
+            let mut flags = 0;
+            if options.silent {
+                flags |= DISCORD_SUPPRESS_NOTIFICATIONS_FLAG;
+            }
+
+            let mut payload = serde_json::json!({
                 "embeds": [{
                     "title": "New Transaction Detected",
-                    "description": description,
-                    "color": 3447003, // Blue color
+                    "description": event.description,
+                    "color": event.severity.unwrap_or(Severity::Info).discord_color(),
                     "fields": [
                         {
                             "name": "Amount",
-                            "value": format!("{:.2} {unit}", amount),
+                            "value": format!("{:.2} {}", event.amount, event.currency_unit),
                             "inline": true
                         },
                         {
                             "name": "Transaction",
-                            "value": format!("[View on Explorer]({}/tx/{})", self.config.explorer_url, sig),
+                            "value": format!("[View on Explorer]({})", event.explorer_url),
+                            "inline": true
+                        },
+                        {
+                            "name": "Time",
+                            "value": event.local_time(),
                             "inline": true
                         }
                     ],
-                    "timestamp": chrono::Utc::now().to_rfc3339()
-                }]
+                    "timestamp": event.time().to_rfc3339()
+                }],
+                "flags": flags
             });
 
-            let client = reqwest::Client::new();
-            let response = client
-                .post(webhook_url)
-                .header("Content-Type", "application/json")
-                .json(&payload)
-                .send()
+            // Embeds would be hidden, so send the rendered template as plain content instead
+            if options.suppress_embeds {
+                let message = self.render_template("discord", event);
+
+                payload = serde_json::json!({
+                    "content": message,
+                    "flags": flags | DISCORD_SUPPRESS_EMBEDS_FLAG
+                });
+            }
+
+            let retry = self.config.retry;
+            let client = &self.http_client;
+            let response = retry
+                .send(
+                    client
+                        .post(webhook_url)
+                        .header("Content-Type", "application/json")
+                        .json(&payload),
+                )
                 .await;
 
             match response {
                 Ok(res) => {
+                    let status = res.status().to_string();
+                    self.record_audit(
+                        "discord",
+                        &event.transaction_signature,
+                        &payload.to_string(),
+                        &status,
+                    );
+
                     if res.status().is_success() {
                         self.epoch_metrics.increment_success_notification_count();
                         return Ok(());
@@ -859,6 +5164,12 @@ impl JitoBellHandler {
                     }
                 }
                 Err(e) => {
+                    self.record_audit(
+                        "discord",
+                        &event.transaction_signature,
+                        &payload.to_string(),
+                        &e.to_string(),
+                    );
                     self.epoch_metrics.increment_fail_notification_count();
                     return Err(JitoBellError::Notification(format!(
                         "Error sending Discord message: {:?}",
@@ -873,30 +5184,33 @@ impl JitoBellHandler {
 
     /// Send message to Slack
     async fn send_slack_message(
-        &mut self,
-        description: &str,
-        amount: f64,
-        unit: &str,
-        sig: &str,
+        &self,
+        event: &NotificationEvent,
+        options: &SlackDeliveryOptions,
     ) -> Result<(), JitoBellError> {
         if let Some(slack_config) = &self.config.notifications.slack {
-            let webhook_url = &slack_config.webhook_url;
+            let title = match event.severity {
+                Some(severity) => format!("{} New Transaction Detected", severity.slack_emoji()),
+                None => "New Transaction Detected".to_string(),
+            };
 
             // Build a Slack message with blocks for better formatting
             let payload = serde_json::json!({
+                "text": event.description,
                 "blocks": [
                     {
                         "type": "header",
                         "text": {
                             "type": "plain_text",
-                            "text": "New Transaction Detected"
+                            "text": title,
+                            "emoji": true
                         }
                     },
                     {
                         "type": "section",
                         "text": {
                             "type": "mrkdwn",
-                            "text": format!("*Description:* {}", description)
+                            "text": format!("*Description:* {}", event.description)
                         }
                     },
                     {
@@ -904,27 +5218,96 @@ impl JitoBellHandler {
                         "fields": [
                             {
                                 "type": "mrkdwn",
-                                "text": format!("*Amount:* {:.2} {unit}", amount)
+                                "text": format!("*Amount:* {:.2} {}", event.amount, event.currency_unit)
                             },
                             {
                                 "type": "mrkdwn",
-                                "text": format!("*Transaction:* <{}/tx/{}|View on Explorer>", self.config.explorer_url, sig)
+                                "text": format!("*Transaction:* <{}|View on Explorer>", event.explorer_url)
+                            }
+                        ]
+                    },
+                    {
+                        "type": "context",
+                        "elements": [
+                            {
+                                "type": "mrkdwn",
+                                "text": event.local_time()
                             }
                         ]
                     }
-                ]
+                ],
+                "unfurl_links": options.unfurl_links,
+                "unfurl_media": options.unfurl_media
             });
 
-            let client = reqwest::Client::new();
-            let response = client
-                .post(webhook_url)
-                .header("Content-Type", "application/json")
-                .json(&payload)
-                .send()
+            if let Some(bot_token) = &slack_config.bot_token {
+                let slack_bot_client =
+                    SlackBotClient::new(bot_token, self.config.retry, self.http_client.clone());
+                let follow_up_finalized = slack_config.follow_up_finalized;
+
+                match slack_bot_client
+                    .post_message(&slack_config.channel, &payload, None)
+                    .await
+                {
+                    Ok(message) => {
+                        self.record_audit(
+                            "slack",
+                            &event.transaction_signature,
+                            &payload.to_string(),
+                            "ok",
+                        );
+                        self.epoch_metrics.increment_success_notification_count();
+
+                        if follow_up_finalized {
+                            tokio::spawn(slack_bot::follow_up_finalized(
+                                slack_bot_client,
+                                self.rpc_client.url(),
+                                event.transaction_signature.clone(),
+                                message,
+                                payload,
+                            ));
+                        }
+
+                        return Ok(());
+                    }
+                    Err(e) => {
+                        self.record_audit(
+                            "slack",
+                            &event.transaction_signature,
+                            &payload.to_string(),
+                            &e.to_string(),
+                        );
+                        self.epoch_metrics.increment_fail_notification_count();
+                        return Err(e);
+                    }
+                }
+            }
+
+            let webhook_url = slack_config.webhook_url.as_ref().ok_or_else(|| {
+                JitoBellError::Config("slack requires webhook_url or bot_token".to_string())
+            })?;
+
+            let retry = self.config.retry;
+            let client = &self.http_client;
+            let response = retry
+                .send(
+                    client
+                        .post(webhook_url)
+                        .header("Content-Type", "application/json")
+                        .json(&payload),
+                )
                 .await;
 
             match response {
                 Ok(res) => {
+                    let status = res.status().to_string();
+                    self.record_audit(
+                        "slack",
+                        &event.transaction_signature,
+                        &payload.to_string(),
+                        &status,
+                    );
+
                     if res.status().is_success() {
                         self.epoch_metrics.increment_success_notification_count();
                         return Ok(());
@@ -937,6 +5320,12 @@ impl JitoBellHandler {
                     }
                 }
                 Err(e) => {
+                    self.record_audit(
+                        "slack",
+                        &event.transaction_signature,
+                        &payload.to_string(),
+                        &e.to_string(),
+                    );
                     self.epoch_metrics.increment_fail_notification_count();
                     return Err(JitoBellError::Notification(format!(
                         "Slack request error: {}",
@@ -950,14 +5339,19 @@ impl JitoBellHandler {
     }
 
     /// Send message to Twitter
-    async fn send_twitter_message(
-        &mut self,
-        description: &str,
-        amount: f64,
-        unit: &str,
-        sig: &str,
-    ) -> Result<(), JitoBellError> {
+    async fn send_twitter_message(&self, event: &NotificationEvent) -> Result<(), JitoBellError> {
         if let Some(twitter_config) = &self.config.notifications.twitter {
+            if twitter_config
+                .min_amount
+                .is_some_and(|min_amount| event.amount < min_amount)
+            {
+                debug!(
+                    "Skip Twitter post below min_amount: {}",
+                    event.transaction_signature
+                );
+                return Ok(());
+            }
+
             let credentials = TwitterCredentials::new(
                 twitter_config.twitter_api_key.clone(),
                 twitter_config.twitter_api_secret.clone(),
@@ -967,31 +5361,33 @@ impl JitoBellHandler {
 
             let client = TwitterClient::new(credentials);
 
-            let mut tweet_text = format!(
-                "Jito Bell\n\n🚨 {}\n\n💰 Amount: {:.2} {}\n🔗 Transaction: {}/tx/{}\n\n",
-                description, amount, unit, self.config.explorer_url, sig,
-            );
-
-            // Check Twitter's 280 character limit
-            if tweet_text.len() > 280 {
-                // Create a shorter version
-                let short_text = format!(
-                    "Jito Bell\n\n🚨 {}\n💰 {:.2} {}\n🔗 {}/tx/{}\n",
-                    description,
-                    amount,
-                    unit,
-                    self.config.explorer_url,
-                    &sig[..8], // Truncate hash
-                );
-                tweet_text = short_text;
-            }
+            // Rule or configured twitter template, the built-in format otherwise
+            let locale = self.destination_locale("twitter", event);
+            let template = event
+                .template
+                .as_ref()
+                .or_else(|| self.configured_template("twitter", locale));
+            let tweet_text = match template {
+                Some(template) => twitter::fit_tweet(&event.render_localized(template, locale)),
+                None => twitter::fit_tweet(&format!(
+                    "Jito Bell\n\n🚨 {}\n\n💰 Amount: {:.2} {}\n🔗 Transaction: {}",
+                    event.description, event.amount, event.currency_unit, event.explorer_url,
+                )),
+            };
 
-            match client.tweet(tweet_text).await {
+            match client.tweet(tweet_text.clone()).await {
                 Ok(_res) => {
+                    self.record_audit("twitter", &event.transaction_signature, &tweet_text, "sent");
                     self.epoch_metrics.increment_success_notification_count();
                     return Ok(());
                 }
                 Err(e) => {
+                    self.record_audit(
+                        "twitter",
+                        &event.transaction_signature,
+                        &tweet_text,
+                        &e.to_string(),
+                    );
                     self.epoch_metrics.increment_fail_notification_count();
                     return Err(JitoBellError::Notification(format!(
                         "Error sending Twitter message: {:?}",
@@ -1003,4 +5399,226 @@ impl JitoBellHandler {
 
         Ok(())
     }
+
+    /// Publish message to MQTT broker
+    async fn send_mqtt_message(&self, event: &NotificationEvent) -> Result<(), JitoBellError> {
+        if let Some(mqtt_notifier) = &self.mqtt_notifier {
+            let schema_version = self
+                .config
+                .notifications
+                .mqtt
+                .as_ref()
+                .map(|mqtt_config| mqtt_config.schema_version)
+                .unwrap_or_default();
+            let payload = schema::notification_payload(event, schema_version)
+                .map(|payload| payload.to_string())
+                .map_err(|e| {
+                    JitoBellError::Notification(format!("Failed to serialize MQTT message: {e}"))
+                })?;
+
+            match mqtt_notifier.publish(payload.clone().into_bytes()).await {
+                Ok(()) => {
+                    self.record_audit("mqtt", &event.transaction_signature, &payload, "published");
+                    self.epoch_metrics.increment_success_notification_count();
+                    return Ok(());
+                }
+                Err(e) => {
+                    self.record_audit(
+                        "mqtt",
+                        &event.transaction_signature,
+                        &payload,
+                        &e.to_string(),
+                    );
+                    self.epoch_metrics.increment_fail_notification_count();
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Send message to XMPP recipients and rooms
+    async fn send_xmpp_message(&self, event: &NotificationEvent) -> Result<(), JitoBellError> {
+        if let Some(xmpp_notifier) = &self.xmpp_notifier {
+            let message = self.render_template("xmpp", event);
+
+            match xmpp_notifier.send(&message).await {
+                Ok(()) => {
+                    self.record_audit("xmpp", &event.transaction_signature, &message, "queued");
+                    self.epoch_metrics.increment_success_notification_count();
+                    return Ok(());
+                }
+                Err(e) => {
+                    self.record_audit(
+                        "xmpp",
+                        &event.transaction_signature,
+                        &message,
+                        &e.to_string(),
+                    );
+                    self.epoch_metrics.increment_fail_notification_count();
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Send message to IRC channels
+    async fn send_irc_message(&self, event: &NotificationEvent) -> Result<(), JitoBellError> {
+        if let Some(irc_notifier) = &self.irc_notifier {
+            let message = self.render_template("irc", event);
+
+            match irc_notifier.send(&message).await {
+                Ok(()) => {
+                    self.record_audit("irc", &event.transaction_signature, &message, "queued");
+                    self.epoch_metrics.increment_success_notification_count();
+                    return Ok(());
+                }
+                Err(e) => {
+                    self.record_audit(
+                        "irc",
+                        &event.transaction_signature,
+                        &message,
+                        &e.to_string(),
+                    );
+                    self.epoch_metrics.increment_fail_notification_count();
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Publish message to Redis channel or stream
+    async fn send_redis_message(&self, event: &NotificationEvent) -> Result<(), JitoBellError> {
+        if let Some(redis_notifier) = &self.redis_notifier {
+            let schema_version = self
+                .config
+                .notifications
+                .redis
+                .as_ref()
+                .map(|redis_config| redis_config.schema_version)
+                .unwrap_or_default();
+            let payload = schema::notification_payload(event, schema_version)
+                .map(|payload| payload.to_string())
+                .map_err(|e| {
+                    JitoBellError::Notification(format!("Failed to serialize Redis message: {e}"))
+                })?;
+
+            match redis_notifier.send(&payload).await {
+                Ok(()) => {
+                    self.record_audit("redis", &event.transaction_signature, &payload, "sent");
+                    self.epoch_metrics.increment_success_notification_count();
+                    return Ok(());
+                }
+                Err(e) => {
+                    self.record_audit(
+                        "redis",
+                        &event.transaction_signature,
+                        &payload,
+                        &e.to_string(),
+                    );
+                    self.epoch_metrics.increment_fail_notification_count();
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Send RFC 5424 message to syslog collector
+    async fn send_syslog_message(
+        &self,
+        event: &NotificationEvent,
+        delivery: &SyslogDeliveryOptions,
+    ) -> Result<(), JitoBellError> {
+        if let Some(syslog_notifier) = &self.syslog_notifier {
+            let message = self.render_template("syslog", event);
+            let severity = delivery
+                .severity
+                .or(event.severity.map(SyslogSeverity::from))
+                .unwrap_or_else(|| {
+                    self.config
+                        .notifications
+                        .syslog
+                        .as_ref()
+                        .map(|syslog_config| syslog_config.severity)
+                        .unwrap_or_default()
+                });
+
+            match syslog_notifier.send(event, &message, severity).await {
+                Ok(line) => {
+                    self.record_audit("syslog", &event.transaction_signature, &line, "sent");
+                    self.epoch_metrics.increment_success_notification_count();
+                    return Ok(());
+                }
+                Err(e) => {
+                    self.record_audit(
+                        "syslog",
+                        &event.transaction_signature,
+                        &message,
+                        &e.to_string(),
+                    );
+                    self.epoch_metrics.increment_fail_notification_count();
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Append notification to JSON lines file
+    fn send_file_message(&self, event: &NotificationEvent) -> Result<(), JitoBellError> {
+        if let Some(file_notifier) = &self.file_notifier {
+            match file_notifier.append_notification(event) {
+                Ok(line) => {
+                    self.record_audit("file", &event.transaction_signature, &line, "appended");
+                    self.epoch_metrics.increment_success_notification_count();
+                    return Ok(());
+                }
+                Err(e) => {
+                    self.record_audit(
+                        "file",
+                        &event.transaction_signature,
+                        &event.description,
+                        &e.to_string(),
+                    );
+                    self.epoch_metrics.increment_fail_notification_count();
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Print notification to stdout as a JSON line
+    fn send_stdout_message(&self, event: &NotificationEvent) -> Result<(), JitoBellError> {
+        if let Some(stdout_notifier) = &self.stdout_notifier {
+            match stdout_notifier.print_notification(event) {
+                Ok(line) => {
+                    self.record_audit("stdout", &event.transaction_signature, &line, "printed");
+                    self.epoch_metrics.increment_success_notification_count();
+                    return Ok(());
+                }
+                Err(e) => {
+                    self.record_audit(
+                        "stdout",
+                        &event.transaction_signature,
+                        &event.description,
+                        &e.to_string(),
+                    );
+                    self.epoch_metrics.increment_fail_notification_count();
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,76 @@
+/// Group and decimal separators of a locale (BCP 47 tag, e.g. "ja", "de-CH")
+///
+/// - Languages without an entry use English separators
+fn separators(locale: &str) -> (&'static str, &'static str) {
+    let locale = locale.replace('_', "-").to_ascii_lowercase();
+    if locale == "de-ch" {
+        return ("'", ".");
+    }
+
+    let language = locale.split('-').next().unwrap_or_default();
+    match language {
+        "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" | "el" | "ro" | "vi" => (".", ","),
+        "fr" => ("\u{202f}", ","),
+        "ru" | "uk" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "no" | "hu" | "bg" => {
+            ("\u{a0}", ",")
+        }
+        _ => (",", "."),
+    }
+}
+
+/// Format a number with `decimals` fraction digits and the separators of `locale`
+pub fn format_number(value: f64, decimals: usize, locale: &str) -> String {
+    let (group, decimal) = separators(locale);
+
+    let formatted = format!("{:.*}", decimals, value.abs());
+    let (integer, fraction) = match formatted.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (formatted.as_str(), None),
+    };
+
+    let mut number = String::with_capacity(formatted.len() + integer.len() / 3 * group.len());
+    if value.is_sign_negative()
+        && formatted
+            .bytes()
+            .any(|digit| digit != b'0' && digit != b'.')
+    {
+        number.push('-');
+    }
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            number.push_str(group);
+        }
+        number.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        number.push_str(decimal);
+        number.push_str(fraction);
+    }
+
+    number
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locale::format_number;
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(1234567.891, 2, "en"), "1,234,567.89");
+        assert_eq!(format_number(1234567.891, 2, "ja-JP"), "1,234,567.89");
+        assert_eq!(format_number(1234567.891, 2, "de"), "1.234.567,89");
+        assert_eq!(
+            format_number(1234567.891, 2, "fr"),
+            "1\u{202f}234\u{202f}567,89"
+        );
+        assert_eq!(
+            format_number(1234567.891, 2, "ru"),
+            "1\u{a0}234\u{a0}567,89"
+        );
+        assert_eq!(format_number(1234567.891, 2, "de_CH"), "1'234'567.89");
+        assert_eq!(format_number(999.5, 0, "en"), "1,000");
+        assert_eq!(format_number(12.0, 2, "en"), "12.00");
+        assert_eq!(format_number(-1234.5, 1, "en"), "-1,234.5");
+        assert_eq!(format_number(-0.001, 2, "en"), "0.00");
+    }
+}
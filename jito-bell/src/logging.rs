@@ -0,0 +1,335 @@
+use std::{
+    fmt::Debug,
+    io::Write,
+    str::FromStr,
+    sync::{Mutex, PoisonError},
+    time::Instant,
+};
+
+use chrono::{SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Event, Level, Subscriber,
+};
+use tracing_subscriber::{
+    filter::{EnvFilter, LevelFilter},
+    layer::{Context, SubscriberExt},
+    registry::LookupSpan,
+    util::SubscriberInitExt,
+    Layer, Registry,
+};
+
+use crate::{
+    error::JitoBellError,
+    pipeline_trace::{self, TracingConfig},
+};
+
+/// Prefix of the fields `log` records carry once converted to `tracing` events
+const LOG_FIELD_PREFIX: &str = "log.";
+
+/// Format of the log lines
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFormat {
+    /// "<time> [<level>] <target>: <message>"
+    #[default]
+    Text,
+
+    /// One JSON object per line, with the fields of the pipeline spans (signature, slot,
+    /// program, instruction, destination, ...)
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = JitoBellError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(JitoBellError::Config(format!(
+                "Unknown log format {s}, expected text or json"
+            ))),
+        }
+    }
+}
+
+/// Logging configuration
+///
+/// - `LOG_FORMAT` overrides `format`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LoggingConfig {
+    /// Format of the log lines
+    #[serde(default)]
+    pub format: LogFormat,
+}
+
+/// Install JSON logging as the global `tracing` subscriber, `log` records included
+///
+/// - Lines enabled by `filter` are written to `writer`
+/// - Pipeline tracing is installed alongside when configured, since there is one global
+///   subscriber. Its closed spans are logged as JSON lines with `log_spans`, and exported with
+///   `http_client` with `otlp_endpoint`
+pub fn init_json<W>(
+    writer: W,
+    filter: EnvFilter,
+    tracing_config: Option<&TracingConfig>,
+    http_client: reqwest::Client,
+) -> Result<(), JitoBellError>
+where
+    W: Write + Send + 'static,
+{
+    let log_spans = tracing_config.is_some_and(|tracing_config| tracing_config.log_spans);
+    // Spans are logged by the JSON layer, a record logged by another layer is dropped
+    let pipeline_config = tracing_config.map(|tracing_config| TracingConfig {
+        log_spans: false,
+        ..tracing_config.clone()
+    });
+
+    Registry::default()
+        .with(JsonLayer::new(writer, log_spans).with_filter(filter))
+        .with(pipeline_config.as_ref().map(pipeline_trace::layer))
+        .with(
+            tracing_config
+                .and_then(|tracing_config| pipeline_trace::otlp_layer(tracing_config, http_client)),
+        )
+        .try_init()
+        .map_err(|e| JitoBellError::Config(format!("Failed to install JSON logging: {e}")))
+}
+
+/// Filter of `RUST_LOG` directives (`info`, `jito_bell=debug,h2=warn`, ...), `info` when unset
+/// like text logging
+pub fn env_filter(rust_log: Option<&str>) -> EnvFilter {
+    EnvFilter::builder()
+        .with_default_directive(LevelFilter::INFO.into())
+        .parse_lossy(rust_log.unwrap_or_default())
+}
+
+/// Layer writing events as JSON lines
+struct JsonLayer<W> {
+    /// Log sink
+    writer: Mutex<W>,
+
+    /// Log every closed span
+    log_spans: bool,
+}
+
+/// Fields of a span, merged into the lines logged inside it
+struct SpanFields {
+    /// Creation time
+    started: Instant,
+
+    /// Span fields
+    fields: Map<String, Value>,
+}
+
+impl<W: Write> JsonLayer<W> {
+    fn new(writer: W, log_spans: bool) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+            log_spans,
+        }
+    }
+
+    /// Write a line
+    fn write(&self, level: &Level, target: String, fields: Map<String, Value>) {
+        let line = json_line(
+            Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            level,
+            target,
+            fields,
+        );
+
+        let mut writer = self.writer.lock().unwrap_or_else(PoisonError::into_inner);
+        let _ = writeln!(writer, "{line}");
+    }
+}
+
+impl<S, W> Layer<S> for JsonLayer<W>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: Write + Send + 'static,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let mut fields = Map::new();
+        attrs.record(&mut JsonVisitor(&mut fields));
+        span.extensions_mut().insert(SpanFields {
+            started: Instant::now(),
+            fields,
+        });
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let mut extensions = span.extensions_mut();
+        if let Some(span_fields) = extensions.get_mut::<SpanFields>() {
+            values.record(&mut JsonVisitor(&mut span_fields.fields));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut fields = Map::new();
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope.from_root() {
+                if let Some(span_fields) = span.extensions().get::<SpanFields>() {
+                    fields.extend(span_fields.fields.clone());
+                }
+            }
+        }
+        event.record(&mut JsonVisitor(&mut fields));
+
+        let target = match fields.remove("log.target") {
+            Some(Value::String(target)) => target,
+            _ => event.metadata().target().to_string(),
+        };
+        fields.retain(|name, _| !name.starts_with(LOG_FIELD_PREFIX));
+
+        self.write(event.metadata().level(), target, fields);
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        if !self.log_spans {
+            return;
+        }
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        if !pipeline_trace::is_pipeline_target(span.metadata().target()) {
+            return;
+        }
+        let extensions = span.extensions();
+        let Some(span_fields) = extensions.get::<SpanFields>() else {
+            return;
+        };
+
+        let duration_ms = span_fields.started.elapsed().as_secs_f64() * 1_000.0;
+        let mut fields = span_fields.fields.clone();
+        fields.insert(
+            "message".to_string(),
+            Value::from(format!("Span {} took {duration_ms:.3}ms", span.name())),
+        );
+        fields.insert("span".to_string(), Value::from(span.name()));
+        fields.insert("duration_ms".to_string(), Value::from(duration_ms));
+
+        self.write(&Level::INFO, span.metadata().target().to_string(), fields);
+    }
+}
+
+/// JSON line of an event, `fields` holding the message and the span and event fields
+fn json_line(
+    timestamp: String,
+    level: &Level,
+    target: String,
+    mut fields: Map<String, Value>,
+) -> Value {
+    let mut line = Map::new();
+    line.insert("timestamp".to_string(), Value::from(timestamp));
+    line.insert("level".to_string(), Value::from(level.as_str()));
+    line.insert("target".to_string(), Value::from(target));
+    if let Some(message) = fields.remove("message") {
+        line.insert("message".to_string(), message);
+    }
+    line.extend(fields);
+
+    Value::Object(line)
+}
+
+/// Records fields as JSON values
+struct JsonVisitor<'a>(&'a mut Map<String, Value>);
+
+impl Visit for JsonVisitor<'_> {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0
+            .insert(field.name().to_string(), Value::from(format!("{value:?}")));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Map, Value};
+    use tracing::Level;
+    use tracing_subscriber::{layer::Filter, Registry};
+
+    use crate::logging::{env_filter, json_line, LogFormat};
+
+    #[test]
+    fn test_log_format() {
+        assert_eq!("json".parse::<LogFormat>().unwrap(), LogFormat::Json);
+        assert_eq!("TEXT".parse::<LogFormat>().unwrap(), LogFormat::Text);
+        assert!("xml".parse::<LogFormat>().is_err());
+    }
+
+    #[test]
+    fn test_env_filter() {
+        assert_eq!(
+            Filter::<Registry>::max_level_hint(&env_filter(None)),
+            Some(Level::INFO.into())
+        );
+        assert_eq!(
+            Filter::<Registry>::max_level_hint(&env_filter(Some("debug"))),
+            Some(Level::DEBUG.into())
+        );
+
+        let filter = env_filter(Some("jito_bell=debug,h2=warn"));
+        assert_eq!(
+            Filter::<Registry>::max_level_hint(&filter),
+            Some(Level::DEBUG.into())
+        );
+    }
+
+    #[test]
+    fn test_json_line() {
+        let mut fields = Map::new();
+        fields.insert("signature".to_string(), Value::from("abc"));
+        fields.insert("message".to_string(), Value::from("Failed to send"));
+        fields.insert("destination".to_string(), Value::from("slack"));
+
+        let line = json_line(
+            "2025-01-01T00:00:00.000Z".to_string(),
+            &Level::ERROR,
+            "jito_bell".to_string(),
+            fields,
+        );
+        assert_eq!(
+            line,
+            json!({
+                "timestamp": "2025-01-01T00:00:00.000Z",
+                "level": "ERROR",
+                "target": "jito_bell",
+                "message": "Failed to send",
+                "signature": "abc",
+                "destination": "slack",
+            })
+        );
+    }
+}
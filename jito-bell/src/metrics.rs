@@ -1,7 +1,25 @@
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use crate::parser::ProgramCoverage;
+
+/// Counted through a shared reference, as destinations are sent to concurrently
 #[derive(Debug, Default)]
 pub(crate) struct NotificationMetrics {
-    pub(crate) success: u64,
-    pub(crate) fail: u64,
+    pub(crate) success: AtomicU64,
+    pub(crate) fail: AtomicU64,
+}
+
+impl NotificationMetrics {
+    pub fn success(&self) -> u64 {
+        self.success.load(Ordering::Relaxed)
+    }
+
+    pub fn fail(&self) -> u64 {
+        self.fail.load(Ordering::Relaxed)
+    }
 }
 
 #[derive(Debug, Default)]
@@ -14,6 +32,18 @@ pub(crate) struct EpochMetrics {
 
     /// Notification Metrics
     pub(crate) notification: NotificationMetrics,
+
+    /// Parser coverage metrics keyed by program name
+    pub(crate) programs: HashMap<&'static str, ProgramMetrics>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct ProgramMetrics {
+    /// Transactions with at least one instruction of the program
+    pub(crate) tx: u64,
+
+    /// Instruction coverage
+    pub(crate) coverage: ProgramCoverage,
 }
 
 impl EpochMetrics {
@@ -28,11 +58,22 @@ impl EpochMetrics {
         self.tx += 1;
     }
 
-    pub fn increment_success_notification_count(&mut self) {
-        self.notification.success += 1;
+    pub fn record_parser_coverage(&mut self, coverage: &HashMap<&'static str, ProgramCoverage>) {
+        for (program, tx_coverage) in coverage.iter() {
+            let metrics = self.programs.entry(program).or_default();
+            metrics.tx += 1;
+            metrics.coverage.outer_decoded += tx_coverage.outer_decoded;
+            metrics.coverage.outer_skipped += tx_coverage.outer_skipped;
+            metrics.coverage.inner_decoded += tx_coverage.inner_decoded;
+            metrics.coverage.inner_skipped += tx_coverage.inner_skipped;
+        }
+    }
+
+    pub fn increment_success_notification_count(&self) {
+        self.notification.success.fetch_add(1, Ordering::Relaxed);
     }
 
-    pub fn increment_fail_notification_count(&mut self) {
-        self.notification.fail += 1;
+    pub fn increment_fail_notification_count(&self) {
+        self.notification.fail.fetch_add(1, Ordering::Relaxed);
     }
 }
@@ -3,6 +3,9 @@ use std::env;
 #[derive(Default)]
 pub struct MultiWriter {
     file: Option<std::fs::File>,
+
+    /// Write to stderr instead of stdout (stdout is reserved for JSON events)
+    stderr: bool,
 }
 
 impl MultiWriter {
@@ -22,14 +25,27 @@ impl MultiWriter {
             }
         };
 
-        Self { file }
+        Self {
+            file,
+            stderr: false,
+        }
+    }
+
+    /// Write console output to stderr
+    pub fn with_stderr(mut self) -> Self {
+        self.stderr = true;
+        self
     }
 }
 
 impl std::io::Write for MultiWriter {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        // Always write to stdout
-        std::io::stdout().write_all(buf)?;
+        // Always write to console
+        if self.stderr {
+            std::io::stderr().write_all(buf)?;
+        } else {
+            std::io::stdout().write_all(buf)?;
+        }
 
         // Also write to file if available
         if let Some(file) = &mut self.file {
@@ -40,7 +56,11 @@ impl std::io::Write for MultiWriter {
     }
 
     fn flush(&mut self) -> std::io::Result<()> {
-        std::io::stdout().flush()?;
+        if self.stderr {
+            std::io::stderr().flush()?;
+        } else {
+            std::io::stdout().flush()?;
+        }
         if let Some(file) = &mut self.file {
             file.flush()?;
         }
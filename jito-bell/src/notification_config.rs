@@ -1,30 +1,324 @@
-use serde::Deserialize;
+use std::{collections::HashMap, path::PathBuf};
 
-#[derive(Debug, Deserialize)]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::SchemaVersion;
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct SlackConfig {
-    /// Webhook URL
-    pub webhook_url: String,
+    /// Webhook URL, used when `bot_token` is not set
+    pub webhook_url: Option<String>,
 
-    /// Channel
+    /// Bot token, posts with `chat.postMessage` (private channels, threads, edits)
+    pub bot_token: Option<String>,
+
+    /// Channel (channel ID in bot token mode)
     pub channel: String,
+
+    /// Bot token mode: reply in the thread and mark the message once the transaction is finalized
+    #[serde(default)]
+    pub follow_up_finalized: bool,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct DiscordConfig {
     /// Webhook ULR
     pub webhook_url: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DiscordBotConfig {
+    /// Bot token
+    pub bot_token: String,
+
+    /// Channel IDs to post to
+    pub channel_ids: Vec<String>,
+
+    /// Application public key (hex), enables `POST /discord/interactions` on the API server
+    pub public_key: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct TelegramConfig {
     /// BOT Token
     pub bot_token: String,
 
     /// Chat ID
     pub chat_id: String,
+
+    /// Forum topic of `chat_id`, None posts to the general topic
+    #[serde(default)]
+    pub message_thread_id: Option<i64>,
+
+    /// Formatting of the templates, plain text when None
+    #[serde(default)]
+    pub parse_mode: Option<TelegramParseMode>,
+
+    /// Do not show a preview of the first link (e.g. the explorer link)
+    #[serde(default)]
+    pub disable_web_page_preview: bool,
+
+    /// Send every message silently, rules can also set `delivery.telegram.disable_notification`
+    #[serde(default)]
+    pub disable_notification: bool,
+}
+
+/// Telegram formatting of the templates
+///
+/// - Templates are written in the chosen markup, variable values are escaped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TelegramParseMode {
+    MarkdownV2,
+
+    #[serde(rename = "HTML")]
+    Html,
+}
+
+impl TelegramParseMode {
+    /// Value of the `parse_mode` parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MarkdownV2 => "MarkdownV2",
+            Self::Html => "HTML",
+        }
+    }
+
+    /// Escape text so it renders literally
+    pub fn escape(&self, text: &str) -> String {
+        match self {
+            Self::MarkdownV2 => {
+                let mut escaped = String::with_capacity(text.len());
+                for c in text.chars() {
+                    if matches!(
+                        c,
+                        '_' | '*'
+                            | '['
+                            | ']'
+                            | '('
+                            | ')'
+                            | '~'
+                            | '`'
+                            | '>'
+                            | '#'
+                            | '+'
+                            | '-'
+                            | '='
+                            | '|'
+                            | '{'
+                            | '}'
+                            | '.'
+                            | '!'
+                            | '\\'
+                    ) {
+                        escaped.push('\\');
+                    }
+                    escaped.push(c);
+                }
+                escaped
+            }
+            Self::Html => text
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SignalConfig {
+    /// signal-cli REST API URL (e.g. "http://localhost:8080")
+    pub api_url: String,
+
+    /// Registered sender number
+    pub number: String,
+
+    /// Recipients, phone numbers or group IDs ("group.<id>")
+    pub recipients: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ZulipConfig {
+    /// Zulip site URL (e.g. "https://example.zulipchat.com")
+    pub site: String,
+
+    /// Bot email
+    pub bot_email: String,
+
+    /// Bot API key
+    pub api_key: String,
+
+    /// Stream (channel) to post to
+    pub stream: String,
+
+    /// Topic template, e.g. "{{pool}}" for per-pool topics
+    #[serde(default = "default_zulip_topic")]
+    pub topic: String,
+}
+
+fn default_zulip_topic() -> String {
+    "{{pool}}".to_string()
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WebexConfig {
+    /// Bot access token
+    pub bot_token: String,
+
+    /// Room ID to post to
+    pub room_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WebhookConfig {
+    /// Endpoint URL
+    pub url: String,
+
+    /// Query parameters, values are message templates (e.g. "{{pool}}")
+    #[serde(default)]
+    pub query: HashMap<String, String>,
+
+    /// Headers, values are message templates
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+
+    /// Event schema version
+    #[serde(default)]
+    pub schema_version: SchemaVersion,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NostrConfig {
+    /// Secret key (64 hex characters or NIP-19 nsec)
+    pub secret_key: String,
+
+    /// Relay URLs (e.g. "wss://relay.damus.io")
+    pub relays: Vec<String>,
+
+    /// Event kind, 1 for text notes
+    #[serde(default = "default_nostr_kind")]
+    pub kind: u16,
+
+    /// Hashtags attached as `t` tags
+    #[serde(default)]
+    pub hashtags: Vec<String>,
+}
+
+fn default_nostr_kind() -> u16 {
+    1
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PushConfig {
+    /// Firebase Cloud Messaging (Android, iOS and web apps)
+    pub fcm: Option<FcmConfig>,
+
+    /// Apple Push Notification service (iOS apps)
+    pub apns: Option<ApnsConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FcmConfig {
+    /// Firebase project ID
+    pub project_id: String,
+
+    /// Service account key file (JSON) with the Firebase Cloud Messaging permission
+    pub service_account_path: PathBuf,
+
+    /// Device registration tokens
+    #[serde(default)]
+    pub device_tokens: Vec<String>,
+
+    /// Topics the app subscribes to (e.g. "stake-pool-alerts")
+    #[serde(default)]
+    pub topics: Vec<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ApnsConfig {
+    /// Apple Developer team ID
+    pub team_id: String,
+
+    /// Key ID of the APNs signing key
+    pub key_id: String,
+
+    /// APNs signing key (.p8)
+    pub private_key_path: PathBuf,
+
+    /// App bundle ID, sent as the APNs topic
+    pub bundle_id: String,
+
+    /// Device tokens
+    #[serde(default)]
+    pub device_tokens: Vec<String>,
+
+    /// Use the sandbox environment (development builds of the app)
+    #[serde(default)]
+    pub sandbox: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct XmppConfig {
+    /// Account JID used for SASL authentication (e.g. "bot@example.com")
+    pub jid: String,
+
+    /// Account password
+    pub password: String,
+
+    /// Direct recipient JIDs
+    #[serde(default)]
+    pub recipients: Vec<String>,
+
+    /// MUC room JIDs (e.g. "alerts@conference.example.com")
+    #[serde(default)]
+    pub rooms: Vec<String>,
+
+    /// Nickname in MUC rooms
+    #[serde(default = "default_xmpp_nickname")]
+    pub nickname: String,
+}
+
+fn default_xmpp_nickname() -> String {
+    "jito-bell".to_string()
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IrcConfig {
+    /// Server host
+    pub server: String,
+
+    /// Server port
+    #[serde(default = "default_irc_port")]
+    pub port: u16,
+
+    /// Connect with TLS
+    #[serde(default = "default_irc_tls")]
+    pub tls: bool,
+
+    /// Nickname
+    pub nick: String,
+
+    /// Channels to join and post to (e.g. "#jito-alerts")
+    pub channels: Vec<String>,
+
+    /// Server password (PASS)
+    pub server_password: Option<String>,
+
+    /// SASL PLAIN username, defaults to the nickname
+    pub sasl_username: Option<String>,
+
+    /// SASL PLAIN password, enables SASL when set
+    pub sasl_password: Option<String>,
+}
+
+fn default_irc_port() -> u16 {
+    6697
+}
+
+fn default_irc_tls() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct TwitterConfig {
     /// Twitter bearer token
     pub twitter_bearer_token: String,
@@ -40,9 +334,235 @@ pub struct TwitterConfig {
 
     /// Twitter Access Token Secret
     pub twitter_access_token_secret: String,
+
+    /// Only post events of at least this amount, keeps public posts to flows larger than the
+    /// thresholds of private channels sharing the rule
+    #[serde(default)]
+    pub min_amount: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MqttConfig {
+    /// Broker host
+    pub host: String,
+
+    /// Broker port
+    #[serde(default = "default_mqtt_port")]
+    pub port: u16,
+
+    /// Client ID
+    #[serde(default = "default_mqtt_client_id")]
+    pub client_id: String,
+
+    /// Username
+    pub username: Option<String>,
+
+    /// Password
+    pub password: Option<String>,
+
+    /// Connect with TLS
+    #[serde(default)]
+    pub tls: bool,
+
+    /// Topic to publish events to
+    pub topic: String,
+
+    /// Quality of service (0, 1 or 2)
+    #[serde(default = "default_mqtt_qos")]
+    pub qos: u8,
+
+    /// Retained flag
+    #[serde(default)]
+    pub retain: bool,
+
+    /// Event schema version
+    #[serde(default)]
+    pub schema_version: SchemaVersion,
+}
+
+fn default_mqtt_port() -> u16 {
+    1883
+}
+
+fn default_mqtt_client_id() -> String {
+    "jito-bell".to_string()
+}
+
+fn default_mqtt_qos() -> u8 {
+    1
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RedisMode {
+    /// PUBLISH to a channel
+    #[default]
+    Publish,
+
+    /// XADD to a stream
+    Stream,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RedisConfig {
+    /// Connection URL (e.g. "redis://127.0.0.1:6379")
+    pub url: String,
+
+    /// Delivery mode
+    #[serde(default)]
+    pub mode: RedisMode,
+
+    /// Channel (publish mode) or stream key (stream mode)
+    pub key: String,
+
+    /// Approximate maximum stream length (stream mode)
+    pub maxlen: Option<usize>,
+
+    /// Event schema version
+    #[serde(default)]
+    pub schema_version: SchemaVersion,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyslogTransport {
+    /// UDP datagrams (RFC 5426)
+    #[default]
+    Udp,
+
+    /// TCP with octet-counting framing (RFC 6587)
+    Tcp,
+
+    /// TLS with octet-counting framing (RFC 5425)
+    Tls,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyslogSeverity {
+    Emergency,
+    Alert,
+    Critical,
+    Error,
+    Warning,
+    #[default]
+    Notice,
+    Informational,
+    Debug,
+}
+
+impl SyslogSeverity {
+    /// Numerical code defined by RFC 5424
+    pub fn code(&self) -> u8 {
+        match self {
+            Self::Emergency => 0,
+            Self::Alert => 1,
+            Self::Critical => 2,
+            Self::Error => 3,
+            Self::Warning => 4,
+            Self::Notice => 5,
+            Self::Informational => 6,
+            Self::Debug => 7,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SyslogConfig {
+    /// Collector address (e.g. "127.0.0.1:514")
+    pub address: String,
+
+    /// Transport
+    #[serde(default)]
+    pub transport: SyslogTransport,
+
+    /// Facility code (0-23)
+    #[serde(default = "default_syslog_facility")]
+    pub facility: u8,
+
+    /// Default severity, can be overridden per rule
+    #[serde(default)]
+    pub severity: SyslogSeverity,
+
+    /// HOSTNAME field, defaults to $HOSTNAME
+    pub hostname: Option<String>,
+
+    /// APP-NAME field
+    #[serde(default = "default_syslog_app_name")]
+    pub app_name: String,
+}
+
+fn default_syslog_facility() -> u8 {
+    // user-level messages
+    1
+}
+
+fn default_syslog_app_name() -> String {
+    "jito-bell".to_string()
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FileConfig {
+    /// Output path of the JSON lines file
+    pub path: PathBuf,
+
+    /// Also append every parsed transaction
+    #[serde(default)]
+    pub include_transactions: bool,
+
+    /// Event schema version
+    #[serde(default)]
+    pub schema_version: SchemaVersion,
+
+    /// Rotate the file when it reaches this size in bytes
+    pub max_bytes: Option<u64>,
+
+    /// Rotate the file after this many seconds
+    pub rotation_interval_secs: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StdoutConfig {
+    /// Also print every parsed transaction
+    #[serde(default)]
+    pub include_transactions: bool,
+
+    /// Event schema version
+    #[serde(default)]
+    pub schema_version: SchemaVersion,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WebSocketConfig {
+    /// Bind address (e.g. "0.0.0.0:8081")
+    pub bind_address: String,
+
+    /// Path of the WebSocket endpoint
+    #[serde(default = "default_websocket_path")]
+    pub path: String,
+
+    /// Number of events buffered per client before it starts skipping
+    #[serde(default = "default_websocket_capacity")]
+    pub capacity: usize,
+
+    /// Also broadcast every parsed transaction
+    #[serde(default)]
+    pub include_transactions: bool,
+
+    /// Event schema version
+    #[serde(default)]
+    pub schema_version: SchemaVersion,
 }
 
-#[derive(Debug, Deserialize)]
+fn default_websocket_path() -> String {
+    "/ws".to_string()
+}
+
+fn default_websocket_capacity() -> usize {
+    1024
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct NotificationConfig {
     /// Slack notification configuration
     pub slack: Option<SlackConfig>,
@@ -50,9 +570,132 @@ pub struct NotificationConfig {
     /// Discord notification configuration
     pub discord: Option<DiscordConfig>,
 
+    /// Discord bot notification configuration
+    pub discord_bot: Option<DiscordBotConfig>,
+
     /// Telegram notification configuration
     pub telegram: Option<TelegramConfig>,
 
     /// Twitter notification configuration
     pub twitter: Option<TwitterConfig>,
+
+    /// Signal notification configuration
+    pub signal: Option<SignalConfig>,
+
+    /// Zulip notification configuration
+    pub zulip: Option<ZulipConfig>,
+
+    /// Webex notification configuration
+    pub webex: Option<WebexConfig>,
+
+    /// Mobile push notification configuration
+    pub push: Option<PushConfig>,
+
+    /// Nostr notification configuration
+    pub nostr: Option<NostrConfig>,
+
+    /// Generic webhook configuration
+    pub webhook: Option<WebhookConfig>,
+
+    /// XMPP notification configuration
+    pub xmpp: Option<XmppConfig>,
+
+    /// IRC notification configuration
+    pub irc: Option<IrcConfig>,
+
+    /// MQTT notification configuration
+    pub mqtt: Option<MqttConfig>,
+
+    /// Redis notification configuration
+    pub redis: Option<RedisConfig>,
+
+    /// Syslog notification configuration
+    pub syslog: Option<SyslogConfig>,
+
+    /// JSON lines file configuration
+    pub file: Option<FileConfig>,
+
+    /// JSON lines stdout configuration
+    pub stdout: Option<StdoutConfig>,
+
+    /// WebSocket broadcast server configuration
+    ///
+    /// - Every notification is broadcast, regardless of rule destinations
+    pub websocket: Option<WebSocketConfig>,
+}
+
+/// Names of every destination
+pub const DESTINATIONS: [&str; 18] = [
+    "slack",
+    "discord",
+    "discord_bot",
+    "telegram",
+    "twitter",
+    "signal",
+    "zulip",
+    "webex",
+    "push",
+    "nostr",
+    "webhook",
+    "xmpp",
+    "irc",
+    "mqtt",
+    "redis",
+    "syslog",
+    "file",
+    "stdout",
+];
+
+impl NotificationConfig {
+    /// Names of the configured destinations
+    pub fn configured(&self) -> Vec<&'static str> {
+        DESTINATIONS
+            .into_iter()
+            .filter(|destination| self.is_configured(destination) == Some(true))
+            .collect()
+    }
+
+    /// Whether a destination is configured, None for names that are not a destination
+    pub fn is_configured(&self, destination: &str) -> Option<bool> {
+        let configured = match destination {
+            "slack" => self.slack.is_some(),
+            "discord" => self.discord.is_some(),
+            "discord_bot" => self.discord_bot.is_some(),
+            "telegram" => self.telegram.is_some(),
+            "twitter" => self.twitter.is_some(),
+            "signal" => self.signal.is_some(),
+            "zulip" => self.zulip.is_some(),
+            "webex" => self.webex.is_some(),
+            "push" => self.push.is_some(),
+            "nostr" => self.nostr.is_some(),
+            "webhook" => self.webhook.is_some(),
+            "xmpp" => self.xmpp.is_some(),
+            "irc" => self.irc.is_some(),
+            "mqtt" => self.mqtt.is_some(),
+            "redis" => self.redis.is_some(),
+            "syslog" => self.syslog.is_some(),
+            "file" => self.file.is_some(),
+            "stdout" => self.stdout.is_some(),
+            _ => return None,
+        };
+
+        Some(configured)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::notification_config::TelegramParseMode;
+
+    #[test]
+    fn test_telegram_escape() {
+        assert_eq!(
+            TelegramParseMode::MarkdownV2.escape("[failed] 1,234.50 - a_b!"),
+            "\\[failed\\] 1,234\\.50 \\- a\\_b\\!"
+        );
+        assert_eq!(
+            TelegramParseMode::Html.escape("<b>A & B</b>"),
+            "&lt;b&gt;A &amp; B&lt;/b&gt;"
+        );
+    }
 }
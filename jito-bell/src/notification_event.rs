@@ -0,0 +1,480 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use solana_sdk::native_token::lamports_to_sol;
+
+use std::{borrow::Cow, collections::HashMap};
+
+use crate::{
+    locale::format_number,
+    notification_info::{Destination, NotificationInfo, Severity},
+    parser::JitoTransactionParser,
+    stake_pool_fee::FeeChange,
+    timezone::Timezone,
+};
+
+/// Where the SOL of a pool deposit came from, or where the SOL of a withdrawal went
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SolFlow {
+    /// Deposit of SOL not unwrapped by the transaction
+    FreshSol,
+
+    /// Deposit of SOL unwrapped from wSOL by the transaction (e.g. the output of a swap)
+    RecycledWsol,
+
+    /// Withdrawal kept as SOL
+    NativeSol,
+
+    /// Withdrawal wrapped into wSOL by the transaction
+    WrappedWsol,
+}
+
+impl SolFlow {
+    /// Label of the template variable
+    pub fn label(&self) -> &'static str {
+        match self {
+            SolFlow::FreshSol => "fresh SOL",
+            SolFlow::RecycledWsol => "recycled wSOL",
+            SolFlow::NativeSol => "native SOL",
+            SolFlow::WrappedWsol => "wrapped wSOL",
+        }
+    }
+}
+
+/// Machine-readable notification event
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NotificationEvent {
+    /// Description
+    pub description: String,
+
+    /// Pool (stake pool or vault) address
+    pub pool: String,
+
+    /// Name of the pool from `pool_names`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool_name: Option<String>,
+
+    /// Program of the instruction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub program: Option<String>,
+
+    /// Instruction name, the key under `instructions` in the config
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instruction: Option<String>,
+
+    /// Account the funds came from, the fee payer unless the instruction names one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender: Option<String>,
+
+    /// Account the funds went to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiver: Option<String>,
+
+    /// Validator vote account, if the event concerns a single validator
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validator: Option<String>,
+
+    /// Claimant of a reward claim
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claimant: Option<String>,
+
+    /// Governance proposal of the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proposal: Option<String>,
+
+    /// SPL Memo attached to the transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+
+    /// Priority fee (lamports) paid by the transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority_fee: Option<u64>,
+
+    /// Stake pool fee change of a `set_fee` instruction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_change: Option<FeeChange>,
+
+    /// Whether the depositor's pool token account was created by the transaction
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub first_time_depositor: bool,
+
+    /// wSOL correlation of a SOL deposit or withdrawal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sol_flow: Option<SolFlow>,
+
+    /// Severity of the notification
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+
+    /// Amount
+    pub amount: f64,
+
+    /// Currency unit (SOL, VRT symbol, USD)
+    pub currency_unit: String,
+
+    /// Amount in USD, when the price is known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_usd: Option<f64>,
+
+    /// Transaction signature
+    pub transaction_signature: String,
+
+    /// Whether the transaction failed
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub failed: bool,
+
+    /// Slot of the transaction
+    pub slot: u64,
+
+    /// Index of the transaction in the slot
+    pub transaction_index: u64,
+
+    /// Block time of the slot (unix timestamp), when the block meta was seen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_time: Option<i64>,
+
+    /// Per-pool sequence number (ordered delivery only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<u64>,
+
+    /// Block explorer url of the transaction
+    pub explorer_url: String,
+
+    /// Time the notification was generated (RFC 3339)
+    pub timestamp: String,
+
+    /// Message template of the matched rule
+    #[serde(skip)]
+    pub template: Option<String>,
+
+    /// Locale of the destination the event is sent to
+    #[serde(skip)]
+    pub locale: Option<String>,
+
+    /// Timezone of `{{local_time}}` and the local time of embeds
+    #[serde(skip)]
+    pub timezone: Timezone,
+
+    /// Labels of the event's accounts from `account_labels`, rendered instead of the pubkeys
+    #[serde(skip)]
+    pub account_labels: HashMap<String, String>,
+}
+
+impl NotificationEvent {
+    /// Initialize a new notification event
+    pub fn new(
+        parser: &JitoTransactionParser,
+        notification: &NotificationInfo,
+        pool: &str,
+        amount: f64,
+        currency_unit: &str,
+        explorer_url: &str,
+    ) -> Self {
+        let description = if parser.failed {
+            format!("[failed] {}", notification.description)
+        } else {
+            notification.description.clone()
+        };
+
+        Self {
+            description,
+            pool: pool.to_string(),
+            pool_name: None,
+            program: None,
+            instruction: None,
+            sender: parser.fee_payer.map(|fee_payer| fee_payer.to_string()),
+            receiver: None,
+            validator: None,
+            claimant: None,
+            proposal: None,
+            memo: parser.memo.clone(),
+            priority_fee: parser.compute_budget.priority_fee(),
+            fee_change: None,
+            first_time_depositor: false,
+            sol_flow: None,
+            severity: notification.severity,
+            amount,
+            currency_unit: currency_unit.to_string(),
+            amount_usd: None,
+            transaction_signature: parser.transaction_signature.clone(),
+            failed: parser.failed,
+            slot: parser.slot,
+            transaction_index: parser.transaction_index,
+            block_time: None,
+            sequence: None,
+            explorer_url: format!("{explorer_url}/tx/{}", parser.transaction_signature),
+            timestamp: Utc::now().to_rfc3339(),
+            template: notification.template.clone(),
+            locale: None,
+            timezone: Timezone::default(),
+            account_labels: HashMap::new(),
+        }
+    }
+
+    /// Initialize a notification event not tied to a transaction (e.g. scheduled reports)
+    pub fn scheduled(
+        notification: &NotificationInfo,
+        description: String,
+        pool: &str,
+        amount: f64,
+        currency_unit: &str,
+    ) -> Self {
+        Self {
+            description,
+            pool: pool.to_string(),
+            pool_name: None,
+            program: None,
+            instruction: None,
+            sender: None,
+            receiver: None,
+            validator: None,
+            claimant: None,
+            proposal: None,
+            memo: None,
+            priority_fee: None,
+            fee_change: None,
+            first_time_depositor: false,
+            sol_flow: None,
+            severity: notification.severity,
+            amount,
+            currency_unit: currency_unit.to_string(),
+            amount_usd: None,
+            transaction_signature: String::new(),
+            failed: false,
+            slot: 0,
+            transaction_index: 0,
+            block_time: None,
+            sequence: None,
+            explorer_url: String::new(),
+            timestamp: Utc::now().to_rfc3339(),
+            template: notification.template.clone(),
+            locale: None,
+            timezone: Timezone::default(),
+            account_labels: HashMap::new(),
+        }
+    }
+
+    /// Event sent to a destination, with the destination's template, severity and locale overrides
+    pub fn for_destination(&self, destination: &Destination) -> Cow<'_, Self> {
+        if destination.template().is_none()
+            && destination.severity().is_none()
+            && destination.locale().is_none()
+        {
+            return Cow::Borrowed(self);
+        }
+
+        let mut event = self.clone();
+        if let Some(template) = destination.template() {
+            event.template = Some(template.to_string());
+        }
+        if let Some(severity) = destination.severity() {
+            event.severity = Some(severity);
+        }
+        if let Some(locale) = destination.locale() {
+            event.locale = Some(locale.to_string());
+        }
+
+        Cow::Owned(event)
+    }
+
+    /// Block time of the slot, when the block meta was seen
+    pub fn block_time(&self) -> Option<DateTime<Utc>> {
+        self.block_time
+            .and_then(|block_time| DateTime::from_timestamp(block_time, 0))
+    }
+
+    /// Time of the event: the block time, or the time the notification was generated
+    pub fn time(&self) -> DateTime<Utc> {
+        self.block_time()
+            .or_else(|| {
+                DateTime::parse_from_rfc3339(&self.timestamp)
+                    .ok()
+                    .map(|timestamp| timestamp.with_timezone(&Utc))
+            })
+            .unwrap_or_else(Utc::now)
+    }
+
+    /// Human-readable time of the event in its timezone
+    pub fn local_time(&self) -> String {
+        self.timezone.format(self.time())
+    }
+
+    /// Variables substituted by `render`, written as `{{name}}` in templates
+    pub const TEMPLATE_VARIABLES: &'static [&'static str] = &[
+        "description",
+        "amount",
+        "amount_usd",
+        "currency_unit",
+        "tx_hash",
+        "slot",
+        "block_time",
+        "local_time",
+        "status",
+        "pool",
+        "pool_name",
+        "program",
+        "instruction",
+        "sender",
+        "receiver",
+        "validator",
+        "claimant",
+        "proposal",
+        "memo",
+        "priority_fee",
+        "fee_type",
+        "fee_before",
+        "fee_after",
+        "first_time_depositor",
+        "sol_flow",
+        "severity",
+        "sequence",
+    ];
+
+    /// Accounts of the event (pool, sender, receiver, validator, claimant and proposal)
+    pub fn accounts(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.pool.as_str()).chain(
+            [
+                &self.sender,
+                &self.receiver,
+                &self.validator,
+                &self.claimant,
+                &self.proposal,
+            ]
+            .into_iter()
+            .filter_map(|account| account.as_deref()),
+        )
+    }
+
+    /// Label of an account from `account_labels`, the account itself when unlabeled
+    pub fn label<'a>(&'a self, account: &'a str) -> &'a str {
+        self.account_labels
+            .get(account)
+            .map(String::as_str)
+            .unwrap_or(account)
+    }
+
+    /// Label of an optional account, empty without one
+    fn optional_label<'a>(&'a self, account: &'a Option<String>) -> &'a str {
+        account
+            .as_deref()
+            .map(|account| self.label(account))
+            .unwrap_or_default()
+    }
+
+    /// Substitute template variables, numbers formatted for the event's locale
+    pub fn render(&self, template: &str) -> String {
+        self.render_localized(template, self.locale.as_deref())
+    }
+
+    /// Substitute template variables, numbers formatted for `locale` (`{:.2}` without one)
+    pub fn render_localized(&self, template: &str, locale: Option<&str>) -> String {
+        self.render_escaped(template, locale, str::to_string)
+    }
+
+    /// Substitute template variables escaped with `escape` (e.g. for Telegram MarkdownV2), the
+    /// template itself is kept as written
+    pub fn render_escaped(
+        &self,
+        template: &str,
+        locale: Option<&str>,
+        escape: impl Fn(&str) -> String,
+    ) -> String {
+        let format_amount = |amount: f64| match locale {
+            Some(locale) => format_number(amount, 2, locale),
+            None => format!("{amount:.2}"),
+        };
+        let priority_fee = self
+            .priority_fee
+            .map(|lamports| lamports_to_sol(lamports).to_string())
+            .unwrap_or_default();
+        let (fee_type, fee_before, fee_after) = match &self.fee_change {
+            Some(fee_change) => (
+                fee_change.fee_type.as_str(),
+                fee_change.before.as_deref().unwrap_or("unknown"),
+                fee_change.after.as_str(),
+            ),
+            None => ("", "", ""),
+        };
+        let sequence = self
+            .sequence
+            .map(|sequence| sequence.to_string())
+            .unwrap_or_default();
+        let amount_usd = self.amount_usd.map(format_amount).unwrap_or_default();
+        let block_time = self
+            .block_time()
+            .map(|block_time| block_time.to_rfc3339())
+            .unwrap_or_else(|| self.timestamp.clone());
+
+        template
+            .replace("{{description}}", &escape(&self.description))
+            .replace("{{amount}}", &escape(&format_amount(self.amount)))
+            .replace("{{amount_usd}}", &escape(&amount_usd))
+            .replace("{{currency_unit}}", &escape(&self.currency_unit))
+            .replace("{{tx_hash}}", &escape(&self.transaction_signature))
+            .replace("{{slot}}", &escape(&self.slot.to_string()))
+            .replace("{{block_time}}", &escape(&block_time))
+            .replace("{{local_time}}", &escape(&self.local_time()))
+            .replace(
+                "{{status}}",
+                &escape(if self.failed { "failed" } else { "success" }),
+            )
+            .replace(
+                "{{pool_name}}",
+                &escape(
+                    self.pool_name
+                        .as_deref()
+                        .unwrap_or_else(|| self.label(&self.pool)),
+                ),
+            )
+            .replace("{{pool}}", &escape(self.label(&self.pool)))
+            .replace(
+                "{{program}}",
+                &escape(self.program.as_deref().unwrap_or_default()),
+            )
+            .replace(
+                "{{instruction}}",
+                &escape(self.instruction.as_deref().unwrap_or_default()),
+            )
+            .replace("{{sender}}", &escape(self.optional_label(&self.sender)))
+            .replace("{{receiver}}", &escape(self.optional_label(&self.receiver)))
+            .replace(
+                "{{validator}}",
+                &escape(self.optional_label(&self.validator)),
+            )
+            .replace("{{claimant}}", &escape(self.optional_label(&self.claimant)))
+            .replace("{{proposal}}", &escape(self.optional_label(&self.proposal)))
+            .replace(
+                "{{memo}}",
+                &escape(self.memo.as_deref().unwrap_or_default()),
+            )
+            .replace("{{priority_fee}}", &escape(&priority_fee))
+            .replace("{{fee_type}}", &escape(fee_type))
+            .replace("{{fee_before}}", &escape(fee_before))
+            .replace("{{fee_after}}", &escape(fee_after))
+            .replace(
+                "{{first_time_depositor}}",
+                &escape(if self.first_time_depositor {
+                    "first-time depositor"
+                } else {
+                    ""
+                }),
+            )
+            .replace(
+                "{{sol_flow}}",
+                &escape(
+                    self.sol_flow
+                        .map(|sol_flow| sol_flow.label())
+                        .unwrap_or_default(),
+                ),
+            )
+            .replace(
+                "{{severity}}",
+                &escape(
+                    self.severity
+                        .map(|severity| severity.as_str())
+                        .unwrap_or_default(),
+                ),
+            )
+            .replace("{{sequence}}", &escape(&sequence))
+    }
+}
@@ -1,6 +1,10 @@
-use serde::Deserialize;
+use std::collections::HashMap;
 
-#[derive(Debug, Clone, Deserialize)]
+use serde::{Deserialize, Serialize};
+
+use crate::notification_config::SyslogSeverity;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct NotificationInfo {
     /// Description
     pub description: String,
@@ -9,5 +13,311 @@ pub struct NotificationInfo {
     /// - Telegram
     /// - Discord
     /// - Slack
-    pub destinations: Vec<String>,
+    pub destinations: Vec<Destination>,
+
+    /// Message template of this rule, overrides `message_templates`
+    pub template: Option<String>,
+
+    /// Severity of this rule's notifications (e.g. per threshold tier)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+
+    /// Per-channel delivery options
+    #[serde(default, skip_serializing_if = "DeliveryOptions::is_default")]
+    pub delivery: DeliveryOptions,
+
+    /// Amount divided by the threshold that fired, set when the threshold is evaluated
+    #[serde(skip)]
+    pub threshold_ratio: Option<f64>,
+}
+
+/// Severity of a notification, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    Critical,
+}
+
+impl Severity {
+    /// Name, as written in the config
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Critical => "critical",
+        }
+    }
+
+    /// Discord embed color
+    pub fn discord_color(&self) -> u32 {
+        match self {
+            Severity::Info => 0x3498db,
+            Severity::Warning => 0xe67e22,
+            Severity::Critical => 0xe74c3c,
+        }
+    }
+
+    /// Slack emoji of the message header
+    pub fn slack_emoji(&self) -> &'static str {
+        match self {
+            Severity::Info => ":information_source:",
+            Severity::Warning => ":warning:",
+            Severity::Critical => ":rotating_light:",
+        }
+    }
+
+    /// Prefix of Telegram messages
+    pub fn telegram_prefix(&self) -> &'static str {
+        match self {
+            Severity::Info => "ℹ️",
+            Severity::Warning => "⚠️",
+            Severity::Critical => "🚨",
+        }
+    }
+}
+
+impl From<Severity> for SyslogSeverity {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Info => SyslogSeverity::Informational,
+            Severity::Warning => SyslogSeverity::Warning,
+            Severity::Critical => SyslogSeverity::Critical,
+        }
+    }
+}
+
+/// Destination of a rule, a name or a name with overrides
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Destination {
+    /// Destination name (e.g. "telegram")
+    Name(String),
+
+    /// Destination with its own template, threshold multiplier, severity or locale
+    Override(DestinationOverride),
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DestinationOverride {
+    /// Destination name (e.g. "telegram")
+    pub name: String,
+
+    /// Message template of this destination, overrides the rule template
+    pub template: Option<String>,
+
+    /// Only notify this destination once the amount reaches the threshold times this multiplier
+    pub threshold_multiplier: Option<f64>,
+
+    /// Severity of this destination's notifications
+    pub severity: Option<Severity>,
+
+    /// Locale of this destination's templates and numbers, overrides `destination_locales`
+    pub locale: Option<String>,
+}
+
+impl Destination {
+    /// Destination name
+    pub fn name(&self) -> &str {
+        match self {
+            Destination::Name(name) => name,
+            Destination::Override(destination) => &destination.name,
+        }
+    }
+
+    /// Message template of this destination
+    pub fn template(&self) -> Option<&str> {
+        match self {
+            Destination::Name(_) => None,
+            Destination::Override(destination) => destination.template.as_deref(),
+        }
+    }
+
+    /// Severity of this destination's notifications
+    pub fn severity(&self) -> Option<Severity> {
+        match self {
+            Destination::Name(_) => None,
+            Destination::Override(destination) => destination.severity,
+        }
+    }
+
+    /// Locale of this destination
+    pub fn locale(&self) -> Option<&str> {
+        match self {
+            Destination::Name(_) => None,
+            Destination::Override(destination) => destination.locale.as_deref(),
+        }
+    }
+
+    /// Whether the destination is notified of an amount `threshold_ratio` times the threshold
+    ///
+    /// - Notifications not tied to a threshold reach every destination
+    pub fn accepts(&self, threshold_ratio: Option<f64>) -> bool {
+        match (self, threshold_ratio) {
+            (
+                Destination::Override(DestinationOverride {
+                    threshold_multiplier: Some(multiplier),
+                    ..
+                }),
+                Some(ratio),
+            ) => ratio >= *multiplier,
+            _ => true,
+        }
+    }
+}
+
+impl From<&str> for Destination {
+    fn from(name: &str) -> Self {
+        Destination::Name(name.to_string())
+    }
+}
+
+impl std::fmt::Display for Destination {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize, Serialize)]
+pub struct DeliveryOptions {
+    /// Telegram delivery options
+    #[serde(default)]
+    pub telegram: TelegramDeliveryOptions,
+
+    /// Discord delivery options
+    #[serde(default)]
+    pub discord: DiscordDeliveryOptions,
+
+    /// Slack delivery options
+    #[serde(default)]
+    pub slack: SlackDeliveryOptions,
+
+    /// Syslog delivery options
+    #[serde(default)]
+    pub syslog: SyslogDeliveryOptions,
+
+    /// Webhook delivery options
+    #[serde(default)]
+    pub webhook: WebhookDeliveryOptions,
+}
+
+impl DeliveryOptions {
+    /// Whether no delivery option was changed
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize, Serialize)]
+pub struct TelegramDeliveryOptions {
+    /// Send the message silently, users receive a notification with no sound
+    #[serde(default)]
+    pub disable_notification: bool,
+
+    /// Forum topic of `telegram.chat_id` for this rule, overrides `telegram.message_thread_id`
+    #[serde(default)]
+    pub message_thread_id: Option<i64>,
+
+    /// Chats of this rule, replaces `telegram.chat_id` when not empty
+    #[serde(default)]
+    pub chats: Vec<TelegramChat>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TelegramChat {
+    /// Chat ID
+    pub chat_id: String,
+
+    /// Forum topic, None posts to the general topic
+    #[serde(default)]
+    pub message_thread_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize, Serialize)]
+pub struct DiscordDeliveryOptions {
+    /// Do not trigger push and desktop notifications (`@silent`)
+    #[serde(default)]
+    pub silent: bool,
+
+    /// Send plain text without embeds or link previews
+    #[serde(default)]
+    pub suppress_embeds: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize, Serialize)]
+pub struct SyslogDeliveryOptions {
+    /// Severity of this rule's messages, defaults to `syslog.severity`
+    pub severity: Option<SyslogSeverity>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize, Serialize)]
+pub struct WebhookDeliveryOptions {
+    /// Query parameters of this rule, added to or overriding `webhook.query`
+    #[serde(default)]
+    pub query: HashMap<String, String>,
+
+    /// Headers of this rule, added to or overriding `webhook.headers`
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SlackDeliveryOptions {
+    /// Unfurl text-based content such as explorer links
+    #[serde(default = "default_unfurl")]
+    pub unfurl_links: bool,
+
+    /// Unfurl media content
+    #[serde(default = "default_unfurl")]
+    pub unfurl_media: bool,
+}
+
+impl Default for SlackDeliveryOptions {
+    fn default() -> Self {
+        Self {
+            unfurl_links: default_unfurl(),
+            unfurl_media: default_unfurl(),
+        }
+    }
+}
+
+fn default_unfurl() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::notification_info::{Destination, NotificationInfo, Severity};
+
+    #[test]
+    fn test_destination_overrides() {
+        let notification: NotificationInfo = serde_yaml::from_str(
+            r#"
+description: "Deposit detected"
+destinations:
+  - telegram
+  - name: webhook
+    threshold_multiplier: 10.0
+    severity: critical
+    locale: ja
+    template: "{{description}} {{amount}}"
+"#,
+        )
+        .unwrap();
+
+        let telegram = &notification.destinations[0];
+        let webhook = &notification.destinations[1];
+        assert_eq!(telegram, &Destination::from("telegram"));
+        assert_eq!(webhook.name(), "webhook");
+        assert_eq!(webhook.severity(), Some(Severity::Critical));
+        assert_eq!(webhook.locale(), Some("ja"));
+        assert_eq!(telegram.locale(), None);
+        assert_eq!(webhook.template(), Some("{{description}} {{amount}}"));
+
+        assert!(telegram.accepts(Some(1.0)));
+        assert!(!webhook.accepts(Some(9.9)));
+        assert!(webhook.accepts(Some(10.0)));
+        assert!(webhook.accepts(None));
+    }
 }
@@ -0,0 +1,237 @@
+use std::time::Duration;
+
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use serde_json::Value;
+
+use crate::{
+    notification_event::NotificationEvent, notification_info::Severity, suppression::Suppressions,
+};
+
+/// Discord REST API base URL
+pub const DISCORD_API_URL: &str = "https://discord.com/api/v10";
+
+/// Button custom ID prefix to acknowledge an alert
+const ACKNOWLEDGE_PREFIX: &str = "ack:";
+
+/// Button custom ID prefix to mute the pool of an alert
+const MUTE_PREFIX: &str = "mute:";
+
+/// Mute duration of the "Mute 1h" button
+const MUTE_DURATION: Duration = Duration::from_secs(3600);
+
+/// Interaction types
+const INTERACTION_PING: u64 = 1;
+const INTERACTION_MESSAGE_COMPONENT: u64 = 3;
+
+/// Interaction callback types
+const CALLBACK_PONG: u64 = 1;
+const CALLBACK_CHANNEL_MESSAGE: u64 = 4;
+const CALLBACK_UPDATE_MESSAGE: u64 = 7;
+
+/// Ephemeral message flag
+const EPHEMERAL_FLAG: u64 = 1 << 6;
+
+/// Message with an embed and "Acknowledge" / "Mute 1h" buttons
+pub fn message_payload(event: &NotificationEvent) -> Value {
+    serde_json::json!({
+        "embeds": [{
+            "title": "New Transaction Detected",
+            "description": event.description,
+            "color": event.severity.unwrap_or(Severity::Info).discord_color(),
+            "fields": [
+                {
+                    "name": "Amount",
+                    "value": format!("{:.2} {}", event.amount, event.currency_unit),
+                    "inline": true
+                },
+                {
+                    "name": "Transaction",
+                    "value": format!("[View on Explorer]({})", event.explorer_url),
+                    "inline": true
+                },
+                {
+                    "name": "Time",
+                    "value": event.local_time(),
+                    "inline": true
+                }
+            ],
+            "timestamp": event.time().to_rfc3339()
+        }],
+        "components": [{
+            "type": 1,
+            "components": [
+                {
+                    "type": 2,
+                    "style": 3,
+                    "label": "Acknowledge",
+                    "custom_id": format!("{ACKNOWLEDGE_PREFIX}{}", event.transaction_signature)
+                },
+                {
+                    "type": 2,
+                    "style": 2,
+                    "label": "Mute 1h",
+                    "custom_id": format!("{MUTE_PREFIX}{}", event.pool)
+                }
+            ]
+        }]
+    })
+}
+
+/// Verify the Ed25519 signature Discord attaches to interaction requests
+///
+/// - Signed message is the timestamp followed by the raw body
+pub fn verify_signature(public_key: &str, signature: &str, timestamp: &str, body: &[u8]) -> bool {
+    let Some(public_key) = decode_hex::<32>(public_key) else {
+        return false;
+    };
+    let Some(signature) = decode_hex::<64>(signature) else {
+        return false;
+    };
+    let Ok(verifying_key) = VerifyingKey::from_bytes(&public_key) else {
+        return false;
+    };
+
+    let mut message = timestamp.as_bytes().to_vec();
+    message.extend_from_slice(body);
+
+    verifying_key
+        .verify(&message, &Signature::from_bytes(&signature))
+        .is_ok()
+}
+
+/// Respond to an interaction
+///
+/// - PING: PONG
+/// - "Acknowledge": remove the buttons and note who acknowledged
+/// - "Mute 1h": mute the pool in `suppressions` and note who muted it
+pub fn handle_interaction(interaction: &Value, suppressions: &Suppressions) -> Value {
+    let interaction_type = interaction.get("type").and_then(Value::as_u64);
+    if interaction_type == Some(INTERACTION_PING) {
+        return serde_json::json!({ "type": CALLBACK_PONG });
+    }
+
+    let custom_id = interaction
+        .get("data")
+        .and_then(|data| data.get("custom_id"))
+        .and_then(Value::as_str)
+        .filter(|_| interaction_type == Some(INTERACTION_MESSAGE_COMPONENT))
+        .unwrap_or_default();
+
+    // Guild interactions carry `member.user`, DMs carry `user`
+    let user = interaction
+        .get("member")
+        .and_then(|member| member.get("user"))
+        .or_else(|| interaction.get("user"))
+        .and_then(|user| user.get("username"))
+        .and_then(Value::as_str)
+        .unwrap_or("unknown");
+
+    if custom_id.starts_with(ACKNOWLEDGE_PREFIX) {
+        update_message(format!("Acknowledged by {user}"))
+    } else if let Some(pool) = custom_id.strip_prefix(MUTE_PREFIX) {
+        suppressions.mute(pool, MUTE_DURATION);
+        update_message(format!("{pool} muted for 1h by {user}"))
+    } else {
+        serde_json::json!({
+            "type": CALLBACK_CHANNEL_MESSAGE,
+            "data": {
+                "content": "Unsupported interaction",
+                "flags": EPHEMERAL_FLAG
+            }
+        })
+    }
+}
+
+/// Update the original message, keeping its embed and removing the buttons
+fn update_message(content: String) -> Value {
+    serde_json::json!({
+        "type": CALLBACK_UPDATE_MESSAGE,
+        "data": {
+            "content": content,
+            "components": []
+        }
+    })
+}
+
+/// Decode a fixed length hex string
+fn decode_hex<const N: usize>(hex: &str) -> Option<[u8; N]> {
+    if hex.len() != N * 2 {
+        return None;
+    }
+
+    let mut bytes = [0u8; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(hex.get(i * 2..i * 2 + 2)?, 16).ok()?;
+    }
+
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::{Signer, SigningKey};
+
+    use crate::{
+        notifier::discord_bot::{handle_interaction, verify_signature},
+        suppression::Suppressions,
+    };
+
+    #[test]
+    fn test_verify_signature() {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let public_key: String = signing_key
+            .verifying_key()
+            .to_bytes()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+
+        let body = br#"{"type":1}"#;
+        let signature: String = signing_key
+            .sign(&[b"1700000000".as_slice(), body].concat())
+            .to_bytes()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+
+        assert!(verify_signature(
+            &public_key,
+            &signature,
+            "1700000000",
+            body
+        ));
+        assert!(!verify_signature(
+            &public_key,
+            &signature,
+            "1700000001",
+            body
+        ));
+        assert!(!verify_signature(&public_key, "zz", "1700000000", body));
+    }
+
+    #[test]
+    fn test_handle_interaction() {
+        let suppressions = Suppressions::default();
+
+        let pong = handle_interaction(&serde_json::json!({ "type": 1 }), &suppressions);
+        assert_eq!(pong["type"], 1);
+
+        let mute = serde_json::json!({
+            "type": 3,
+            "data": { "custom_id": "mute:pool" },
+            "member": { "user": { "username": "alice" } }
+        });
+        let response = handle_interaction(&mute, &suppressions);
+        assert_eq!(response["type"], 7);
+        assert_eq!(response["data"]["content"], "pool muted for 1h by alice");
+        assert!(suppressions.is_muted("pool"));
+
+        let acknowledge = serde_json::json!({
+            "type": 3,
+            "data": { "custom_id": "ack:signature" },
+            "user": { "username": "bob" }
+        });
+        let response = handle_interaction(&acknowledge, &suppressions);
+        assert_eq!(response["data"]["content"], "Acknowledged by bob");
+    }
+}
@@ -0,0 +1,225 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use chrono::Utc;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    error::JitoBellError,
+    notification_config::FileConfig,
+    notification_event::NotificationEvent,
+    parser::JitoTransactionParser,
+    schema::{self, SchemaVersion},
+};
+
+/// Parsed transaction line
+#[derive(Debug, Clone, Serialize)]
+pub struct TransactionRecord {
+    /// Transaction signature
+    pub transaction_signature: String,
+
+    /// Slot of the transaction
+    pub slot: u64,
+
+    /// Index of the transaction in the slot
+    pub transaction_index: u64,
+
+    /// Parsed programs and instructions
+    pub programs: Vec<String>,
+
+    /// Time the transaction was recorded (RFC 3339)
+    pub timestamp: String,
+}
+
+impl TransactionRecord {
+    /// Initialize record from parsed transaction
+    pub fn new(parser: &JitoTransactionParser) -> Self {
+        Self {
+            transaction_signature: parser.transaction_signature.clone(),
+            slot: parser.slot,
+            transaction_index: parser.transaction_index,
+            programs: parser
+                .programs
+                .iter()
+                .map(|program| format!("{program}: {program:?}"))
+                .collect(),
+            timestamp: Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// JSON line, tagged with its kind
+pub(crate) enum JsonLineRecord<'a> {
+    Notification(&'a NotificationEvent),
+    Transaction(&'a TransactionRecord),
+}
+
+impl JsonLineRecord<'_> {
+    /// Serialize in the given schema version as a single line
+    pub(crate) fn to_line(&self, version: SchemaVersion) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&self.to_value(version)?)
+    }
+
+    /// Serialize in the given schema version, tagged with its kind
+    pub(crate) fn to_value(&self, version: SchemaVersion) -> Result<Value, serde_json::Error> {
+        let (kind, mut payload) = match self {
+            JsonLineRecord::Notification(event) => (
+                "notification",
+                schema::notification_payload(event, version)?,
+            ),
+            JsonLineRecord::Transaction(record) => {
+                ("transaction", schema::transaction_payload(record, version)?)
+            }
+        };
+
+        if let Value::Object(object) = &mut payload {
+            object.insert("kind".to_string(), Value::from(kind));
+        }
+
+        Ok(payload)
+    }
+}
+
+/// Open file and its rotation state
+struct ActiveFile {
+    /// File handle in append mode
+    file: File,
+
+    /// Current size in bytes
+    size: u64,
+
+    /// Time the file was opened
+    opened_at: Instant,
+}
+
+/// File Notifier
+///
+/// - Append events as JSON lines, rotating the file by size and/or age
+pub struct FileNotifier {
+    /// Output path
+    path: PathBuf,
+
+    /// Also append every parsed transaction
+    include_transactions: bool,
+
+    /// Schema version of the lines
+    schema_version: SchemaVersion,
+
+    /// Rotate when the file grows beyond this size
+    max_bytes: Option<u64>,
+
+    /// Rotate when the file is older than this
+    max_age: Option<Duration>,
+
+    /// Active file
+    active: Mutex<ActiveFile>,
+}
+
+impl FileNotifier {
+    /// Initialize File Notifier
+    pub fn new(config: &FileConfig) -> Result<Self, JitoBellError> {
+        if let Some(parent) = config.path.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent)?;
+            }
+        }
+
+        let active = open(&config.path)?;
+
+        Ok(Self {
+            path: config.path.clone(),
+            include_transactions: config.include_transactions,
+            schema_version: config.schema_version,
+            max_bytes: config.max_bytes,
+            max_age: config.rotation_interval_secs.map(Duration::from_secs),
+            active: Mutex::new(active),
+        })
+    }
+
+    /// Whether parsed transactions should be appended
+    pub fn include_transactions(&self) -> bool {
+        self.include_transactions
+    }
+
+    /// Append notification event
+    ///
+    /// - Return the written line
+    pub fn append_notification(&self, event: &NotificationEvent) -> Result<String, JitoBellError> {
+        self.append(&JsonLineRecord::Notification(event))
+    }
+
+    /// Append parsed transaction
+    pub fn append_transaction(&self, record: &TransactionRecord) -> Result<(), JitoBellError> {
+        self.append(&JsonLineRecord::Transaction(record))
+            .map(|_| ())
+    }
+
+    /// Serialize record and append it as a single line
+    fn append(&self, record: &JsonLineRecord) -> Result<String, JitoBellError> {
+        let line = record.to_line(self.schema_version).map_err(|e| {
+            JitoBellError::Notification(format!("Failed to serialize JSON line: {e}"))
+        })?;
+
+        let mut active = self
+            .active
+            .lock()
+            .map_err(|_| JitoBellError::Notification("File sink lock poisoned".to_string()))?;
+
+        if self.should_rotate(&active) {
+            self.rotate(&mut active)?;
+        }
+
+        active.file.write_all(format!("{line}\n").as_bytes())?;
+        active.size += line.len() as u64 + 1;
+
+        Ok(line)
+    }
+
+    /// Whether the active file exceeds the size or age limit
+    fn should_rotate(&self, active: &ActiveFile) -> bool {
+        if active.size == 0 {
+            return false;
+        }
+
+        let too_large = self
+            .max_bytes
+            .is_some_and(|max_bytes| active.size >= max_bytes);
+        let too_old = self
+            .max_age
+            .is_some_and(|max_age| active.opened_at.elapsed() >= max_age);
+
+        too_large || too_old
+    }
+
+    /// Rename the active file to `<path>.<UTC timestamp>` and start a new one
+    fn rotate(&self, active: &mut ActiveFile) -> Result<(), JitoBellError> {
+        active.file.flush()?;
+
+        let suffix = Utc::now().format("%Y%m%dT%H%M%S%.3fZ");
+        let mut rotated = self.path.clone().into_os_string();
+        rotated.push(format!(".{suffix}"));
+        fs::rename(&self.path, &rotated)?;
+
+        *active = open(&self.path)?;
+
+        Ok(())
+    }
+}
+
+/// Open file in append mode
+fn open(path: &Path) -> Result<ActiveFile, JitoBellError> {
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let size = file.metadata()?.len();
+
+    Ok(ActiveFile {
+        file,
+        size,
+        opened_at: Instant::now(),
+    })
+}
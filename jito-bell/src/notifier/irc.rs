@@ -0,0 +1,231 @@
+use std::time::Duration;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use log::{debug, error, info, warn};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader},
+    net::TcpStream,
+    sync::mpsc,
+};
+
+use crate::{error::JitoBellError, notification_config::IrcConfig};
+
+/// Capacity of the outgoing message channel, messages wait here while reconnecting
+const IRC_MESSAGE_CAPACITY: usize = 64;
+
+/// Maximum PRIVMSG text length in bytes, leaving room for the prefix within 512 byte lines
+const IRC_MAX_TEXT_BYTES: usize = 400;
+
+/// Initial and maximum reconnect delay
+const IRC_RECONNECT_DELAY: Duration = Duration::from_secs(5);
+const IRC_MAX_RECONNECT_DELAY: Duration = Duration::from_secs(300);
+
+/// Connection (TCP or TLS)
+trait IrcStream: AsyncRead + AsyncWrite + Unpin + Send {}
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> IrcStream for T {}
+
+/// IRC Notifier
+///
+/// - Keeps a single connection alive in a background task, reconnecting with exponential backoff
+///   (reset once a session registers)
+/// - A message being written when the connection fails is sent again after reconnecting, so it
+///   may be repeated in the channels it already reached
+pub struct IrcNotifier {
+    /// Outgoing messages
+    sender: mpsc::Sender<String>,
+}
+
+impl IrcNotifier {
+    /// Initialize IRC Notifier and spawn its connection task
+    pub fn new(config: &IrcConfig) -> Self {
+        let (sender, mut receiver) = mpsc::channel::<String>(IRC_MESSAGE_CAPACITY);
+        let config = config.clone();
+
+        tokio::spawn(async move {
+            let mut delay = IRC_RECONNECT_DELAY;
+            let mut pending = None;
+            loop {
+                let mut registered = false;
+                match run_session(&config, &mut receiver, &mut pending, &mut registered).await {
+                    // Notifier dropped
+                    Ok(()) => break,
+                    Err(e) => {
+                        if registered {
+                            delay = IRC_RECONNECT_DELAY;
+                        }
+                        error!("IRC connection error: {e}, reconnecting in {delay:?}");
+                        tokio::time::sleep(delay).await;
+                        delay = (delay * 2).min(IRC_MAX_RECONNECT_DELAY);
+                    }
+                }
+            }
+        });
+
+        Self { sender }
+    }
+
+    /// Queue message for all channels
+    ///
+    /// - Success means queued, messages still queued on shutdown are not delivered
+    pub async fn send(&self, message: &str) -> Result<(), JitoBellError> {
+        self.sender
+            .send(message.to_string())
+            .await
+            .map_err(|_| JitoBellError::Notification("IRC connection task stopped".to_string()))
+    }
+}
+
+/// Connect, register and relay messages until the connection fails
+///
+/// - Return Ok only when the notifier was dropped
+/// - `pending` holds the message being sent until every channel got it, and is sent first once
+///   registered
+/// - `registered` is set once the server accepted the registration
+async fn run_session(
+    config: &IrcConfig,
+    receiver: &mut mpsc::Receiver<String>,
+    pending: &mut Option<String>,
+    registered: &mut bool,
+) -> Result<(), JitoBellError> {
+    let stream = connect(config).await?;
+    let (reader, mut writer) = tokio::io::split(stream);
+    let mut lines = BufReader::new(reader).lines();
+
+    if config.sasl_password.is_some() {
+        send_line(&mut writer, "CAP REQ :sasl").await?;
+    }
+    if let Some(password) = &config.server_password {
+        send_line(&mut writer, &format!("PASS {password}")).await?;
+    }
+    send_line(&mut writer, &format!("NICK {}", config.nick)).await?;
+    send_line(
+        &mut writer,
+        &format!("USER {} 0 * :{}", config.nick, config.nick),
+    )
+    .await?;
+
+    loop {
+        tokio::select! {
+            line = lines.next_line() => {
+                let line = line?.ok_or_else(|| {
+                    JitoBellError::Notification("IRC server closed the connection".to_string())
+                })?;
+                debug!("IRC < {line}");
+
+                let mut parts = line.split(' ');
+                let first = parts.next().unwrap_or_default();
+                if first == "PING" {
+                    let token = line.trim_start_matches("PING ");
+                    send_line(&mut writer, &format!("PONG {token}")).await?;
+                    continue;
+                }
+
+                match parts.next().unwrap_or_default() {
+                    "CAP" if line.contains(" ACK ") => {
+                        send_line(&mut writer, "AUTHENTICATE PLAIN").await?;
+                    }
+                    "CAP" if line.contains(" NAK ") => {
+                        warn!("IRC server does not support SASL");
+                        send_line(&mut writer, "CAP END").await?;
+                    }
+                    "903" => send_line(&mut writer, "CAP END").await?,
+                    "904" | "905" => {
+                        return Err(JitoBellError::Notification(
+                            "IRC SASL authentication failed".to_string(),
+                        ));
+                    }
+                    "433" => {
+                        return Err(JitoBellError::Notification(format!(
+                            "IRC nick {} is already in use",
+                            config.nick
+                        )));
+                    }
+                    "001" => {
+                        info!("IRC registered as {}", config.nick);
+                        *registered = true;
+                        for channel in config.channels.iter() {
+                            send_line(&mut writer, &format!("JOIN {channel}")).await?;
+                        }
+                        if let Some(message) = pending.as_deref() {
+                            send_message(&mut writer, config, message).await?;
+                            *pending = None;
+                        }
+                    }
+                    _ if first == "AUTHENTICATE" => {
+                        let username = config.sasl_username.as_deref().unwrap_or(&config.nick);
+                        let password = config.sasl_password.as_deref().unwrap_or_default();
+                        let credentials = STANDARD.encode(format!("\0{username}\0{password}"));
+                        send_line(&mut writer, &format!("AUTHENTICATE {credentials}")).await?;
+                    }
+                    _ => {}
+                }
+            }
+            message = receiver.recv(), if *registered => {
+                let Some(message) = message else {
+                    send_line(&mut writer, "QUIT :Shutting down").await?;
+                    return Ok(());
+                };
+
+                let message = pending.insert(message);
+                send_message(&mut writer, config, message).await?;
+                *pending = None;
+            }
+        }
+    }
+}
+
+/// Send a message to every channel, a PRIVMSG per line
+async fn send_message<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    config: &IrcConfig,
+    message: &str,
+) -> Result<(), JitoBellError> {
+    for channel in config.channels.iter() {
+        for text in message.lines().filter(|text| !text.is_empty()) {
+            let text = truncate(text, IRC_MAX_TEXT_BYTES);
+            send_line(writer, &format!("PRIVMSG {channel} :{text}")).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Open TCP or TLS connection
+async fn connect(config: &IrcConfig) -> Result<Box<dyn IrcStream>, JitoBellError> {
+    let tcp = TcpStream::connect((config.server.as_str(), config.port)).await?;
+
+    if !config.tls {
+        return Ok(Box::new(tcp));
+    }
+
+    let connector = tokio_native_tls::native_tls::TlsConnector::new()
+        .map_err(|e| JitoBellError::Notification(format!("Failed to build TLS connector: {e}")))?;
+    let tls = tokio_native_tls::TlsConnector::from(connector)
+        .connect(&config.server, tcp)
+        .await
+        .map_err(|e| JitoBellError::Notification(format!("IRC TLS handshake error: {e}")))?;
+
+    Ok(Box::new(tls))
+}
+
+/// Write a single line terminated with CRLF
+async fn send_line<W: AsyncWrite + Unpin>(writer: &mut W, line: &str) -> Result<(), JitoBellError> {
+    writer.write_all(format!("{line}\r\n").as_bytes()).await?;
+    writer.flush().await?;
+
+    Ok(())
+}
+
+/// Truncate text to at most `max_bytes`, on a char boundary
+fn truncate(text: &str, max_bytes: usize) -> &str {
+    if text.len() <= max_bytes {
+        return text;
+    }
+
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    &text[..end]
+}
@@ -0,0 +1,14 @@
+pub mod discord_bot;
+pub mod file;
+pub mod irc;
+pub mod mqtt;
+pub mod nostr;
+pub mod push;
+pub mod redis;
+pub mod slack_bot;
+pub mod stdout;
+pub mod syslog;
+pub mod twitter;
+pub mod webhook;
+pub mod websocket;
+pub mod xmpp;
@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use log::error;
+use rumqttc::{AsyncClient, MqttOptions, QoS, Transport};
+
+use crate::{error::JitoBellError, notification_config::MqttConfig};
+
+/// Capacity of the request channel between the client and its event loop
+const MQTT_REQUEST_CAPACITY: usize = 64;
+
+/// MQTT Notifier
+///
+/// - Keeps a single broker connection alive in a background task
+pub struct MqttNotifier {
+    /// MQTT client
+    client: AsyncClient,
+
+    /// Topic
+    topic: String,
+
+    /// Quality of service
+    qos: QoS,
+
+    /// Retained flag
+    retain: bool,
+}
+
+impl MqttNotifier {
+    /// Initialize MQTT Notifier and spawn its event loop
+    pub fn new(config: &MqttConfig) -> Result<Self, JitoBellError> {
+        let qos = match config.qos {
+            0 => QoS::AtMostOnce,
+            1 => QoS::AtLeastOnce,
+            2 => QoS::ExactlyOnce,
+            qos => {
+                return Err(JitoBellError::Config(format!("Invalid MQTT QoS: {qos}")));
+            }
+        };
+
+        let mut options = MqttOptions::new(&config.client_id, &config.host, config.port);
+        options.set_keep_alive(Duration::from_secs(30));
+
+        if let Some(username) = &config.username {
+            options.set_credentials(username, config.password.clone().unwrap_or_default());
+        }
+
+        if config.tls {
+            options.set_transport(Transport::tls_with_default_config());
+        }
+
+        let (client, mut event_loop) = AsyncClient::new(options, MQTT_REQUEST_CAPACITY);
+
+        // The event loop drives the connection, reconnecting on the next poll after an error
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = event_loop.poll().await {
+                    error!("MQTT connection error: {e}");
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                }
+            }
+        });
+
+        Ok(Self {
+            client,
+            topic: config.topic.clone(),
+            qos,
+            retain: config.retain,
+        })
+    }
+
+    /// Publish payload to the configured topic
+    pub async fn publish(&self, payload: Vec<u8>) -> Result<(), JitoBellError> {
+        self.client
+            .publish(&self.topic, self.qos, self.retain, payload)
+            .await
+            .map_err(|e| {
+                JitoBellError::Notification(format!("Failed to publish MQTT message: {e}"))
+            })
+    }
+}
@@ -0,0 +1,272 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures::{SinkExt, StreamExt};
+use log::{debug, warn};
+use secp256k1::{Keypair, Message, Secp256k1};
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use tokio_tungstenite::tungstenite;
+
+use crate::{error::JitoBellError, notification_config::NostrConfig};
+
+/// How long to wait for a relay to accept an event
+const NOSTR_RELAY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Signed Nostr event (NIP-01)
+#[derive(Debug, Clone, Serialize)]
+pub struct NostrEvent {
+    pub id: String,
+    pub pubkey: String,
+    pub created_at: u64,
+    pub kind: u16,
+    pub tags: Vec<Vec<String>>,
+    pub content: String,
+    pub sig: String,
+}
+
+/// Nostr Notifier
+///
+/// - Signs events with the configured key and publishes them to every relay
+pub struct NostrNotifier {
+    /// Signing key
+    keypair: Keypair,
+
+    /// Relay URLs (wss://...)
+    relays: Vec<String>,
+
+    /// Event kind
+    kind: u16,
+
+    /// Tags attached to every event
+    tags: Vec<Vec<String>>,
+}
+
+impl NostrNotifier {
+    /// Initialize Nostr Notifier
+    pub fn new(config: &NostrConfig) -> Result<Self, JitoBellError> {
+        let secret_key = decode_secret_key(&config.secret_key)?;
+        let keypair = Keypair::from_seckey_slice(&Secp256k1::new(), &secret_key)
+            .map_err(|e| JitoBellError::Config(format!("Invalid Nostr secret key: {e}")))?;
+
+        let tags = config
+            .hashtags
+            .iter()
+            .map(|hashtag| vec!["t".to_string(), hashtag.to_lowercase()])
+            .collect();
+
+        Ok(Self {
+            keypair,
+            relays: config.relays.clone(),
+            kind: config.kind,
+            tags,
+        })
+    }
+
+    /// Sign and publish the content to every relay
+    ///
+    /// - Returns the number of relays that accepted the event, or an error when none did
+    pub async fn publish(&self, content: &str) -> Result<usize, JitoBellError> {
+        let event = sign_event(
+            &self.keypair,
+            unix_timestamp(),
+            self.kind,
+            self.tags.clone(),
+            content,
+        );
+        let message = serde_json::json!(["EVENT", event]).to_string();
+
+        let mut accepted = 0;
+        let mut last_error = None;
+        for relay in self.relays.iter() {
+            match tokio::time::timeout(
+                NOSTR_RELAY_TIMEOUT,
+                publish_to_relay(relay, &event.id, &message),
+            )
+            .await
+            {
+                Ok(Ok(())) => accepted += 1,
+                Ok(Err(e)) => {
+                    warn!("Nostr relay {relay} rejected event: {e}");
+                    last_error = Some(e);
+                }
+                Err(_) => {
+                    warn!("Nostr relay {relay} timed out");
+                    last_error = Some(JitoBellError::Notification(format!(
+                        "Nostr relay {relay} timed out"
+                    )));
+                }
+            }
+        }
+
+        match last_error {
+            Some(e) if accepted == 0 => Err(e),
+            _ => Ok(accepted),
+        }
+    }
+}
+
+/// Send the event and wait for the relay's `OK` (NIP-20)
+async fn publish_to_relay(relay: &str, event_id: &str, message: &str) -> Result<(), JitoBellError> {
+    let (mut stream, _) = tokio_tungstenite::connect_async(relay)
+        .await
+        .map_err(|e| JitoBellError::Notification(format!("Failed to connect to {relay}: {e}")))?;
+
+    stream
+        .send(tungstenite::Message::text(message))
+        .await
+        .map_err(|e| JitoBellError::Notification(format!("Failed to send to {relay}: {e}")))?;
+
+    let result = loop {
+        let Some(frame) = stream.next().await else {
+            break Err(JitoBellError::Notification(format!(
+                "{relay} closed the connection"
+            )));
+        };
+        let frame = frame.map_err(|e| {
+            JitoBellError::Notification(format!("Failed to read from {relay}: {e}"))
+        })?;
+
+        let tungstenite::Message::Text(text) = frame else {
+            continue;
+        };
+        let Ok(Value::Array(reply)) = serde_json::from_str::<Value>(text.as_str()) else {
+            continue;
+        };
+
+        // ["OK", <event id>, <accepted>, <message>]
+        if reply.first().and_then(Value::as_str) == Some("OK")
+            && reply.get(1).and_then(Value::as_str) == Some(event_id)
+        {
+            break match reply.get(2).and_then(Value::as_bool) {
+                Some(true) => Ok(()),
+                _ => Err(JitoBellError::Notification(
+                    reply
+                        .get(3)
+                        .and_then(Value::as_str)
+                        .unwrap_or("rejected")
+                        .to_string(),
+                )),
+            };
+        }
+
+        debug!("Nostr relay {relay}: {text}");
+    };
+
+    let _ = stream.close(None).await;
+
+    result
+}
+
+/// Build and sign an event
+///
+/// - id: sha256 of `[0, pubkey, created_at, kind, tags, content]`
+/// - sig: BIP-340 Schnorr signature of the id
+pub fn sign_event(
+    keypair: &Keypair,
+    created_at: u64,
+    kind: u16,
+    tags: Vec<Vec<String>>,
+    content: &str,
+) -> NostrEvent {
+    let pubkey = to_hex(&keypair.x_only_public_key().0.serialize());
+
+    let serialized = serde_json::json!([0, pubkey, created_at, kind, tags, content]).to_string();
+    let id: [u8; 32] = Sha256::digest(serialized.as_bytes()).into();
+
+    let signature = Secp256k1::new().sign_schnorr_no_aux_rand(&Message::from_digest(id), keypair);
+
+    NostrEvent {
+        id: to_hex(&id),
+        pubkey,
+        created_at,
+        kind,
+        tags,
+        content: content.to_string(),
+        sig: to_hex(&signature.serialize()),
+    }
+}
+
+/// Decode a secret key given as hex or NIP-19 `nsec`
+fn decode_secret_key(secret_key: &str) -> Result<Vec<u8>, JitoBellError> {
+    if secret_key.starts_with("nsec1") {
+        let (hrp, data) = bech32::decode(secret_key)
+            .map_err(|e| JitoBellError::Config(format!("Invalid Nostr nsec: {e}")))?;
+        if hrp.as_str() != "nsec" {
+            return Err(JitoBellError::Config(format!(
+                "Invalid Nostr nsec prefix: {hrp}"
+            )));
+        }
+        return Ok(data);
+    }
+
+    if secret_key.len() != 64 {
+        return Err(JitoBellError::Config(
+            "Nostr secret key must be 64 hex characters or an nsec".to_string(),
+        ));
+    }
+    (0..secret_key.len())
+        .step_by(2)
+        .map(|i| {
+            secret_key
+                .get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+        })
+        .collect::<Option<Vec<u8>>>()
+        .ok_or_else(|| JitoBellError::Config("Invalid Nostr secret key".to_string()))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use secp256k1::{schnorr::Signature, Keypair, Message, Secp256k1, XOnlyPublicKey};
+    use sha2::{Digest, Sha256};
+
+    use crate::notifier::nostr::{decode_secret_key, sign_event, to_hex};
+
+    #[test]
+    fn test_sign_event() {
+        let secp = Secp256k1::new();
+        let keypair = Keypair::from_seckey_slice(&secp, &[3u8; 32]).unwrap();
+        let tags = vec![vec!["t".to_string(), "jitosol".to_string()]];
+
+        let event = sign_event(&keypair, 1_700_000_000, 1, tags, "Deposit 1000 SOL");
+
+        let serialized = format!(
+            r#"[0,"{}",1700000000,1,[["t","jitosol"]],"Deposit 1000 SOL"]"#,
+            event.pubkey
+        );
+        let id: [u8; 32] = Sha256::digest(serialized.as_bytes()).into();
+        assert_eq!(event.id, to_hex(&id));
+
+        let signature = Signature::from_slice(&decode_hex(&event.sig)).unwrap();
+        let pubkey = XOnlyPublicKey::from_slice(&decode_hex(&event.pubkey)).unwrap();
+        assert!(secp
+            .verify_schnorr(&signature, &Message::from_digest(id), &pubkey)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_decode_secret_key() {
+        assert_eq!(decode_secret_key(&"01".repeat(32)).unwrap(), vec![1u8; 32]);
+        assert!(decode_secret_key("abc").is_err());
+        assert!(decode_secret_key(&"zz".repeat(32)).is_err());
+    }
+
+    fn decode_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+}
@@ -0,0 +1,452 @@
+use std::{
+    fs,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tokio::sync::Mutex;
+
+use crate::{
+    error::JitoBellError,
+    http_timeout::HttpTimeouts,
+    notification_config::{ApnsConfig, FcmConfig, PushConfig},
+    notification_event::NotificationEvent,
+    retry::RetryPolicy,
+};
+
+/// OAuth scope required by the FCM HTTP v1 API
+const FCM_SCOPE: &str = "https://www.googleapis.com/auth/firebase.messaging";
+
+/// APNs production host
+const APNS_PRODUCTION_URL: &str = "https://api.push.apple.com";
+
+/// APNs sandbox host (development builds of the app)
+const APNS_SANDBOX_URL: &str = "https://api.sandbox.push.apple.com";
+
+/// Refresh credentials this long before they expire
+const TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(300);
+
+/// APNs provider tokens are valid for one hour
+const APNS_TOKEN_LIFETIME: Duration = Duration::from_secs(3600);
+
+/// Google service account key file
+#[derive(Deserialize)]
+struct ServiceAccount {
+    client_email: String,
+    private_key: String,
+    token_uri: String,
+}
+
+/// Claims of the JWT exchanged for an OAuth access token
+#[derive(Serialize)]
+struct GoogleClaims<'a> {
+    iss: &'a str,
+    scope: &'a str,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+}
+
+/// Claims of the APNs provider token
+#[derive(Serialize)]
+struct ApnsClaims<'a> {
+    iss: &'a str,
+    iat: u64,
+}
+
+#[derive(Deserialize)]
+struct AccessTokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+/// Bearer token and the time it should be refreshed
+struct CachedToken {
+    token: String,
+    refresh_at: Instant,
+}
+
+/// Firebase Cloud Messaging sender (HTTP v1 API)
+struct FcmSender {
+    config: FcmConfig,
+    service_account: ServiceAccount,
+    encoding_key: EncodingKey,
+    access_token: Mutex<Option<CachedToken>>,
+}
+
+/// Apple Push Notification service sender (token-based authentication)
+struct ApnsSender {
+    config: ApnsConfig,
+
+    /// APNs only speaks HTTP/2
+    client: reqwest::Client,
+
+    encoding_key: EncodingKey,
+    provider_token: Mutex<Option<CachedToken>>,
+}
+
+/// Push Notifier
+///
+/// - FCM: device tokens and topics, authenticated with a service account
+/// - APNs: device tokens, authenticated with a .p8 signing key
+pub struct PushNotifier {
+    /// HTTP client
+    client: reqwest::Client,
+
+    /// FCM sender
+    fcm: Option<FcmSender>,
+
+    /// APNs sender
+    apns: Option<ApnsSender>,
+
+    /// Retry policy of the FCM and APNs requests
+    retry: RetryPolicy,
+}
+
+impl PushNotifier {
+    /// Initialize Push Notifier, loading the signing keys
+    pub fn new(
+        config: &PushConfig,
+        retry: RetryPolicy,
+        client: reqwest::Client,
+        timeouts: HttpTimeouts,
+    ) -> Result<Self, JitoBellError> {
+        let fcm = match &config.fcm {
+            Some(fcm_config) => {
+                let service_account: ServiceAccount =
+                    serde_json::from_str(&fs::read_to_string(&fcm_config.service_account_path)?)
+                        .map_err(|e| {
+                            JitoBellError::Config(format!("Invalid FCM service account file: {e}"))
+                        })?;
+                let encoding_key =
+                    EncodingKey::from_rsa_pem(service_account.private_key.as_bytes()).map_err(
+                        |e| JitoBellError::Config(format!("Invalid FCM service account key: {e}")),
+                    )?;
+
+                Some(FcmSender {
+                    config: fcm_config.clone(),
+                    service_account,
+                    encoding_key,
+                    access_token: Mutex::new(None),
+                })
+            }
+            None => None,
+        };
+
+        let apns = match &config.apns {
+            Some(apns_config) => {
+                let encoding_key =
+                    EncodingKey::from_ec_pem(&fs::read(&apns_config.private_key_path)?)
+                        .map_err(|e| JitoBellError::Config(format!("Invalid APNs key: {e}")))?;
+
+                Some(ApnsSender {
+                    config: apns_config.clone(),
+                    client: timeouts.client_builder().http2_prior_knowledge().build()?,
+                    encoding_key,
+                    provider_token: Mutex::new(None),
+                })
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            client,
+            fcm,
+            apns,
+            retry,
+        })
+    }
+
+    /// Push the message to every configured device and topic
+    ///
+    /// - Returns the number of accepted pushes, or the last failure
+    pub async fn send(
+        &self,
+        event: &NotificationEvent,
+        body: &str,
+    ) -> Result<usize, JitoBellError> {
+        let mut delivered = 0;
+        let mut result = Ok(());
+
+        if let Some(fcm) = &self.fcm {
+            let access_token = fcm.access_token(&self.client).await?;
+            let url = format!(
+                "https://fcm.googleapis.com/v1/projects/{}/messages:send",
+                fcm.config.project_id
+            );
+
+            let targets = fcm
+                .config
+                .device_tokens
+                .iter()
+                .map(|token| ("token", token))
+                .chain(fcm.config.topics.iter().map(|topic| ("topic", topic)));
+            for (kind, target) in targets {
+                let message = fcm_message(kind, target, event, body);
+                match post(
+                    &self.client,
+                    &self.retry,
+                    &url,
+                    &access_token,
+                    &message,
+                    &[],
+                )
+                .await
+                {
+                    Ok(()) => delivered += 1,
+                    Err(e) => {
+                        result = Err(JitoBellError::Notification(format!(
+                            "Failed to send FCM message to {kind} {target}: {e}"
+                        )))
+                    }
+                }
+            }
+        }
+
+        if let Some(apns) = &self.apns {
+            let provider_token = apns.provider_token().await?;
+            let host = if apns.config.sandbox {
+                APNS_SANDBOX_URL
+            } else {
+                APNS_PRODUCTION_URL
+            };
+            let payload = apns_payload(event, body);
+            let headers = [
+                ("apns-topic", apns.config.bundle_id.as_str()),
+                ("apns-push-type", "alert"),
+            ];
+
+            for device_token in apns.config.device_tokens.iter() {
+                let url = format!("{host}/3/device/{device_token}");
+                match post(
+                    &apns.client,
+                    &self.retry,
+                    &url,
+                    &provider_token,
+                    &payload,
+                    &headers,
+                )
+                .await
+                {
+                    Ok(()) => delivered += 1,
+                    Err(e) => {
+                        result = Err(JitoBellError::Notification(format!(
+                            "Failed to send APNs message to {device_token}: {e}"
+                        )))
+                    }
+                }
+            }
+        }
+
+        result.map(|()| delivered)
+    }
+}
+
+impl FcmSender {
+    /// OAuth access token, exchanged for a signed service account JWT when missing or expiring
+    async fn access_token(&self, client: &reqwest::Client) -> Result<String, JitoBellError> {
+        let mut cached = self.access_token.lock().await;
+        if let Some(token) = cached
+            .as_ref()
+            .filter(|token| Instant::now() < token.refresh_at)
+        {
+            return Ok(token.token.clone());
+        }
+
+        let iat = unix_timestamp();
+        let claims = GoogleClaims {
+            iss: &self.service_account.client_email,
+            scope: FCM_SCOPE,
+            aud: &self.service_account.token_uri,
+            iat,
+            exp: iat + 3600,
+        };
+        let assertion =
+            jsonwebtoken::encode(&Header::new(Algorithm::RS256), &claims, &self.encoding_key)
+                .map_err(|e| {
+                    JitoBellError::Notification(format!("Failed to sign FCM assertion: {e}"))
+                })?;
+
+        let response: AccessTokenResponse = client
+            .post(&self.service_account.token_uri)
+            .form(&[
+                ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+                ("assertion", assertion.as_str()),
+            ])
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        let lifetime = Duration::from_secs(response.expires_in);
+        *cached = Some(CachedToken {
+            token: response.access_token.clone(),
+            refresh_at: Instant::now() + lifetime.saturating_sub(TOKEN_REFRESH_MARGIN),
+        });
+
+        Ok(response.access_token)
+    }
+}
+
+impl ApnsSender {
+    /// Provider token, re-signed when expiring (APNs rejects tokens refreshed too often)
+    async fn provider_token(&self) -> Result<String, JitoBellError> {
+        let mut cached = self.provider_token.lock().await;
+        if let Some(token) = cached
+            .as_ref()
+            .filter(|token| Instant::now() < token.refresh_at)
+        {
+            return Ok(token.token.clone());
+        }
+
+        let mut header = Header::new(Algorithm::ES256);
+        header.kid = Some(self.config.key_id.clone());
+        let claims = ApnsClaims {
+            iss: &self.config.team_id,
+            iat: unix_timestamp(),
+        };
+        let token = jsonwebtoken::encode(&header, &claims, &self.encoding_key).map_err(|e| {
+            JitoBellError::Notification(format!("Failed to sign APNs provider token: {e}"))
+        })?;
+
+        *cached = Some(CachedToken {
+            token: token.clone(),
+            refresh_at: Instant::now() + APNS_TOKEN_LIFETIME - TOKEN_REFRESH_MARGIN,
+        });
+
+        Ok(token)
+    }
+}
+
+/// Event fields passed to the app alongside the alert
+///
+/// - FCM data values must be strings
+fn event_data(event: &NotificationEvent) -> Map<String, Value> {
+    let mut data = Map::new();
+    data.insert("pool".to_string(), Value::from(event.pool.as_str()));
+    data.insert("amount".to_string(), Value::from(event.amount.to_string()));
+    data.insert(
+        "currency_unit".to_string(),
+        Value::from(event.currency_unit.as_str()),
+    );
+    data.insert(
+        "transaction_signature".to_string(),
+        Value::from(event.transaction_signature.as_str()),
+    );
+    data.insert("slot".to_string(), Value::from(event.slot.to_string()));
+    data.insert(
+        "explorer_url".to_string(),
+        Value::from(event.explorer_url.as_str()),
+    );
+    if let Some(validator) = &event.validator {
+        data.insert("validator".to_string(), Value::from(validator.as_str()));
+    }
+
+    data
+}
+
+/// FCM message addressed to a device token or topic
+fn fcm_message(kind: &str, target: &str, event: &NotificationEvent, body: &str) -> Value {
+    serde_json::json!({
+        "message": {
+            kind: target,
+            "notification": {
+                "title": event.description,
+                "body": body,
+            },
+            "data": event_data(event),
+        }
+    })
+}
+
+/// APNs payload, event fields are set next to `aps`
+fn apns_payload(event: &NotificationEvent, body: &str) -> Value {
+    let mut payload = event_data(event);
+    payload.insert(
+        "aps".to_string(),
+        serde_json::json!({
+            "alert": {
+                "title": event.description,
+                "body": body,
+            },
+            "sound": "default",
+        }),
+    );
+
+    Value::Object(payload)
+}
+
+/// POST a JSON payload with a bearer token
+async fn post(
+    client: &reqwest::Client,
+    retry: &RetryPolicy,
+    url: &str,
+    bearer_token: &str,
+    payload: &Value,
+    headers: &[(&str, &str)],
+) -> Result<(), JitoBellError> {
+    let mut request = client.post(url).bearer_auth(bearer_token).json(payload);
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+
+    let response = retry.send(request).await?;
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        Err(JitoBellError::Notification(format!("{status} {body}")))
+    }
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        notification_event::NotificationEvent,
+        notification_info::NotificationInfo,
+        notifier::push::{apns_payload, fcm_message},
+    };
+
+    fn event() -> NotificationEvent {
+        let mut event = NotificationEvent::scheduled(
+            &NotificationInfo::default(),
+            "Deposit".to_string(),
+            "pool",
+            1.5,
+            "SOL",
+        );
+        event.transaction_signature = "signature".to_string();
+        event.slot = 10;
+        event
+    }
+
+    #[test]
+    fn test_fcm_message() {
+        let message = fcm_message("topic", "alerts", &event(), "1.5 SOL");
+
+        assert_eq!(message["message"]["topic"], "alerts");
+        assert!(message["message"].get("token").is_none());
+        assert_eq!(message["message"]["notification"]["title"], "Deposit");
+        assert_eq!(message["message"]["data"]["amount"], "1.5");
+        assert_eq!(message["message"]["data"]["slot"], "10");
+    }
+
+    #[test]
+    fn test_apns_payload() {
+        let payload = apns_payload(&event(), "1.5 SOL");
+
+        assert_eq!(payload["aps"]["alert"]["body"], "1.5 SOL");
+        assert_eq!(payload["transaction_signature"], "signature");
+    }
+}
@@ -0,0 +1,77 @@
+use ::redis::{aio::MultiplexedConnection, streams::StreamMaxlen, AsyncCommands, Client};
+
+use crate::{
+    error::JitoBellError,
+    notification_config::{RedisConfig, RedisMode},
+};
+
+/// Field name of the event payload in stream entries
+const STREAM_EVENT_FIELD: &str = "event";
+
+/// Redis Notifier
+///
+/// - PUBLISH events to a channel or XADD them to a stream
+pub struct RedisNotifier {
+    /// Multiplexed connection, cheap to clone per command
+    conn: MultiplexedConnection,
+
+    /// Delivery mode
+    mode: RedisMode,
+
+    /// Channel or stream key
+    key: String,
+
+    /// Approximate maximum stream length
+    maxlen: Option<usize>,
+}
+
+impl RedisNotifier {
+    /// Initialize Redis Notifier
+    pub async fn new(config: &RedisConfig) -> Result<Self, JitoBellError> {
+        let client = Client::open(config.url.as_str())
+            .map_err(|e| JitoBellError::Config(format!("Invalid Redis URL: {e}")))?;
+        let conn = client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|e| JitoBellError::Notification(format!("Failed to connect to Redis: {e}")))?;
+
+        Ok(Self {
+            conn,
+            mode: config.mode,
+            key: config.key.clone(),
+            maxlen: config.maxlen,
+        })
+    }
+
+    /// Send payload to the configured channel or stream
+    pub async fn send(&self, payload: &str) -> Result<(), JitoBellError> {
+        let mut conn = self.conn.clone();
+
+        match (self.mode, self.maxlen) {
+            (RedisMode::Publish, _) => {
+                let _receivers: i64 = conn.publish(&self.key, payload).await.map_err(|e| {
+                    JitoBellError::Notification(format!("Redis PUBLISH error: {e}"))
+                })?;
+            }
+            (RedisMode::Stream, Some(maxlen)) => {
+                let _id: String = conn
+                    .xadd_maxlen(
+                        &self.key,
+                        StreamMaxlen::Approx(maxlen),
+                        "*",
+                        &[(STREAM_EVENT_FIELD, payload)],
+                    )
+                    .await
+                    .map_err(|e| JitoBellError::Notification(format!("Redis XADD error: {e}")))?;
+            }
+            (RedisMode::Stream, None) => {
+                let _id: String = conn
+                    .xadd(&self.key, "*", &[(STREAM_EVENT_FIELD, payload)])
+                    .await
+                    .map_err(|e| JitoBellError::Notification(format!("Redis XADD error: {e}")))?;
+            }
+        }
+
+        Ok(())
+    }
+}
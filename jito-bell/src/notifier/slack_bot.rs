@@ -0,0 +1,176 @@
+use std::time::Duration;
+
+use log::{debug, error, warn};
+use serde_json::Value;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{commitment_config::CommitmentConfig, signature::Signature};
+
+use crate::{error::JitoBellError, retry::RetryPolicy};
+
+/// Slack Web API base URL
+const SLACK_API_URL: &str = "https://slack.com/api";
+
+/// Interval between finality checks
+const FINALITY_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Give up waiting for finality after this long
+const FINALITY_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Posted Slack message, used to thread replies and edit it later
+#[derive(Debug, Clone)]
+pub struct SlackMessageRef {
+    /// Channel ID
+    pub channel: String,
+
+    /// Message timestamp (message ID)
+    pub ts: String,
+}
+
+/// Slack Bot Client
+///
+/// - `chat.postMessage` / `chat.update` with a bot token, works in private channels the bot was
+///   invited to
+#[derive(Clone)]
+pub struct SlackBotClient {
+    /// HTTP client
+    client: reqwest::Client,
+
+    /// Bot token (xoxb-...)
+    token: String,
+
+    /// Retry policy of Web API calls
+    retry: RetryPolicy,
+}
+
+impl SlackBotClient {
+    /// Initialize Slack Bot Client
+    pub fn new(token: &str, retry: RetryPolicy, client: reqwest::Client) -> Self {
+        Self {
+            client,
+            token: token.to_string(),
+            retry,
+        }
+    }
+
+    /// Post a message, as a thread reply when `thread_ts` is set
+    pub async fn post_message(
+        &self,
+        channel: &str,
+        payload: &Value,
+        thread_ts: Option<&str>,
+    ) -> Result<SlackMessageRef, JitoBellError> {
+        let mut payload = payload.clone();
+        payload["channel"] = Value::from(channel);
+        if let Some(thread_ts) = thread_ts {
+            payload["thread_ts"] = Value::from(thread_ts);
+        }
+
+        let response = self.call("chat.postMessage", &payload).await?;
+
+        Ok(SlackMessageRef {
+            channel: response["channel"].as_str().unwrap_or(channel).to_string(),
+            ts: response["ts"].as_str().unwrap_or_default().to_string(),
+        })
+    }
+
+    /// Replace the content of a posted message
+    pub async fn update_message(
+        &self,
+        message: &SlackMessageRef,
+        payload: &Value,
+    ) -> Result<(), JitoBellError> {
+        let mut payload = payload.clone();
+        payload["channel"] = Value::from(message.channel.as_str());
+        payload["ts"] = Value::from(message.ts.as_str());
+
+        self.call("chat.update", &payload).await.map(|_| ())
+    }
+
+    /// Call a Web API method, which reports failures as `"ok": false` with HTTP 200
+    async fn call(&self, method: &str, payload: &Value) -> Result<Value, JitoBellError> {
+        let request = self
+            .client
+            .post(format!("{SLACK_API_URL}/{method}"))
+            .bearer_auth(&self.token)
+            .json(payload);
+        let response: Value = self
+            .retry
+            .send(request)
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        if response["ok"].as_bool() == Some(true) {
+            Ok(response)
+        } else {
+            Err(JitoBellError::Notification(format!(
+                "Slack {method} failed: {}",
+                response["error"].as_str().unwrap_or("unknown error")
+            )))
+        }
+    }
+}
+
+/// Wait for the transaction to be finalized, then reply in the message's thread and mark the
+/// original message as finalized
+///
+/// - Runs in the background, gives up after `FINALITY_TIMEOUT`
+pub async fn follow_up_finalized(
+    slack_bot_client: SlackBotClient,
+    rpc_url: String,
+    signature: String,
+    message: SlackMessageRef,
+    payload: Value,
+) {
+    let Ok(tx_signature) = signature.parse::<Signature>() else {
+        warn!("Skip finality follow-up, invalid signature: {signature}");
+        return;
+    };
+    let rpc_client = RpcClient::new_with_commitment(rpc_url, CommitmentConfig::finalized());
+
+    let deadline = tokio::time::Instant::now() + FINALITY_TIMEOUT;
+    let status = loop {
+        match rpc_client
+            .get_signature_status_with_commitment(&tx_signature, CommitmentConfig::finalized())
+            .await
+        {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) => debug!("Transaction not finalized yet: {signature}"),
+            Err(e) => debug!("Failed to fetch signature status: {e}"),
+        }
+
+        if tokio::time::Instant::now() >= deadline {
+            break None;
+        }
+        tokio::time::sleep(FINALITY_POLL_INTERVAL).await;
+    };
+
+    let (reply, marker) = match status {
+        Some(Ok(())) => ("Transaction finalized", ":white_check_mark: Finalized"),
+        Some(Err(_)) => ("Transaction failed", ":x: Failed"),
+        None => (
+            "Transaction not finalized within 2 minutes",
+            ":warning: Not finalized",
+        ),
+    };
+
+    let reply_payload = serde_json::json!({ "text": reply });
+    if let Err(e) = slack_bot_client
+        .post_message(&message.channel, &reply_payload, Some(&message.ts))
+        .await
+    {
+        error!("Failed to post Slack thread reply: {e}");
+    }
+
+    let mut updated = payload;
+    if let Some(blocks) = updated["blocks"].as_array_mut() {
+        blocks.push(serde_json::json!({
+            "type": "context",
+            "elements": [{ "type": "mrkdwn", "text": marker }]
+        }));
+    }
+    if let Err(e) = slack_bot_client.update_message(&message, &updated).await {
+        error!("Failed to update Slack message: {e}");
+    }
+}
@@ -0,0 +1,60 @@
+use std::io::Write;
+
+use crate::{
+    error::JitoBellError,
+    notification_config::StdoutConfig,
+    notification_event::NotificationEvent,
+    notifier::file::{JsonLineRecord, TransactionRecord},
+    schema::SchemaVersion,
+};
+
+/// Stdout Notifier
+///
+/// - Print events as JSON lines to stdout, logs go to stderr while this sink is enabled
+pub struct StdoutNotifier {
+    /// Also print every parsed transaction
+    include_transactions: bool,
+
+    /// Schema version of the lines
+    schema_version: SchemaVersion,
+}
+
+impl StdoutNotifier {
+    /// Initialize Stdout Notifier
+    pub fn new(config: &StdoutConfig) -> Self {
+        Self {
+            include_transactions: config.include_transactions,
+            schema_version: config.schema_version,
+        }
+    }
+
+    /// Whether parsed transactions should be printed
+    pub fn include_transactions(&self) -> bool {
+        self.include_transactions
+    }
+
+    /// Print notification event
+    ///
+    /// - Return the printed line
+    pub fn print_notification(&self, event: &NotificationEvent) -> Result<String, JitoBellError> {
+        self.print(&JsonLineRecord::Notification(event))
+    }
+
+    /// Print parsed transaction
+    pub fn print_transaction(&self, record: &TransactionRecord) -> Result<(), JitoBellError> {
+        self.print(&JsonLineRecord::Transaction(record)).map(|_| ())
+    }
+
+    /// Serialize record and print it as a single line, flushing so readers see it immediately
+    fn print(&self, record: &JsonLineRecord) -> Result<String, JitoBellError> {
+        let line = record.to_line(self.schema_version).map_err(|e| {
+            JitoBellError::Notification(format!("Failed to serialize JSON line: {e}"))
+        })?;
+
+        let mut stdout = std::io::stdout().lock();
+        writeln!(stdout, "{line}")?;
+        stdout.flush()?;
+
+        Ok(line)
+    }
+}
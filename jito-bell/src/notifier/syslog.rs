@@ -0,0 +1,258 @@
+use std::process;
+
+use chrono::{SecondsFormat, Utc};
+use tokio::{
+    io::{AsyncWrite, AsyncWriteExt},
+    net::{TcpStream, UdpSocket},
+    sync::Mutex,
+};
+
+use crate::{
+    error::JitoBellError,
+    notification_config::{SyslogConfig, SyslogSeverity, SyslogTransport},
+    notification_event::NotificationEvent,
+};
+
+/// Structured data ID (private enterprise number reserved for documentation, RFC 5612)
+const STRUCTURED_DATA_ID: &str = "jitobell@32473";
+
+/// MSGID of notification messages
+const MSG_ID: &str = "notification";
+
+/// Largest facility code defined by RFC 5424
+const MAX_FACILITY: u8 = 23;
+
+/// Stream connection (TCP or TLS)
+type SyslogStream = Box<dyn AsyncWrite + Unpin + Send>;
+
+/// Syslog Notifier
+///
+/// - Send RFC 5424 messages over UDP, or over TCP/TLS with octet-counting framing (RFC 6587)
+pub struct SyslogNotifier {
+    /// Collector address (host:port)
+    address: String,
+
+    /// Transport
+    transport: SyslogTransport,
+
+    /// Facility code
+    facility: u8,
+
+    /// HOSTNAME field
+    hostname: String,
+
+    /// APP-NAME field
+    app_name: String,
+
+    /// Bound UDP socket (UDP transport only)
+    socket: Option<UdpSocket>,
+
+    /// Lazily (re)connected stream (TCP/TLS transport only)
+    stream: Mutex<Option<SyslogStream>>,
+}
+
+impl SyslogNotifier {
+    /// Initialize Syslog Notifier
+    pub async fn new(config: &SyslogConfig) -> Result<Self, JitoBellError> {
+        if config.facility > MAX_FACILITY {
+            return Err(JitoBellError::Config(format!(
+                "Invalid syslog facility: {}",
+                config.facility
+            )));
+        }
+
+        let socket = match config.transport {
+            SyslogTransport::Udp => {
+                let socket = UdpSocket::bind("0.0.0.0:0").await?;
+                socket.connect(&config.address).await?;
+                Some(socket)
+            }
+            SyslogTransport::Tcp | SyslogTransport::Tls => None,
+        };
+
+        let hostname = config
+            .hostname
+            .clone()
+            .or_else(|| std::env::var("HOSTNAME").ok())
+            .unwrap_or_else(|| "-".to_string());
+
+        Ok(Self {
+            address: config.address.clone(),
+            transport: config.transport,
+            facility: config.facility,
+            hostname,
+            app_name: config.app_name.clone(),
+            socket,
+            stream: Mutex::new(None),
+        })
+    }
+
+    /// Send notification with the given severity
+    ///
+    /// - Return the formatted syslog message
+    pub async fn send(
+        &self,
+        event: &NotificationEvent,
+        message: &str,
+        severity: SyslogSeverity,
+    ) -> Result<String, JitoBellError> {
+        let line = format_message(
+            self.facility,
+            severity,
+            &self.hostname,
+            &self.app_name,
+            event,
+            message,
+        );
+
+        match &self.socket {
+            Some(socket) => {
+                socket.send(line.as_bytes()).await?;
+            }
+            None => {
+                let frame = format!("{} {line}", line.len());
+                let mut stream = self.stream.lock().await;
+
+                // Reconnect once if the collector dropped the previous connection
+                for attempt in 0..2 {
+                    if stream.is_none() {
+                        *stream = Some(self.connect().await?);
+                    }
+
+                    if let Some(conn) = stream.as_mut() {
+                        match conn.write_all(frame.as_bytes()).await {
+                            Ok(()) => break,
+                            Err(e) => {
+                                *stream = None;
+                                if attempt == 1 {
+                                    return Err(e.into());
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(line)
+    }
+
+    /// Connect to the collector over TCP or TLS
+    async fn connect(&self) -> Result<SyslogStream, JitoBellError> {
+        let tcp = TcpStream::connect(&self.address).await?;
+
+        match self.transport {
+            SyslogTransport::Tls => {
+                let domain = self
+                    .address
+                    .rsplit_once(':')
+                    .map_or(self.address.as_str(), |(host, _)| host);
+                let connector = tokio_native_tls::native_tls::TlsConnector::new().map_err(|e| {
+                    JitoBellError::Notification(format!("Failed to build TLS connector: {e}"))
+                })?;
+                let tls = tokio_native_tls::TlsConnector::from(connector)
+                    .connect(domain, tcp)
+                    .await
+                    .map_err(|e| {
+                        JitoBellError::Notification(format!("Syslog TLS handshake error: {e}"))
+                    })?;
+
+                Ok(Box::new(tls))
+            }
+            SyslogTransport::Tcp | SyslogTransport::Udp => Ok(Box::new(tcp)),
+        }
+    }
+}
+
+/// Format RFC 5424 message
+///
+/// `<PRI>1 TIMESTAMP HOSTNAME APP-NAME PROCID MSGID [SD-ELEMENT] MSG`
+fn format_message(
+    facility: u8,
+    severity: SyslogSeverity,
+    hostname: &str,
+    app_name: &str,
+    event: &NotificationEvent,
+    message: &str,
+) -> String {
+    let pri = u16::from(facility) * 8 + u16::from(severity.code());
+    let timestamp = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
+
+    let mut params = vec![
+        ("signature", event.transaction_signature.clone()),
+        ("pool", event.pool.clone()),
+        ("amount", event.amount.to_string()),
+        ("unit", event.currency_unit.clone()),
+        ("slot", event.slot.to_string()),
+    ];
+    if let Some(sequence) = event.sequence {
+        params.push(("sequence", sequence.to_string()));
+    }
+
+    let params: Vec<String> = params
+        .iter()
+        .map(|(name, value)| format!("{name}=\"{}\"", escape_param_value(value)))
+        .collect();
+
+    format!(
+        "<{pri}>1 {timestamp} {hostname} {app_name} {} {MSG_ID} [{STRUCTURED_DATA_ID} {}] {message}",
+        process::id(),
+        params.join(" ")
+    )
+}
+
+/// Escape `"`, `\` and `]` in structured data parameter values
+fn escape_param_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '"' | '\\' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        notification_config::SyslogSeverity,
+        notification_event::NotificationEvent,
+        notification_info::NotificationInfo,
+        notifier::syslog::{escape_param_value, format_message},
+    };
+
+    #[test]
+    fn test_format_message() {
+        let mut event = NotificationEvent::scheduled(
+            &NotificationInfo::default(),
+            "Deposit detected".to_string(),
+            "pool",
+            1.5,
+            "SOL",
+        );
+        event.transaction_signature = "sig".to_string();
+        event.slot = 10;
+
+        // facility user (1), severity warning (4)
+        let line = format_message(
+            1,
+            SyslogSeverity::Warning,
+            "host",
+            "jito-bell",
+            &event,
+            "msg",
+        );
+
+        assert!(line.starts_with("<12>1 "));
+        assert!(line.contains(" host jito-bell "));
+        assert!(line.contains(
+            "[jitobell@32473 signature=\"sig\" pool=\"pool\" amount=\"1.5\" unit=\"SOL\" slot=\"10\"] msg"
+        ));
+    }
+
+    #[test]
+    fn test_escape_param_value() {
+        assert_eq!(escape_param_value(r#"a"b\c]d"#), r#"a\"b\\c\]d"#);
+    }
+}
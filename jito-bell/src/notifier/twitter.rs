@@ -0,0 +1,103 @@
+/// Maximum weighted length of a post
+const TWEET_MAX_WEIGHT: usize = 280;
+
+/// Every URL counts as a t.co link regardless of its length
+const TWEET_URL_WEIGHT: usize = 23;
+
+/// Appended to truncated posts
+const ELLIPSIS: char = '…';
+
+/// Fit text into a post
+///
+/// - URLs are kept whole or dropped, other text is cut at a character boundary
+pub fn fit_tweet(text: &str) -> String {
+    if weighted_len(text) <= TWEET_MAX_WEIGHT {
+        return text.to_string();
+    }
+
+    let budget = TWEET_MAX_WEIGHT - char_weight(ELLIPSIS);
+    let mut fitted = String::new();
+    let mut weight = 0;
+
+    'tokens: for token in text.split_inclusive(char::is_whitespace) {
+        let word = token.trim_end();
+        if is_url(word) {
+            let token_weight = TWEET_URL_WEIGHT + (token.len() - word.len());
+            if weight + token_weight > budget {
+                break;
+            }
+            fitted.push_str(token);
+            weight += token_weight;
+            continue;
+        }
+
+        for c in token.chars() {
+            if weight + char_weight(c) > budget {
+                break 'tokens;
+            }
+            fitted.push(c);
+            weight += char_weight(c);
+        }
+    }
+
+    let mut fitted = fitted.trim_end().to_string();
+    fitted.push(ELLIPSIS);
+
+    fitted
+}
+
+/// Length as counted by X: URLs count 23, wide characters (CJK, emoji) count 2
+fn weighted_len(text: &str) -> usize {
+    text.split_inclusive(char::is_whitespace)
+        .map(|token| {
+            let word = token.trim_end();
+            if is_url(word) {
+                TWEET_URL_WEIGHT + (token.len() - word.len())
+            } else {
+                token.chars().map(char_weight).sum()
+            }
+        })
+        .sum()
+}
+
+fn char_weight(c: char) -> usize {
+    if (c as u32) <= 0x10FF {
+        1
+    } else {
+        2
+    }
+}
+
+fn is_url(word: &str) -> bool {
+    word.starts_with("https://") || word.starts_with("http://")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::notifier::twitter::{fit_tweet, weighted_len};
+
+    #[test]
+    fn test_weighted_len() {
+        assert_eq!(weighted_len("abc"), 3);
+        assert_eq!(weighted_len("🚨 abc"), 6);
+        assert_eq!(
+            weighted_len(
+                "tx https://explorer.solana.com/tx/5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnb"
+            ),
+            26
+        );
+    }
+
+    #[test]
+    fn test_fit_tweet() {
+        assert_eq!(fit_tweet("short"), "short");
+
+        let url = "https://explorer.solana.com/tx/5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnb";
+        let text = format!("{url} {}", "a".repeat(300));
+        let fitted = fit_tweet(&text);
+
+        assert!(fitted.starts_with(url));
+        assert!(fitted.ends_with('…'));
+        assert_eq!(weighted_len(&fitted), 280);
+    }
+}
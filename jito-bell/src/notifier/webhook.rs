@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use crate::{
+    error::JitoBellError, notification_config::WebhookConfig,
+    notification_event::NotificationEvent, notification_info::WebhookDeliveryOptions,
+    retry::RetryPolicy, schema,
+};
+
+/// Webhook Notifier
+///
+/// - POST the event (JSON, versioned schema) to a single endpoint
+/// - Query parameters and headers are templated from the event, rules can add or override them
+pub struct WebhookNotifier {
+    /// HTTP client
+    client: reqwest::Client,
+
+    /// Webhook configuration
+    config: WebhookConfig,
+
+    /// Retry policy of the POST
+    retry: RetryPolicy,
+}
+
+impl WebhookNotifier {
+    /// Initialize Webhook Notifier
+    pub fn new(config: &WebhookConfig, retry: RetryPolicy, client: reqwest::Client) -> Self {
+        Self {
+            client,
+            config: config.clone(),
+            retry,
+        }
+    }
+
+    /// Send event, returns the response status
+    pub async fn send(
+        &self,
+        event: &NotificationEvent,
+        options: &WebhookDeliveryOptions,
+    ) -> Result<reqwest::StatusCode, JitoBellError> {
+        let payload =
+            schema::notification_payload(event, self.config.schema_version).map_err(|e| {
+                JitoBellError::Notification(format!("Failed to serialize webhook payload: {e}"))
+            })?;
+
+        let mut request = self
+            .client
+            .post(&self.config.url)
+            .query(&render_pairs(&self.config.query, &options.query, event))
+            .json(&payload);
+        for (name, value) in render_pairs(&self.config.headers, &options.headers, event) {
+            request = request.header(name, value);
+        }
+
+        let response = self.retry.send(request).await?;
+        let status = response.status();
+        if status.is_success() {
+            Ok(status)
+        } else {
+            Err(JitoBellError::Notification(format!(
+                "Failed to send webhook: {status}"
+            )))
+        }
+    }
+}
+
+/// Merge the rule's pairs over the destination's and render them with the event
+///
+/// - Sorted by name so requests are reproducible
+fn render_pairs(
+    defaults: &HashMap<String, String>,
+    overrides: &HashMap<String, String>,
+    event: &NotificationEvent,
+) -> Vec<(String, String)> {
+    let mut merged = defaults.clone();
+    merged.extend(overrides.clone());
+
+    let mut pairs: Vec<(String, String)> = merged
+        .into_iter()
+        .map(|(name, template)| (name, event.render(&template)))
+        .collect();
+    pairs.sort();
+
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        notification_event::NotificationEvent, notification_info::NotificationInfo,
+        notifier::webhook::render_pairs,
+    };
+
+    fn event() -> NotificationEvent {
+        NotificationEvent::scheduled(
+            &NotificationInfo::default(),
+            "Deposit".to_string(),
+            "pool",
+            1.5,
+            "SOL",
+        )
+    }
+
+    #[test]
+    fn test_render_pairs() {
+        let defaults = HashMap::from([
+            ("source".to_string(), "jito-bell".to_string()),
+            ("routing_key".to_string(), "default".to_string()),
+        ]);
+        let overrides = HashMap::from([("routing_key".to_string(), "pools.{{pool}}".to_string())]);
+
+        assert_eq!(
+            render_pairs(&defaults, &overrides, &event()),
+            vec![
+                ("routing_key".to_string(), "pools.pool".to_string()),
+                ("source".to_string(), "jito-bell".to_string()),
+            ]
+        );
+    }
+}
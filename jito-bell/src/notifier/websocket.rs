@@ -0,0 +1,133 @@
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        State,
+    },
+    response::Response,
+    routing::get,
+    Router,
+};
+use log::{debug, error, info, warn};
+use tokio::{
+    net::TcpListener,
+    sync::broadcast::{self, error::RecvError},
+};
+
+use crate::{
+    error::JitoBellError,
+    notification_config::WebSocketConfig,
+    notification_event::NotificationEvent,
+    notifier::file::{JsonLineRecord, TransactionRecord},
+    schema::SchemaVersion,
+};
+
+/// WebSocket Broadcaster
+///
+/// - Serve a WebSocket endpoint and broadcast every event as a JSON text frame to all clients
+/// - Slow clients skip the events they lagged behind on instead of blocking delivery
+pub struct WebSocketBroadcaster {
+    /// Broadcast channel to connected clients
+    sender: broadcast::Sender<String>,
+
+    /// Also broadcast every parsed transaction
+    include_transactions: bool,
+
+    /// Schema version of the events
+    schema_version: SchemaVersion,
+}
+
+impl WebSocketBroadcaster {
+    /// Bind the WebSocket server and serve it in the background
+    pub async fn new(config: &WebSocketConfig) -> Result<Self, JitoBellError> {
+        let (sender, _) = broadcast::channel(config.capacity);
+
+        let listener = TcpListener::bind(&config.bind_address).await?;
+        info!(
+            "WebSocket server listening on {}{}",
+            config.bind_address, config.path
+        );
+
+        let router = Router::new()
+            .route(&config.path, get(upgrade))
+            .with_state(sender.clone());
+
+        tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, router).await {
+                error!("WebSocket server error: {e}");
+            }
+        });
+
+        Ok(Self {
+            sender,
+            include_transactions: config.include_transactions,
+            schema_version: config.schema_version,
+        })
+    }
+
+    /// Whether parsed transactions should be broadcast
+    pub fn include_transactions(&self) -> bool {
+        self.include_transactions
+    }
+
+    /// Broadcast notification event
+    pub fn broadcast_notification(&self, event: &NotificationEvent) -> Result<(), JitoBellError> {
+        self.broadcast(&JsonLineRecord::Notification(event))
+    }
+
+    /// Broadcast parsed transaction
+    pub fn broadcast_transaction(&self, record: &TransactionRecord) -> Result<(), JitoBellError> {
+        self.broadcast(&JsonLineRecord::Transaction(record))
+    }
+
+    /// Serialize record and send it to all connected clients
+    fn broadcast(&self, record: &JsonLineRecord) -> Result<(), JitoBellError> {
+        // Nothing to do without clients
+        if self.sender.receiver_count() == 0 {
+            return Ok(());
+        }
+
+        let text = record.to_line(self.schema_version).map_err(|e| {
+            JitoBellError::Notification(format!("Failed to serialize WebSocket event: {e}"))
+        })?;
+
+        // Only fails when every client disconnected in the meantime
+        let _ = self.sender.send(text);
+
+        Ok(())
+    }
+}
+
+/// GET <path>: upgrade to WebSocket
+async fn upgrade(
+    ws: WebSocketUpgrade,
+    State(sender): State<broadcast::Sender<String>>,
+) -> Response {
+    let receiver = sender.subscribe();
+    ws.on_upgrade(move |socket| forward(socket, receiver))
+}
+
+/// Forward broadcast events to a single client until it disconnects
+async fn forward(mut socket: WebSocket, mut receiver: broadcast::Receiver<String>) {
+    loop {
+        tokio::select! {
+            event = receiver.recv() => match event {
+                Ok(text) => {
+                    if socket.send(Message::Text(text.into())).await.is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("WebSocket client lagged, skipped {skipped} events");
+                }
+                Err(RecvError::Closed) => break,
+            },
+            message = socket.recv() => match message {
+                // Clients only listen, ignore anything they send except close
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => continue,
+            },
+        }
+    }
+
+    debug!("WebSocket client disconnected");
+}
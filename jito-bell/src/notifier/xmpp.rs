@@ -0,0 +1,116 @@
+use std::str::FromStr;
+
+use futures::StreamExt;
+use log::{error, info, warn};
+use tokio::sync::mpsc;
+use tokio_xmpp::{
+    jid::{BareJid, Jid},
+    parsers::{
+        message::{Body, Message, MessageType},
+        muc::Muc,
+        presence::{Presence, Type as PresenceType},
+    },
+    starttls::StartTlsAsyncClient,
+    Event,
+};
+
+use crate::{error::JitoBellError, notification_config::XmppConfig};
+
+/// Capacity of the outgoing message channel
+const XMPP_MESSAGE_CAPACITY: usize = 64;
+
+/// XMPP Notifier
+///
+/// - Keeps a single SASL-authenticated session alive in a background task, rejoining MUC rooms
+///   after every (re)connection
+pub struct XmppNotifier {
+    /// Outgoing message bodies
+    sender: mpsc::Sender<String>,
+}
+
+impl XmppNotifier {
+    /// Initialize XMPP Notifier and spawn its session task
+    pub fn new(config: &XmppConfig) -> Result<Self, JitoBellError> {
+        let jid = BareJid::from_str(&config.jid)
+            .map_err(|e| JitoBellError::Config(format!("Invalid XMPP JID: {e}")))?;
+        let recipients = parse_jids(&config.recipients)?;
+        let rooms = parse_jids(&config.rooms)?;
+        let nickname = config.nickname.clone();
+
+        let mut client = StartTlsAsyncClient::new(jid, config.password.clone());
+        client.set_reconnect(true);
+
+        let (sender, mut receiver) = mpsc::channel::<String>(XMPP_MESSAGE_CAPACITY);
+
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    event = client.next() => match event {
+                        Some(Event::Online { bound_jid, .. }) => {
+                            info!("XMPP session online as {bound_jid}");
+                            for room in rooms.iter() {
+                                let occupant = format!("{room}/{nickname}");
+                                let Ok(occupant) = Jid::from_str(&occupant) else {
+                                    warn!("Invalid XMPP room occupant: {occupant}");
+                                    continue;
+                                };
+                                let presence = Presence::new(PresenceType::None)
+                                    .with_to(occupant)
+                                    .with_payloads(vec![Muc::new().into()]);
+                                if let Err(e) = client.send_stanza(presence.into()).await {
+                                    error!("Failed to join XMPP room {room}: {e}");
+                                }
+                            }
+                        }
+                        Some(Event::Disconnected(e)) => warn!("XMPP session disconnected: {e}"),
+                        Some(Event::Stanza(_)) => {}
+                        None => {
+                            error!("XMPP session closed");
+                            break;
+                        }
+                    },
+                    body = receiver.recv() => {
+                        let Some(body) = body else {
+                            break;
+                        };
+
+                        let messages = recipients
+                            .iter()
+                            .map(|to| (to, MessageType::Chat))
+                            .chain(rooms.iter().map(|to| (to, MessageType::Groupchat)));
+                        for (to, type_) in messages {
+                            let mut message = Message::new(Some(to.clone()));
+                            message.type_ = type_;
+                            message
+                                .bodies
+                                .insert(String::new(), Body(body.clone()));
+                            if let Err(e) = client.send_stanza(message.into()).await {
+                                error!("Failed to send XMPP message to {to}: {e}");
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(Self { sender })
+    }
+
+    /// Queue message for all recipients and rooms
+    pub async fn send(&self, body: &str) -> Result<(), JitoBellError> {
+        self.sender
+            .send(body.to_string())
+            .await
+            .map_err(|_| JitoBellError::Notification("XMPP session task stopped".to_string()))
+    }
+}
+
+/// Parse JIDs
+fn parse_jids(jids: &[String]) -> Result<Vec<Jid>, JitoBellError> {
+    jids.iter()
+        .map(|jid| {
+            Jid::from_str(jid)
+                .map_err(|e| JitoBellError::Config(format!("Invalid XMPP JID {jid}: {e}")))
+        })
+        .collect()
+}
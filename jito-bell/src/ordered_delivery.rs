@@ -0,0 +1,316 @@
+use std::collections::{BTreeMap, HashMap};
+
+use log::warn;
+
+use crate::{notification_event::NotificationEvent, notification_info::NotificationInfo};
+
+/// Number of slots to remember delivered notifications for duplicate detection
+const DEDUP_RETENTION_SLOTS: u64 = 1_000;
+
+/// Notification waiting to be released in order
+#[derive(Debug)]
+pub struct PendingNotification {
+    /// Notification configuration of the matched threshold
+    pub notification: NotificationInfo,
+
+    /// Event to deliver
+    pub event: NotificationEvent,
+}
+
+/// Per-pool ordering queue
+#[derive(Debug, Default)]
+struct PoolQueue {
+    /// Pending notifications keyed by (slot, transaction index, arrival order)
+    pending: BTreeMap<(u64, u64, u64), PendingNotification>,
+
+    /// Last assigned sequence number
+    sequence: u64,
+
+    /// Position (slot, transaction index) of the latest released notification
+    last_released: Option<(u64, u64)>,
+}
+
+/// Result of queueing a notification
+#[derive(Debug)]
+pub enum PushOutcome {
+    /// Held for ordered release
+    Queued,
+
+    /// Same transaction and rule already queued
+    Duplicate,
+
+    /// Queue is full, deliver the notification right away (out of order)
+    Overflow(Box<PendingNotification>),
+}
+
+/// Ordered Delivery
+///
+/// - Hold notifications for `hold_slots` slots, then release them per pool in (slot, transaction
+///   index) order with increasing sequence numbers
+/// - Drop duplicates of the same transaction arriving more than once (e.g. from multiple
+///   endpoints)
+/// - Bounded: at most `max_pending` held notifications and `max_dedup_entries` remembered pairs
+#[derive(Debug)]
+pub struct OrderedDelivery {
+    /// Number of slots to hold notifications
+    hold_slots: u64,
+
+    /// Maximum number of held notifications across pools
+    max_pending: usize,
+
+    /// Maximum number of remembered (signature, description) pairs
+    max_dedup_entries: usize,
+
+    /// Number of held notifications across pools
+    pending_len: usize,
+
+    /// Notifications delivered out of order because the queue was full
+    overflowed: u64,
+
+    /// Remembered pairs evicted before their retention window
+    dedup_evicted: u64,
+
+    /// Queues keyed by pool address
+    pools: HashMap<String, PoolQueue>,
+
+    /// Recently queued (signature, description) pairs and their slots
+    seen: HashMap<(String, String), u64>,
+
+    /// Arrival counter to keep ties stable
+    arrivals: u64,
+}
+
+impl OrderedDelivery {
+    /// Initialize Ordered Delivery
+    pub fn new(hold_slots: u64, max_pending: usize, max_dedup_entries: usize) -> Self {
+        Self {
+            hold_slots,
+            max_pending,
+            max_dedup_entries,
+            pending_len: 0,
+            overflowed: 0,
+            dedup_evicted: 0,
+            pools: HashMap::new(),
+            seen: HashMap::new(),
+            arrivals: 0,
+        }
+    }
+
+    /// Queue notification
+    pub fn push(&mut self, pending: PendingNotification) -> PushOutcome {
+        let key = (
+            pending.event.transaction_signature.clone(),
+            pending.event.description.clone(),
+        );
+        if self.seen.contains_key(&key) {
+            return PushOutcome::Duplicate;
+        }
+        if self.seen.len() >= self.max_dedup_entries {
+            self.evict_oldest_seen();
+        }
+        self.seen.insert(key, pending.event.slot);
+
+        if self.pending_len >= self.max_pending {
+            self.overflowed += 1;
+            return PushOutcome::Overflow(Box::new(pending));
+        }
+        self.pending_len += 1;
+
+        self.arrivals += 1;
+        let position = (
+            pending.event.slot,
+            pending.event.transaction_index,
+            self.arrivals,
+        );
+        self.pools
+            .entry(pending.event.pool.clone())
+            .or_default()
+            .pending
+            .insert(position, pending);
+
+        PushOutcome::Queued
+    }
+
+    /// Release notifications whose hold window has passed at `tip_slot`
+    pub fn release(&mut self, tip_slot: u64) -> Vec<PendingNotification> {
+        let mut released = Vec::new();
+
+        for (pool, queue) in self.pools.iter_mut() {
+            while let Some(entry) = queue.pending.first_entry() {
+                let (slot, index, _) = *entry.key();
+                if slot.saturating_add(self.hold_slots) > tip_slot {
+                    break;
+                }
+
+                let mut pending = entry.remove();
+                self.pending_len -= 1;
+                match queue.last_released {
+                    Some(last) if (slot, index) < last => {
+                        warn!(
+                            "Notification for pool {pool} arrived after its hold window: {}",
+                            pending.event.transaction_signature
+                        );
+                    }
+                    _ => queue.last_released = Some((slot, index)),
+                }
+
+                queue.sequence += 1;
+                pending.event.sequence = Some(queue.sequence);
+                released.push(pending);
+            }
+        }
+
+        self.seen
+            .retain(|_, slot| slot.saturating_add(DEDUP_RETENTION_SLOTS) > tip_slot);
+        self.pools.retain(|_, queue| !queue.pending.is_empty());
+
+        released
+    }
+
+    /// Number of held notifications
+    pub fn pending_len(&self) -> usize {
+        self.pending_len
+    }
+
+    /// Number of remembered (signature, description) pairs
+    pub fn dedup_len(&self) -> usize {
+        self.seen.len()
+    }
+
+    /// Notifications delivered out of order because the queue was full
+    pub fn overflowed(&self) -> u64 {
+        self.overflowed
+    }
+
+    /// Remembered pairs evicted before their retention window
+    pub fn dedup_evicted(&self) -> u64 {
+        self.dedup_evicted
+    }
+
+    /// Forget the oldest tenth of the remembered pairs (at least one)
+    fn evict_oldest_seen(&mut self) {
+        let mut slots: Vec<u64> = self.seen.values().copied().collect();
+        let count = (slots.len() / 10).max(1);
+        if slots.is_empty() {
+            return;
+        }
+        let (_, cutoff, _) = slots.select_nth_unstable(count - 1);
+        let cutoff = *cutoff;
+
+        let before = self.seen.len();
+        self.seen.retain(|_, slot| *slot > cutoff);
+        self.dedup_evicted += (before - self.seen.len()) as u64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        notification_event::NotificationEvent,
+        notification_info::NotificationInfo,
+        ordered_delivery::{OrderedDelivery, PendingNotification, PushOutcome},
+    };
+
+    fn create_pending(pool: &str, signature: &str, slot: u64, index: u64) -> PendingNotification {
+        let notification = NotificationInfo {
+            description: "Deposit detected".to_string(),
+            destinations: vec!["slack".into()],
+            template: None,
+            severity: None,
+            delivery: Default::default(),
+            threshold_ratio: None,
+        };
+        let mut event = NotificationEvent::scheduled(
+            &notification,
+            notification.description.clone(),
+            pool,
+            1.0,
+            "SOL",
+        );
+        event.transaction_signature = signature.to_string();
+        event.slot = slot;
+        event.transaction_index = index;
+
+        PendingNotification {
+            notification,
+            event,
+        }
+    }
+
+    #[test]
+    fn test_release_in_order() {
+        let mut ordered_delivery = OrderedDelivery::new(2, 100, 100);
+
+        assert!(matches!(
+            ordered_delivery.push(create_pending("pool", "withdraw", 11, 0)),
+            PushOutcome::Queued
+        ));
+        assert!(matches!(
+            ordered_delivery.push(create_pending("pool", "deposit", 10, 5)),
+            PushOutcome::Queued
+        ));
+
+        // Hold window has not passed yet
+        assert!(ordered_delivery.release(11).is_empty());
+
+        let released = ordered_delivery.release(13);
+        let signatures: Vec<&str> = released
+            .iter()
+            .map(|pending| pending.event.transaction_signature.as_str())
+            .collect();
+        assert_eq!(signatures, vec!["deposit", "withdraw"]);
+
+        let sequences: Vec<Option<u64>> = released
+            .iter()
+            .map(|pending| pending.event.sequence)
+            .collect();
+        assert_eq!(sequences, vec![Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn test_skip_duplicate() {
+        let mut ordered_delivery = OrderedDelivery::new(0, 100, 100);
+
+        assert!(matches!(
+            ordered_delivery.push(create_pending("pool", "deposit", 10, 0)),
+            PushOutcome::Queued
+        ));
+        assert!(matches!(
+            ordered_delivery.push(create_pending("pool", "deposit", 10, 0)),
+            PushOutcome::Duplicate
+        ));
+
+        assert_eq!(ordered_delivery.release(10).len(), 1);
+    }
+
+    #[test]
+    fn test_bounded_queue() {
+        let mut ordered_delivery = OrderedDelivery::new(2, 1, 100);
+
+        assert!(matches!(
+            ordered_delivery.push(create_pending("pool", "deposit", 10, 0)),
+            PushOutcome::Queued
+        ));
+        assert!(matches!(
+            ordered_delivery.push(create_pending("pool", "withdraw", 10, 1)),
+            PushOutcome::Overflow(_)
+        ));
+        assert_eq!(ordered_delivery.pending_len(), 1);
+        assert_eq!(ordered_delivery.overflowed(), 1);
+
+        assert_eq!(ordered_delivery.release(12).len(), 1);
+        assert_eq!(ordered_delivery.pending_len(), 0);
+    }
+
+    #[test]
+    fn test_bounded_dedup() {
+        let mut ordered_delivery = OrderedDelivery::new(0, 100, 2);
+
+        ordered_delivery.push(create_pending("pool", "first", 10, 0));
+        ordered_delivery.push(create_pending("pool", "second", 11, 0));
+        ordered_delivery.push(create_pending("pool", "third", 12, 0));
+
+        assert_eq!(ordered_delivery.dedup_len(), 2);
+        assert_eq!(ordered_delivery.dedup_evicted(), 1);
+    }
+}
@@ -0,0 +1,318 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use log::warn;
+use serde_json::{json, Value};
+use tokio::sync::mpsc;
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Subscriber,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+use crate::pipeline_trace::is_pipeline_target;
+
+/// Instrumentation scope and `service.name` of the exported spans
+const OTLP_SERVICE_NAME: &str = "jito-bell";
+
+/// Spans exported per request
+const OTLP_BATCH_SIZE: usize = 512;
+
+/// Interval between exports of a partial batch
+const OTLP_EXPORT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Closed spans waiting for export, newer spans are dropped past it
+const OTLP_QUEUE_CAPACITY: usize = 4_096;
+
+/// `SPAN_KIND_INTERNAL`
+const OTLP_SPAN_KIND_INTERNAL: u8 = 1;
+
+/// Layer exporting the pipeline spans to an OpenTelemetry collector over OTLP/HTTP (JSON)
+///
+/// - Spans are batched and posted to `<endpoint>/v1/traces` in the background, spans still
+///   batched on exit are lost
+/// - A span inherits the trace of its closest pipeline ancestor, each `transaction` span
+///   starts a trace
+pub struct OtlpLayer {
+    /// Closed spans, in OTLP JSON
+    spans: mpsc::Sender<Value>,
+}
+
+/// Trace, timing and attributes of a pipeline span
+struct OtlpSpan {
+    trace_id: u128,
+    span_id: u64,
+    parent_span_id: Option<u64>,
+
+    /// Creation time (unix nanoseconds)
+    start_time: u128,
+
+    /// Attributes in OTLP JSON
+    attributes: Vec<Value>,
+}
+
+impl OtlpLayer {
+    /// Create the layer and spawn its exporter, posting with `http_client`
+    pub fn new(endpoint: &str, http_client: reqwest::Client) -> Self {
+        let url = format!("{}/v1/traces", endpoint.trim_end_matches('/'));
+        let (spans, receiver) = mpsc::channel(OTLP_QUEUE_CAPACITY);
+        tokio::spawn(export(url, http_client, receiver));
+
+        Self { spans }
+    }
+}
+
+impl<S> Layer<S> for OtlpLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if !is_pipeline_target(attrs.metadata().target()) {
+            return;
+        }
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let parent = span.scope().skip(1).find_map(|ancestor| {
+            ancestor
+                .extensions()
+                .get::<OtlpSpan>()
+                .map(|parent| (parent.trace_id, parent.span_id))
+        });
+        let (trace_id, parent_span_id) = match parent {
+            Some((trace_id, span_id)) => (trace_id, Some(span_id)),
+            None => (nonzero_id(rand::random()), None),
+        };
+
+        let mut attributes = Vec::new();
+        attrs.record(&mut AttributeVisitor(&mut attributes));
+        span.extensions_mut().insert(OtlpSpan {
+            trace_id,
+            span_id: nonzero_id(rand::random()),
+            parent_span_id,
+            start_time: unix_nanos(),
+            attributes,
+        });
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let mut extensions = span.extensions_mut();
+        if let Some(otlp_span) = extensions.get_mut::<OtlpSpan>() {
+            values.record(&mut AttributeVisitor(&mut otlp_span.attributes));
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let extensions = span.extensions();
+        let Some(otlp_span) = extensions.get::<OtlpSpan>() else {
+            return;
+        };
+
+        // Dropped when the collector can't keep up, tracing must not slow down the pipeline
+        let _ = self
+            .spans
+            .try_send(span_json(span.name(), otlp_span, unix_nanos()));
+    }
+}
+
+/// Post batches of spans to `url` until the layer is dropped
+async fn export(url: String, http_client: reqwest::Client, mut spans: mpsc::Receiver<Value>) {
+    let mut batch = Vec::new();
+    let mut interval = tokio::time::interval(OTLP_EXPORT_INTERVAL);
+
+    loop {
+        let closed = tokio::select! {
+            span = spans.recv() => match span {
+                Some(span) => {
+                    batch.push(span);
+                    if batch.len() < OTLP_BATCH_SIZE {
+                        continue;
+                    }
+                    false
+                }
+                None => true,
+            },
+            _ = interval.tick() => false,
+        };
+
+        if !batch.is_empty() {
+            let request = export_request(std::mem::take(&mut batch));
+            let response = http_client
+                .post(&url)
+                .json(&request)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+            if let Err(e) = response {
+                warn!("Failed to export spans to {url}: {e}");
+            }
+        }
+        if closed {
+            return;
+        }
+    }
+}
+
+/// `ExportTraceServiceRequest` of `spans`
+fn export_request(spans: Vec<Value>) -> Value {
+    let service_name = attribute("service.name", json!({ "stringValue": OTLP_SERVICE_NAME }));
+
+    json!({
+        "resourceSpans": [{
+            "resource": { "attributes": [service_name] },
+            "scopeSpans": [{
+                "scope": { "name": OTLP_SERVICE_NAME },
+                "spans": spans,
+            }],
+        }],
+    })
+}
+
+/// OTLP JSON of a span closed at `end_time` (unix nanoseconds)
+fn span_json(name: &str, span: &OtlpSpan, end_time: u128) -> Value {
+    let mut value = json!({
+        "traceId": format!("{:032x}", span.trace_id),
+        "spanId": format!("{:016x}", span.span_id),
+        "name": name,
+        "kind": OTLP_SPAN_KIND_INTERNAL,
+        "startTimeUnixNano": span.start_time.to_string(),
+        "endTimeUnixNano": end_time.to_string(),
+        "attributes": span.attributes,
+    });
+    if let Some(parent_span_id) = span.parent_span_id {
+        value["parentSpanId"] = Value::from(format!("{parent_span_id:016x}"));
+    }
+
+    value
+}
+
+/// OTLP JSON of an attribute
+fn attribute(key: &str, value: Value) -> Value {
+    json!({ "key": key, "value": value })
+}
+
+/// Trace and span IDs must not be all zeros
+fn nonzero_id<T: Default + PartialEq + From<u8>>(id: T) -> T {
+    if id == T::default() {
+        T::from(1)
+    } else {
+        id
+    }
+}
+
+/// Current time in unix nanoseconds
+fn unix_nanos() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos()
+}
+
+/// Appends the visited fields as OTLP attributes
+struct AttributeVisitor<'a>(&'a mut Vec<Value>);
+
+impl Visit for AttributeVisitor<'_> {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.0
+            .push(attribute(field.name(), json!({ "doubleValue": value })));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.push(attribute(
+            field.name(),
+            json!({ "intValue": value.to_string() }),
+        ));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.push(attribute(
+            field.name(),
+            json!({ "intValue": value.to_string() }),
+        ));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0
+            .push(attribute(field.name(), json!({ "boolValue": value })));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0
+            .push(attribute(field.name(), json!({ "stringValue": value })));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.push(attribute(
+            field.name(),
+            json!({ "stringValue": format!("{value:?}") }),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::otlp::{attribute, export_request, nonzero_id, span_json, OtlpSpan};
+
+    #[test]
+    fn test_span_json() {
+        let span = OtlpSpan {
+            trace_id: 0xab,
+            span_id: 0x2,
+            parent_span_id: Some(0x1),
+            start_time: 1_000,
+            attributes: vec![attribute("slot", json!({ "intValue": "42" }))],
+        };
+
+        assert_eq!(
+            span_json("dispatch", &span, 2_500),
+            json!({
+                "traceId": "000000000000000000000000000000ab",
+                "spanId": "0000000000000002",
+                "parentSpanId": "0000000000000001",
+                "name": "dispatch",
+                "kind": 1,
+                "startTimeUnixNano": "1000",
+                "endTimeUnixNano": "2500",
+                "attributes": [{ "key": "slot", "value": { "intValue": "42" } }],
+            })
+        );
+
+        let root = OtlpSpan {
+            parent_span_id: None,
+            ..span
+        };
+        assert!(span_json("transaction", &root, 2_500)
+            .get("parentSpanId")
+            .is_none());
+    }
+
+    #[test]
+    fn test_export_request() {
+        let request = export_request(vec![json!({ "name": "send" })]);
+
+        assert_eq!(
+            request["resourceSpans"][0]["resource"]["attributes"][0],
+            json!({ "key": "service.name", "value": { "stringValue": "jito-bell" } })
+        );
+        assert_eq!(
+            request["resourceSpans"][0]["scopeSpans"][0]["spans"],
+            json!([{ "name": "send" }])
+        );
+    }
+
+    #[test]
+    fn test_nonzero_id() {
+        assert_eq!(nonzero_id(0u64), 1);
+        assert_eq!(nonzero_id(7u128), 7);
+    }
+}
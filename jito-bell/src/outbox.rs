@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    notification_event::NotificationEvent, notification_info::NotificationInfo, timezone::Timezone,
+};
+
+/// Notification waiting in the outbox for a destination
+///
+/// - Stored as JSON in the event store, with the event fields not part of its JSON payload
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OutboxEntry {
+    /// Notification of the rule, for the per-destination delivery options
+    pub notification: NotificationInfo,
+
+    /// Event, already specialized for the destination
+    pub event: NotificationEvent,
+
+    /// Message template of the event
+    pub template: Option<String>,
+
+    /// Locale of the event
+    pub locale: Option<String>,
+
+    /// Timezone of the event
+    #[serde(default)]
+    pub timezone: Timezone,
+
+    /// Account labels of the event
+    #[serde(default)]
+    pub account_labels: HashMap<String, String>,
+
+    /// Delivery receipt of the notification, when the event store has one
+    #[serde(default)]
+    pub receipt_id: Option<i64>,
+}
+
+impl OutboxEntry {
+    /// Entry of an event about to be sent
+    pub fn new(notification: &NotificationInfo, event: &NotificationEvent) -> Self {
+        Self {
+            notification: notification.clone(),
+            event: event.clone(),
+            template: event.template.clone(),
+            locale: event.locale.clone(),
+            timezone: event.timezone,
+            account_labels: event.account_labels.clone(),
+            receipt_id: None,
+        }
+    }
+
+    /// Notification and event to send again
+    pub fn into_parts(self) -> (NotificationInfo, NotificationEvent) {
+        let mut event = self.event;
+        event.template = self.template;
+        event.locale = self.locale;
+        event.timezone = self.timezone;
+        event.account_labels = self.account_labels;
+
+        (self.notification, event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        notification_event::NotificationEvent, notification_info::NotificationInfo,
+        outbox::OutboxEntry, timezone::Timezone,
+    };
+
+    #[test]
+    fn test_outbox_entry_roundtrip() {
+        let notification = NotificationInfo {
+            description: "Large withdrawal".to_string(),
+            ..Default::default()
+        };
+        let mut event = NotificationEvent::scheduled(
+            &notification,
+            "Large withdrawal".to_string(),
+            "pool",
+            1500.0,
+            "SOL",
+        );
+        event.template = Some("{{description}} {{sender}}".to_string());
+        event.locale = Some("ja".to_string());
+        event.timezone = "+09:00".parse::<Timezone>().unwrap();
+        event.account_labels = HashMap::from([("sender".to_string(), "Treasury".to_string())]);
+
+        let json = serde_json::to_string(&OutboxEntry::new(&notification, &event)).unwrap();
+        let entry: OutboxEntry = serde_json::from_str(&json).unwrap();
+        let (notification, restored) = entry.into_parts();
+
+        assert_eq!(notification.description, "Large withdrawal");
+        assert_eq!(restored.amount, 1500.0);
+        assert_eq!(restored.template, event.template);
+        assert_eq!(restored.locale, event.locale);
+        assert_eq!(restored.timezone, event.timezone);
+        assert_eq!(restored.account_labels, event.account_labels);
+    }
+}
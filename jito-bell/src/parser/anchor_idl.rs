@@ -0,0 +1,510 @@
+use std::collections::HashMap;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::instruction::ParsableInstruction;
+
+/// Anchor IDL file, both the legacy (< 0.30) and the current format
+#[derive(Deserialize)]
+struct IdlFile {
+    instructions: Vec<IdlInstruction>,
+
+    #[serde(default)]
+    types: Vec<IdlTypeDef>,
+}
+
+#[derive(Deserialize)]
+struct IdlInstruction {
+    name: String,
+
+    /// Absent in legacy IDLs, derived from the name
+    #[serde(default)]
+    discriminator: Option<Vec<u8>>,
+
+    #[serde(default)]
+    accounts: Vec<IdlAccountItem>,
+
+    #[serde(default)]
+    args: Vec<IdlField>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IdlAccountItem {
+    Composite {
+        name: String,
+        accounts: Vec<IdlAccountItem>,
+    },
+    Single {
+        name: String,
+    },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct IdlField {
+    name: String,
+
+    #[serde(rename = "type")]
+    ty: Value,
+}
+
+#[derive(Deserialize)]
+struct IdlTypeDef {
+    name: String,
+
+    #[serde(rename = "type")]
+    ty: IdlTypeDefTy,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+enum IdlTypeDefTy {
+    Struct {
+        /// Named fields (`{ name, type }`) or tuple fields (types)
+        #[serde(default)]
+        fields: Vec<Value>,
+    },
+    Enum {
+        variants: Vec<IdlEnumVariant>,
+    },
+    #[serde(other)]
+    Unsupported,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct IdlEnumVariant {
+    name: String,
+
+    #[serde(default)]
+    fields: Vec<Value>,
+}
+
+/// Instruction layout read from the IDL
+#[derive(Debug, Clone)]
+struct InstructionLayout {
+    name: String,
+    discriminator: Vec<u8>,
+    accounts: Vec<String>,
+    args: Vec<IdlField>,
+}
+
+/// Parser of an Anchor program configured with its IDL
+#[derive(Debug, Clone)]
+pub struct AnchorIdlParser {
+    /// Program name (key under `programs` in the config)
+    pub name: String,
+
+    /// Program ID
+    pub program_id: Pubkey,
+
+    instructions: Vec<InstructionLayout>,
+    types: HashMap<String, IdlTypeDefTy>,
+}
+
+/// Instruction decoded with an Anchor IDL
+#[derive(Debug)]
+pub struct AnchorIdlProgram {
+    /// Program name (key under `programs` in the config)
+    pub program: String,
+
+    /// Instruction name, as written in the IDL
+    pub instruction: String,
+
+    pub ix: Instruction,
+
+    /// Accounts named after the IDL, nested accounts as `<group>.<name>`
+    pub accounts: Vec<(String, Pubkey)>,
+
+    /// Arguments decoded up to the first one of an unsupported type
+    pub args: Vec<(String, Value)>,
+}
+
+impl std::fmt::Display for AnchorIdlProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.instruction)
+    }
+}
+
+impl AnchorIdlProgram {
+    /// Account by IDL name
+    pub fn account(&self, name: &str) -> Option<&Pubkey> {
+        self.accounts
+            .iter()
+            .find_map(|(account_name, pubkey)| (account_name == name).then_some(pubkey))
+    }
+
+    /// Decoded argument by IDL name
+    pub fn arg(&self, name: &str) -> Option<&Value> {
+        self.args
+            .iter()
+            .find_map(|(arg_name, value)| (arg_name == name).then_some(value))
+    }
+}
+
+impl AnchorIdlParser {
+    /// Initialize a parser from the IDL JSON
+    pub fn from_json(name: &str, program_id: Pubkey, idl: &str) -> Result<Self, serde_json::Error> {
+        let idl: IdlFile = serde_json::from_str(idl)?;
+
+        let instructions = idl
+            .instructions
+            .into_iter()
+            .map(|instruction| {
+                let discriminator = instruction.discriminator.unwrap_or_else(|| {
+                    Sha256::digest(format!("global:{}", instruction.name))[..8].to_vec()
+                });
+                let mut accounts = Vec::new();
+                flatten_accounts(&instruction.accounts, None, &mut accounts);
+
+                InstructionLayout {
+                    name: instruction.name,
+                    discriminator,
+                    accounts,
+                    args: instruction.args,
+                }
+            })
+            .collect();
+
+        let types = idl
+            .types
+            .into_iter()
+            .map(|type_def| (type_def.name, type_def.ty))
+            .collect();
+
+        Ok(Self {
+            name: name.to_string(),
+            program_id,
+            instructions,
+            types,
+        })
+    }
+
+    /// Instruction names, as written in the IDL
+    pub fn instruction_names(&self) -> impl Iterator<Item = &str> {
+        self.instructions
+            .iter()
+            .map(|instruction| instruction.name.as_str())
+    }
+
+    /// Parse an instruction of the program
+    pub fn parse<T: ParsableInstruction>(
+        &self,
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<AnchorIdlProgram> {
+        let data = instruction.data();
+        let layout = self.instructions.iter().find(|layout| {
+            !layout.discriminator.is_empty() && data.starts_with(&layout.discriminator)
+        })?;
+
+        let keys: Vec<Pubkey> = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize).copied())
+            .collect();
+        let accounts = layout.accounts.iter().cloned().zip(keys.clone()).collect();
+
+        let mut rest = &data[layout.discriminator.len()..];
+        let mut args = Vec::new();
+        for arg in layout.args.iter() {
+            match self.decode(&arg.ty, &mut rest) {
+                Some(value) => args.push((arg.name.clone(), value)),
+                None => break,
+            }
+        }
+
+        let ix = Instruction {
+            program_id: self.program_id,
+            accounts: keys
+                .into_iter()
+                .map(|pubkey| AccountMeta::new_readonly(pubkey, false))
+                .collect(),
+            data: data.to_vec(),
+        };
+
+        Some(AnchorIdlProgram {
+            program: self.name.clone(),
+            instruction: layout.name.clone(),
+            ix,
+            accounts,
+            args,
+        })
+    }
+
+    /// Decode a Borsh value of an IDL type
+    fn decode(&self, ty: &Value, data: &mut &[u8]) -> Option<Value> {
+        match ty {
+            Value::String(ty) => decode_primitive(ty, data),
+            Value::Object(ty) => {
+                if let Some(inner) = ty.get("option") {
+                    match take(data, 1)?[0] {
+                        0 => Some(Value::Null),
+                        1 => self.decode(inner, data),
+                        _ => None,
+                    }
+                } else if let Some(inner) = ty.get("vec") {
+                    let len = u32::from_le_bytes(take(data, 4)?.try_into().ok()?) as usize;
+                    if len > data.len() {
+                        return None;
+                    }
+                    (0..len)
+                        .map(|_| self.decode(inner, data))
+                        .collect::<Option<Vec<_>>>()
+                        .map(Value::Array)
+                } else if let Some([inner, len]) =
+                    ty.get("array").and_then(Value::as_array).map(Vec::as_slice)
+                {
+                    (0..len.as_u64()?)
+                        .map(|_| self.decode(inner, data))
+                        .collect::<Option<Vec<_>>>()
+                        .map(Value::Array)
+                } else if let Some(defined) = ty.get("defined") {
+                    let name = defined
+                        .as_str()
+                        .or_else(|| defined.get("name").and_then(Value::as_str))?;
+                    self.decode_defined(self.types.get(name)?, data)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Decode a Borsh value of a user-defined type
+    fn decode_defined(&self, ty: &IdlTypeDefTy, data: &mut &[u8]) -> Option<Value> {
+        match ty {
+            IdlTypeDefTy::Struct { fields } => self.decode_fields(fields, data),
+            IdlTypeDefTy::Enum { variants } => {
+                let variant = variants.get(take(data, 1)?[0] as usize)?;
+                if variant.fields.is_empty() {
+                    return Some(Value::String(variant.name.clone()));
+                }
+
+                let fields = self.decode_fields(&variant.fields, data)?;
+                Some(Value::Object(Map::from_iter([(
+                    variant.name.clone(),
+                    fields,
+                )])))
+            }
+            IdlTypeDefTy::Unsupported => None,
+        }
+    }
+
+    /// Decode named fields into an object, tuple fields into an array
+    fn decode_fields(&self, fields: &[Value], data: &mut &[u8]) -> Option<Value> {
+        let named = fields
+            .iter()
+            .all(|field| field.get("name").is_some() && field.get("type").is_some());
+
+        if named {
+            let mut object = Map::new();
+            for field in fields {
+                let name = field.get("name")?.as_str()?;
+                object.insert(name.to_string(), self.decode(field.get("type")?, data)?);
+            }
+            Some(Value::Object(object))
+        } else {
+            fields
+                .iter()
+                .map(|field| self.decode(field, data))
+                .collect::<Option<Vec<_>>>()
+                .map(Value::Array)
+        }
+    }
+}
+
+/// Flatten nested account groups into `<group>.<name>`
+fn flatten_accounts(items: &[IdlAccountItem], prefix: Option<&str>, accounts: &mut Vec<String>) {
+    for item in items {
+        let name = match item {
+            IdlAccountItem::Composite { name, .. } | IdlAccountItem::Single { name } => name,
+        };
+        let name = match prefix {
+            Some(prefix) => format!("{prefix}.{name}"),
+            None => name.clone(),
+        };
+
+        match item {
+            IdlAccountItem::Composite {
+                accounts: nested, ..
+            } => flatten_accounts(nested, Some(&name), accounts),
+            IdlAccountItem::Single { .. } => accounts.push(name),
+        }
+    }
+}
+
+/// Split off the first `len` bytes
+fn take<'a>(data: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if data.len() < len {
+        return None;
+    }
+    let (head, tail) = data.split_at(len);
+    *data = tail;
+    Some(head)
+}
+
+/// Decode a Borsh value of a primitive IDL type, 128-bit integers as strings
+fn decode_primitive(ty: &str, data: &mut &[u8]) -> Option<Value> {
+    macro_rules! le {
+        ($int:ty) => {
+            <$int>::from_le_bytes(take(data, std::mem::size_of::<$int>())?.try_into().ok()?)
+        };
+    }
+
+    let value = match ty {
+        "bool" => Value::Bool(take(data, 1)?[0] != 0),
+        "u8" => Value::from(le!(u8)),
+        "i8" => Value::from(le!(i8)),
+        "u16" => Value::from(le!(u16)),
+        "i16" => Value::from(le!(i16)),
+        "u32" => Value::from(le!(u32)),
+        "i32" => Value::from(le!(i32)),
+        "u64" => Value::from(le!(u64)),
+        "i64" => Value::from(le!(i64)),
+        "f32" => Value::from(le!(f32)),
+        "f64" => Value::from(le!(f64)),
+        "u128" => Value::String(le!(u128).to_string()),
+        "i128" => Value::String(le!(i128).to_string()),
+        "pubkey" | "publicKey" => {
+            Value::String(Pubkey::try_from(take(data, 32)?).ok()?.to_string())
+        }
+        "string" => {
+            let len = le!(u32) as usize;
+            Value::String(String::from_utf8(take(data, len)?.to_vec()).ok()?)
+        }
+        "bytes" => {
+            let len = le!(u32) as usize;
+            Value::String(STANDARD.encode(take(data, len)?))
+        }
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use sha2::{Digest, Sha256};
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::anchor_idl::AnchorIdlParser;
+
+    fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Keypair::new().pubkey()).collect()
+    }
+
+    #[test]
+    fn test_idl_instruction() {
+        let idl = json!({
+            "instructions": [{
+                "name": "deposit",
+                "discriminator": [1, 2, 3, 4, 5, 6, 7, 8],
+                "accounts": [
+                    { "name": "pool", "writable": true },
+                    { "name": "user", "accounts": [{ "name": "authority", "signer": true }] }
+                ],
+                "args": [
+                    { "name": "amount", "type": "u64" },
+                    { "name": "params", "type": { "defined": { "name": "Params" } } },
+                    { "name": "memo", "type": { "option": "string" } }
+                ]
+            }],
+            "types": [{
+                "name": "Params",
+                "type": {
+                    "kind": "struct",
+                    "fields": [
+                        { "name": "slippage_bps", "type": "u16" },
+                        { "name": "side", "type": { "defined": { "name": "Side" } } }
+                    ]
+                }
+            }, {
+                "name": "Side",
+                "type": { "kind": "enum", "variants": [{ "name": "Buy" }, { "name": "Sell" }] }
+            }]
+        });
+        let program_id = Pubkey::new_unique();
+        let parser =
+            AnchorIdlParser::from_json("my_program", program_id, &idl.to_string()).unwrap();
+
+        let mut data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        data.extend_from_slice(&5_000_000_000_u64.to_le_bytes());
+        data.extend_from_slice(&50_u16.to_le_bytes());
+        data.push(1);
+        data.push(0);
+
+        let account_keys = create_test_pubkeys(2);
+        let instruction = CompiledInstruction {
+            program_id_index: 2,
+            accounts: vec![0, 1],
+            data,
+        };
+
+        let program = parser.parse(&instruction, &account_keys).unwrap();
+
+        assert_eq!(program.program, "my_program");
+        assert_eq!(program.instruction, "deposit");
+        assert_eq!(program.account("pool"), Some(&account_keys[0]));
+        assert_eq!(program.account("user.authority"), Some(&account_keys[1]));
+        assert_eq!(program.arg("amount"), Some(&json!(5_000_000_000_u64)));
+        assert_eq!(
+            program.arg("params"),
+            Some(&json!({ "slippage_bps": 50, "side": "Sell" }))
+        );
+        assert_eq!(program.arg("memo"), Some(&Value::Null));
+    }
+
+    #[test]
+    fn test_legacy_idl_discriminator() {
+        let idl = json!({
+            "instructions": [{
+                "name": "withdraw",
+                "accounts": [{ "name": "pool", "isMut": true, "isSigner": false }],
+                "args": [{ "name": "owner", "type": "publicKey" }]
+            }]
+        });
+        let parser =
+            AnchorIdlParser::from_json("legacy", Pubkey::new_unique(), &idl.to_string()).unwrap();
+
+        let owner = Pubkey::new_unique();
+        let mut data = Sha256::digest("global:withdraw")[..8].to_vec();
+        data.extend_from_slice(owner.as_ref());
+
+        let instruction = CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data,
+        };
+
+        let program = parser.parse(&instruction, &create_test_pubkeys(1)).unwrap();
+
+        assert_eq!(program.instruction, "withdraw");
+        assert_eq!(program.arg("owner"), Some(&json!(owner.to_string())));
+    }
+
+    #[test]
+    fn test_unknown_discriminator() {
+        let idl = json!({ "instructions": [{ "name": "deposit", "accounts": [], "args": [] }] });
+        let parser =
+            AnchorIdlParser::from_json("my_program", Pubkey::new_unique(), &idl.to_string())
+                .unwrap();
+
+        let instruction = CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![],
+            data: vec![0; 8],
+        };
+
+        assert!(parser.parse(&instruction, &[]).is_none());
+    }
+}
@@ -0,0 +1,110 @@
+use std::str::FromStr;
+
+use solana_sdk::pubkey::Pubkey;
+
+use super::instruction::ParsableInstruction;
+
+/// Associated Token Account instruction discriminators (borsh u8 enum tag), empty data is `Create`
+const CREATE: u8 = 0;
+const CREATE_IDEMPOTENT: u8 = 1;
+
+/// SPL Associated Token Account Program
+///
+/// - `Create` and `CreateIdempotent` share the accounts:
+///
+/// #[account(0, writable, signer, name = "funding_account")]
+/// #[account(1, writable, name = "associated_token_account")]
+/// #[account(2, name = "wallet")]
+/// #[account(3, name = "token_mint")]
+/// #[account(4, name = "system_program")]
+/// #[account(5, name = "token_program")]
+pub struct SplAssociatedTokenAccountProgram;
+
+impl SplAssociatedTokenAccountProgram {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL").unwrap()
+    }
+
+    /// Parse SPL Associated Token Account Program, `(associated token account, mint)` of a
+    /// `Create` or `CreateIdempotent` instruction
+    pub fn parse_create<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<(Pubkey, Pubkey)> {
+        let program_id = account_keys.get(instruction.program_id_index() as usize)?;
+        if !program_id.eq(&Self::program_id()) {
+            return None;
+        }
+
+        match instruction.data().first() {
+            None | Some(&CREATE) | Some(&CREATE_IDEMPOTENT) => {}
+            _ => return None,
+        }
+
+        let account = |index: usize| {
+            instruction
+                .accounts()
+                .get(index)
+                .and_then(|account| account_keys.get(*account as usize))
+                .copied()
+        };
+
+        Some((account(1)?, account(3)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::associated_token_account::{
+        SplAssociatedTokenAccountProgram, CREATE_IDEMPOTENT,
+    };
+
+    fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
+        let mut account_keys: Vec<Pubkey> = (0..count).map(|_| Keypair::new().pubkey()).collect();
+        account_keys.push(SplAssociatedTokenAccountProgram::program_id());
+        account_keys
+    }
+
+    fn create_instruction(data: Vec<u8>) -> CompiledInstruction {
+        CompiledInstruction {
+            program_id_index: 6,
+            accounts: (0..6).collect(),
+            data,
+        }
+    }
+
+    #[test]
+    fn test_create() {
+        let account_keys = create_test_pubkeys(6);
+        let instruction = create_instruction(vec![]);
+
+        assert_eq!(
+            SplAssociatedTokenAccountProgram::parse_create(&instruction, &account_keys),
+            Some((account_keys[1], account_keys[3]))
+        );
+    }
+
+    #[test]
+    fn test_create_idempotent() {
+        let account_keys = create_test_pubkeys(6);
+        let instruction = create_instruction(vec![CREATE_IDEMPOTENT]);
+
+        assert_eq!(
+            SplAssociatedTokenAccountProgram::parse_create(&instruction, &account_keys),
+            Some((account_keys[1], account_keys[3]))
+        );
+    }
+
+    #[test]
+    fn test_recover_nested() {
+        let account_keys = create_test_pubkeys(6);
+        let instruction = create_instruction(vec![2]);
+
+        assert!(
+            SplAssociatedTokenAccountProgram::parse_create(&instruction, &account_keys).is_none()
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use solana_sdk::{compute_budget, pubkey::Pubkey};
+
+use super::instruction::ParsableInstruction;
+
+/// ComputeBudget instruction discriminators
+const SET_COMPUTE_UNIT_LIMIT: u8 = 2;
+const SET_COMPUTE_UNIT_PRICE: u8 = 3;
+
+/// Compute units allotted to each non-ComputeBudget instruction without `SetComputeUnitLimit`
+const DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT: u64 = 200_000;
+
+/// Maximum compute units of a transaction
+const MAX_COMPUTE_UNIT_LIMIT: u64 = 1_400_000;
+
+const MICRO_LAMPORTS_PER_LAMPORT: u128 = 1_000_000;
+
+/// Compute budget requested by a transaction
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ComputeBudget {
+    /// `SetComputeUnitLimit` units
+    pub compute_unit_limit: Option<u32>,
+
+    /// `SetComputeUnitPrice` price (micro-lamports per compute unit)
+    pub compute_unit_price: Option<u64>,
+
+    /// Number of top-level instructions other than ComputeBudget ones
+    pub instruction_count: u64,
+}
+
+impl ComputeBudget {
+    /// Record a top-level instruction, decoding it if it belongs to the ComputeBudget program
+    pub fn record<T: ParsableInstruction>(&mut self, instruction: &T, account_keys: &[Pubkey]) {
+        let is_compute_budget = account_keys
+            .get(instruction.program_id_index() as usize)
+            .is_some_and(|program_id| program_id.eq(&compute_budget::id()));
+        if !is_compute_budget {
+            self.instruction_count += 1;
+            return;
+        }
+
+        let data = instruction.data();
+        match data.first() {
+            Some(&SET_COMPUTE_UNIT_LIMIT) => {
+                if let Some(units) = data.get(1..5).and_then(|units| units.try_into().ok()) {
+                    self.compute_unit_limit = Some(u32::from_le_bytes(units));
+                }
+            }
+            Some(&SET_COMPUTE_UNIT_PRICE) => {
+                if let Some(price) = data.get(1..9).and_then(|price| price.try_into().ok()) {
+                    self.compute_unit_price = Some(u64::from_le_bytes(price));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Priority fee (lamports) of the requested compute units, `None` without `SetComputeUnitPrice`
+    pub fn priority_fee(&self) -> Option<u64> {
+        let price = self.compute_unit_price?;
+        let limit = match self.compute_unit_limit {
+            Some(limit) => u64::from(limit),
+            None => DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT.saturating_mul(self.instruction_count),
+        }
+        .min(MAX_COMPUTE_UNIT_LIMIT);
+
+        let micro_lamports = u128::from(price) * u128::from(limit);
+        let lamports = micro_lamports.div_ceil(MICRO_LAMPORTS_PER_LAMPORT);
+
+        Some(u64::try_from(lamports).unwrap_or(u64::MAX))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{compute_budget, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::compute_budget::ComputeBudget;
+
+    fn compute_budget_ix(data: Vec<u8>) -> CompiledInstruction {
+        CompiledInstruction {
+            program_id_index: 0,
+            accounts: vec![],
+            data,
+        }
+    }
+
+    #[test]
+    fn test_priority_fee() {
+        let account_keys = vec![compute_budget::id(), Keypair::new().pubkey()];
+
+        let mut limit = vec![2];
+        limit.extend_from_slice(&300_000_u32.to_le_bytes());
+        let mut price = vec![3];
+        price.extend_from_slice(&10_000_u64.to_le_bytes());
+
+        let mut compute_budget = ComputeBudget::default();
+        compute_budget.record(&compute_budget_ix(limit), &account_keys);
+        compute_budget.record(&compute_budget_ix(price), &account_keys);
+
+        // 10_000 micro-lamports * 300_000 units
+        assert_eq!(compute_budget.priority_fee(), Some(3_000));
+    }
+
+    #[test]
+    fn test_default_compute_unit_limit() {
+        let account_keys = vec![compute_budget::id(), Keypair::new().pubkey()];
+        let other = CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![],
+            data: vec![1, 2, 3],
+        };
+
+        let mut price = vec![3];
+        price.extend_from_slice(&1_u64.to_le_bytes());
+
+        let mut compute_budget = ComputeBudget::default();
+        assert_eq!(compute_budget.priority_fee(), None);
+
+        compute_budget.record(&compute_budget_ix(price), &account_keys);
+        compute_budget.record(&other, &account_keys);
+        compute_budget.record(&other, &account_keys);
+
+        // 1 micro-lamport * 400_000 units, rounded up
+        assert_eq!(compute_budget.priority_fee(), Some(1));
+    }
+}
@@ -0,0 +1,118 @@
+use std::str::FromStr;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::instruction::ParsableInstruction;
+
+/// Anchor instruction discriminators: sha256("global:<instruction name>")[..8]
+const CREATE_ORDER: [u8; 8] = [141, 54, 37, 207, 237, 210, 250, 215];
+const CREATE_ORDER_WITH_NONCE: [u8; 8] = [130, 131, 98, 190, 40, 206, 68, 50];
+
+/// deBridge DLN Source Program
+///
+/// - Orders give tokens on Solana to take tokens on another chain
+/// - Arguments start with the order, whose first field is `give_original_amount: u64`
+#[derive(Debug)]
+pub enum DebridgeDlnSourceProgram {
+    /// create_order(_with_nonce)
+    ///
+    /// #[account(0, writable, signer, name = "maker")]
+    /// #[account(1, name = "state")]
+    /// #[account(2, name = "token_mint")]
+    /// #[account(3, writable, name = "give_order_state")]
+    /// #[account(4, writable, name = "authorized_native_sender")]
+    /// #[account(5, writable, name = "maker_wallet")]
+    /// #[account(6, writable, name = "give_order_wallet")]
+    CreateOrder { ix: Instruction, amount: u64 },
+}
+
+impl std::fmt::Display for DebridgeDlnSourceProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DebridgeDlnSourceProgram::CreateOrder { .. } => write!(f, "create_order"),
+        }
+    }
+}
+
+impl DebridgeDlnSourceProgram {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("src5qyZHqTqecJV4aY6Cb6zDZLMDzrDKKezs22MPHr4").unwrap()
+    }
+
+    /// Parse deBridge DLN Source Program
+    pub fn parse_debridge_dln_source_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<DebridgeDlnSourceProgram> {
+        let data = instruction.data();
+        let discriminator = <[u8; 8]>::try_from(data.get(..8)?).ok()?;
+        if discriminator != CREATE_ORDER && discriminator != CREATE_ORDER_WITH_NONCE {
+            return None;
+        }
+        let amount = u64::from_le_bytes(data.get(8..16)?.try_into().ok()?);
+
+        let ix = Self::build_ix(instruction, account_keys);
+        if ix.accounts.len() < 3 {
+            return None;
+        }
+
+        Some(Self::CreateOrder { ix, amount })
+    }
+
+    /// Build instruction with the accounts in their original order
+    fn build_ix<T: ParsableInstruction>(instruction: &T, account_keys: &[Pubkey]) -> Instruction {
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: instruction.data().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::debridge::{DebridgeDlnSourceProgram, CREATE_ORDER_WITH_NONCE};
+
+    fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Keypair::new().pubkey()).collect()
+    }
+
+    #[test]
+    fn test_create_order_with_nonce() {
+        let account_keys = create_test_pubkeys(12);
+
+        let mut data = CREATE_ORDER_WITH_NONCE.to_vec();
+        data.extend_from_slice(&3_000_000_000_u64.to_le_bytes());
+
+        let instruction = CompiledInstruction {
+            program_id_index: 12,
+            accounts: (0..12).collect(),
+            data,
+        };
+
+        let parsed = DebridgeDlnSourceProgram::parse_debridge_dln_source_program(
+            &instruction,
+            &account_keys,
+        );
+
+        if let Some(DebridgeDlnSourceProgram::CreateOrder { ix, amount }) = parsed {
+            assert_eq!(amount, 3_000_000_000);
+            assert_eq!(ix.accounts[2].pubkey, account_keys[2]);
+        } else {
+            panic!("Expected CreateOrder variant");
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use std::str::FromStr;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::instruction::ParsableInstruction;
+
+/// SPL Governance instruction discriminators (borsh u8 enum tag)
+const CREATE_PROPOSAL: u8 = 6;
+const EXECUTE_TRANSACTION: u8 = 16;
+
+/// SPL Governance (Realms) Program
+#[derive(Debug)]
+pub enum SplGovernanceProgram {
+    /// #[account(0, name = "realm")]
+    /// #[account(1, writable, name = "proposal")]
+    /// #[account(2, writable, name = "governance")]
+    /// #[account(3, writable, name = "proposal_owner_record")]
+    /// #[account(4, name = "governing_token_mint")]
+    /// #[account(5, signer, name = "governance_authority")]
+    /// #[account(6, signer, name = "payer")]
+    CreateProposal { ix: Instruction },
+
+    /// #[account(0, name = "governance")]
+    /// #[account(1, writable, name = "proposal")]
+    /// #[account(2, writable, name = "proposal_transaction")]
+    ExecuteTransaction { ix: Instruction },
+}
+
+impl std::fmt::Display for SplGovernanceProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SplGovernanceProgram::CreateProposal { .. } => write!(f, "create_proposal"),
+            SplGovernanceProgram::ExecuteTransaction { .. } => write!(f, "execute_transaction"),
+        }
+    }
+}
+
+impl SplGovernanceProgram {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("GovER5Lthms3bLBqWub97yVrMmEogzX7xNjdXpPPCVZw").unwrap()
+    }
+
+    /// Parse SPL Governance Program, only proposal creation and execution are parsed
+    pub fn parse_spl_governance_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<SplGovernanceProgram> {
+        let discriminator = *instruction.data().first()?;
+        let ix = Self::build_ix(instruction, account_keys);
+
+        let program = match discriminator {
+            CREATE_PROPOSAL if ix.accounts.len() > 2 => Self::CreateProposal { ix },
+            EXECUTE_TRANSACTION if ix.accounts.len() > 1 => Self::ExecuteTransaction { ix },
+            _ => return None,
+        };
+
+        Some(program)
+    }
+
+    /// Proposal account of the instruction
+    pub fn proposal(&self) -> Pubkey {
+        match self {
+            SplGovernanceProgram::CreateProposal { ix }
+            | SplGovernanceProgram::ExecuteTransaction { ix } => ix.accounts[1].pubkey,
+        }
+    }
+
+    /// Governance account of the instruction
+    pub fn governance(&self) -> Pubkey {
+        match self {
+            SplGovernanceProgram::CreateProposal { ix } => ix.accounts[2].pubkey,
+            SplGovernanceProgram::ExecuteTransaction { ix } => ix.accounts[0].pubkey,
+        }
+    }
+
+    /// Build instruction with the accounts in their original order
+    fn build_ix<T: ParsableInstruction>(instruction: &T, account_keys: &[Pubkey]) -> Instruction {
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: instruction.data().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::governance::{SplGovernanceProgram, CREATE_PROPOSAL, EXECUTE_TRANSACTION};
+
+    fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Keypair::new().pubkey()).collect()
+    }
+
+    #[test]
+    fn test_create_proposal() {
+        let account_keys = create_test_pubkeys(11);
+
+        let name = "Raise epoch fee";
+        let mut data = vec![CREATE_PROPOSAL];
+        data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        data.extend_from_slice(name.as_bytes());
+
+        let instruction = CompiledInstruction {
+            program_id_index: 11,
+            accounts: (0..11).collect(),
+            data,
+        };
+
+        let parsed =
+            SplGovernanceProgram::parse_spl_governance_program(&instruction, &account_keys)
+                .expect("Expected CreateProposal variant");
+
+        assert!(matches!(
+            parsed,
+            SplGovernanceProgram::CreateProposal { .. }
+        ));
+        assert_eq!(parsed.proposal(), account_keys[1]);
+        assert_eq!(parsed.governance(), account_keys[2]);
+    }
+
+    #[test]
+    fn test_execute_transaction() {
+        let account_keys = create_test_pubkeys(5);
+
+        let instruction = CompiledInstruction {
+            program_id_index: 5,
+            accounts: (0..5).collect(),
+            data: vec![EXECUTE_TRANSACTION],
+        };
+
+        let parsed =
+            SplGovernanceProgram::parse_spl_governance_program(&instruction, &account_keys)
+                .expect("Expected ExecuteTransaction variant");
+
+        assert!(matches!(
+            parsed,
+            SplGovernanceProgram::ExecuteTransaction { .. }
+        ));
+        assert_eq!(parsed.proposal(), account_keys[1]);
+        assert_eq!(parsed.governance(), account_keys[0]);
+    }
+
+    #[test]
+    fn test_cast_vote() {
+        let account_keys = create_test_pubkeys(3);
+
+        let instruction = CompiledInstruction {
+            program_id_index: 3,
+            accounts: vec![0, 1, 2],
+            data: vec![13, 0],
+        };
+
+        assert!(
+            SplGovernanceProgram::parse_spl_governance_program(&instruction, &account_keys)
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::str::FromStr;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::instruction::ParsableInstruction;
+
+/// Anchor instruction discriminators: sha256("global:<instruction name>")[..8]
+const ROUTE: [u8; 8] = [229, 23, 203, 151, 122, 227, 173, 42];
+const SHARED_ACCOUNTS_ROUTE: [u8; 8] = [193, 32, 155, 51, 65, 214, 156, 129];
+const EXACT_OUT_ROUTE: [u8; 8] = [208, 51, 239, 151, 123, 43, 237, 92];
+const SHARED_ACCOUNTS_EXACT_OUT_ROUTE: [u8; 8] = [176, 209, 105, 168, 154, 125, 69, 62];
+
+/// Every route instruction ends with `(u64, u64, slippage_bps: u16, platform_fee_bps: u8)`
+const ROUTE_ARGS_TAIL_LEN: usize = 8 + 8 + 2 + 1;
+
+/// Token flow of a swap
+#[derive(Debug, Clone, PartialEq)]
+pub struct JupiterSwap {
+    /// Token account the input is taken from
+    pub source_token_account: Pubkey,
+
+    /// Input mint, `route` does not pass it (resolve it from the source token account)
+    pub source_mint: Option<Pubkey>,
+
+    /// Output mint
+    pub destination_mint: Pubkey,
+
+    /// Input amount (quoted for exact-out routes)
+    pub in_amount: u64,
+
+    /// Output amount (quoted for exact-in routes)
+    pub out_amount: u64,
+}
+
+/// Jupiter Aggregator v6 Program
+#[derive(Debug)]
+pub enum JupiterProgram {
+    /// route, shared_accounts_route, exact_out_route and shared_accounts_exact_out_route
+    Swap { ix: Instruction, swap: JupiterSwap },
+}
+
+impl std::fmt::Display for JupiterProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JupiterProgram::Swap { .. } => write!(f, "swap"),
+        }
+    }
+}
+
+impl JupiterProgram {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4").unwrap()
+    }
+
+    /// Parse Jupiter Program
+    ///
+    /// - The route plan is skipped, amounts are read from the fixed-size tail of the arguments
+    pub fn parse_jupiter_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<JupiterProgram> {
+        let data = instruction.data();
+        let discriminator = <[u8; 8]>::try_from(data.get(..8)?).ok()?;
+        let tail = data.get(data.len().checked_sub(ROUTE_ARGS_TAIL_LEN)?..)?;
+        let first = u64::from_le_bytes(tail.get(..8)?.try_into().ok()?);
+        let second = u64::from_le_bytes(tail.get(8..16)?.try_into().ok()?);
+
+        let ix = Self::build_ix(instruction, account_keys);
+        let account = |index: usize| ix.accounts.get(index).map(|meta| meta.pubkey);
+
+        let swap = match discriminator {
+            ROUTE => JupiterSwap {
+                source_token_account: account(2)?,
+                source_mint: None,
+                destination_mint: account(5)?,
+                in_amount: first,
+                out_amount: second,
+            },
+            EXACT_OUT_ROUTE => JupiterSwap {
+                source_token_account: account(2)?,
+                source_mint: Some(account(5)?),
+                destination_mint: account(6)?,
+                in_amount: second,
+                out_amount: first,
+            },
+            SHARED_ACCOUNTS_ROUTE => JupiterSwap {
+                source_token_account: account(3)?,
+                source_mint: Some(account(7)?),
+                destination_mint: account(8)?,
+                in_amount: first,
+                out_amount: second,
+            },
+            SHARED_ACCOUNTS_EXACT_OUT_ROUTE => JupiterSwap {
+                source_token_account: account(3)?,
+                source_mint: Some(account(7)?),
+                destination_mint: account(8)?,
+                in_amount: second,
+                out_amount: first,
+            },
+            _ => return None,
+        };
+
+        Some(Self::Swap { ix, swap })
+    }
+
+    /// Build instruction with the accounts in their original order
+    fn build_ix<T: ParsableInstruction>(instruction: &T, account_keys: &[Pubkey]) -> Instruction {
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: instruction.data().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::jupiter::{JupiterProgram, EXACT_OUT_ROUTE, SHARED_ACCOUNTS_ROUTE};
+
+    fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Keypair::new().pubkey()).collect()
+    }
+
+    fn route_data(discriminator: [u8; 8], first: u64, second: u64) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        // Route plan (opaque to the parser)
+        data.extend_from_slice(&[1, 0, 0, 0, 7, 100, 0, 1]);
+        data.extend_from_slice(&first.to_le_bytes());
+        data.extend_from_slice(&second.to_le_bytes());
+        data.extend_from_slice(&50_u16.to_le_bytes());
+        data.push(0);
+        data
+    }
+
+    #[test]
+    fn test_shared_accounts_route() {
+        let num_account = 13;
+        let account_keys = create_test_pubkeys(num_account);
+        let instruction = CompiledInstruction {
+            program_id_index: 1,
+            accounts: (0..num_account).map(|i| i as u8).collect(),
+            data: route_data(SHARED_ACCOUNTS_ROUTE, 1_000, 990),
+        };
+
+        let Some(JupiterProgram::Swap { swap, .. }) =
+            JupiterProgram::parse_jupiter_program(&instruction, &account_keys)
+        else {
+            panic!("Expected Swap variant");
+        };
+
+        assert_eq!(swap.source_token_account, account_keys[3]);
+        assert_eq!(swap.source_mint, Some(account_keys[7]));
+        assert_eq!(swap.destination_mint, account_keys[8]);
+        assert_eq!(swap.in_amount, 1_000);
+        assert_eq!(swap.out_amount, 990);
+    }
+
+    #[test]
+    fn test_exact_out_route() {
+        let num_account = 11;
+        let account_keys = create_test_pubkeys(num_account);
+        let instruction = CompiledInstruction {
+            program_id_index: 1,
+            accounts: (0..num_account).map(|i| i as u8).collect(),
+            data: route_data(EXACT_OUT_ROUTE, 500, 510),
+        };
+
+        let Some(JupiterProgram::Swap { swap, .. }) =
+            JupiterProgram::parse_jupiter_program(&instruction, &account_keys)
+        else {
+            panic!("Expected Swap variant");
+        };
+
+        assert_eq!(swap.source_mint, Some(account_keys[5]));
+        assert_eq!(swap.destination_mint, account_keys[6]);
+        assert_eq!(swap.in_amount, 510);
+        assert_eq!(swap.out_amount, 500);
+    }
+}
@@ -0,0 +1,174 @@
+use std::str::FromStr;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::instruction::ParsableInstruction;
+
+/// Anchor instruction discriminators: sha256("global:<instruction name>")[..8]
+const DEPOSIT_RESERVE_LIQUIDITY_AND_OBLIGATION_COLLATERAL: [u8; 8] =
+    [129, 199, 4, 2, 222, 39, 26, 46];
+const DEPOSIT_RESERVE_LIQUIDITY_AND_OBLIGATION_COLLATERAL_V2: [u8; 8] =
+    [216, 224, 191, 27, 204, 151, 102, 175];
+const WITHDRAW_OBLIGATION_COLLATERAL_AND_REDEEM_RESERVE_COLLATERAL: [u8; 8] =
+    [75, 93, 93, 220, 34, 150, 218, 196];
+const WITHDRAW_OBLIGATION_COLLATERAL_AND_REDEEM_RESERVE_COLLATERAL_V2: [u8; 8] =
+    [235, 52, 119, 152, 149, 197, 20, 7];
+
+/// Kamino Lend Program
+///
+/// - The v2 instructions put the same accounts first, followed by farm accounts
+#[derive(Debug)]
+pub enum KaminoLendProgram {
+    /// deposit_reserve_liquidity_and_obligation_collateral(_v2)
+    ///
+    /// #[account(0, signer, name = "owner")]
+    /// #[account(1, writable, name = "obligation")]
+    /// #[account(2, name = "lending_market")]
+    /// #[account(3, name = "lending_market_authority")]
+    /// #[account(4, writable, name = "reserve")]
+    /// #[account(5, name = "reserve_liquidity_mint")]
+    /// #[account(6, writable, name = "reserve_liquidity_supply")]
+    /// #[account(7, writable, name = "reserve_collateral_mint")]
+    /// #[account(8, writable, name = "reserve_destination_deposit_collateral")]
+    /// #[account(9, writable, name = "user_source_liquidity")]
+    DepositCollateral { ix: Instruction, amount: u64 },
+
+    /// withdraw_obligation_collateral_and_redeem_reserve_collateral(_v2), amount in reserve collateral tokens
+    ///
+    /// #[account(0, signer, name = "owner")]
+    /// #[account(1, writable, name = "obligation")]
+    /// #[account(2, name = "lending_market")]
+    /// #[account(3, name = "lending_market_authority")]
+    /// #[account(4, writable, name = "withdraw_reserve")]
+    /// #[account(5, name = "reserve_liquidity_mint")]
+    /// #[account(6, writable, name = "reserve_source_collateral")]
+    /// #[account(7, writable, name = "reserve_collateral_mint")]
+    /// #[account(8, writable, name = "reserve_liquidity_supply")]
+    /// #[account(9, writable, name = "user_destination_liquidity")]
+    WithdrawCollateral { ix: Instruction, amount: u64 },
+}
+
+impl std::fmt::Display for KaminoLendProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KaminoLendProgram::DepositCollateral { .. } => write!(f, "deposit_collateral"),
+            KaminoLendProgram::WithdrawCollateral { .. } => write!(f, "withdraw_collateral"),
+        }
+    }
+}
+
+impl KaminoLendProgram {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD").unwrap()
+    }
+
+    /// Parse Kamino Lend Program
+    pub fn parse_kamino_lend_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<KaminoLendProgram> {
+        let data = instruction.data();
+        let discriminator = <[u8; 8]>::try_from(data.get(..8)?).ok()?;
+        let amount = u64::from_le_bytes(data.get(8..16)?.try_into().ok()?);
+        let ix = Self::build_ix(instruction, account_keys);
+
+        let program = match discriminator {
+            DEPOSIT_RESERVE_LIQUIDITY_AND_OBLIGATION_COLLATERAL
+            | DEPOSIT_RESERVE_LIQUIDITY_AND_OBLIGATION_COLLATERAL_V2 => {
+                Self::DepositCollateral { ix, amount }
+            }
+            WITHDRAW_OBLIGATION_COLLATERAL_AND_REDEEM_RESERVE_COLLATERAL
+            | WITHDRAW_OBLIGATION_COLLATERAL_AND_REDEEM_RESERVE_COLLATERAL_V2 => {
+                Self::WithdrawCollateral { ix, amount }
+            }
+            _ => return None,
+        };
+
+        Some(program)
+    }
+
+    /// Build instruction with the accounts in their original order
+    fn build_ix<T: ParsableInstruction>(instruction: &T, account_keys: &[Pubkey]) -> Instruction {
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: instruction.data().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::kamino::{
+        KaminoLendProgram, DEPOSIT_RESERVE_LIQUIDITY_AND_OBLIGATION_COLLATERAL_V2,
+        WITHDRAW_OBLIGATION_COLLATERAL_AND_REDEEM_RESERVE_COLLATERAL,
+    };
+
+    fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Keypair::new().pubkey()).collect()
+    }
+
+    fn create_instruction(
+        num_account: usize,
+        discriminator: [u8; 8],
+        amount: u64,
+    ) -> CompiledInstruction {
+        let mut data = discriminator.to_vec();
+        data.extend_from_slice(&amount.to_le_bytes());
+
+        CompiledInstruction {
+            program_id_index: 1,
+            accounts: (0..num_account).map(|i| i as u8).collect(),
+            data,
+        }
+    }
+
+    #[test]
+    fn test_deposit_collateral_v2() {
+        let account_keys = create_test_pubkeys(17);
+        let instruction = create_instruction(
+            17,
+            DEPOSIT_RESERVE_LIQUIDITY_AND_OBLIGATION_COLLATERAL_V2,
+            3_000_000_000,
+        );
+
+        let parsed = KaminoLendProgram::parse_kamino_lend_program(&instruction, &account_keys);
+
+        if let Some(KaminoLendProgram::DepositCollateral { ix, amount }) = parsed {
+            assert_eq!(amount, 3_000_000_000);
+            assert_eq!(ix.accounts[5].pubkey, account_keys[5]);
+        } else {
+            panic!("Expected DepositCollateral variant");
+        }
+    }
+
+    #[test]
+    fn test_withdraw_collateral() {
+        let account_keys = create_test_pubkeys(14);
+        let instruction = create_instruction(
+            14,
+            WITHDRAW_OBLIGATION_COLLATERAL_AND_REDEEM_RESERVE_COLLATERAL,
+            1_000,
+        );
+
+        let parsed = KaminoLendProgram::parse_kamino_lend_program(&instruction, &account_keys);
+
+        assert!(matches!(
+            parsed,
+            Some(KaminoLendProgram::WithdrawCollateral { amount: 1_000, .. })
+        ));
+    }
+}
@@ -0,0 +1,128 @@
+use std::str::FromStr;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::instruction::ParsableInstruction;
+
+/// Anchor instruction discriminators: sha256("global:<instruction name>")[..8]
+const LENDING_ACCOUNT_DEPOSIT: [u8; 8] = [171, 94, 235, 103, 82, 64, 212, 140];
+const LENDING_ACCOUNT_WITHDRAW: [u8; 8] = [36, 72, 74, 19, 210, 210, 192, 192];
+
+/// marginfi v2 Program
+///
+/// - The bank mint is not an instruction account, resolve it from the user token account
+#[derive(Debug)]
+pub enum MarginfiProgram {
+    /// lending_account_deposit
+    ///
+    /// #[account(0, name = "marginfi_group")]
+    /// #[account(1, writable, name = "marginfi_account")]
+    /// #[account(2, signer, name = "signer")]
+    /// #[account(3, writable, name = "bank")]
+    /// #[account(4, writable, name = "signer_token_account")]
+    /// #[account(5, writable, name = "bank_liquidity_vault")]
+    /// #[account(6, name = "token_program")]
+    Deposit { ix: Instruction, amount: u64 },
+
+    /// lending_account_withdraw
+    ///
+    /// #[account(0, name = "marginfi_group")]
+    /// #[account(1, writable, name = "marginfi_account")]
+    /// #[account(2, signer, name = "signer")]
+    /// #[account(3, writable, name = "bank")]
+    /// #[account(4, writable, name = "destination_token_account")]
+    /// #[account(5, writable, name = "bank_liquidity_vault_authority")]
+    /// #[account(6, writable, name = "bank_liquidity_vault")]
+    /// #[account(7, name = "token_program")]
+    Withdraw { ix: Instruction, amount: u64 },
+}
+
+impl std::fmt::Display for MarginfiProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MarginfiProgram::Deposit { .. } => write!(f, "deposit"),
+            MarginfiProgram::Withdraw { .. } => write!(f, "withdraw"),
+        }
+    }
+}
+
+impl MarginfiProgram {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("MFv2hWf31Z9kbCa1snEPYctwafyhdvnV7FDnsc3Ykc8").unwrap()
+    }
+
+    /// Parse marginfi Program
+    pub fn parse_marginfi_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<MarginfiProgram> {
+        let data = instruction.data();
+        let discriminator = <[u8; 8]>::try_from(data.get(..8)?).ok()?;
+        let amount = u64::from_le_bytes(data.get(8..16)?.try_into().ok()?);
+        let ix = Self::build_ix(instruction, account_keys);
+
+        let program = match discriminator {
+            LENDING_ACCOUNT_DEPOSIT => Self::Deposit { ix, amount },
+            LENDING_ACCOUNT_WITHDRAW => Self::Withdraw { ix, amount },
+            _ => return None,
+        };
+
+        Some(program)
+    }
+
+    /// Build instruction with the accounts in their original order
+    fn build_ix<T: ParsableInstruction>(instruction: &T, account_keys: &[Pubkey]) -> Instruction {
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: instruction.data().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::marginfi::{MarginfiProgram, LENDING_ACCOUNT_DEPOSIT};
+
+    fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Keypair::new().pubkey()).collect()
+    }
+
+    #[test]
+    fn test_deposit() {
+        let account_keys = create_test_pubkeys(7);
+
+        let mut data = LENDING_ACCOUNT_DEPOSIT.to_vec();
+        data.extend_from_slice(&2_000_000_000_u64.to_le_bytes());
+        // deposit_up_to_limit: None
+        data.push(0);
+
+        let instruction = CompiledInstruction {
+            program_id_index: 1,
+            accounts: (0..7).collect(),
+            data,
+        };
+
+        let parsed = MarginfiProgram::parse_marginfi_program(&instruction, &account_keys);
+
+        if let Some(MarginfiProgram::Deposit { ix, amount }) = parsed {
+            assert_eq!(amount, 2_000_000_000);
+            assert_eq!(ix.accounts[4].pubkey, account_keys[4]);
+        } else {
+            panic!("Expected Deposit variant");
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::str::FromStr;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    native_token::lamports_to_sol,
+    pubkey::Pubkey,
+};
+
+use super::instruction::ParsableInstruction;
+
+/// Anchor instruction discriminators: sha256("global:<instruction name>")[..8]
+const DEPOSIT: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
+const LIQUID_UNSTAKE: [u8; 8] = [30, 30, 119, 240, 191, 227, 12, 16];
+const ORDER_UNSTAKE: [u8; 8] = [97, 167, 144, 107, 117, 190, 128, 36];
+
+/// Marinade Liquid Staking Program
+#[derive(Debug)]
+pub enum MarinadeProgram {
+    /// #[account(0, writable, name = "state")]
+    /// #[account(1, writable, name = "msol_mint")]
+    /// #[account(2, writable, name = "liq_pool_sol_leg_pda")]
+    /// #[account(3, writable, name = "liq_pool_msol_leg")]
+    /// #[account(4, name = "liq_pool_msol_leg_authority")]
+    /// #[account(5, writable, name = "reserve_pda")]
+    /// #[account(6, writable, signer, name = "transfer_from")]
+    /// #[account(7, writable, name = "mint_to")]
+    /// #[account(8, name = "msol_mint_authority")]
+    /// #[account(9, name = "system_program")]
+    /// #[account(10, name = "token_program")]
+    Deposit { ix: Instruction, amount: f64 },
+
+    /// #[account(0, writable, name = "state")]
+    /// #[account(1, writable, name = "msol_mint")]
+    /// #[account(2, writable, name = "liq_pool_sol_leg_pda")]
+    /// #[account(3, writable, name = "liq_pool_msol_leg")]
+    /// #[account(4, writable, name = "treasury_msol_account")]
+    /// #[account(5, writable, name = "get_msol_from")]
+    /// #[account(6, signer, name = "get_msol_from_authority")]
+    /// #[account(7, writable, name = "transfer_sol_to")]
+    /// #[account(8, name = "system_program")]
+    /// #[account(9, name = "token_program")]
+    LiquidUnstake { ix: Instruction, amount: f64 },
+
+    /// #[account(0, writable, name = "state")]
+    /// #[account(1, writable, name = "msol_mint")]
+    /// #[account(2, writable, name = "burn_msol_from")]
+    /// #[account(3, signer, name = "burn_msol_authority")]
+    /// #[account(4, writable, name = "new_ticket_account")]
+    /// #[account(5, name = "clock")]
+    /// #[account(6, name = "rent")]
+    /// #[account(7, name = "token_program")]
+    OrderUnstake { ix: Instruction, amount: f64 },
+}
+
+impl std::fmt::Display for MarinadeProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MarinadeProgram::Deposit { .. } => write!(f, "deposit"),
+            MarinadeProgram::LiquidUnstake { .. } => write!(f, "liquid_unstake"),
+            MarinadeProgram::OrderUnstake { .. } => write!(f, "order_unstake"),
+        }
+    }
+}
+
+impl MarinadeProgram {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("MarBmsSgKXdrN1egZf5sqe1TMai9K1rChYNDJgjq7aD").unwrap()
+    }
+
+    /// Parse Marinade Program
+    ///
+    /// - Amounts are in SOL (deposit) or mSOL (unstake), both have 9 decimals
+    pub fn parse_marinade_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<MarinadeProgram> {
+        let data = instruction.data();
+        let discriminator = <[u8; 8]>::try_from(data.get(..8)?).ok()?;
+        let amount = lamports_to_sol(u64::from_le_bytes(data.get(8..16)?.try_into().ok()?));
+        let ix = Self::build_ix(instruction, account_keys);
+
+        let program = match discriminator {
+            DEPOSIT => Self::Deposit { ix, amount },
+            LIQUID_UNSTAKE => Self::LiquidUnstake { ix, amount },
+            ORDER_UNSTAKE => Self::OrderUnstake { ix, amount },
+            _ => return None,
+        };
+
+        Some(program)
+    }
+
+    /// Build instruction with the accounts in their original order
+    fn build_ix<T: ParsableInstruction>(instruction: &T, account_keys: &[Pubkey]) -> Instruction {
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: instruction.data().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::marinade::{MarinadeProgram, DEPOSIT, ORDER_UNSTAKE};
+
+    fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Keypair::new().pubkey()).collect()
+    }
+
+    fn create_instruction(
+        num_account: usize,
+        discriminator: [u8; 8],
+        amount: u64,
+    ) -> CompiledInstruction {
+        let mut data = discriminator.to_vec();
+        data.extend_from_slice(&amount.to_le_bytes());
+
+        CompiledInstruction {
+            program_id_index: 1,
+            accounts: (0..num_account).map(|i| i as u8).collect(),
+            data,
+        }
+    }
+
+    #[test]
+    fn test_deposit() {
+        let account_keys = create_test_pubkeys(11);
+        let instruction = create_instruction(11, DEPOSIT, 1_500_000_000);
+
+        let parsed = MarinadeProgram::parse_marinade_program(&instruction, &account_keys);
+
+        if let Some(MarinadeProgram::Deposit { ix, amount }) = parsed {
+            assert_eq!(amount, 1.5);
+            assert_eq!(ix.accounts[1].pubkey, account_keys[1]);
+        } else {
+            panic!("Expected Deposit variant");
+        }
+    }
+
+    #[test]
+    fn test_order_unstake() {
+        let account_keys = create_test_pubkeys(8);
+        let instruction = create_instruction(8, ORDER_UNSTAKE, 2_000_000_000);
+
+        let parsed = MarinadeProgram::parse_marinade_program(&instruction, &account_keys);
+
+        if let Some(MarinadeProgram::OrderUnstake { amount, .. }) = parsed {
+            assert_eq!(amount, 2.0);
+        } else {
+            panic!("Expected OrderUnstake variant");
+        }
+    }
+}
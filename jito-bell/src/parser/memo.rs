@@ -0,0 +1,70 @@
+use std::str::FromStr;
+
+use solana_sdk::pubkey::Pubkey;
+
+use super::instruction::ParsableInstruction;
+
+/// SPL Memo Program (v1 and v2)
+pub struct SplMemoProgram;
+
+impl SplMemoProgram {
+    pub fn program_ids() -> [Pubkey; 2] {
+        [
+            Pubkey::from_str("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr").unwrap(),
+            Pubkey::from_str("Memo1UhkJRfHyvLMcVucJwxXeuD728EQVDDwQDxFMNo").unwrap(),
+        ]
+    }
+
+    /// Parse SPL Memo Program, the instruction data is the UTF-8 memo
+    pub fn parse_memo<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<String> {
+        let program_id = account_keys.get(instruction.program_id_index() as usize)?;
+        if !Self::program_ids().contains(program_id) {
+            return None;
+        }
+
+        let memo = std::str::from_utf8(instruction.data()).ok()?.trim();
+        if memo.is_empty() {
+            return None;
+        }
+
+        Some(memo.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::memo::SplMemoProgram;
+
+    #[test]
+    fn test_memo() {
+        let account_keys = vec![Keypair::new().pubkey(), SplMemoProgram::program_ids()[0]];
+        let instruction = CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: b"treasury rebalance #42".to_vec(),
+        };
+
+        assert_eq!(
+            SplMemoProgram::parse_memo(&instruction, &account_keys).as_deref(),
+            Some("treasury rebalance #42")
+        );
+    }
+
+    #[test]
+    fn test_other_program() {
+        let account_keys = vec![Keypair::new().pubkey(), Keypair::new().pubkey()];
+        let instruction = CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: b"not a memo".to_vec(),
+        };
+
+        assert!(SplMemoProgram::parse_memo(&instruction, &account_keys).is_none());
+    }
+}
@@ -1,27 +1,297 @@
+use std::{collections::HashMap, str::FromStr};
+
+use anchor_idl::{AnchorIdlParser, AnchorIdlProgram};
+use associated_token_account::SplAssociatedTokenAccountProgram;
+use compute_budget::ComputeBudget;
+use debridge::DebridgeDlnSourceProgram;
+use governance::SplGovernanceProgram;
+use instruction::ParsableInstruction;
+use jupiter::JupiterProgram;
+use kamino::KaminoLendProgram;
+use marginfi::MarginfiProgram;
+use marinade::MarinadeProgram;
+use memo::SplMemoProgram;
+use orca::OrcaWhirlpoolProgram;
+use raydium::RaydiumCpmmProgram;
+use single_pool::SplSinglePoolProgram;
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use squads::SquadsMultisigProgram;
 use stake_pool::SplStakePoolProgram;
+use steward::JitoStewardProgram;
+use system::SystemProgram;
+use tip_distribution::JitoTipDistributionProgram;
+use tip_payment::JitoTipPaymentProgram;
+use tip_router::JitoTipRouterProgram;
 use token_2022::SplToken2022Program;
 use vault::JitoVaultProgram;
+use vote::VoteProgram;
+use wormhole::WormholeTokenBridgeProgram;
+use wsol::WsolInstruction;
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransaction;
 
+pub mod anchor_idl;
+pub mod associated_token_account;
+pub mod compute_budget;
+pub mod debridge;
+pub mod governance;
 pub mod instruction;
+pub mod jupiter;
+pub mod kamino;
+pub mod marginfi;
+pub mod marinade;
+pub mod memo;
+pub mod orca;
+pub mod raydium;
+pub mod single_pool;
+pub mod squads;
 pub mod stake_pool;
+pub mod steward;
+pub mod system;
+pub mod tip_distribution;
+pub mod tip_payment;
+pub mod tip_router;
 pub mod token_2022;
 pub mod vault;
+pub mod vote;
+pub mod wormhole;
+pub mod wsol;
 
 #[derive(Debug)]
 pub enum JitoBellProgram {
     SplToken2022(SplToken2022Program),
     SplStakePool(SplStakePoolProgram),
     JitoVault(JitoVaultProgram),
+    JitoSteward(JitoStewardProgram),
+    JitoTipRouter(JitoTipRouterProgram),
+    JitoTipPayment(JitoTipPaymentProgram),
+    JitoTipDistribution(JitoTipDistributionProgram),
+    Marinade(MarinadeProgram),
+    System(SystemProgram),
+    Jupiter(JupiterProgram),
+    KaminoLend(KaminoLendProgram),
+    Marginfi(MarginfiProgram),
+    OrcaWhirlpool(OrcaWhirlpoolProgram),
+    RaydiumCpmm(RaydiumCpmmProgram),
+    Vote(VoteProgram),
+    SplGovernance(SplGovernanceProgram),
+    SquadsMultisig(SquadsMultisigProgram),
+    WormholeTokenBridge(WormholeTokenBridgeProgram),
+    DebridgeDlnSource(DebridgeDlnSourceProgram),
+    SplSinglePool(SplSinglePoolProgram),
+    AnchorIdl(AnchorIdlProgram),
 }
 
+const SPL_TOKEN_2022_PROGRAM_NAME: &str = "spl-token-2022";
+const SPL_STAKE_POOL_PROGRAM_NAME: &str = "spl_stake_pool";
+const JITO_VAULT_PROGRAM_NAME: &str = "jito_vault";
+const JITO_STEWARD_PROGRAM_NAME: &str = "jito_steward";
+const JITO_TIP_ROUTER_PROGRAM_NAME: &str = "jito_tip_router";
+const JITO_TIP_PAYMENT_PROGRAM_NAME: &str = "jito_tip_payment";
+const JITO_TIP_DISTRIBUTION_PROGRAM_NAME: &str = "jito_tip_distribution";
+const MARINADE_PROGRAM_NAME: &str = "marinade";
+const SYSTEM_PROGRAM_NAME: &str = "system";
+const JUPITER_PROGRAM_NAME: &str = "jupiter";
+const KAMINO_LEND_PROGRAM_NAME: &str = "kamino_lend";
+const MARGINFI_PROGRAM_NAME: &str = "marginfi";
+const ORCA_WHIRLPOOL_PROGRAM_NAME: &str = "orca_whirlpool";
+const RAYDIUM_CPMM_PROGRAM_NAME: &str = "raydium_cpmm";
+const VOTE_PROGRAM_NAME: &str = "vote";
+const SPL_GOVERNANCE_PROGRAM_NAME: &str = "spl_governance";
+const SQUADS_MULTISIG_PROGRAM_NAME: &str = "squads_multisig";
+const WORMHOLE_TOKEN_BRIDGE_PROGRAM_NAME: &str = "wormhole_token_bridge";
+const DEBRIDGE_DLN_SOURCE_PROGRAM_NAME: &str = "debridge_dln_source";
+const SPL_SINGLE_POOL_PROGRAM_NAME: &str = "spl_single_pool";
+const ANCHOR_IDL_PROGRAM_NAME: &str = "anchor_idl";
+
 impl std::fmt::Display for JitoBellProgram {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            JitoBellProgram::SplToken2022(_) => write!(f, "spl-token-2022"),
-            JitoBellProgram::SplStakePool(_) => write!(f, "spl_stake_pool"),
-            JitoBellProgram::JitoVault(_) => write!(f, "jito_vault"),
+            JitoBellProgram::SplToken2022(_) => write!(f, "{SPL_TOKEN_2022_PROGRAM_NAME}"),
+            JitoBellProgram::SplStakePool(_) => write!(f, "{SPL_STAKE_POOL_PROGRAM_NAME}"),
+            JitoBellProgram::JitoVault(_) => write!(f, "{JITO_VAULT_PROGRAM_NAME}"),
+            JitoBellProgram::JitoSteward(_) => write!(f, "{JITO_STEWARD_PROGRAM_NAME}"),
+            JitoBellProgram::JitoTipRouter(_) => write!(f, "{JITO_TIP_ROUTER_PROGRAM_NAME}"),
+            JitoBellProgram::JitoTipPayment(_) => write!(f, "{JITO_TIP_PAYMENT_PROGRAM_NAME}"),
+            JitoBellProgram::JitoTipDistribution(_) => {
+                write!(f, "{JITO_TIP_DISTRIBUTION_PROGRAM_NAME}")
+            }
+            JitoBellProgram::Marinade(_) => write!(f, "{MARINADE_PROGRAM_NAME}"),
+            JitoBellProgram::System(_) => write!(f, "{SYSTEM_PROGRAM_NAME}"),
+            JitoBellProgram::Jupiter(_) => write!(f, "{JUPITER_PROGRAM_NAME}"),
+            JitoBellProgram::KaminoLend(_) => write!(f, "{KAMINO_LEND_PROGRAM_NAME}"),
+            JitoBellProgram::Marginfi(_) => write!(f, "{MARGINFI_PROGRAM_NAME}"),
+            JitoBellProgram::OrcaWhirlpool(_) => write!(f, "{ORCA_WHIRLPOOL_PROGRAM_NAME}"),
+            JitoBellProgram::RaydiumCpmm(_) => write!(f, "{RAYDIUM_CPMM_PROGRAM_NAME}"),
+            JitoBellProgram::Vote(_) => write!(f, "{VOTE_PROGRAM_NAME}"),
+            JitoBellProgram::SplGovernance(_) => write!(f, "{SPL_GOVERNANCE_PROGRAM_NAME}"),
+            JitoBellProgram::SquadsMultisig(_) => write!(f, "{SQUADS_MULTISIG_PROGRAM_NAME}"),
+            JitoBellProgram::WormholeTokenBridge(_) => {
+                write!(f, "{WORMHOLE_TOKEN_BRIDGE_PROGRAM_NAME}")
+            }
+            JitoBellProgram::DebridgeDlnSource(_) => {
+                write!(f, "{DEBRIDGE_DLN_SOURCE_PROGRAM_NAME}")
+            }
+            JitoBellProgram::SplSinglePool(_) => write!(f, "{SPL_SINGLE_POOL_PROGRAM_NAME}"),
+            JitoBellProgram::AnchorIdl(program) => write!(f, "{}", program.program),
+        }
+    }
+}
+
+impl JitoBellProgram {
+    /// Name of the decoded instruction, the key under `instructions` in the config
+    pub fn instruction_name(&self) -> String {
+        match self {
+            JitoBellProgram::SplToken2022(program) => program.to_string(),
+            JitoBellProgram::SplStakePool(program) => program.to_string(),
+            JitoBellProgram::JitoVault(program) => program.to_string(),
+            JitoBellProgram::JitoSteward(program) => program.to_string(),
+            JitoBellProgram::JitoTipRouter(program) => program.to_string(),
+            JitoBellProgram::JitoTipPayment(program) => program.to_string(),
+            JitoBellProgram::JitoTipDistribution(program) => program.to_string(),
+            JitoBellProgram::Marinade(program) => program.to_string(),
+            JitoBellProgram::System(program) => program.to_string(),
+            JitoBellProgram::Jupiter(program) => program.to_string(),
+            JitoBellProgram::KaminoLend(program) => program.to_string(),
+            JitoBellProgram::Marginfi(program) => program.to_string(),
+            JitoBellProgram::OrcaWhirlpool(program) => program.to_string(),
+            JitoBellProgram::RaydiumCpmm(program) => program.to_string(),
+            JitoBellProgram::Vote(program) => program.to_string(),
+            JitoBellProgram::SplGovernance(program) => program.to_string(),
+            JitoBellProgram::SquadsMultisig(program) => program.to_string(),
+            JitoBellProgram::WormholeTokenBridge(program) => program.to_string(),
+            JitoBellProgram::DebridgeDlnSource(program) => program.to_string(),
+            JitoBellProgram::SplSinglePool(program) => program.to_string(),
+            JitoBellProgram::AnchorIdl(program) => program.to_string(),
+        }
+    }
+
+    /// Instruction names of a built-in program, the keys accepted under `instructions`
+    ///
+    /// - None for programs without a built-in parser (e.g. decoded with an Anchor IDL)
+    pub fn instruction_names(program: &str) -> Option<&'static [&'static str]> {
+        match program {
+            SPL_TOKEN_2022_PROGRAM_NAME => Some(&[
+                "mint_to",
+                "transfer",
+                "transfer_checked",
+                "transfer_checked_with_fee",
+                "burn",
+                "burn_checked",
+            ]),
+            SPL_STAKE_POOL_PROGRAM_NAME => Some(&[
+                "initialize",
+                "add_validator_to_pool",
+                "remove_validator_from_pool",
+                "decrease_validator_stake",
+                "increase_validator_stake",
+                "set_preferred_validator",
+                "update_validator_list_balance",
+                "update_stake_pool_balance",
+                "cleanup_removed_validator_entries",
+                "deposit_stake",
+                "withdraw_stake",
+                "set_manager",
+                "set_fee",
+                "set_staker",
+                "deposit_sol",
+                "set_funding_authority",
+                "withdraw_sol",
+                "create_token_metadata",
+                "update_token_metadata",
+                "increase_additional_validator_stake",
+                "decrease_additional_validator_stake",
+                "decrease_validator_stake_with_reserve",
+                "redelegate",
+                "deposit_stake_with_slippage",
+                "withdraw_stake_with_slippage",
+                "deposit_sol_with_slippage",
+                "withdraw_sol_with_slippage",
+            ]),
+            JITO_VAULT_PROGRAM_NAME => Some(&[
+                "initialize_config",
+                "initialize_vault",
+                "initialize_vault_with_mint",
+                "initialize_vault_operator_delegation",
+                "initialize_vault_ncn_ticket",
+                "initialize_vault_ncn_slasher_operator_ticket",
+                "initialize_vault_ncn_slasher_ticket",
+                "warmup_vault_ncn_ticket",
+                "cooldown_vault_ncn_ticket",
+                "warmup_vault_ncn_slasher_ticket",
+                "cooldown_vault_ncn_slasher_ticket",
+                "mint_to",
+                "enqueue_withdrawal",
+                "change_withdrawal_ticket_owner",
+                "burn_withdrawal_ticket",
+                "set_deposit_capacity",
+                "set_fees",
+                "set_program_fee",
+                "set_program_fee_wallet",
+                "set_is_paused",
+                "delegate_token_account",
+                "set_admin",
+                "set_secondary_admin",
+                "add_delegation",
+                "cooldown_delegation",
+                "update_vault_balance",
+                "initialize_vault_update_state_tracker",
+                "crank_vault_update_state_tracker",
+                "close_vault_update_state_tracker",
+                "create_token_metadata",
+                "update_token_metadata",
+                "set_config_admin",
+            ]),
+            JITO_STEWARD_PROGRAM_NAME => Some(&[
+                "rebalance",
+                "compute_score",
+                "compute_instant_unstake",
+                "compute_delegations",
+                "update_parameters",
+                "add_validator_to_blacklist",
+                "remove_validator_from_blacklist",
+                "auto_add_validator_to_pool",
+                "auto_remove_validator_from_pool",
+                "instant_remove_validator",
+                "pause_steward",
+                "resume_steward",
+            ]),
+            JITO_TIP_ROUTER_PROGRAM_NAME => Some(&[
+                "cast_vote",
+                "set_merkle_root",
+                "route_base_rewards",
+                "route_ncn_rewards",
+                "distribute_base_rewards",
+                "distribute_base_ncn_reward_route",
+                "distribute_ncn_operator_rewards",
+                "distribute_ncn_vault_rewards",
+                "admin_set_tie_breaker",
+            ]),
+            JITO_TIP_PAYMENT_PROGRAM_NAME => Some(&[
+                "tip",
+                "claim_tips",
+                "change_tip_receiver",
+                "change_block_builder",
+            ]),
+            JITO_TIP_DISTRIBUTION_PROGRAM_NAME => Some(&["claim", "upload_merkle_root"]),
+            MARINADE_PROGRAM_NAME => Some(&["deposit", "liquid_unstake", "order_unstake"]),
+            SYSTEM_PROGRAM_NAME => Some(&["transfer"]),
+            JUPITER_PROGRAM_NAME => Some(&["swap"]),
+            KAMINO_LEND_PROGRAM_NAME => Some(&["deposit_collateral", "withdraw_collateral"]),
+            MARGINFI_PROGRAM_NAME => Some(&["deposit", "withdraw"]),
+            ORCA_WHIRLPOOL_PROGRAM_NAME => Some(&["increase_liquidity", "decrease_liquidity"]),
+            RAYDIUM_CPMM_PROGRAM_NAME => Some(&["deposit", "withdraw"]),
+            VOTE_PROGRAM_NAME => Some(&["update_commission"]),
+            SPL_GOVERNANCE_PROGRAM_NAME => Some(&["create_proposal", "execute_transaction"]),
+            SQUADS_MULTISIG_PROGRAM_NAME => Some(&[
+                "proposal_create",
+                "proposal_approve",
+                "vault_transaction_execute",
+                "config_transaction_execute",
+            ]),
+            WORMHOLE_TOKEN_BRIDGE_PROGRAM_NAME => Some(&["transfer_native"]),
+            DEBRIDGE_DLN_SOURCE_PROGRAM_NAME => Some(&["create_order"]),
+            SPL_SINGLE_POOL_PROGRAM_NAME => Some(&["deposit_stake", "withdraw_stake"]),
+            _ => None,
         }
     }
 }
@@ -32,140 +302,539 @@ pub struct JitoTransactionParser {
     /// Transaction signature
     pub transaction_signature: String,
 
+    /// Slot of the transaction
+    pub slot: u64,
+
+    /// Index of the transaction in the slot
+    pub transaction_index: u64,
+
     /// The array of programs related to Jito Network
     pub programs: Vec<JitoBellProgram>,
+
+    /// Parser coverage keyed by program name
+    pub coverage: HashMap<&'static str, ProgramCoverage>,
+
+    /// Token account balances (raw amount) before the transaction
+    pub pre_token_balances: HashMap<Pubkey, u64>,
+
+    /// Token account balances (raw amount) after the transaction
+    pub post_token_balances: HashMap<Pubkey, u64>,
+
+    /// Mint of every token account with a balance change record
+    pub token_account_mints: HashMap<Pubkey, Pubkey>,
+
+    /// Mint of every associated token account created by the transaction
+    pub created_token_accounts: HashMap<Pubkey, Pubkey>,
+
+    /// wSOL wraps and unwraps of the transaction
+    pub wsol: Vec<WsolInstruction>,
+
+    /// SPL Memo contents of the transaction, joined when there are several
+    pub memo: Option<String>,
+
+    /// Fee payer of the transaction
+    pub fee_payer: Option<Pubkey>,
+
+    /// Compute budget requested by the transaction
+    pub compute_budget: ComputeBudget,
+
+    /// Whether the transaction failed (`meta.err`), its instructions had no effect
+    pub failed: bool,
 }
 
 impl JitoTransactionParser {
     /// Initialize new parser
-    pub fn new(transaction: SubscribeUpdateTransaction) -> Self {
+    ///
+    /// - `stake_pool_program_ids`: programs parsed as SPL stake pools besides the canonical one
+    /// - `idl_parsers`: programs parsed with their Anchor IDL
+    pub fn new(
+        transaction: SubscribeUpdateTransaction,
+        stake_pool_program_ids: &[Pubkey],
+        idl_parsers: &[AnchorIdlParser],
+    ) -> Self {
         let mut transaction_signature = String::new();
+        let slot = transaction.slot;
+        let mut transaction_index = 0;
         let mut programs = Vec::new();
+        let mut coverage = HashMap::new();
+        let mut pre_token_balances = HashMap::new();
+        let mut post_token_balances = HashMap::new();
+        let mut token_account_mints = HashMap::new();
+        let mut created_token_accounts = HashMap::new();
+        let mut transfer_hooks = Vec::new();
+        let mut wsol = Vec::new();
+        let mut memos = Vec::new();
+        let mut compute_budget = ComputeBudget::default();
+        let mut failed = false;
         let mut pubkeys: Vec<Pubkey> = Vec::new();
 
         if let Some(tx) = transaction.transaction {
+            transaction_index = tx.index;
+
             if let Some(ref meta) = tx.meta {
-                if meta.err.is_none() {
-                    if let Some(tx) = tx.transaction {
-                        let signature_slice = &tx.signatures[0];
-                        let mut slice = [0; 64];
-                        slice.copy_from_slice(&signature_slice[..64]);
-                        let tx_signature = Signature::from(slice);
-                        transaction_signature = tx_signature.to_string();
-
-                        if let Some(msg) = tx.message {
-                            pubkeys = msg
-                                .account_keys
-                                .iter()
-                                .map(|account_key| {
-                                    let mut slice = [0; 32];
-                                    slice.copy_from_slice(&account_key[..32]);
-                                    Pubkey::new_from_array(slice)
-                                })
-                                .collect();
-
-                            for instruction in &msg.instructions {
-                                if let Some(program_id) =
-                                    &pubkeys.get(instruction.program_id_index as usize)
-                                {
-                                    match *program_id {
-                                        program_id
-                                            if program_id
-                                                .eq(&SplToken2022Program::program_id()) =>
-                                        {
-                                            if let Some(ix_info) =
-                                                SplToken2022Program::parse_spl_token_2022_program(
-                                                    instruction,
-                                                    &pubkeys,
-                                                )
-                                            {
-                                                programs
-                                                    .push(JitoBellProgram::SplToken2022(ix_info));
-                                            }
-                                        }
-                                        program_id
-                                            if program_id
-                                                .eq(&SplStakePoolProgram::program_id()) =>
-                                        {
-                                            if let Some(ix_info) =
-                                                SplStakePoolProgram::parse_spl_stake_pool_program(
-                                                    instruction,
-                                                    &pubkeys,
-                                                )
-                                            {
-                                                programs
-                                                    .push(JitoBellProgram::SplStakePool(ix_info));
-                                            }
-                                        }
-                                        program_id
-                                            if program_id.eq(&JitoVaultProgram::program_id()) =>
-                                        {
-                                            if let Some(ix_info) =
-                                                JitoVaultProgram::parse_jito_vault_program(
-                                                    instruction,
-                                                    &pubkeys,
-                                                )
-                                            {
-                                                programs.push(JitoBellProgram::JitoVault(ix_info));
-                                            }
-                                        }
-                                        _ => continue,
-                                    }
-                                }
-                            }
+                failed = meta.err.is_some();
+
+                if let Some(tx) = tx.transaction {
+                    let signature_slice = &tx.signatures[0];
+                    let mut slice = [0; 64];
+                    slice.copy_from_slice(&signature_slice[..64]);
+                    let tx_signature = Signature::from(slice);
+                    transaction_signature = tx_signature.to_string();
+
+                    if let Some(msg) = tx.message {
+                        // v0 transactions index lookup table accounts after the static keys,
+                        // writable ones first
+                        pubkeys = msg
+                            .account_keys
+                            .iter()
+                            .chain(&meta.loaded_writable_addresses)
+                            .chain(&meta.loaded_readonly_addresses)
+                            .map(|account_key| {
+                                let mut slice = [0; 32];
+                                slice.copy_from_slice(&account_key[..32]);
+                                Pubkey::new_from_array(slice)
+                            })
+                            .collect();
+
+                        for instruction in &msg.instructions {
+                            memos.extend(SplMemoProgram::parse_memo(instruction, &pubkeys));
+                            created_token_accounts.extend(
+                                SplAssociatedTokenAccountProgram::parse_create(
+                                    instruction,
+                                    &pubkeys,
+                                ),
+                            );
+                            wsol.extend(WsolInstruction::parse(instruction, &pubkeys));
+                            compute_budget.record(instruction, &pubkeys);
+                            Self::parse_instruction(
+                                instruction,
+                                &pubkeys,
+                                stake_pool_program_ids,
+                                idl_parsers,
+                                false,
+                                &mut programs,
+                                &mut coverage,
+                            );
                         }
                     }
                 }
             }
 
             if let Some(meta) = tx.meta {
+                for balance in meta
+                    .pre_token_balances
+                    .iter()
+                    .chain(&meta.post_token_balances)
+                {
+                    if let (Some(pubkey), Ok(mint)) = (
+                        pubkeys.get(balance.account_index as usize),
+                        Pubkey::from_str(&balance.mint),
+                    ) {
+                        token_account_mints.insert(*pubkey, mint);
+                    }
+                }
+
+                for (balances, raw_balances) in [
+                    (meta.pre_token_balances, &mut pre_token_balances),
+                    (meta.post_token_balances, &mut post_token_balances),
+                ] {
+                    for balance in balances {
+                        let pubkey = pubkeys.get(balance.account_index as usize);
+                        let amount = balance
+                            .ui_token_amount
+                            .and_then(|amount| amount.amount.parse::<u64>().ok());
+                        if let (Some(pubkey), Some(amount)) = (pubkey, amount) {
+                            raw_balances.insert(*pubkey, amount);
+                        }
+                    }
+                }
+
                 for instructions in meta.inner_instructions {
                     for instruction in instructions.instructions {
-                        if let Some(program_id) =
-                            &pubkeys.get(instruction.program_id_index as usize)
+                        // Transfer hooks belong to the Token-2022 transfer invoking them, not to
+                        // the hook program
+                        if let Some(hook) =
+                            SplToken2022Program::parse_transfer_hook_execute(&instruction, &pubkeys)
                         {
-                            match *program_id {
-                                program_id if program_id.eq(&SplToken2022Program::program_id()) => {
-                                    if let Some(ix_info) =
-                                        SplToken2022Program::parse_spl_token_2022_program(
-                                            &instruction,
-                                            &pubkeys,
-                                        )
-                                    {
-                                        programs.push(JitoBellProgram::SplToken2022(ix_info));
-                                    }
-                                }
-                                program_id if program_id.eq(&SplStakePoolProgram::program_id()) => {
-                                    if let Some(ix_info) =
-                                        SplStakePoolProgram::parse_spl_stake_pool_program(
-                                            &instruction,
-                                            &pubkeys,
-                                        )
-                                    {
-                                        programs.push(JitoBellProgram::SplStakePool(ix_info));
-                                    }
-                                }
-                                program_id if program_id.eq(&JitoVaultProgram::program_id()) => {
-                                    if let Some(ix_info) =
-                                        JitoVaultProgram::parse_jito_vault_program(
-                                            &instruction,
-                                            &pubkeys,
-                                        )
-                                    {
-                                        programs.push(JitoBellProgram::JitoVault(ix_info));
-                                    }
-                                }
-                                _ => continue,
-                            }
+                            transfer_hooks.push(hook);
+                            continue;
                         }
+
+                        memos.extend(SplMemoProgram::parse_memo(&instruction, &pubkeys));
+                        created_token_accounts.extend(
+                            SplAssociatedTokenAccountProgram::parse_create(&instruction, &pubkeys),
+                        );
+                        wsol.extend(WsolInstruction::parse(&instruction, &pubkeys));
+                        Self::parse_instruction(
+                            &instruction,
+                            &pubkeys,
+                            stake_pool_program_ids,
+                            idl_parsers,
+                            true,
+                            &mut programs,
+                            &mut coverage,
+                        );
                     }
                 }
             }
         }
 
+        for hook in &transfer_hooks {
+            for program in programs.iter_mut() {
+                if let JitoBellProgram::SplToken2022(transfer) = program {
+                    if transfer.attribute_transfer_hook(hook) {
+                        break;
+                    }
+                }
+            }
+        }
+
+        let native_mints = WsolInstruction::native_mints();
+        wsol.retain(|instruction| {
+            !failed
+                && token_account_mints
+                    .get(instruction.account())
+                    .is_some_and(|mint| native_mints.contains(mint))
+        });
+
+        // `CreateIdempotent` is a no-op for existing accounts, which have a balance record before,
+        // and nothing is created by a failed transaction
+        created_token_accounts
+            .retain(|account, _| !failed && !pre_token_balances.contains_key(account));
+
         Self {
             transaction_signature,
+            slot,
+            transaction_index,
             programs,
+            coverage,
+            pre_token_balances,
+            post_token_balances,
+            token_account_mints,
+            created_token_accounts,
+            wsol,
+            memo: (!memos.is_empty()).then(|| memos.join(" | ")),
+            fee_payer: pubkeys.first().copied(),
+            compute_budget,
+            failed,
         }
     }
+
+    /// Pool tokens (base units) burned from `account` by a Token-2022 burn in this transaction
+    pub fn burned_pool_tokens(&self, mint: &Pubkey, account: &Pubkey) -> Option<u64> {
+        self.programs.iter().find_map(|program| match program {
+            JitoBellProgram::SplToken2022(program) => program.burned_from(mint, account),
+            _ => None,
+        })
+    }
+
+    /// Whether the token account of `mint` was created by the transaction (e.g. a first-time depositor)
+    pub fn created_token_account(&self, account: &Pubkey, mint: &Pubkey) -> bool {
+        self.created_token_accounts
+            .get(account)
+            .is_some_and(|created_mint| created_mint.eq(mint))
+    }
+
+    /// Whether SOL unwrapped from a wSOL account by the transaction went to `account`
+    pub fn unwrapped_wsol_to(&self, account: &Pubkey) -> bool {
+        self.wsol.iter().any(|instruction| {
+            matches!(instruction, WsolInstruction::CloseAccount { destination, .. } if destination.eq(account))
+        })
+    }
+
+    /// Whether the transaction wrapped SOL into a wSOL account
+    pub fn wrapped_wsol(&self) -> bool {
+        self.wsol
+            .iter()
+            .any(|instruction| matches!(instruction, WsolInstruction::SyncNative { .. }))
+    }
+
+    /// Raw amount moved in or out of a token account by the transaction
+    ///
+    /// - `None` for failed transactions, their balances did not change
+    pub fn token_balance_change(&self, account: &Pubkey) -> Option<u64> {
+        if self.failed {
+            return None;
+        }
+
+        let pre = self
+            .pre_token_balances
+            .get(account)
+            .copied()
+            .unwrap_or_default();
+        let post = self.post_token_balances.get(account)?;
+
+        Some(pre.abs_diff(*post))
+    }
+
+    /// Parse instruction of a watched program
+    ///
+    /// - Push decoded instruction into `programs`
+    /// - Count decoded and skipped instructions per program into `coverage`
+    fn parse_instruction<T: ParsableInstruction>(
+        instruction: &T,
+        pubkeys: &[Pubkey],
+        stake_pool_program_ids: &[Pubkey],
+        idl_parsers: &[AnchorIdlParser],
+        inner: bool,
+        programs: &mut Vec<JitoBellProgram>,
+        coverage: &mut HashMap<&'static str, ProgramCoverage>,
+    ) {
+        let Some(program_id) = pubkeys.get(instruction.program_id_index() as usize) else {
+            return;
+        };
+
+        let (program_name, program) = match *program_id {
+            program_id if program_id.eq(&SplToken2022Program::program_id()) => (
+                SPL_TOKEN_2022_PROGRAM_NAME,
+                SplToken2022Program::parse_spl_token_2022_program(instruction, pubkeys)
+                    .map(JitoBellProgram::SplToken2022),
+            ),
+            program_id
+                if program_id.eq(&SplStakePoolProgram::program_id())
+                    || stake_pool_program_ids.contains(&program_id) =>
+            {
+                (
+                    SPL_STAKE_POOL_PROGRAM_NAME,
+                    SplStakePoolProgram::parse_spl_stake_pool_program(instruction, pubkeys)
+                        .map(JitoBellProgram::SplStakePool),
+                )
+            }
+            program_id if program_id.eq(&JitoVaultProgram::program_id()) => (
+                JITO_VAULT_PROGRAM_NAME,
+                JitoVaultProgram::parse_jito_vault_program(instruction, pubkeys)
+                    .map(JitoBellProgram::JitoVault),
+            ),
+            program_id if program_id.eq(&JitoStewardProgram::program_id()) => (
+                JITO_STEWARD_PROGRAM_NAME,
+                JitoStewardProgram::parse_jito_steward_program(instruction, pubkeys)
+                    .map(JitoBellProgram::JitoSteward),
+            ),
+            program_id if program_id.eq(&JitoTipRouterProgram::program_id()) => (
+                JITO_TIP_ROUTER_PROGRAM_NAME,
+                JitoTipRouterProgram::parse_jito_tip_router_program(instruction, pubkeys)
+                    .map(JitoBellProgram::JitoTipRouter),
+            ),
+            program_id if program_id.eq(&JitoTipPaymentProgram::program_id()) => (
+                JITO_TIP_PAYMENT_PROGRAM_NAME,
+                JitoTipPaymentProgram::parse_jito_tip_payment_program(instruction, pubkeys)
+                    .map(JitoBellProgram::JitoTipPayment),
+            ),
+            program_id if program_id.eq(&JitoTipDistributionProgram::program_id()) => (
+                JITO_TIP_DISTRIBUTION_PROGRAM_NAME,
+                JitoTipDistributionProgram::parse_jito_tip_distribution_program(
+                    instruction,
+                    pubkeys,
+                )
+                .map(JitoBellProgram::JitoTipDistribution),
+            ),
+            program_id if program_id.eq(&MarinadeProgram::program_id()) => (
+                MARINADE_PROGRAM_NAME,
+                MarinadeProgram::parse_marinade_program(instruction, pubkeys)
+                    .map(JitoBellProgram::Marinade),
+            ),
+            program_id if program_id.eq(&JupiterProgram::program_id()) => (
+                JUPITER_PROGRAM_NAME,
+                JupiterProgram::parse_jupiter_program(instruction, pubkeys)
+                    .map(JitoBellProgram::Jupiter),
+            ),
+            program_id if program_id.eq(&KaminoLendProgram::program_id()) => (
+                KAMINO_LEND_PROGRAM_NAME,
+                KaminoLendProgram::parse_kamino_lend_program(instruction, pubkeys)
+                    .map(JitoBellProgram::KaminoLend),
+            ),
+            program_id if program_id.eq(&MarginfiProgram::program_id()) => (
+                MARGINFI_PROGRAM_NAME,
+                MarginfiProgram::parse_marginfi_program(instruction, pubkeys)
+                    .map(JitoBellProgram::Marginfi),
+            ),
+            program_id if program_id.eq(&OrcaWhirlpoolProgram::program_id()) => (
+                ORCA_WHIRLPOOL_PROGRAM_NAME,
+                OrcaWhirlpoolProgram::parse_orca_whirlpool_program(instruction, pubkeys)
+                    .map(JitoBellProgram::OrcaWhirlpool),
+            ),
+            program_id if program_id.eq(&RaydiumCpmmProgram::program_id()) => (
+                RAYDIUM_CPMM_PROGRAM_NAME,
+                RaydiumCpmmProgram::parse_raydium_cpmm_program(instruction, pubkeys)
+                    .map(JitoBellProgram::RaydiumCpmm),
+            ),
+            program_id if program_id.eq(&SplGovernanceProgram::program_id()) => (
+                SPL_GOVERNANCE_PROGRAM_NAME,
+                SplGovernanceProgram::parse_spl_governance_program(instruction, pubkeys)
+                    .map(JitoBellProgram::SplGovernance),
+            ),
+            program_id if program_id.eq(&SquadsMultisigProgram::program_id()) => (
+                SQUADS_MULTISIG_PROGRAM_NAME,
+                SquadsMultisigProgram::parse_squads_multisig_program(instruction, pubkeys)
+                    .map(JitoBellProgram::SquadsMultisig),
+            ),
+            program_id if program_id.eq(&WormholeTokenBridgeProgram::program_id()) => (
+                WORMHOLE_TOKEN_BRIDGE_PROGRAM_NAME,
+                WormholeTokenBridgeProgram::parse_wormhole_token_bridge_program(
+                    instruction,
+                    pubkeys,
+                )
+                .map(JitoBellProgram::WormholeTokenBridge),
+            ),
+            program_id if program_id.eq(&DebridgeDlnSourceProgram::program_id()) => (
+                DEBRIDGE_DLN_SOURCE_PROGRAM_NAME,
+                DebridgeDlnSourceProgram::parse_debridge_dln_source_program(instruction, pubkeys)
+                    .map(JitoBellProgram::DebridgeDlnSource),
+            ),
+            program_id if program_id.eq(&SplSinglePoolProgram::program_id()) => (
+                SPL_SINGLE_POOL_PROGRAM_NAME,
+                SplSinglePoolProgram::parse_spl_single_pool_program(instruction, pubkeys)
+                    .map(JitoBellProgram::SplSinglePool),
+            ),
+            program_id if program_id.eq(&VoteProgram::program_id()) => {
+                // Only commission updates are watched, other Vote instructions are not counted
+                let Some(vote) = VoteProgram::parse_vote_program(instruction, pubkeys) else {
+                    return;
+                };
+                (VOTE_PROGRAM_NAME, Some(JitoBellProgram::Vote(vote)))
+            }
+            program_id if program_id.eq(&SystemProgram::program_id()) => {
+                // Only transfers are watched, other System instructions are not counted
+                if let Some(tip) = JitoTipPaymentProgram::parse_tip_transfer(instruction, pubkeys) {
+                    (
+                        JITO_TIP_PAYMENT_PROGRAM_NAME,
+                        Some(JitoBellProgram::JitoTipPayment(tip)),
+                    )
+                } else if let Some(transfer) =
+                    SystemProgram::parse_system_program(instruction, pubkeys)
+                {
+                    (SYSTEM_PROGRAM_NAME, Some(JitoBellProgram::System(transfer)))
+                } else {
+                    return;
+                }
+            }
+            program_id => {
+                let Some(idl_parser) = idl_parsers
+                    .iter()
+                    .find(|idl_parser| idl_parser.program_id.eq(&program_id))
+                else {
+                    return;
+                };
+                (
+                    ANCHOR_IDL_PROGRAM_NAME,
+                    idl_parser
+                        .parse(instruction, pubkeys)
+                        .map(JitoBellProgram::AnchorIdl),
+                )
+            }
+        };
+
+        coverage
+            .entry(program_name)
+            .or_default()
+            .record(inner, program.is_some());
+
+        if let Some(program) = program {
+            programs.push(program);
+        }
+    }
+}
+
+/// Token side of a liquidity instruction
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiquidityLeg {
+    /// Token mint, when passed to the instruction
+    pub mint: Option<Pubkey>,
+
+    /// Pool token vault
+    pub vault: Pubkey,
+
+    /// Amount bound of the instruction (maximum deposited or minimum withdrawn)
+    pub amount: u64,
+}
+
+/// Parser coverage of a watched program within a transaction
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ProgramCoverage {
+    /// Outer instructions decoded
+    pub outer_decoded: u64,
+
+    /// Outer instructions skipped (unknown or not decodable)
+    pub outer_skipped: u64,
+
+    /// Inner instructions decoded
+    pub inner_decoded: u64,
+
+    /// Inner instructions skipped (unknown or not decodable)
+    pub inner_skipped: u64,
+}
+
+impl ProgramCoverage {
+    /// Count instruction
+    fn record(&mut self, inner: bool, decoded: bool) {
+        match (inner, decoded) {
+            (false, true) => self.outer_decoded += 1,
+            (false, false) => self.outer_skipped += 1,
+            (true, true) => self.inner_decoded += 1,
+            (true, false) => self.inner_skipped += 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::{
+        geyser::{SubscribeUpdateTransaction, SubscribeUpdateTransactionInfo},
+        prelude::{
+            CompiledInstruction, Message, TokenBalance, Transaction, TransactionStatusMeta,
+            UiTokenAmount,
+        },
+    };
+
+    use crate::parser::{memo::SplMemoProgram, JitoTransactionParser};
+
+    #[test]
+    fn test_loaded_addresses() {
+        let fee_payer = Keypair::new().pubkey();
+        let token_account = Keypair::new().pubkey();
+        let mint = Keypair::new().pubkey();
+        let memo_program = SplMemoProgram::program_ids()[0];
+
+        // Static keys, then the lookup table's writable and readonly addresses
+        let transaction = SubscribeUpdateTransaction {
+            transaction: Some(SubscribeUpdateTransactionInfo {
+                transaction: Some(Transaction {
+                    signatures: vec![vec![1; 64]],
+                    message: Some(Message {
+                        account_keys: vec![fee_payer.to_bytes().to_vec()],
+                        instructions: vec![CompiledInstruction {
+                            program_id_index: 2,
+                            accounts: vec![0],
+                            data: b"rebalance".to_vec(),
+                        }],
+                        versioned: true,
+                        ..Default::default()
+                    }),
+                }),
+                meta: Some(TransactionStatusMeta {
+                    loaded_writable_addresses: vec![token_account.to_bytes().to_vec()],
+                    loaded_readonly_addresses: vec![memo_program.to_bytes().to_vec()],
+                    post_token_balances: vec![TokenBalance {
+                        account_index: 1,
+                        mint: mint.to_string(),
+                        ui_token_amount: Some(UiTokenAmount {
+                            amount: "500".to_string(),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            slot: 42,
+        };
+
+        let parser = JitoTransactionParser::new(transaction, &[], &[]);
+
+        assert_eq!(parser.memo.as_deref(), Some("rebalance"));
+        assert_eq!(parser.post_token_balances.get(&token_account), Some(&500));
+        assert_eq!(parser.token_account_mints.get(&token_account), Some(&mint));
+    }
 }
@@ -0,0 +1,199 @@
+use std::str::FromStr;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::{instruction::ParsableInstruction, LiquidityLeg};
+
+/// Anchor instruction discriminators: sha256("global:<instruction name>")[..8]
+const INCREASE_LIQUIDITY: [u8; 8] = [46, 156, 243, 118, 13, 205, 251, 178];
+const INCREASE_LIQUIDITY_V2: [u8; 8] = [133, 29, 89, 223, 69, 238, 176, 10];
+const DECREASE_LIQUIDITY: [u8; 8] = [160, 38, 208, 111, 104, 91, 44, 1];
+const DECREASE_LIQUIDITY_V2: [u8; 8] = [58, 127, 188, 62, 79, 82, 196, 96];
+
+/// Orca Whirlpool Program
+///
+/// - Arguments are `(liquidity_amount: u128, token_a: u64, token_b: u64)`, token amounts are
+///   the maximum deposited or minimum withdrawn
+#[derive(Debug)]
+pub enum OrcaWhirlpoolProgram {
+    /// increase_liquidity
+    ///
+    /// #[account(0, writable, name = "whirlpool")]
+    /// #[account(1, name = "token_program")]
+    /// #[account(2, signer, name = "position_authority")]
+    /// #[account(3, writable, name = "position")]
+    /// #[account(4, name = "position_token_account")]
+    /// #[account(5, writable, name = "token_owner_account_a")]
+    /// #[account(6, writable, name = "token_owner_account_b")]
+    /// #[account(7, writable, name = "token_vault_a")]
+    /// #[account(8, writable, name = "token_vault_b")]
+    ///
+    /// increase_liquidity_v2
+    ///
+    /// #[account(0, writable, name = "whirlpool")]
+    /// #[account(1, name = "token_program_a")]
+    /// #[account(2, name = "token_program_b")]
+    /// #[account(3, name = "memo_program")]
+    /// #[account(4, signer, name = "position_authority")]
+    /// #[account(5, writable, name = "position")]
+    /// #[account(6, name = "position_token_account")]
+    /// #[account(7, name = "token_mint_a")]
+    /// #[account(8, name = "token_mint_b")]
+    /// #[account(9, writable, name = "token_owner_account_a")]
+    /// #[account(10, writable, name = "token_owner_account_b")]
+    /// #[account(11, writable, name = "token_vault_a")]
+    /// #[account(12, writable, name = "token_vault_b")]
+    IncreaseLiquidity {
+        ix: Instruction,
+        legs: [LiquidityLeg; 2],
+    },
+
+    /// decrease_liquidity(_v2), same accounts as increase_liquidity(_v2)
+    DecreaseLiquidity {
+        ix: Instruction,
+        legs: [LiquidityLeg; 2],
+    },
+}
+
+impl std::fmt::Display for OrcaWhirlpoolProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OrcaWhirlpoolProgram::IncreaseLiquidity { .. } => write!(f, "increase_liquidity"),
+            OrcaWhirlpoolProgram::DecreaseLiquidity { .. } => write!(f, "decrease_liquidity"),
+        }
+    }
+}
+
+impl OrcaWhirlpoolProgram {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc").unwrap()
+    }
+
+    /// Parse Orca Whirlpool Program
+    pub fn parse_orca_whirlpool_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<OrcaWhirlpoolProgram> {
+        let data = instruction.data();
+        let discriminator = <[u8; 8]>::try_from(data.get(..8)?).ok()?;
+        let token_a = u64::from_le_bytes(data.get(24..32)?.try_into().ok()?);
+        let token_b = u64::from_le_bytes(data.get(32..40)?.try_into().ok()?);
+
+        let ix = Self::build_ix(instruction, account_keys);
+        let account = |index: usize| ix.accounts.get(index).map(|meta| meta.pubkey);
+
+        let (mints, vaults) = match discriminator {
+            INCREASE_LIQUIDITY | DECREASE_LIQUIDITY => ([None, None], [account(7)?, account(8)?]),
+            INCREASE_LIQUIDITY_V2 | DECREASE_LIQUIDITY_V2 => (
+                [Some(account(7)?), Some(account(8)?)],
+                [account(11)?, account(12)?],
+            ),
+            _ => return None,
+        };
+        let legs = [
+            LiquidityLeg {
+                mint: mints[0],
+                vault: vaults[0],
+                amount: token_a,
+            },
+            LiquidityLeg {
+                mint: mints[1],
+                vault: vaults[1],
+                amount: token_b,
+            },
+        ];
+
+        let program = match discriminator {
+            INCREASE_LIQUIDITY | INCREASE_LIQUIDITY_V2 => Self::IncreaseLiquidity { ix, legs },
+            _ => Self::DecreaseLiquidity { ix, legs },
+        };
+
+        Some(program)
+    }
+
+    /// Build instruction with the accounts in their original order
+    fn build_ix<T: ParsableInstruction>(instruction: &T, account_keys: &[Pubkey]) -> Instruction {
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: instruction.data().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::orca::{OrcaWhirlpoolProgram, DECREASE_LIQUIDITY_V2, INCREASE_LIQUIDITY};
+
+    fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Keypair::new().pubkey()).collect()
+    }
+
+    fn create_instruction(
+        num_account: usize,
+        discriminator: [u8; 8],
+        token_a: u64,
+        token_b: u64,
+    ) -> CompiledInstruction {
+        let mut data = discriminator.to_vec();
+        data.extend_from_slice(&1_000_000_u128.to_le_bytes());
+        data.extend_from_slice(&token_a.to_le_bytes());
+        data.extend_from_slice(&token_b.to_le_bytes());
+
+        CompiledInstruction {
+            program_id_index: 1,
+            accounts: (0..num_account).map(|i| i as u8).collect(),
+            data,
+        }
+    }
+
+    #[test]
+    fn test_increase_liquidity() {
+        let account_keys = create_test_pubkeys(11);
+        let instruction = create_instruction(11, INCREASE_LIQUIDITY, 500, 700);
+
+        let parsed =
+            OrcaWhirlpoolProgram::parse_orca_whirlpool_program(&instruction, &account_keys);
+
+        if let Some(OrcaWhirlpoolProgram::IncreaseLiquidity { legs, .. }) = parsed {
+            assert_eq!(legs[0].mint, None);
+            assert_eq!(legs[0].vault, account_keys[7]);
+            assert_eq!(legs[0].amount, 500);
+            assert_eq!(legs[1].vault, account_keys[8]);
+            assert_eq!(legs[1].amount, 700);
+        } else {
+            panic!("Expected IncreaseLiquidity variant");
+        }
+    }
+
+    #[test]
+    fn test_decrease_liquidity_v2() {
+        let account_keys = create_test_pubkeys(15);
+        let instruction = create_instruction(15, DECREASE_LIQUIDITY_V2, 1, 2);
+
+        let parsed =
+            OrcaWhirlpoolProgram::parse_orca_whirlpool_program(&instruction, &account_keys);
+
+        if let Some(OrcaWhirlpoolProgram::DecreaseLiquidity { legs, .. }) = parsed {
+            assert_eq!(legs[0].mint, Some(account_keys[7]));
+            assert_eq!(legs[1].mint, Some(account_keys[8]));
+            assert_eq!(legs[0].vault, account_keys[11]);
+            assert_eq!(legs[1].vault, account_keys[12]);
+        } else {
+            panic!("Expected DecreaseLiquidity variant");
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::str::FromStr;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::{instruction::ParsableInstruction, LiquidityLeg};
+
+/// Anchor instruction discriminators: sha256("global:<instruction name>")[..8]
+const DEPOSIT: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
+const WITHDRAW: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
+
+/// Raydium CPMM (constant product) Program
+///
+/// - Arguments are `(lp_token_amount: u64, token_0: u64, token_1: u64)`, token amounts are
+///   the maximum deposited or minimum withdrawn
+#[derive(Debug)]
+pub enum RaydiumCpmmProgram {
+    /// #[account(0, signer, name = "owner")]
+    /// #[account(1, name = "authority")]
+    /// #[account(2, writable, name = "pool_state")]
+    /// #[account(3, writable, name = "owner_lp_token")]
+    /// #[account(4, writable, name = "token_0_account")]
+    /// #[account(5, writable, name = "token_1_account")]
+    /// #[account(6, writable, name = "token_0_vault")]
+    /// #[account(7, writable, name = "token_1_vault")]
+    /// #[account(8, name = "token_program")]
+    /// #[account(9, name = "token_program_2022")]
+    /// #[account(10, name = "vault_0_mint")]
+    /// #[account(11, name = "vault_1_mint")]
+    /// #[account(12, writable, name = "lp_mint")]
+    Deposit {
+        ix: Instruction,
+        legs: [LiquidityLeg; 2],
+    },
+
+    /// Same accounts as deposit, followed by the memo program
+    Withdraw {
+        ix: Instruction,
+        legs: [LiquidityLeg; 2],
+    },
+}
+
+impl std::fmt::Display for RaydiumCpmmProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RaydiumCpmmProgram::Deposit { .. } => write!(f, "deposit"),
+            RaydiumCpmmProgram::Withdraw { .. } => write!(f, "withdraw"),
+        }
+    }
+}
+
+impl RaydiumCpmmProgram {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C").unwrap()
+    }
+
+    /// Parse Raydium CPMM Program
+    pub fn parse_raydium_cpmm_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<RaydiumCpmmProgram> {
+        let data = instruction.data();
+        let discriminator = <[u8; 8]>::try_from(data.get(..8)?).ok()?;
+        let token_0 = u64::from_le_bytes(data.get(16..24)?.try_into().ok()?);
+        let token_1 = u64::from_le_bytes(data.get(24..32)?.try_into().ok()?);
+
+        let ix = Self::build_ix(instruction, account_keys);
+        let account = |index: usize| ix.accounts.get(index).map(|meta| meta.pubkey);
+        let legs = [
+            LiquidityLeg {
+                mint: Some(account(10)?),
+                vault: account(6)?,
+                amount: token_0,
+            },
+            LiquidityLeg {
+                mint: Some(account(11)?),
+                vault: account(7)?,
+                amount: token_1,
+            },
+        ];
+
+        let program = match discriminator {
+            DEPOSIT => Self::Deposit { ix, legs },
+            WITHDRAW => Self::Withdraw { ix, legs },
+            _ => return None,
+        };
+
+        Some(program)
+    }
+
+    /// Build instruction with the accounts in their original order
+    fn build_ix<T: ParsableInstruction>(instruction: &T, account_keys: &[Pubkey]) -> Instruction {
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: instruction.data().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::raydium::{RaydiumCpmmProgram, WITHDRAW};
+
+    fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Keypair::new().pubkey()).collect()
+    }
+
+    #[test]
+    fn test_withdraw() {
+        let account_keys = create_test_pubkeys(14);
+
+        let mut data = WITHDRAW.to_vec();
+        data.extend_from_slice(&10_u64.to_le_bytes());
+        data.extend_from_slice(&4_000_u64.to_le_bytes());
+        data.extend_from_slice(&5_000_u64.to_le_bytes());
+
+        let instruction = CompiledInstruction {
+            program_id_index: 1,
+            accounts: (0..14).collect(),
+            data,
+        };
+
+        let parsed = RaydiumCpmmProgram::parse_raydium_cpmm_program(&instruction, &account_keys);
+
+        if let Some(RaydiumCpmmProgram::Withdraw { legs, .. }) = parsed {
+            assert_eq!(legs[0].mint, Some(account_keys[10]));
+            assert_eq!(legs[0].vault, account_keys[6]);
+            assert_eq!(legs[0].amount, 4_000);
+            assert_eq!(legs[1].mint, Some(account_keys[11]));
+            assert_eq!(legs[1].amount, 5_000);
+        } else {
+            panic!("Expected Withdraw variant");
+        }
+    }
+}
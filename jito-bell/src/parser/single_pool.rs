@@ -0,0 +1,159 @@
+use std::str::FromStr;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::instruction::ParsableInstruction;
+
+/// Single Pool instruction discriminators (borsh u8 enum tag)
+const DEPOSIT_STAKE: u8 = 2;
+const WITHDRAW_STAKE: u8 = 3;
+
+/// SPL Single-Validator Stake Pool Program
+///
+/// - The pool is always the first account
+/// - The pool mint is resolved from the user token account, the only token account of the
+///   instruction
+#[derive(Debug)]
+pub enum SplSinglePoolProgram {
+    /// #[account(0, name = "pool")]
+    /// #[account(1, writable, name = "pool_stake")]
+    /// #[account(2, writable, name = "pool_mint")]
+    /// #[account(3, name = "pool_stake_authority")]
+    /// #[account(4, name = "pool_mint_authority")]
+    /// #[account(5, writable, name = "user_stake_account")]
+    /// #[account(6, writable, name = "user_token_account")]
+    /// #[account(7, writable, name = "user_lamport_account")]
+    /// #[account(8, name = "clock")]
+    /// #[account(9, name = "stake_history")]
+    /// #[account(10, name = "token_program")]
+    /// #[account(11, name = "stake_program")]
+    DepositStake { ix: Instruction },
+
+    /// #[account(0, name = "pool")]
+    /// #[account(1, writable, name = "pool_stake")]
+    /// #[account(2, writable, name = "pool_mint")]
+    /// #[account(3, name = "pool_stake_authority")]
+    /// #[account(4, name = "pool_mint_authority")]
+    /// #[account(5, writable, name = "user_stake_account")]
+    /// #[account(6, writable, name = "user_token_account")]
+    /// #[account(7, name = "clock")]
+    /// #[account(8, name = "token_program")]
+    /// #[account(9, name = "stake_program")]
+    WithdrawStake { ix: Instruction, token_amount: u64 },
+}
+
+impl std::fmt::Display for SplSinglePoolProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SplSinglePoolProgram::DepositStake { .. } => write!(f, "deposit_stake"),
+            SplSinglePoolProgram::WithdrawStake { .. } => write!(f, "withdraw_stake"),
+        }
+    }
+}
+
+impl SplSinglePoolProgram {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("SVSPxpvHdN29nkVg9rPapPNDddN5DipNLRUFhyjFThE").unwrap()
+    }
+
+    /// Parse SPL Single Pool Program, only deposits and withdrawals are parsed
+    pub fn parse_spl_single_pool_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<SplSinglePoolProgram> {
+        let data = instruction.data();
+        let ix = Self::build_ix(instruction, account_keys);
+        if ix.accounts.is_empty() {
+            return None;
+        }
+
+        let program = match *data.first()? {
+            DEPOSIT_STAKE => Self::DepositStake { ix },
+            WITHDRAW_STAKE => {
+                // (user_stake_authority: Pubkey, token_amount: u64)
+                let token_amount = u64::from_le_bytes(data.get(33..41)?.try_into().ok()?);
+                Self::WithdrawStake { ix, token_amount }
+            }
+            _ => return None,
+        };
+
+        Some(program)
+    }
+
+    /// Build instruction with the accounts in their original order
+    fn build_ix<T: ParsableInstruction>(instruction: &T, account_keys: &[Pubkey]) -> Instruction {
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: instruction.data().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::single_pool::{SplSinglePoolProgram, DEPOSIT_STAKE, WITHDRAW_STAKE};
+
+    fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Keypair::new().pubkey()).collect()
+    }
+
+    #[test]
+    fn test_deposit_stake() {
+        let account_keys = create_test_pubkeys(12);
+
+        let instruction = CompiledInstruction {
+            program_id_index: 12,
+            accounts: (0..12).collect(),
+            data: vec![DEPOSIT_STAKE],
+        };
+
+        let parsed =
+            SplSinglePoolProgram::parse_spl_single_pool_program(&instruction, &account_keys);
+
+        if let Some(SplSinglePoolProgram::DepositStake { ix }) = parsed {
+            assert_eq!(ix.accounts[0].pubkey, account_keys[0]);
+        } else {
+            panic!("Expected DepositStake variant");
+        }
+    }
+
+    #[test]
+    fn test_withdraw_stake() {
+        let account_keys = create_test_pubkeys(10);
+
+        let mut data = vec![WITHDRAW_STAKE];
+        data.extend_from_slice(&Keypair::new().pubkey().to_bytes());
+        data.extend_from_slice(&7_000_000_000_u64.to_le_bytes());
+
+        let instruction = CompiledInstruction {
+            program_id_index: 10,
+            accounts: (0..10).collect(),
+            data,
+        };
+
+        let parsed =
+            SplSinglePoolProgram::parse_spl_single_pool_program(&instruction, &account_keys);
+
+        assert!(matches!(
+            parsed,
+            Some(SplSinglePoolProgram::WithdrawStake {
+                token_amount: 7_000_000_000,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,191 @@
+use std::str::FromStr;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::instruction::ParsableInstruction;
+
+/// Anchor instruction discriminators: sha256("global:<instruction name>")[..8]
+const PROPOSAL_CREATE: [u8; 8] = [220, 60, 73, 224, 30, 108, 79, 159];
+const PROPOSAL_APPROVE: [u8; 8] = [144, 37, 164, 136, 188, 216, 42, 248];
+const VAULT_TRANSACTION_EXECUTE: [u8; 8] = [194, 8, 161, 87, 153, 164, 25, 171];
+const CONFIG_TRANSACTION_EXECUTE: [u8; 8] = [114, 146, 244, 189, 252, 140, 36, 40];
+
+/// Squads v4 Multisig Program
+///
+/// - The multisig is always the first account
+#[derive(Debug)]
+pub enum SquadsMultisigProgram {
+    /// #[account(0, name = "multisig")]
+    /// #[account(1, writable, name = "proposal")]
+    /// #[account(2, signer, name = "creator")]
+    /// #[account(3, writable, signer, name = "rent_payer")]
+    ProposalCreate { ix: Instruction },
+
+    /// #[account(0, name = "multisig")]
+    /// #[account(1, writable, signer, name = "member")]
+    /// #[account(2, writable, name = "proposal")]
+    ProposalApprove { ix: Instruction },
+
+    /// #[account(0, name = "multisig")]
+    /// #[account(1, writable, name = "proposal")]
+    /// #[account(2, name = "transaction")]
+    /// #[account(3, signer, name = "member")]
+    VaultTransactionExecute { ix: Instruction },
+
+    /// #[account(0, writable, name = "multisig")]
+    /// #[account(1, signer, name = "member")]
+    /// #[account(2, writable, name = "proposal")]
+    /// #[account(3, name = "transaction")]
+    ConfigTransactionExecute { ix: Instruction },
+}
+
+impl std::fmt::Display for SquadsMultisigProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SquadsMultisigProgram::ProposalCreate { .. } => write!(f, "proposal_create"),
+            SquadsMultisigProgram::ProposalApprove { .. } => write!(f, "proposal_approve"),
+            SquadsMultisigProgram::VaultTransactionExecute { .. } => {
+                write!(f, "vault_transaction_execute")
+            }
+            SquadsMultisigProgram::ConfigTransactionExecute { .. } => {
+                write!(f, "config_transaction_execute")
+            }
+        }
+    }
+}
+
+impl SquadsMultisigProgram {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf").unwrap()
+    }
+
+    /// Parse Squads v4 Multisig Program
+    pub fn parse_squads_multisig_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<SquadsMultisigProgram> {
+        let discriminator = <[u8; 8]>::try_from(instruction.data().get(..8)?).ok()?;
+        let ix = Self::build_ix(instruction, account_keys);
+        if ix.accounts.len() < 3 {
+            return None;
+        }
+
+        let program = match discriminator {
+            PROPOSAL_CREATE => Self::ProposalCreate { ix },
+            PROPOSAL_APPROVE => Self::ProposalApprove { ix },
+            VAULT_TRANSACTION_EXECUTE => Self::VaultTransactionExecute { ix },
+            CONFIG_TRANSACTION_EXECUTE => Self::ConfigTransactionExecute { ix },
+            _ => return None,
+        };
+
+        Some(program)
+    }
+
+    /// Multisig account of the instruction
+    pub fn multisig(&self) -> Pubkey {
+        self.ix().accounts[0].pubkey
+    }
+
+    /// Proposal account of the instruction
+    pub fn proposal(&self) -> Pubkey {
+        match self {
+            SquadsMultisigProgram::ProposalCreate { ix }
+            | SquadsMultisigProgram::VaultTransactionExecute { ix } => ix.accounts[1].pubkey,
+            SquadsMultisigProgram::ProposalApprove { ix }
+            | SquadsMultisigProgram::ConfigTransactionExecute { ix } => ix.accounts[2].pubkey,
+        }
+    }
+
+    /// Instruction of the parsed program
+    pub fn ix(&self) -> &Instruction {
+        match self {
+            SquadsMultisigProgram::ProposalCreate { ix }
+            | SquadsMultisigProgram::ProposalApprove { ix }
+            | SquadsMultisigProgram::VaultTransactionExecute { ix }
+            | SquadsMultisigProgram::ConfigTransactionExecute { ix } => ix,
+        }
+    }
+
+    /// Build instruction with the accounts in their original order
+    fn build_ix<T: ParsableInstruction>(instruction: &T, account_keys: &[Pubkey]) -> Instruction {
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: instruction.data().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::squads::{SquadsMultisigProgram, PROPOSAL_APPROVE, PROPOSAL_CREATE};
+
+    fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Keypair::new().pubkey()).collect()
+    }
+
+    fn create_instruction(num_account: usize, discriminator: [u8; 8]) -> CompiledInstruction {
+        CompiledInstruction {
+            program_id_index: num_account as u32,
+            accounts: (0..num_account).map(|i| i as u8).collect(),
+            data: discriminator.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_proposal_create() {
+        let account_keys = create_test_pubkeys(5);
+        let mut instruction = create_instruction(5, PROPOSAL_CREATE);
+        // transaction_index: 7, draft: false
+        instruction.data.extend_from_slice(&7_u64.to_le_bytes());
+        instruction.data.push(0);
+
+        let parsed =
+            SquadsMultisigProgram::parse_squads_multisig_program(&instruction, &account_keys)
+                .expect("Expected ProposalCreate variant");
+
+        assert!(matches!(
+            parsed,
+            SquadsMultisigProgram::ProposalCreate { .. }
+        ));
+        assert_eq!(parsed.multisig(), account_keys[0]);
+        assert_eq!(parsed.proposal(), account_keys[1]);
+    }
+
+    #[test]
+    fn test_proposal_approve() {
+        let account_keys = create_test_pubkeys(3);
+        let instruction = create_instruction(3, PROPOSAL_APPROVE);
+
+        let parsed =
+            SquadsMultisigProgram::parse_squads_multisig_program(&instruction, &account_keys)
+                .expect("Expected ProposalApprove variant");
+
+        assert_eq!(parsed.to_string(), "proposal_approve");
+        assert_eq!(parsed.proposal(), account_keys[2]);
+    }
+
+    #[test]
+    fn test_unknown_instruction() {
+        let account_keys = create_test_pubkeys(3);
+        let instruction = create_instruction(3, [0; 8]);
+
+        assert!(
+            SquadsMultisigProgram::parse_squads_multisig_program(&instruction, &account_keys)
+                .is_none()
+        );
+    }
+}
@@ -5,25 +5,43 @@ use solana_sdk::{
     native_token::lamports_to_sol,
     pubkey::Pubkey,
 };
-use spl_stake_pool::instruction::StakePoolInstruction;
+use spl_stake_pool::{instruction::StakePoolInstruction, state::FeeType};
 
 use super::instruction::ParsableInstruction;
 
 /// SPL Stake Pool Program
 #[derive(Debug, PartialEq)]
 pub enum SplStakePoolProgram {
-    Initialize,
-    AddValidatorToPool,
-    RemoveValidatorFromPool,
-    DecreaseValidatorStake,
+    Initialize {
+        ix: Instruction,
+    },
+    AddValidatorToPool {
+        ix: Instruction,
+    },
+    RemoveValidatorFromPool {
+        ix: Instruction,
+    },
+    DecreaseValidatorStake {
+        ix: Instruction,
+        amount: f64,
+    },
     IncreaseValidatorStake {
         ix: Instruction,
         amount: f64,
     },
-    SetPreferredValidator,
-    UpdateValidatorListBalance,
-    UpdateStakePoolBalance,
-    CleanupRemovedValidatorEntries,
+    SetPreferredValidator {
+        ix: Instruction,
+        vote_account: Option<Pubkey>,
+    },
+    UpdateValidatorListBalance {
+        ix: Instruction,
+    },
+    UpdateStakePoolBalance {
+        ix: Instruction,
+    },
+    CleanupRemovedValidatorEntries {
+        ix: Instruction,
+    },
     DepositStake {
         ix: Instruction,
     },
@@ -31,49 +49,90 @@ pub enum SplStakePoolProgram {
         ix: Instruction,
         minimum_lamports_out: f64,
     },
-    SetManager,
-    SetFee,
-    SetStaker,
+    SetManager {
+        ix: Instruction,
+    },
+    SetFee {
+        ix: Instruction,
+        fee: FeeType,
+    },
+    SetStaker {
+        ix: Instruction,
+    },
     DepositSol {
         ix: Instruction,
         amount: f64,
     },
-    SetFundingAuthority,
+    SetFundingAuthority {
+        ix: Instruction,
+    },
     WithdrawSol {
         ix: Instruction,
         amount: f64,
     },
-    CreateTokenMetadata,
-    UpdateTokenMetadata,
-    IncreaseAdditionalValidatorStake,
-    DecreaseAdditionalValidatorStake,
+    CreateTokenMetadata {
+        ix: Instruction,
+    },
+    UpdateTokenMetadata {
+        ix: Instruction,
+    },
+    IncreaseAdditionalValidatorStake {
+        ix: Instruction,
+        amount: f64,
+    },
+    DecreaseAdditionalValidatorStake {
+        ix: Instruction,
+        amount: f64,
+    },
     DecreaseValidatorStakeWithReserve {
         ix: Instruction,
         amount: f64,
     },
-    Redelegate,
-    DepositStakeWithSlippage,
-    WithdrawStakeWithSlippage,
-    DepositSolWithSlippage,
-    WithdrawSolWithSlippage,
+    Redelegate {
+        ix: Instruction,
+        amount: f64,
+    },
+    DepositStakeWithSlippage {
+        ix: Instruction,
+    },
+    WithdrawStakeWithSlippage {
+        ix: Instruction,
+        amount: f64,
+    },
+    DepositSolWithSlippage {
+        ix: Instruction,
+        amount: f64,
+    },
+    WithdrawSolWithSlippage {
+        ix: Instruction,
+        amount: f64,
+    },
 }
 
 impl std::fmt::Display for SplStakePoolProgram {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            SplStakePoolProgram::Initialize => write!(f, "initialize"),
-            SplStakePoolProgram::AddValidatorToPool => write!(f, "add_validator_pool"),
-            SplStakePoolProgram::RemoveValidatorFromPool => write!(f, "remove_validator_from_pool"),
-            SplStakePoolProgram::DecreaseValidatorStake => write!(f, "decrease_validator_stake"),
+            SplStakePoolProgram::Initialize { .. } => write!(f, "initialize"),
+            SplStakePoolProgram::AddValidatorToPool { .. } => write!(f, "add_validator_to_pool"),
+            SplStakePoolProgram::RemoveValidatorFromPool { .. } => {
+                write!(f, "remove_validator_from_pool")
+            }
+            SplStakePoolProgram::DecreaseValidatorStake { .. } => {
+                write!(f, "decrease_validator_stake")
+            }
             SplStakePoolProgram::IncreaseValidatorStake { ix: _, amount: _ } => {
                 write!(f, "increase_validator_stake")
             }
-            SplStakePoolProgram::SetPreferredValidator => write!(f, "set_preferred_validator"),
-            SplStakePoolProgram::UpdateValidatorListBalance => {
+            SplStakePoolProgram::SetPreferredValidator { .. } => {
+                write!(f, "set_preferred_validator")
+            }
+            SplStakePoolProgram::UpdateValidatorListBalance { .. } => {
                 write!(f, "update_validator_list_balance")
             }
-            SplStakePoolProgram::UpdateStakePoolBalance => write!(f, "update_stake_pool_balance"),
-            SplStakePoolProgram::CleanupRemovedValidatorEntries => {
+            SplStakePoolProgram::UpdateStakePoolBalance { .. } => {
+                write!(f, "update_stake_pool_balance")
+            }
+            SplStakePoolProgram::CleanupRemovedValidatorEntries { .. } => {
                 write!(f, "cleanup_removed_validator_entries")
             }
             SplStakePoolProgram::DepositStake { ix: _ } => write!(f, "deposit_stake"),
@@ -81,32 +140,36 @@ impl std::fmt::Display for SplStakePoolProgram {
                 ix: _,
                 minimum_lamports_out: _,
             } => write!(f, "withdraw_stake"),
-            SplStakePoolProgram::SetManager => write!(f, "set_manager"),
-            SplStakePoolProgram::SetFee => write!(f, "set_fee"),
-            SplStakePoolProgram::SetStaker => write!(f, "set_staker"),
+            SplStakePoolProgram::SetManager { .. } => write!(f, "set_manager"),
+            SplStakePoolProgram::SetFee { .. } => write!(f, "set_fee"),
+            SplStakePoolProgram::SetStaker { .. } => write!(f, "set_staker"),
             SplStakePoolProgram::DepositSol { ix: _, amount: _ } => write!(f, "deposit_sol"),
-            SplStakePoolProgram::SetFundingAuthority => write!(f, "set_funding_authority"),
+            SplStakePoolProgram::SetFundingAuthority { .. } => write!(f, "set_funding_authority"),
             SplStakePoolProgram::WithdrawSol { ix: _, amount: _ } => write!(f, "withdraw_sol"),
-            SplStakePoolProgram::CreateTokenMetadata => write!(f, "create_token_metadata"),
-            SplStakePoolProgram::UpdateTokenMetadata => write!(f, "update_token_metadata"),
-            SplStakePoolProgram::IncreaseAdditionalValidatorStake => {
+            SplStakePoolProgram::CreateTokenMetadata { .. } => write!(f, "create_token_metadata"),
+            SplStakePoolProgram::UpdateTokenMetadata { .. } => write!(f, "update_token_metadata"),
+            SplStakePoolProgram::IncreaseAdditionalValidatorStake { .. } => {
                 write!(f, "increase_additional_validator_stake")
             }
-            SplStakePoolProgram::DecreaseAdditionalValidatorStake => {
+            SplStakePoolProgram::DecreaseAdditionalValidatorStake { .. } => {
                 write!(f, "decrease_additional_validator_stake")
             }
             SplStakePoolProgram::DecreaseValidatorStakeWithReserve { ix: _, amount: _ } => {
                 write!(f, "decrease_validator_stake_with_reserve")
             }
-            SplStakePoolProgram::Redelegate => write!(f, "redelegate"),
-            SplStakePoolProgram::DepositStakeWithSlippage => {
+            SplStakePoolProgram::Redelegate { .. } => write!(f, "redelegate"),
+            SplStakePoolProgram::DepositStakeWithSlippage { .. } => {
                 write!(f, "deposit_stake_with_slippage")
             }
-            SplStakePoolProgram::WithdrawStakeWithSlippage => {
+            SplStakePoolProgram::WithdrawStakeWithSlippage { .. } => {
                 write!(f, "withdraw_stake_with_slippage")
             }
-            SplStakePoolProgram::DepositSolWithSlippage => write!(f, "deposit_sol_with_slippage"),
-            SplStakePoolProgram::WithdrawSolWithSlippage => write!(f, "withdraw_sol_with_slippage"),
+            SplStakePoolProgram::DepositSolWithSlippage { .. } => {
+                write!(f, "deposit_sol_with_slippage")
+            }
+            SplStakePoolProgram::WithdrawSolWithSlippage { .. } => {
+                write!(f, "withdraw_sol_with_slippage")
+            }
         }
     }
 }
@@ -117,6 +180,41 @@ impl SplStakePoolProgram {
         Pubkey::from_str("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy").unwrap()
     }
 
+    /// Stake pool account of the instruction, the first account of every instruction
+    pub fn stake_pool(&self) -> Option<&Pubkey> {
+        let ix = match self {
+            SplStakePoolProgram::Initialize { ix }
+            | SplStakePoolProgram::AddValidatorToPool { ix }
+            | SplStakePoolProgram::RemoveValidatorFromPool { ix }
+            | SplStakePoolProgram::DecreaseValidatorStake { ix, .. }
+            | SplStakePoolProgram::IncreaseValidatorStake { ix, .. }
+            | SplStakePoolProgram::SetPreferredValidator { ix, .. }
+            | SplStakePoolProgram::UpdateValidatorListBalance { ix }
+            | SplStakePoolProgram::UpdateStakePoolBalance { ix }
+            | SplStakePoolProgram::CleanupRemovedValidatorEntries { ix }
+            | SplStakePoolProgram::DepositStake { ix }
+            | SplStakePoolProgram::WithdrawStake { ix, .. }
+            | SplStakePoolProgram::SetManager { ix }
+            | SplStakePoolProgram::SetFee { ix, .. }
+            | SplStakePoolProgram::SetStaker { ix }
+            | SplStakePoolProgram::DepositSol { ix, .. }
+            | SplStakePoolProgram::SetFundingAuthority { ix }
+            | SplStakePoolProgram::WithdrawSol { ix, .. }
+            | SplStakePoolProgram::CreateTokenMetadata { ix }
+            | SplStakePoolProgram::UpdateTokenMetadata { ix }
+            | SplStakePoolProgram::IncreaseAdditionalValidatorStake { ix, .. }
+            | SplStakePoolProgram::DecreaseAdditionalValidatorStake { ix, .. }
+            | SplStakePoolProgram::DecreaseValidatorStakeWithReserve { ix, .. }
+            | SplStakePoolProgram::Redelegate { ix, .. }
+            | SplStakePoolProgram::DepositStakeWithSlippage { ix }
+            | SplStakePoolProgram::WithdrawStakeWithSlippage { ix, .. }
+            | SplStakePoolProgram::DepositSolWithSlippage { ix, .. }
+            | SplStakePoolProgram::WithdrawSolWithSlippage { ix, .. } => ix,
+        };
+
+        ix.accounts.first().map(|account| &account.pubkey)
+    }
+
     /// Parse SPL Stake Pool program
     pub fn parse_spl_stake_pool_program<T: ParsableInstruction>(
         instruction: &T,
@@ -136,24 +234,44 @@ impl SplStakePoolProgram {
                 account_keys,
                 lamports,
             )),
-            StakePoolInstruction::DepositStake => {
-                Some(Self::parse_deposit_stake_ix(instruction, account_keys))
+            StakePoolInstruction::DepositStake => Some(Self::DepositStake {
+                ix: Self::parse_deposit_stake_ix(instruction, account_keys),
+            }),
+            StakePoolInstruction::WithdrawStake(amount) => Some(Self::WithdrawStake {
+                ix: Self::parse_withdraw_stake_ix(instruction, account_keys),
+                minimum_lamports_out: lamports_to_sol(amount),
+            }),
+            StakePoolInstruction::DepositSol(amount) => Some(Self::DepositSol {
+                ix: Self::parse_deposit_sol_ix(instruction, account_keys),
+                amount: lamports_to_sol(amount),
+            }),
+            StakePoolInstruction::WithdrawSol(amount) => Some(Self::WithdrawSol {
+                ix: Self::parse_withdraw_sol_ix(instruction, account_keys),
+                amount: lamports_to_sol(amount),
+            }),
+            StakePoolInstruction::DepositStakeWithSlippage { .. } => {
+                Some(Self::DepositStakeWithSlippage {
+                    ix: Self::parse_deposit_stake_ix(instruction, account_keys),
+                })
+            }
+            StakePoolInstruction::WithdrawStakeWithSlippage { pool_tokens_in, .. } => {
+                Some(Self::WithdrawStakeWithSlippage {
+                    ix: Self::parse_withdraw_stake_ix(instruction, account_keys),
+                    amount: lamports_to_sol(pool_tokens_in),
+                })
+            }
+            StakePoolInstruction::DepositSolWithSlippage { lamports_in, .. } => {
+                Some(Self::DepositSolWithSlippage {
+                    ix: Self::parse_deposit_sol_ix(instruction, account_keys),
+                    amount: lamports_to_sol(lamports_in),
+                })
+            }
+            StakePoolInstruction::WithdrawSolWithSlippage { pool_tokens_in, .. } => {
+                Some(Self::WithdrawSolWithSlippage {
+                    ix: Self::parse_withdraw_sol_ix(instruction, account_keys),
+                    amount: lamports_to_sol(pool_tokens_in),
+                })
             }
-            StakePoolInstruction::WithdrawStake(amount) => Some(Self::parse_withdraw_stake_ix(
-                instruction,
-                account_keys,
-                amount,
-            )),
-            StakePoolInstruction::DepositSol(amount) => Some(Self::parse_deposit_sol_ix(
-                instruction,
-                account_keys,
-                amount,
-            )),
-            StakePoolInstruction::WithdrawSol(amount) => Some(Self::parse_withdraw_sol_ix(
-                instruction,
-                account_keys,
-                amount,
-            )),
             StakePoolInstruction::DecreaseValidatorStakeWithReserve {
                 lamports,
                 transient_stake_seed: _,
@@ -162,7 +280,89 @@ impl SplStakePoolProgram {
                 account_keys,
                 lamports,
             )),
-            _ => None,
+            StakePoolInstruction::IncreaseAdditionalValidatorStake { lamports, .. } => {
+                Some(Self::IncreaseAdditionalValidatorStake {
+                    ix: Self::build_ix(instruction, account_keys),
+                    amount: lamports_to_sol(lamports),
+                })
+            }
+            StakePoolInstruction::DecreaseAdditionalValidatorStake { lamports, .. } => {
+                Some(Self::DecreaseAdditionalValidatorStake {
+                    ix: Self::build_ix(instruction, account_keys),
+                    amount: lamports_to_sol(lamports),
+                })
+            }
+            #[allow(deprecated)]
+            StakePoolInstruction::DecreaseValidatorStake { lamports, .. } => {
+                Some(Self::DecreaseValidatorStake {
+                    ix: Self::build_ix(instruction, account_keys),
+                    amount: lamports_to_sol(lamports),
+                })
+            }
+            #[allow(deprecated)]
+            StakePoolInstruction::Redelegate { lamports, .. } => Some(Self::Redelegate {
+                ix: Self::build_ix(instruction, account_keys),
+                amount: lamports_to_sol(lamports),
+            }),
+            StakePoolInstruction::SetPreferredValidator {
+                validator_vote_address,
+                ..
+            } => Some(Self::SetPreferredValidator {
+                ix: Self::build_ix(instruction, account_keys),
+                vote_account: validator_vote_address,
+            }),
+            stake_pool_ix => {
+                let ix = Self::build_ix(instruction, account_keys);
+                let program = match stake_pool_ix {
+                    StakePoolInstruction::Initialize { .. } => Self::Initialize { ix },
+                    StakePoolInstruction::AddValidatorToPool(_) => Self::AddValidatorToPool { ix },
+                    StakePoolInstruction::RemoveValidatorFromPool => {
+                        Self::RemoveValidatorFromPool { ix }
+                    }
+                    StakePoolInstruction::UpdateValidatorListBalance { .. } => {
+                        Self::UpdateValidatorListBalance { ix }
+                    }
+                    StakePoolInstruction::UpdateStakePoolBalance => {
+                        Self::UpdateStakePoolBalance { ix }
+                    }
+                    StakePoolInstruction::CleanupRemovedValidatorEntries => {
+                        Self::CleanupRemovedValidatorEntries { ix }
+                    }
+                    StakePoolInstruction::SetManager => Self::SetManager { ix },
+                    StakePoolInstruction::SetFee { fee } => Self::SetFee { ix, fee },
+                    StakePoolInstruction::SetStaker => Self::SetStaker { ix },
+                    StakePoolInstruction::SetFundingAuthority(_) => {
+                        Self::SetFundingAuthority { ix }
+                    }
+                    StakePoolInstruction::CreateTokenMetadata { .. } => {
+                        Self::CreateTokenMetadata { ix }
+                    }
+                    StakePoolInstruction::UpdateTokenMetadata { .. } => {
+                        Self::UpdateTokenMetadata { ix }
+                    }
+                    _ => return None,
+                };
+
+                Some(program)
+            }
+        }
+    }
+
+    /// Build instruction with the accounts in their original order
+    ///
+    /// - Used by the pool management instructions, the stake pool is always the first account
+    fn build_ix<T: ParsableInstruction>(instruction: &T, account_keys: &[Pubkey]) -> Instruction {
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: instruction.data().to_vec(),
         }
     }
 
@@ -227,7 +427,7 @@ impl SplStakePoolProgram {
         }
     }
 
-    /// Parse Deposit Stake Instruction
+    /// Parse Deposit Stake (and Deposit Stake With Slippage) Instruction
     /// https://github.com/solana-labs/solana-program-library/blob/b7dd8fee93815b486fce98d3d43d1d0934980226/stake-pool/program/src/instruction.rs#L271-L289
     ///
     ///   0. `[w]` Stake pool
@@ -252,7 +452,7 @@ impl SplStakePoolProgram {
     fn parse_deposit_stake_ix<T: ParsableInstruction>(
         instruction: &T,
         account_keys: &[Pubkey],
-    ) -> Self {
+    ) -> Instruction {
         let mut account_metas = [
             AccountMeta::new(Pubkey::new_unique(), false),
             AccountMeta::new(Pubkey::new_unique(), false),
@@ -279,16 +479,14 @@ impl SplStakePoolProgram {
             }
         }
 
-        let ix = Instruction {
+        Instruction {
             program_id: SplStakePoolProgram::program_id(),
             accounts: account_metas.to_vec(),
             data: instruction.data().to_vec(),
-        };
-
-        SplStakePoolProgram::DepositStake { ix }
+        }
     }
 
-    /// Parse Withdraw Stake Instruction
+    /// Parse Withdraw Stake (and Withdraw Stake With Slippage) Instruction
     /// https://github.com/solana-labs/solana-program-library/blob/b7dd8fee93815b486fce98d3d43d1d0934980226/stake-pool/program/src/instruction.rs#L313C1-L325C36
     ///
     ///   0. `[w]` Stake pool
@@ -307,8 +505,7 @@ impl SplStakePoolProgram {
     fn parse_withdraw_stake_ix<T: ParsableInstruction>(
         instruction: &T,
         account_keys: &[Pubkey],
-        minimum_lamports_out: u64,
-    ) -> SplStakePoolProgram {
+    ) -> Instruction {
         let mut account_metas = [
             AccountMeta::new(Pubkey::new_unique(), false),
             AccountMeta::new(Pubkey::new_unique(), false),
@@ -333,19 +530,14 @@ impl SplStakePoolProgram {
             }
         }
 
-        let ix = Instruction {
+        Instruction {
             program_id: SplStakePoolProgram::program_id(),
             accounts: account_metas.to_vec(),
             data: instruction.data().to_vec(),
-        };
-
-        SplStakePoolProgram::WithdrawStake {
-            ix,
-            minimum_lamports_out: lamports_to_sol(minimum_lamports_out),
         }
     }
 
-    /// Parse Deposit SOL Instruction
+    /// Parse Deposit SOL (and Deposit SOL With Slippage) Instruction
     /// https://github.com/solana-labs/solana-program-library/blob/b7dd8fee93815b486fce98d3d43d1d0934980226/stake-pool/program/src/instruction.rs#L357C1-L367C64
     ///
     ///   0. `[w]` Stake pool
@@ -362,8 +554,7 @@ impl SplStakePoolProgram {
     fn parse_deposit_sol_ix<T: ParsableInstruction>(
         instruction: &T,
         account_keys: &[Pubkey],
-        amount: u64,
-    ) -> SplStakePoolProgram {
+    ) -> Instruction {
         let mut account_metas = [
             AccountMeta::new(Pubkey::new_unique(), false),
             AccountMeta::new_readonly(Pubkey::new_unique(), false),
@@ -386,19 +577,14 @@ impl SplStakePoolProgram {
             }
         }
 
-        let ix = Instruction {
+        Instruction {
             program_id: SplStakePoolProgram::program_id(),
             accounts: account_metas.to_vec(),
             data: instruction.data().to_vec(),
-        };
-
-        SplStakePoolProgram::DepositSol {
-            ix,
-            amount: lamports_to_sol(amount),
         }
     }
 
-    /// Parse Withdraw SOL Instruction
+    /// Parse Withdraw SOL (and Withdraw SOL With Slippage) Instruction
     /// https://github.com/solana-labs/solana-program-library/blob/b7dd8fee93815b486fce98d3d43d1d0934980226/stake-pool/program/src/instruction.rs#L381C1-L394C64
     ///
     ///   0. `[w]` Stake pool
@@ -418,8 +604,7 @@ impl SplStakePoolProgram {
     fn parse_withdraw_sol_ix<T: ParsableInstruction>(
         instruction: &T,
         account_keys: &[Pubkey],
-        amount: u64,
-    ) -> SplStakePoolProgram {
+    ) -> Instruction {
         let mut account_metas = [
             AccountMeta::new(Pubkey::new_unique(), false),
             AccountMeta::new_readonly(Pubkey::new_unique(), false),
@@ -444,15 +629,10 @@ impl SplStakePoolProgram {
             }
         }
 
-        let ix = Instruction {
+        Instruction {
             program_id: SplStakePoolProgram::program_id(),
             accounts: account_metas.to_vec(),
             data: instruction.data().to_vec(),
-        };
-
-        SplStakePoolProgram::WithdrawSol {
-            ix,
-            amount: lamports_to_sol(amount),
         }
     }
 
@@ -517,7 +697,12 @@ mod tests {
     };
     use yellowstone_grpc_proto::prelude::CompiledInstruction;
 
-    use crate::parser::stake_pool::SplStakePoolProgram;
+    use spl_stake_pool::state::{Fee, FeeType};
+
+    use crate::{
+        notification_info::NotificationInfo, parser::stake_pool::SplStakePoolProgram,
+        threshold_config::ThresholdConfig,
+    };
 
     fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
         (0..count).map(|_| Keypair::new().pubkey()).collect()
@@ -583,6 +768,10 @@ mod tests {
 
         // Validate result
         assert!(parsed.is_some());
+        assert_eq!(
+            parsed.as_ref().and_then(SplStakePoolProgram::stake_pool),
+            Some(&account_keys[0])
+        );
         if let Some(SplStakePoolProgram::DepositStake { ix: _ }) = parsed {
         } else {
             panic!("Expected DepositStake variant");
@@ -647,6 +836,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_deposit_sol_with_slippage() {
+        let ix_number = 25;
+        let num_account = 11;
+        let minimum_pool_tokens_out: u64 = 0;
+
+        let account_keys = create_test_pubkeys(num_account);
+        let thresholds = vec![ThresholdConfig {
+            value: 1000.0,
+            median_multiple: None,
+            notification: NotificationInfo::default(),
+        }];
+
+        // Deposits of 2000 SOL (above the threshold) and 500 SOL (below)
+        for (lamports, reached) in [(2_000_000_000_000_u64, true), (500_000_000_000, false)] {
+            let mut data = vec![ix_number];
+            data.extend_from_slice(&lamports.to_le_bytes());
+            data.extend_from_slice(&minimum_pool_tokens_out.to_le_bytes());
+
+            let accounts = (0..num_account).map(|i| i as u8).collect();
+
+            let instruction = create_compiled_instruction(1, accounts, data);
+
+            // Parse the instruction
+            let parsed =
+                SplStakePoolProgram::parse_spl_stake_pool_program(&instruction, &account_keys);
+
+            // Validate result
+            if let Some(SplStakePoolProgram::DepositSolWithSlippage { ix, amount }) = parsed {
+                assert_eq!(amount, lamports_to_sol(lamports));
+                assert_eq!(ix.accounts[7].pubkey, account_keys[7]);
+                assert_eq!(
+                    ThresholdConfig::reached(&thresholds, amount).is_some(),
+                    reached
+                );
+            } else {
+                panic!("Expected DepositSolWithSlippage variant");
+            }
+        }
+    }
+
     #[test]
     fn test_parse_withdraw_sol() {
         let ix_number = 16;
@@ -704,4 +934,122 @@ mod tests {
             panic!("Expected DecreaseValidatorStakeWithReserve variant");
         }
     }
+
+    #[test]
+    fn test_parse_add_validator_to_pool() {
+        let ix_number = 1;
+        let num_account = 13;
+        let seed: u32 = 0;
+
+        let account_keys = create_test_pubkeys(num_account);
+
+        let mut data = vec![ix_number];
+        data.extend_from_slice(&seed.to_le_bytes());
+
+        let accounts = (0..num_account).map(|i| i as u8).collect();
+
+        let instruction = create_compiled_instruction(1, accounts, data);
+
+        // Parse the instruction
+        let parsed = SplStakePoolProgram::parse_spl_stake_pool_program(&instruction, &account_keys);
+
+        // Validate result
+        if let Some(SplStakePoolProgram::AddValidatorToPool { ix }) = parsed {
+            assert_eq!(ix.accounts[0].pubkey, account_keys[0]);
+            assert_eq!(ix.accounts[6].pubkey, account_keys[6]);
+        } else {
+            panic!("Expected AddValidatorToPool variant");
+        }
+    }
+
+    #[test]
+    fn test_parse_set_manager() {
+        let ix_number = 11;
+        let num_account = 4;
+
+        let account_keys = create_test_pubkeys(num_account);
+
+        let accounts = (0..num_account).map(|i| i as u8).collect();
+
+        let instruction = create_compiled_instruction(1, accounts, vec![ix_number]);
+
+        // Parse the instruction
+        let parsed = SplStakePoolProgram::parse_spl_stake_pool_program(&instruction, &account_keys);
+
+        // Validate result
+        if let Some(SplStakePoolProgram::SetManager { ix }) = parsed {
+            assert_eq!(ix.accounts.len(), num_account);
+            assert_eq!(ix.accounts[2].pubkey, account_keys[2]);
+        } else {
+            panic!("Expected SetManager variant");
+        }
+    }
+
+    #[test]
+    fn test_parse_increase_additional_validator_stake() {
+        let ix_number = 19;
+        let num_account = 14;
+        let lamports: u64 = 2_000_000_000; // 2 SOL
+        let transient_stake_seed: u64 = 1;
+        let ephemeral_stake_seed: u64 = 2;
+
+        let account_keys = create_test_pubkeys(num_account);
+
+        let mut data = vec![ix_number];
+        data.extend_from_slice(&lamports.to_le_bytes());
+        data.extend_from_slice(&transient_stake_seed.to_le_bytes());
+        data.extend_from_slice(&ephemeral_stake_seed.to_le_bytes());
+
+        let accounts = (0..num_account).map(|i| i as u8).collect();
+
+        let instruction = create_compiled_instruction(1, accounts, data);
+
+        // Parse the instruction
+        let parsed = SplStakePoolProgram::parse_spl_stake_pool_program(&instruction, &account_keys);
+
+        // Validate result
+        if let Some(SplStakePoolProgram::IncreaseAdditionalValidatorStake { ix, amount }) = parsed {
+            assert_eq!(amount, lamports_to_sol(lamports));
+            assert_eq!(ix.accounts[8].pubkey, account_keys[8]);
+        } else {
+            panic!("Expected IncreaseAdditionalValidatorStake variant");
+        }
+    }
+
+    #[test]
+    fn test_parse_set_fee() {
+        let ix_number = 12;
+        let num_account = 2;
+        // FeeType::Epoch
+        let fee_type = 2;
+        let denominator: u64 = 100;
+        let numerator: u64 = 5;
+
+        let account_keys = create_test_pubkeys(num_account);
+
+        let mut data = vec![ix_number, fee_type];
+        data.extend_from_slice(&denominator.to_le_bytes());
+        data.extend_from_slice(&numerator.to_le_bytes());
+
+        let accounts = (0..num_account).map(|i| i as u8).collect();
+
+        let instruction = create_compiled_instruction(1, accounts, data);
+
+        // Parse the instruction
+        let parsed = SplStakePoolProgram::parse_spl_stake_pool_program(&instruction, &account_keys);
+
+        // Validate result
+        if let Some(SplStakePoolProgram::SetFee { ix, fee }) = parsed {
+            assert_eq!(ix.accounts[0].pubkey, account_keys[0]);
+            assert_eq!(
+                fee,
+                FeeType::Epoch(Fee {
+                    denominator,
+                    numerator
+                })
+            );
+        } else {
+            panic!("Expected SetFee variant");
+        }
+    }
 }
@@ -0,0 +1,288 @@
+use std::str::FromStr;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::instruction::ParsableInstruction;
+
+/// Anchor instruction discriminators: sha256("global:<instruction name>")[..8]
+const REBALANCE: [u8; 8] = [108, 158, 77, 9, 210, 52, 88, 62];
+const COMPUTE_SCORE: [u8; 8] = [161, 101, 4, 93, 120, 62, 41, 20];
+const COMPUTE_INSTANT_UNSTAKE: [u8; 8] = [172, 220, 51, 183, 2, 94, 253, 251];
+const COMPUTE_DELEGATIONS: [u8; 8] = [249, 138, 49, 247, 69, 32, 11, 175];
+const UPDATE_PARAMETERS: [u8; 8] = [116, 107, 24, 207, 101, 49, 213, 77];
+const ADD_VALIDATOR_TO_BLACKLIST: [u8; 8] = [18, 30, 248, 201, 28, 196, 137, 118];
+const REMOVE_VALIDATOR_FROM_BLACKLIST: [u8; 8] = [253, 48, 101, 237, 109, 14, 153, 208];
+const AUTO_ADD_VALIDATOR_TO_POOL: [u8; 8] = [166, 226, 7, 8, 169, 239, 220, 69];
+const AUTO_REMOVE_VALIDATOR_FROM_POOL: [u8; 8] = [65, 39, 73, 213, 52, 34, 181, 94];
+const INSTANT_REMOVE_VALIDATOR: [u8; 8] = [119, 127, 216, 135, 24, 63, 229, 242];
+const PAUSE_STEWARD: [u8; 8] = [214, 85, 52, 67, 192, 238, 178, 102];
+const RESUME_STEWARD: [u8; 8] = [25, 71, 153, 183, 197, 197, 187, 3];
+
+/// Jito StakeNet Steward Program
+#[derive(Debug)]
+pub enum JitoStewardProgram {
+    /// #[account(0, name = "config")]
+    /// #[account(4, name = "stake_pool")]
+    /// #[account(11, name = "vote_account")]
+    Rebalance {
+        ix: Instruction,
+        validator_list_index: u64,
+    },
+    ComputeScore {
+        ix: Instruction,
+        validator_list_index: u64,
+    },
+    ComputeInstantUnstake {
+        ix: Instruction,
+        validator_list_index: u64,
+    },
+    ComputeDelegations {
+        ix: Instruction,
+    },
+    UpdateParameters {
+        ix: Instruction,
+    },
+    AddValidatorToBlacklist {
+        ix: Instruction,
+        validator_history_index: u32,
+    },
+    RemoveValidatorFromBlacklist {
+        ix: Instruction,
+        validator_history_index: u32,
+    },
+    AutoAddValidatorToPool {
+        ix: Instruction,
+    },
+    AutoRemoveValidatorFromPool {
+        ix: Instruction,
+        validator_list_index: u64,
+    },
+    InstantRemoveValidator {
+        ix: Instruction,
+        validator_list_index: u64,
+    },
+    PauseSteward {
+        ix: Instruction,
+    },
+    ResumeSteward {
+        ix: Instruction,
+    },
+}
+
+impl std::fmt::Display for JitoStewardProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JitoStewardProgram::Rebalance { .. } => write!(f, "rebalance"),
+            JitoStewardProgram::ComputeScore { .. } => write!(f, "compute_score"),
+            JitoStewardProgram::ComputeInstantUnstake { .. } => {
+                write!(f, "compute_instant_unstake")
+            }
+            JitoStewardProgram::ComputeDelegations { .. } => write!(f, "compute_delegations"),
+            JitoStewardProgram::UpdateParameters { .. } => write!(f, "update_parameters"),
+            JitoStewardProgram::AddValidatorToBlacklist { .. } => {
+                write!(f, "add_validator_to_blacklist")
+            }
+            JitoStewardProgram::RemoveValidatorFromBlacklist { .. } => {
+                write!(f, "remove_validator_from_blacklist")
+            }
+            JitoStewardProgram::AutoAddValidatorToPool { .. } => {
+                write!(f, "auto_add_validator_to_pool")
+            }
+            JitoStewardProgram::AutoRemoveValidatorFromPool { .. } => {
+                write!(f, "auto_remove_validator_from_pool")
+            }
+            JitoStewardProgram::InstantRemoveValidator { .. } => {
+                write!(f, "instant_remove_validator")
+            }
+            JitoStewardProgram::PauseSteward { .. } => write!(f, "pause_steward"),
+            JitoStewardProgram::ResumeSteward { .. } => write!(f, "resume_steward"),
+        }
+    }
+}
+
+impl JitoStewardProgram {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("Stewardf95sJbmtcZsyagb2dg4Mo8eVQho8gpECvLx8").unwrap()
+    }
+
+    /// Parse Jito Steward Program
+    pub fn parse_jito_steward_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<JitoStewardProgram> {
+        let data = instruction.data();
+        if data.len() < 8 {
+            return None;
+        }
+        let (discriminator, args) = data.split_at(8);
+        let ix = Self::build_ix(instruction, account_keys);
+
+        let program = match <[u8; 8]>::try_from(discriminator).ok()? {
+            REBALANCE => Self::Rebalance {
+                ix,
+                validator_list_index: read_u64(args)?,
+            },
+            COMPUTE_SCORE => Self::ComputeScore {
+                ix,
+                validator_list_index: read_u64(args)?,
+            },
+            COMPUTE_INSTANT_UNSTAKE => Self::ComputeInstantUnstake {
+                ix,
+                validator_list_index: read_u64(args)?,
+            },
+            COMPUTE_DELEGATIONS => Self::ComputeDelegations { ix },
+            UPDATE_PARAMETERS => Self::UpdateParameters { ix },
+            ADD_VALIDATOR_TO_BLACKLIST => Self::AddValidatorToBlacklist {
+                ix,
+                validator_history_index: read_u32(args)?,
+            },
+            REMOVE_VALIDATOR_FROM_BLACKLIST => Self::RemoveValidatorFromBlacklist {
+                ix,
+                validator_history_index: read_u32(args)?,
+            },
+            AUTO_ADD_VALIDATOR_TO_POOL => Self::AutoAddValidatorToPool { ix },
+            AUTO_REMOVE_VALIDATOR_FROM_POOL => Self::AutoRemoveValidatorFromPool {
+                ix,
+                validator_list_index: read_u64(args)?,
+            },
+            INSTANT_REMOVE_VALIDATOR => Self::InstantRemoveValidator {
+                ix,
+                validator_list_index: read_u64(args)?,
+            },
+            PAUSE_STEWARD => Self::PauseSteward { ix },
+            RESUME_STEWARD => Self::ResumeSteward { ix },
+            _ => return None,
+        };
+
+        Some(program)
+    }
+
+    /// Build instruction with the accounts in their original order
+    fn build_ix<T: ParsableInstruction>(instruction: &T, account_keys: &[Pubkey]) -> Instruction {
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: instruction.data().to_vec(),
+        }
+    }
+
+    /// Instruction of the parsed program
+    pub fn ix(&self) -> &Instruction {
+        match self {
+            JitoStewardProgram::Rebalance { ix, .. }
+            | JitoStewardProgram::ComputeScore { ix, .. }
+            | JitoStewardProgram::ComputeInstantUnstake { ix, .. }
+            | JitoStewardProgram::ComputeDelegations { ix }
+            | JitoStewardProgram::UpdateParameters { ix }
+            | JitoStewardProgram::AddValidatorToBlacklist { ix, .. }
+            | JitoStewardProgram::RemoveValidatorFromBlacklist { ix, .. }
+            | JitoStewardProgram::AutoAddValidatorToPool { ix }
+            | JitoStewardProgram::AutoRemoveValidatorFromPool { ix, .. }
+            | JitoStewardProgram::InstantRemoveValidator { ix, .. }
+            | JitoStewardProgram::PauseSteward { ix }
+            | JitoStewardProgram::ResumeSteward { ix } => ix,
+        }
+    }
+}
+
+fn read_u64(args: &[u8]) -> Option<u64> {
+    Some(u64::from_le_bytes(args.get(..8)?.try_into().ok()?))
+}
+
+fn read_u32(args: &[u8]) -> Option<u32> {
+    Some(u32::from_le_bytes(args.get(..4)?.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::steward::{JitoStewardProgram, ADD_VALIDATOR_TO_BLACKLIST, REBALANCE};
+
+    fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Keypair::new().pubkey()).collect()
+    }
+
+    fn create_compiled_instruction(
+        program_id_index: u32,
+        accounts: Vec<u8>,
+        data: Vec<u8>,
+    ) -> CompiledInstruction {
+        CompiledInstruction {
+            program_id_index,
+            accounts,
+            data,
+        }
+    }
+
+    #[test]
+    fn test_rebalance() {
+        let num_account = 18;
+        let validator_list_index: u64 = 42;
+
+        let account_keys = create_test_pubkeys(num_account);
+
+        let mut data = REBALANCE.to_vec();
+        data.extend_from_slice(&validator_list_index.to_le_bytes());
+
+        let accounts = (0..num_account).map(|i| i as u8).collect();
+
+        let instruction = create_compiled_instruction(1, accounts, data);
+
+        let parsed = JitoStewardProgram::parse_jito_steward_program(&instruction, &account_keys);
+
+        if let Some(JitoStewardProgram::Rebalance {
+            ix,
+            validator_list_index,
+        }) = parsed
+        {
+            assert_eq!(validator_list_index, 42);
+            assert_eq!(ix.accounts[11].pubkey, account_keys[11]);
+        } else {
+            panic!("Expected Rebalance variant");
+        }
+    }
+
+    #[test]
+    fn test_add_validator_to_blacklist() {
+        let account_keys = create_test_pubkeys(3);
+
+        let mut data = ADD_VALIDATOR_TO_BLACKLIST.to_vec();
+        data.extend_from_slice(&7_u32.to_le_bytes());
+
+        let instruction = create_compiled_instruction(1, vec![0, 1, 2], data);
+
+        let parsed = JitoStewardProgram::parse_jito_steward_program(&instruction, &account_keys);
+
+        if let Some(JitoStewardProgram::AddValidatorToBlacklist {
+            validator_history_index,
+            ..
+        }) = parsed
+        {
+            assert_eq!(validator_history_index, 7);
+        } else {
+            panic!("Expected AddValidatorToBlacklist variant");
+        }
+    }
+
+    #[test]
+    fn test_unknown_instruction() {
+        let account_keys = create_test_pubkeys(1);
+        let instruction = create_compiled_instruction(1, vec![0], vec![0; 16]);
+
+        assert!(
+            JitoStewardProgram::parse_jito_steward_program(&instruction, &account_keys).is_none()
+        );
+    }
+}
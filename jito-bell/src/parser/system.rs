@@ -0,0 +1,111 @@
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+
+use super::instruction::ParsableInstruction;
+
+/// System program `Transfer` discriminator
+const SYSTEM_TRANSFER: u32 = 2;
+
+/// System Program
+#[derive(Debug)]
+pub enum SystemProgram {
+    /// #[account(0, writable, signer, name = "from")]
+    /// #[account(1, writable, name = "to")]
+    Transfer { ix: Instruction, lamports: u64 },
+}
+
+impl std::fmt::Display for SystemProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SystemProgram::Transfer { .. } => write!(f, "transfer"),
+        }
+    }
+}
+
+impl SystemProgram {
+    pub fn program_id() -> Pubkey {
+        system_program::id()
+    }
+
+    /// Parse System Program, only transfers are parsed
+    pub fn parse_system_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<SystemProgram> {
+        let data = instruction.data();
+        let discriminator = u32::from_le_bytes(data.get(..4)?.try_into().ok()?);
+        if discriminator != SYSTEM_TRANSFER {
+            return None;
+        }
+        let lamports = u64::from_le_bytes(data.get(4..12)?.try_into().ok()?);
+
+        let mut account_metas = [
+            AccountMeta::new(Pubkey::new_unique(), true),
+            AccountMeta::new(Pubkey::new_unique(), false),
+        ];
+
+        for (index, account) in instruction.accounts().iter().enumerate() {
+            if let Some(account_meta) = account_metas.get_mut(index) {
+                if let Some(account) = account_keys.get(*account as usize) {
+                    account_meta.pubkey = *account;
+                }
+            }
+        }
+
+        let ix = Instruction {
+            program_id: Self::program_id(),
+            accounts: account_metas.to_vec(),
+            data: data.to_vec(),
+        };
+
+        Some(Self::Transfer { ix, lamports })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::system::SystemProgram;
+
+    #[test]
+    fn test_transfer() {
+        let account_keys = vec![Keypair::new().pubkey(), Keypair::new().pubkey()];
+
+        let mut data = 2_u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&3_000_000_000_u64.to_le_bytes());
+        let instruction = CompiledInstruction {
+            program_id_index: 2,
+            accounts: vec![0, 1],
+            data,
+        };
+
+        let parsed = SystemProgram::parse_system_program(&instruction, &account_keys);
+
+        if let Some(SystemProgram::Transfer { ix, lamports }) = parsed {
+            assert_eq!(lamports, 3_000_000_000);
+            assert_eq!(ix.accounts[0].pubkey, account_keys[0]);
+            assert_eq!(ix.accounts[1].pubkey, account_keys[1]);
+        } else {
+            panic!("Expected Transfer variant");
+        }
+    }
+
+    #[test]
+    fn test_other_instruction() {
+        let account_keys = vec![Keypair::new().pubkey()];
+
+        // CreateAccount
+        let instruction = CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: vec![0; 52],
+        };
+
+        assert!(SystemProgram::parse_system_program(&instruction, &account_keys).is_none());
+    }
+}
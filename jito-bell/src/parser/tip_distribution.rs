@@ -0,0 +1,191 @@
+use std::str::FromStr;
+
+use borsh::BorshDeserialize;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::instruction::ParsableInstruction;
+
+/// Anchor instruction discriminators: sha256("global:<instruction name>")[..8]
+const CLAIM: [u8; 8] = [62, 198, 214, 193, 213, 159, 108, 210];
+const UPLOAD_MERKLE_ROOT: [u8; 8] = [70, 3, 110, 29, 199, 190, 205, 176];
+
+#[derive(BorshDeserialize)]
+struct ClaimArgs {
+    _bump: u8,
+    amount: u64,
+    _proof: Vec<[u8; 32]>,
+}
+
+#[derive(BorshDeserialize)]
+struct UploadMerkleRootArgs {
+    _root: [u8; 32],
+    max_total_claim: u64,
+    max_num_nodes: u64,
+}
+
+/// Jito Tip Distribution Program (merkle distributor)
+#[derive(Debug)]
+pub enum JitoTipDistributionProgram {
+    /// #[account(0, name = "config")]
+    /// #[account(1, writable, name = "tip_distribution_account")]
+    /// #[account(2, signer, name = "merkle_root_upload_authority")]
+    /// #[account(3, writable, name = "claim_status")]
+    /// #[account(4, writable, name = "claimant")]
+    /// #[account(5, writable, signer, name = "payer")]
+    /// #[account(6, name = "system_program")]
+    Claim { ix: Instruction, amount: u64 },
+
+    /// #[account(0, name = "config")]
+    /// #[account(1, writable, name = "tip_distribution_account")]
+    /// #[account(2, writable, signer, name = "merkle_root_upload_authority")]
+    UploadMerkleRoot {
+        ix: Instruction,
+        max_total_claim: u64,
+        max_num_nodes: u64,
+    },
+}
+
+impl std::fmt::Display for JitoTipDistributionProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JitoTipDistributionProgram::Claim { .. } => write!(f, "claim"),
+            JitoTipDistributionProgram::UploadMerkleRoot { .. } => {
+                write!(f, "upload_merkle_root")
+            }
+        }
+    }
+}
+
+impl JitoTipDistributionProgram {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("4R3gSG8BpU4t19KYj8CfnbtRpnT8gtk4dvTHxVRwc2r7").unwrap()
+    }
+
+    /// Parse Jito Tip Distribution Program
+    pub fn parse_jito_tip_distribution_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<JitoTipDistributionProgram> {
+        let data = instruction.data();
+        if data.len() < 8 {
+            return None;
+        }
+        let (discriminator, mut args) = data.split_at(8);
+        let ix = Self::build_ix(instruction, account_keys);
+
+        let program = match <[u8; 8]>::try_from(discriminator).ok()? {
+            CLAIM => {
+                let args = ClaimArgs::deserialize(&mut args).ok()?;
+                Self::Claim {
+                    ix,
+                    amount: args.amount,
+                }
+            }
+            UPLOAD_MERKLE_ROOT => {
+                let args = UploadMerkleRootArgs::deserialize(&mut args).ok()?;
+                Self::UploadMerkleRoot {
+                    ix,
+                    max_total_claim: args.max_total_claim,
+                    max_num_nodes: args.max_num_nodes,
+                }
+            }
+            _ => return None,
+        };
+
+        Some(program)
+    }
+
+    /// Build instruction with the accounts in their original order
+    fn build_ix<T: ParsableInstruction>(instruction: &T, account_keys: &[Pubkey]) -> Instruction {
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: instruction.data().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::tip_distribution::{JitoTipDistributionProgram, CLAIM, UPLOAD_MERKLE_ROOT};
+
+    fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Keypair::new().pubkey()).collect()
+    }
+
+    #[test]
+    fn test_claim() {
+        let num_account = 7;
+        let account_keys = create_test_pubkeys(num_account);
+
+        let mut data = CLAIM.to_vec();
+        data.push(254);
+        data.extend_from_slice(&2_500_000_000_u64.to_le_bytes());
+        data.extend_from_slice(&2_u32.to_le_bytes());
+        data.extend_from_slice(&[1; 64]);
+
+        let instruction = CompiledInstruction {
+            program_id_index: 1,
+            accounts: (0..num_account).map(|i| i as u8).collect(),
+            data,
+        };
+
+        let parsed = JitoTipDistributionProgram::parse_jito_tip_distribution_program(
+            &instruction,
+            &account_keys,
+        );
+
+        if let Some(JitoTipDistributionProgram::Claim { ix, amount }) = parsed {
+            assert_eq!(amount, 2_500_000_000);
+            assert_eq!(ix.accounts[4].pubkey, account_keys[4]);
+        } else {
+            panic!("Expected Claim variant");
+        }
+    }
+
+    #[test]
+    fn test_upload_merkle_root() {
+        let account_keys = create_test_pubkeys(3);
+
+        let mut data = UPLOAD_MERKLE_ROOT.to_vec();
+        data.extend_from_slice(&[9; 32]);
+        data.extend_from_slice(&40_000_000_000_u64.to_le_bytes());
+        data.extend_from_slice(&1_200_u64.to_le_bytes());
+
+        let instruction = CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![0, 1, 2],
+            data,
+        };
+
+        let parsed = JitoTipDistributionProgram::parse_jito_tip_distribution_program(
+            &instruction,
+            &account_keys,
+        );
+
+        if let Some(JitoTipDistributionProgram::UploadMerkleRoot {
+            max_total_claim,
+            max_num_nodes,
+            ..
+        }) = parsed
+        {
+            assert_eq!(max_total_claim, 40_000_000_000);
+            assert_eq!(max_num_nodes, 1_200);
+        } else {
+            panic!("Expected UploadMerkleRoot variant");
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use std::str::FromStr;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::{instruction::ParsableInstruction, system::SystemProgram};
+
+/// Anchor instruction discriminators: sha256("global:<instruction name>")[..8]
+const CLAIM_TIPS: [u8; 8] = [247, 28, 193, 228, 55, 238, 31, 113];
+const CHANGE_TIP_RECEIVER: [u8; 8] = [69, 99, 22, 71, 11, 231, 86, 143];
+const CHANGE_BLOCK_BUILDER: [u8; 8] = [134, 80, 38, 137, 165, 21, 114, 123];
+
+/// Tip accounts owned by the tip payment program (mainnet)
+const TIP_ACCOUNTS: [&str; 8] = [
+    "96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5",
+    "HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe",
+    "Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY",
+    "ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49",
+    "DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh",
+    "ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt",
+    "DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL",
+    "3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT",
+];
+
+/// Jito Tip Payment Program
+#[derive(Debug)]
+pub enum JitoTipPaymentProgram {
+    /// System transfer to a tip account
+    ///
+    /// #[account(0, writable, signer, name = "from")]
+    /// #[account(1, writable, name = "tip_account")]
+    Tip {
+        ix: Instruction,
+        lamports: u64,
+    },
+
+    ClaimTips {
+        ix: Instruction,
+    },
+
+    ChangeTipReceiver {
+        ix: Instruction,
+    },
+
+    ChangeBlockBuilder {
+        ix: Instruction,
+    },
+}
+
+impl std::fmt::Display for JitoTipPaymentProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JitoTipPaymentProgram::Tip { .. } => write!(f, "tip"),
+            JitoTipPaymentProgram::ClaimTips { .. } => write!(f, "claim_tips"),
+            JitoTipPaymentProgram::ChangeTipReceiver { .. } => write!(f, "change_tip_receiver"),
+            JitoTipPaymentProgram::ChangeBlockBuilder { .. } => {
+                write!(f, "change_block_builder")
+            }
+        }
+    }
+}
+
+impl JitoTipPaymentProgram {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("T1pyyaTNZsKv2WcRAB8oVnk93mLJw2XzjtVYqCsaHqt").unwrap()
+    }
+
+    /// Whether the account is one of the tip accounts
+    pub fn is_tip_account(pubkey: &Pubkey) -> bool {
+        TIP_ACCOUNTS
+            .iter()
+            .any(|tip_account| Pubkey::from_str(tip_account).is_ok_and(|tip| tip.eq(pubkey)))
+    }
+
+    /// Parse Jito Tip Payment Program
+    pub fn parse_jito_tip_payment_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<JitoTipPaymentProgram> {
+        let discriminator = <[u8; 8]>::try_from(instruction.data().get(..8)?).ok()?;
+        let ix = Self::build_ix(instruction, account_keys);
+
+        let program = match discriminator {
+            CLAIM_TIPS => Self::ClaimTips { ix },
+            CHANGE_TIP_RECEIVER => Self::ChangeTipReceiver { ix },
+            CHANGE_BLOCK_BUILDER => Self::ChangeBlockBuilder { ix },
+            _ => return None,
+        };
+
+        Some(program)
+    }
+
+    /// Parse a System program transfer, only transfers to a tip account are tips
+    pub fn parse_tip_transfer<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<JitoTipPaymentProgram> {
+        let SystemProgram::Transfer { ix, lamports } =
+            SystemProgram::parse_system_program(instruction, account_keys)?;
+        if !Self::is_tip_account(&ix.accounts[1].pubkey) {
+            return None;
+        }
+
+        Some(Self::Tip { ix, lamports })
+    }
+
+    /// Build instruction with the accounts in their original order
+    fn build_ix<T: ParsableInstruction>(instruction: &T, account_keys: &[Pubkey]) -> Instruction {
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: instruction.data().to_vec(),
+        }
+    }
+
+    /// Instruction of the parsed program
+    pub fn ix(&self) -> &Instruction {
+        match self {
+            JitoTipPaymentProgram::Tip { ix, .. }
+            | JitoTipPaymentProgram::ClaimTips { ix }
+            | JitoTipPaymentProgram::ChangeTipReceiver { ix }
+            | JitoTipPaymentProgram::ChangeBlockBuilder { ix } => ix,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::tip_payment::{JitoTipPaymentProgram, CLAIM_TIPS, TIP_ACCOUNTS};
+
+    fn transfer_data(lamports: u64) -> Vec<u8> {
+        let mut data = 2_u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&lamports.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_tip_transfer() {
+        let account_keys = vec![
+            Keypair::new().pubkey(),
+            Pubkey::from_str(TIP_ACCOUNTS[3]).unwrap(),
+        ];
+        let instruction = CompiledInstruction {
+            program_id_index: 2,
+            accounts: vec![0, 1],
+            data: transfer_data(5_000_000),
+        };
+
+        let parsed = JitoTipPaymentProgram::parse_tip_transfer(&instruction, &account_keys);
+
+        if let Some(JitoTipPaymentProgram::Tip { ix, lamports }) = parsed {
+            assert_eq!(lamports, 5_000_000);
+            assert_eq!(ix.accounts[0].pubkey, account_keys[0]);
+        } else {
+            panic!("Expected Tip variant");
+        }
+    }
+
+    #[test]
+    fn test_transfer_to_other_account() {
+        let account_keys = vec![Keypair::new().pubkey(), Keypair::new().pubkey()];
+        let instruction = CompiledInstruction {
+            program_id_index: 2,
+            accounts: vec![0, 1],
+            data: transfer_data(5_000_000),
+        };
+
+        assert!(JitoTipPaymentProgram::parse_tip_transfer(&instruction, &account_keys).is_none());
+    }
+
+    #[test]
+    fn test_claim_tips() {
+        let account_keys = vec![Keypair::new().pubkey()];
+        let instruction = CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: CLAIM_TIPS.to_vec(),
+        };
+
+        assert!(matches!(
+            JitoTipPaymentProgram::parse_jito_tip_payment_program(&instruction, &account_keys),
+            Some(JitoTipPaymentProgram::ClaimTips { .. })
+        ));
+    }
+}
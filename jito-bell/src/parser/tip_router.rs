@@ -0,0 +1,363 @@
+use std::str::FromStr;
+
+use borsh::BorshDeserialize;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::instruction::ParsableInstruction;
+
+/// Instruction discriminators, the variant index of `TipRouterInstruction`
+const CAST_VOTE: u8 = 14;
+const SET_MERKLE_ROOT: u8 = 15;
+const ROUTE_BASE_REWARDS: u8 = 19;
+const ROUTE_NCN_REWARDS: u8 = 20;
+const DISTRIBUTE_BASE_REWARDS: u8 = 21;
+const DISTRIBUTE_BASE_NCN_REWARD_ROUTE: u8 = 22;
+const DISTRIBUTE_NCN_OPERATOR_REWARDS: u8 = 23;
+const DISTRIBUTE_NCN_VAULT_REWARDS: u8 = 24;
+const ADMIN_SET_TIE_BREAKER: u8 = 30;
+
+#[derive(BorshDeserialize)]
+struct CastVoteArgs {
+    meta_merkle_root: [u8; 32],
+    epoch: u64,
+}
+
+#[derive(BorshDeserialize)]
+struct SetMerkleRootArgs {
+    _proof: Vec<[u8; 32]>,
+    _merkle_root: [u8; 32],
+    _max_total_claim: u64,
+    _max_num_nodes: u64,
+    epoch: u64,
+}
+
+#[derive(BorshDeserialize)]
+struct RouteBaseRewardsArgs {
+    _max_iterations: u16,
+    epoch: u64,
+}
+
+#[derive(BorshDeserialize)]
+struct RouteNcnRewardsArgs {
+    ncn_fee_group: u8,
+    _max_iterations: u16,
+    epoch: u64,
+}
+
+#[derive(BorshDeserialize)]
+struct FeeGroupArgs {
+    fee_group: u8,
+    epoch: u64,
+}
+
+/// Jito TipRouter Program
+#[derive(Debug)]
+pub enum JitoTipRouterProgram {
+    /// #[account(0, name = "epoch_state")]
+    /// #[account(1, name = "config")]
+    /// #[account(2, writable, name = "ballot_box")]
+    /// #[account(3, name = "ncn")]
+    /// #[account(4, name = "epoch_snapshot")]
+    /// #[account(5, name = "operator_snapshot")]
+    /// #[account(6, name = "operator")]
+    /// #[account(7, signer, name = "operator_admin")]
+    CastVote {
+        ix: Instruction,
+        meta_merkle_root: [u8; 32],
+        epoch: u64,
+    },
+    SetMerkleRoot {
+        ix: Instruction,
+        epoch: u64,
+    },
+    RouteBaseRewards {
+        ix: Instruction,
+        epoch: u64,
+    },
+    RouteNcnRewards {
+        ix: Instruction,
+        ncn_fee_group: u8,
+        epoch: u64,
+    },
+    DistributeBaseRewards {
+        ix: Instruction,
+        base_fee_group: u8,
+        epoch: u64,
+    },
+    DistributeBaseNcnRewardRoute {
+        ix: Instruction,
+        ncn_fee_group: u8,
+        epoch: u64,
+    },
+    DistributeNcnOperatorRewards {
+        ix: Instruction,
+        ncn_fee_group: u8,
+        epoch: u64,
+    },
+    DistributeNcnVaultRewards {
+        ix: Instruction,
+        ncn_fee_group: u8,
+        epoch: u64,
+    },
+    AdminSetTieBreaker {
+        ix: Instruction,
+        meta_merkle_root: [u8; 32],
+        epoch: u64,
+    },
+}
+
+impl std::fmt::Display for JitoTipRouterProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JitoTipRouterProgram::CastVote { .. } => write!(f, "cast_vote"),
+            JitoTipRouterProgram::SetMerkleRoot { .. } => write!(f, "set_merkle_root"),
+            JitoTipRouterProgram::RouteBaseRewards { .. } => write!(f, "route_base_rewards"),
+            JitoTipRouterProgram::RouteNcnRewards { .. } => write!(f, "route_ncn_rewards"),
+            JitoTipRouterProgram::DistributeBaseRewards { .. } => {
+                write!(f, "distribute_base_rewards")
+            }
+            JitoTipRouterProgram::DistributeBaseNcnRewardRoute { .. } => {
+                write!(f, "distribute_base_ncn_reward_route")
+            }
+            JitoTipRouterProgram::DistributeNcnOperatorRewards { .. } => {
+                write!(f, "distribute_ncn_operator_rewards")
+            }
+            JitoTipRouterProgram::DistributeNcnVaultRewards { .. } => {
+                write!(f, "distribute_ncn_vault_rewards")
+            }
+            JitoTipRouterProgram::AdminSetTieBreaker { .. } => {
+                write!(f, "admin_set_tie_breaker")
+            }
+        }
+    }
+}
+
+impl JitoTipRouterProgram {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("RouterBmuRBkPUbgEDMtdvTZ75GBdSREZR5uGUxxxpb").unwrap()
+    }
+
+    /// Parse Jito TipRouter Program
+    pub fn parse_jito_tip_router_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<JitoTipRouterProgram> {
+        let (discriminator, mut args) = instruction.data().split_first()?;
+        let ix = Self::build_ix(instruction, account_keys);
+
+        let program = match *discriminator {
+            CAST_VOTE => {
+                let args = CastVoteArgs::deserialize(&mut args).ok()?;
+                Self::CastVote {
+                    ix,
+                    meta_merkle_root: args.meta_merkle_root,
+                    epoch: args.epoch,
+                }
+            }
+            SET_MERKLE_ROOT => {
+                let args = SetMerkleRootArgs::deserialize(&mut args).ok()?;
+                Self::SetMerkleRoot {
+                    ix,
+                    epoch: args.epoch,
+                }
+            }
+            ROUTE_BASE_REWARDS => {
+                let args = RouteBaseRewardsArgs::deserialize(&mut args).ok()?;
+                Self::RouteBaseRewards {
+                    ix,
+                    epoch: args.epoch,
+                }
+            }
+            ROUTE_NCN_REWARDS => {
+                let args = RouteNcnRewardsArgs::deserialize(&mut args).ok()?;
+                Self::RouteNcnRewards {
+                    ix,
+                    ncn_fee_group: args.ncn_fee_group,
+                    epoch: args.epoch,
+                }
+            }
+            DISTRIBUTE_BASE_REWARDS => {
+                let args = FeeGroupArgs::deserialize(&mut args).ok()?;
+                Self::DistributeBaseRewards {
+                    ix,
+                    base_fee_group: args.fee_group,
+                    epoch: args.epoch,
+                }
+            }
+            DISTRIBUTE_BASE_NCN_REWARD_ROUTE => {
+                let args = FeeGroupArgs::deserialize(&mut args).ok()?;
+                Self::DistributeBaseNcnRewardRoute {
+                    ix,
+                    ncn_fee_group: args.fee_group,
+                    epoch: args.epoch,
+                }
+            }
+            DISTRIBUTE_NCN_OPERATOR_REWARDS => {
+                let args = FeeGroupArgs::deserialize(&mut args).ok()?;
+                Self::DistributeNcnOperatorRewards {
+                    ix,
+                    ncn_fee_group: args.fee_group,
+                    epoch: args.epoch,
+                }
+            }
+            DISTRIBUTE_NCN_VAULT_REWARDS => {
+                let args = FeeGroupArgs::deserialize(&mut args).ok()?;
+                Self::DistributeNcnVaultRewards {
+                    ix,
+                    ncn_fee_group: args.fee_group,
+                    epoch: args.epoch,
+                }
+            }
+            ADMIN_SET_TIE_BREAKER => {
+                let args = CastVoteArgs::deserialize(&mut args).ok()?;
+                Self::AdminSetTieBreaker {
+                    ix,
+                    meta_merkle_root: args.meta_merkle_root,
+                    epoch: args.epoch,
+                }
+            }
+            _ => return None,
+        };
+
+        Some(program)
+    }
+
+    /// Build instruction with the accounts in their original order
+    fn build_ix<T: ParsableInstruction>(instruction: &T, account_keys: &[Pubkey]) -> Instruction {
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: instruction.data().to_vec(),
+        }
+    }
+
+    /// Instruction of the parsed program
+    pub fn ix(&self) -> &Instruction {
+        match self {
+            JitoTipRouterProgram::CastVote { ix, .. }
+            | JitoTipRouterProgram::SetMerkleRoot { ix, .. }
+            | JitoTipRouterProgram::RouteBaseRewards { ix, .. }
+            | JitoTipRouterProgram::RouteNcnRewards { ix, .. }
+            | JitoTipRouterProgram::DistributeBaseRewards { ix, .. }
+            | JitoTipRouterProgram::DistributeBaseNcnRewardRoute { ix, .. }
+            | JitoTipRouterProgram::DistributeNcnOperatorRewards { ix, .. }
+            | JitoTipRouterProgram::DistributeNcnVaultRewards { ix, .. }
+            | JitoTipRouterProgram::AdminSetTieBreaker { ix, .. } => ix,
+        }
+    }
+
+    /// Epoch the instruction acts on
+    pub fn epoch(&self) -> u64 {
+        match self {
+            JitoTipRouterProgram::CastVote { epoch, .. }
+            | JitoTipRouterProgram::SetMerkleRoot { epoch, .. }
+            | JitoTipRouterProgram::RouteBaseRewards { epoch, .. }
+            | JitoTipRouterProgram::RouteNcnRewards { epoch, .. }
+            | JitoTipRouterProgram::DistributeBaseRewards { epoch, .. }
+            | JitoTipRouterProgram::DistributeBaseNcnRewardRoute { epoch, .. }
+            | JitoTipRouterProgram::DistributeNcnOperatorRewards { epoch, .. }
+            | JitoTipRouterProgram::DistributeNcnVaultRewards { epoch, .. }
+            | JitoTipRouterProgram::AdminSetTieBreaker { epoch, .. } => *epoch,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::tip_router::{JitoTipRouterProgram, CAST_VOTE, ROUTE_NCN_REWARDS};
+
+    fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Keypair::new().pubkey()).collect()
+    }
+
+    fn create_compiled_instruction(
+        program_id_index: u32,
+        accounts: Vec<u8>,
+        data: Vec<u8>,
+    ) -> CompiledInstruction {
+        CompiledInstruction {
+            program_id_index,
+            accounts,
+            data,
+        }
+    }
+
+    #[test]
+    fn test_cast_vote() {
+        let num_account = 9;
+        let account_keys = create_test_pubkeys(num_account);
+
+        let mut data = vec![CAST_VOTE];
+        data.extend_from_slice(&[7; 32]);
+        data.extend_from_slice(&800_u64.to_le_bytes());
+
+        let accounts = (0..num_account).map(|i| i as u8).collect();
+        let instruction = create_compiled_instruction(1, accounts, data);
+
+        let parsed =
+            JitoTipRouterProgram::parse_jito_tip_router_program(&instruction, &account_keys);
+
+        if let Some(JitoTipRouterProgram::CastVote {
+            ix,
+            meta_merkle_root,
+            epoch,
+        }) = parsed
+        {
+            assert_eq!(meta_merkle_root, [7; 32]);
+            assert_eq!(epoch, 800);
+            assert_eq!(ix.accounts[6].pubkey, account_keys[6]);
+        } else {
+            panic!("Expected CastVote variant");
+        }
+    }
+
+    #[test]
+    fn test_route_ncn_rewards() {
+        let account_keys = create_test_pubkeys(3);
+
+        let mut data = vec![ROUTE_NCN_REWARDS, 2];
+        data.extend_from_slice(&100_u16.to_le_bytes());
+        data.extend_from_slice(&801_u64.to_le_bytes());
+
+        let instruction = create_compiled_instruction(1, vec![0, 1, 2], data);
+
+        let parsed =
+            JitoTipRouterProgram::parse_jito_tip_router_program(&instruction, &account_keys);
+
+        if let Some(JitoTipRouterProgram::RouteNcnRewards {
+            ncn_fee_group,
+            epoch,
+            ..
+        }) = parsed
+        {
+            assert_eq!(ncn_fee_group, 2);
+            assert_eq!(epoch, 801);
+        } else {
+            panic!("Expected RouteNcnRewards variant");
+        }
+    }
+
+    #[test]
+    fn test_unknown_instruction() {
+        let account_keys = create_test_pubkeys(1);
+        let instruction = create_compiled_instruction(1, vec![0], vec![0; 16]);
+
+        assert!(
+            JitoTipRouterProgram::parse_jito_tip_router_program(&instruction, &account_keys)
+                .is_none()
+        );
+    }
+}
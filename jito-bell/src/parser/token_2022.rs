@@ -2,20 +2,82 @@ use solana_sdk::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
 };
-use spl_token_2022::instruction::TokenInstruction;
+use spl_token_2022::{
+    extension::transfer_fee::instruction::TransferFeeInstruction, instruction::TokenInstruction,
+};
 
 use super::instruction::ParsableInstruction;
 
-/// SPL Stake Pool Program
+/// Transfer hook interface `Execute` discriminator: sha256("spl-transfer-hook-interface:execute")[..8]
+const TRANSFER_HOOK_EXECUTE: [u8; 8] = [105, 37, 101, 197, 75, 251, 102, 26];
+
+/// Transfer hook invoked by Token-2022 during a transfer of a mint with the TransferHook extension
+///
+/// #[account(0, name = "source")]
+/// #[account(1, name = "mint")]
+/// #[account(2, name = "destination")]
+/// #[account(3, name = "authority")]
+/// #[account(4, name = "extra_account_metas")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferHookExecute {
+    /// Transfer hook program
+    pub program_id: Pubkey,
+
+    pub source: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+/// SPL Token 2022 Program
+///
+/// - Checked transfers carry the transfer hook program invoked for them, if any
 #[derive(Debug)]
 pub enum SplToken2022Program {
-    MintTo { ix: Instruction, amount: u64 },
+    MintTo {
+        ix: Instruction,
+        amount: u64,
+    },
+    Transfer {
+        ix: Instruction,
+        amount: u64,
+    },
+    TransferChecked {
+        ix: Instruction,
+        amount: u64,
+        decimals: u8,
+        hook_program: Option<Pubkey>,
+    },
+    /// TransferFee extension, `amount` includes the `fee` withheld in the destination account
+    TransferCheckedWithFee {
+        ix: Instruction,
+        amount: u64,
+        decimals: u8,
+        fee: u64,
+        hook_program: Option<Pubkey>,
+    },
+    Burn {
+        ix: Instruction,
+        amount: u64,
+    },
+    BurnChecked {
+        ix: Instruction,
+        amount: u64,
+        decimals: u8,
+    },
 }
 
 impl std::fmt::Display for SplToken2022Program {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             SplToken2022Program::MintTo { ix: _, amount: _ } => write!(f, "mint_to"),
+            SplToken2022Program::Transfer { ix: _, amount: _ } => write!(f, "transfer"),
+            SplToken2022Program::TransferChecked { .. } => write!(f, "transfer_checked"),
+            SplToken2022Program::TransferCheckedWithFee { .. } => {
+                write!(f, "transfer_checked_with_fee")
+            }
+            SplToken2022Program::Burn { ix: _, amount: _ } => write!(f, "burn"),
+            SplToken2022Program::BurnChecked { .. } => write!(f, "burn_checked"),
         }
     }
 }
@@ -36,10 +98,55 @@ impl SplToken2022Program {
             Err(_) => return None,
         };
 
+        #[allow(deprecated)]
         match token_ix {
             TokenInstruction::MintTo { amount } => {
                 Some(Self::parse_mint_to_ix(instruction, account_keys, amount))
             }
+            TokenInstruction::Transfer { amount } => {
+                let ix = Self::build_ix(instruction, account_keys, 3);
+                Some(Self::Transfer { ix, amount })
+            }
+            TokenInstruction::TransferChecked { amount, decimals } => {
+                let ix = Self::build_ix(instruction, account_keys, 4);
+                Some(Self::TransferChecked {
+                    ix,
+                    amount,
+                    decimals,
+                    hook_program: None,
+                })
+            }
+            TokenInstruction::TransferFeeExtension => {
+                match TransferFeeInstruction::unpack(instruction.data().get(1..)?).ok()? {
+                    TransferFeeInstruction::TransferCheckedWithFee {
+                        amount,
+                        decimals,
+                        fee,
+                    } => {
+                        let ix = Self::build_ix(instruction, account_keys, 4);
+                        Some(Self::TransferCheckedWithFee {
+                            ix,
+                            amount,
+                            decimals,
+                            fee,
+                            hook_program: None,
+                        })
+                    }
+                    _ => None,
+                }
+            }
+            TokenInstruction::Burn { amount } => {
+                let ix = Self::build_ix(instruction, account_keys, 3);
+                Some(Self::Burn { ix, amount })
+            }
+            TokenInstruction::BurnChecked { amount, decimals } => {
+                let ix = Self::build_ix(instruction, account_keys, 3);
+                Some(Self::BurnChecked {
+                    ix,
+                    amount,
+                    decimals,
+                })
+            }
             _ => None,
         }
     }
@@ -88,6 +195,105 @@ impl SplToken2022Program {
 
         SplToken2022Program::MintTo { ix, amount }
     }
+
+    /// Parse the transfer hook `Execute` instruction Token-2022 invokes on the hook program
+    pub fn parse_transfer_hook_execute<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<TransferHookExecute> {
+        let data = instruction.data();
+        if data.get(..8)? != TRANSFER_HOOK_EXECUTE {
+            return None;
+        }
+        let amount = u64::from_le_bytes(data.get(8..16)?.try_into().ok()?);
+
+        let account = |index: usize| {
+            instruction
+                .accounts()
+                .get(index)
+                .and_then(|account| account_keys.get(*account as usize))
+                .copied()
+        };
+
+        Some(TransferHookExecute {
+            program_id: *account_keys.get(instruction.program_id_index() as usize)?,
+            source: account(0)?,
+            mint: account(1)?,
+            destination: account(2)?,
+            amount,
+        })
+    }
+
+    /// Attribute the transfer hook invocation to this transfer if it was invoked for it
+    pub fn attribute_transfer_hook(&mut self, hook: &TransferHookExecute) -> bool {
+        let (ix, amount, hook_program) = match self {
+            SplToken2022Program::TransferChecked {
+                ix,
+                amount,
+                hook_program,
+                ..
+            }
+            | SplToken2022Program::TransferCheckedWithFee {
+                ix,
+                amount,
+                hook_program,
+                ..
+            } => (ix, amount, hook_program),
+            _ => return false,
+        };
+        if hook_program.is_some() || *amount != hook.amount {
+            return false;
+        }
+
+        let accounts: Vec<Pubkey> = ix.accounts.iter().take(3).map(|meta| meta.pubkey).collect();
+        if accounts != [hook.source, hook.mint, hook.destination] {
+            return false;
+        }
+        *hook_program = Some(hook.program_id);
+
+        true
+    }
+
+    /// Build instruction with the first `num_accounts` accounts in their original order
+    ///
+    /// Transfer: `[source, destination, owner]`
+    /// TransferChecked, TransferCheckedWithFee: `[source, mint, destination, owner]`
+    /// Burn, BurnChecked: `[account, mint, owner]`
+    ///
+    /// Multisignature signers after the owner are dropped
+    fn build_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+        num_accounts: usize,
+    ) -> Instruction {
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .take(num_accounts)
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: instruction.data().to_vec(),
+        }
+    }
+
+    /// Burned amount if the instruction burns `mint` tokens from `account`
+    pub fn burned_from(&self, mint: &Pubkey, account: &Pubkey) -> Option<u64> {
+        match self {
+            SplToken2022Program::Burn { ix, amount }
+            | SplToken2022Program::BurnChecked { ix, amount, .. } => {
+                let account_info = ix.accounts.first()?;
+                let mint_info = ix.accounts.get(1)?;
+
+                (account_info.pubkey.eq(account) && mint_info.pubkey.eq(mint)).then_some(*amount)
+            }
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -95,7 +301,7 @@ mod tests {
     use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
     use yellowstone_grpc_proto::prelude::CompiledInstruction;
 
-    use crate::parser::token_2022::SplToken2022Program;
+    use crate::parser::token_2022::{SplToken2022Program, TRANSFER_HOOK_EXECUTE};
 
     fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
         (0..count).map(|_| Keypair::new().pubkey()).collect()
@@ -140,4 +346,126 @@ mod tests {
             panic!("Expected MintTo variant");
         }
     }
+
+    #[test]
+    fn test_burn_checked() {
+        let ix_number = 15;
+        let num_account = 3;
+        let amount: u64 = 2_000_000_000;
+
+        let account_keys = create_test_pubkeys(num_account);
+
+        let mut data = vec![ix_number];
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.push(9);
+
+        let accounts = (0..num_account).map(|i| i as u8).collect();
+        let instruction = create_compiled_instruction(1, accounts, data);
+
+        let parsed =
+            SplToken2022Program::parse_spl_token_2022_program(&instruction, &account_keys).unwrap();
+
+        if let SplToken2022Program::BurnChecked {
+            amount, decimals, ..
+        } = parsed
+        {
+            assert_eq!(amount, 2_000_000_000);
+            assert_eq!(decimals, 9);
+        } else {
+            panic!("Expected BurnChecked variant");
+        }
+
+        assert_eq!(
+            parsed.burned_from(&account_keys[1], &account_keys[0]),
+            Some(2_000_000_000)
+        );
+        assert_eq!(parsed.burned_from(&account_keys[0], &account_keys[1]), None);
+    }
+
+    #[test]
+    fn test_transfer_checked() {
+        let ix_number = 12;
+        let num_account = 4;
+
+        let account_keys = create_test_pubkeys(num_account);
+
+        let mut data = vec![ix_number];
+        data.extend_from_slice(&700_u64.to_le_bytes());
+        data.push(6);
+
+        let accounts = (0..num_account).map(|i| i as u8).collect();
+        let instruction = create_compiled_instruction(1, accounts, data);
+
+        let parsed = SplToken2022Program::parse_spl_token_2022_program(&instruction, &account_keys);
+
+        if let Some(SplToken2022Program::TransferChecked { ix, amount, .. }) = parsed {
+            assert_eq!(amount, 700);
+            assert_eq!(ix.accounts[2].pubkey, account_keys[2]);
+        } else {
+            panic!("Expected TransferChecked variant");
+        }
+    }
+
+    #[test]
+    fn test_transfer_checked_with_fee() {
+        let num_account = 4;
+
+        let account_keys = create_test_pubkeys(num_account);
+
+        // TransferFeeExtension, TransferCheckedWithFee
+        let mut data = vec![26, 1];
+        data.extend_from_slice(&1_000_u64.to_le_bytes());
+        data.push(9);
+        data.extend_from_slice(&10_u64.to_le_bytes());
+
+        let accounts = (0..num_account).map(|i| i as u8).collect();
+        let instruction = create_compiled_instruction(1, accounts, data);
+
+        let parsed = SplToken2022Program::parse_spl_token_2022_program(&instruction, &account_keys);
+
+        if let Some(SplToken2022Program::TransferCheckedWithFee {
+            ix, amount, fee, ..
+        }) = parsed
+        {
+            assert_eq!(amount, 1_000);
+            assert_eq!(fee, 10);
+            assert_eq!(ix.accounts[1].pubkey, account_keys[1]);
+        } else {
+            panic!("Expected TransferCheckedWithFee variant");
+        }
+    }
+
+    #[test]
+    fn test_attribute_transfer_hook() {
+        let account_keys = create_test_pubkeys(6);
+
+        let transfer_checked = || {
+            let mut data = vec![12];
+            data.extend_from_slice(&700_u64.to_le_bytes());
+            data.push(6);
+            let instruction = create_compiled_instruction(4, vec![0, 1, 2, 3], data);
+            SplToken2022Program::parse_spl_token_2022_program(&instruction, &account_keys).unwrap()
+        };
+        let execute = |amount: u64| {
+            let mut data = TRANSFER_HOOK_EXECUTE.to_vec();
+            data.extend_from_slice(&amount.to_le_bytes());
+            let instruction = create_compiled_instruction(5, vec![0, 1, 2, 3, 4], data);
+            SplToken2022Program::parse_transfer_hook_execute(&instruction, &account_keys).unwrap()
+        };
+
+        let mut transfer = transfer_checked();
+        let hook = execute(700);
+        assert_eq!(hook.program_id, account_keys[5]);
+        assert!(transfer.attribute_transfer_hook(&hook));
+        assert!(!transfer.attribute_transfer_hook(&hook));
+
+        if let SplToken2022Program::TransferChecked { hook_program, .. } = transfer {
+            assert_eq!(hook_program, Some(account_keys[5]));
+        } else {
+            panic!("Expected TransferChecked variant");
+        }
+
+        // Hook of another transfer
+        assert!(!transfer_checked().attribute_transfer_hook(&execute(500)));
+    }
 }
@@ -29,8 +29,12 @@ pub enum JitoVaultProgram {
         ix: Instruction,
         amount: u64,
     },
-    ChangeWithdrawalTicketOwner,
-    BurnWithdrawalTicket,
+    ChangeWithdrawalTicketOwner {
+        ix: Instruction,
+    },
+    BurnWithdrawalTicket {
+        ix: Instruction,
+    },
     SetDepositCapacity,
     SetFees,
     SetProgramFee,
@@ -39,8 +43,14 @@ pub enum JitoVaultProgram {
     DelegateTokenAccount,
     SetAdmin,
     SetSecondaryAdmin,
-    AddDelegation,
-    CooldownDelegation,
+    AddDelegation {
+        ix: Instruction,
+        amount: u64,
+    },
+    CooldownDelegation {
+        ix: Instruction,
+        amount: u64,
+    },
     UpdateVaultBalance,
     InitializeVaultUpdateStateTracker,
     CrankVaultUpdateStateTracker,
@@ -88,10 +98,10 @@ impl std::fmt::Display for JitoVaultProgram {
             JitoVaultProgram::EnqueueWithdrawal { ix: _, amount: _ } => {
                 write!(f, "enqueue_withdrawal")
             }
-            JitoVaultProgram::ChangeWithdrawalTicketOwner => {
+            JitoVaultProgram::ChangeWithdrawalTicketOwner { ix: _ } => {
                 write!(f, "change_withdrawal_ticket_owner")
             }
-            JitoVaultProgram::BurnWithdrawalTicket => {
+            JitoVaultProgram::BurnWithdrawalTicket { ix: _ } => {
                 write!(f, "burn_withdrawal_ticket")
             }
             JitoVaultProgram::SetDepositCapacity => {
@@ -118,10 +128,10 @@ impl std::fmt::Display for JitoVaultProgram {
             JitoVaultProgram::SetSecondaryAdmin => {
                 write!(f, "set_secondary_admin")
             }
-            JitoVaultProgram::AddDelegation => {
+            JitoVaultProgram::AddDelegation { ix: _, amount: _ } => {
                 write!(f, "add_delegation")
             }
-            JitoVaultProgram::CooldownDelegation => {
+            JitoVaultProgram::CooldownDelegation { ix: _, amount: _ } => {
                 write!(f, "cooldown_delegation")
             }
 
@@ -177,6 +187,25 @@ impl JitoVaultProgram {
             VaultInstruction::EnqueueWithdrawal { amount } => Some(
                 Self::parse_enqueue_withdrawal_ix(instruction, account_keys, amount),
             ),
+            VaultInstruction::ChangeWithdrawalTicketOwner => Some(
+                Self::parse_change_withdrawal_ticket_owner_ix(instruction, account_keys),
+            ),
+            VaultInstruction::BurnWithdrawalTicket => Some(Self::parse_burn_withdrawal_ticket_ix(
+                instruction,
+                account_keys,
+            )),
+            VaultInstruction::AddDelegation { amount } => Some(Self::parse_delegation_ix(
+                instruction,
+                account_keys,
+                amount,
+                false,
+            )),
+            VaultInstruction::CooldownDelegation { amount } => Some(Self::parse_delegation_ix(
+                instruction,
+                account_keys,
+                amount,
+                true,
+            )),
             _ => None,
         }
     }
@@ -270,6 +299,130 @@ impl JitoVaultProgram {
 
         Self::EnqueueWithdrawal { ix, amount }
     }
+
+    /// #[account(0, name = "config")]
+    /// #[account(1, name = "vault")]
+    /// #[account(2, writable, name = "vault_staker_withdrawal_ticket")]
+    /// #[account(3, signer, name = "old_owner")]
+    /// #[account(4, name = "new_owner")]
+    pub fn parse_change_withdrawal_ticket_owner_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Self {
+        let mut account_metas = [
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+
+        for (index, account) in instruction.accounts().iter().enumerate() {
+            if let Some(account_meta) = account_metas.get_mut(index) {
+                if let Some(account) = account_keys.get(*account as usize) {
+                    account_meta.pubkey = *account;
+                }
+            }
+        }
+
+        let ix = Instruction {
+            program_id: Self::program_id(),
+            accounts: account_metas.to_vec(),
+            data: instruction.data().to_vec(),
+        };
+
+        Self::ChangeWithdrawalTicketOwner { ix }
+    }
+
+    /// #[account(0, name = "config")]
+    /// #[account(1, writable, name = "vault")]
+    /// #[account(2, writable, name = "vault_token_account")]
+    /// #[account(3, writable, name = "vrt_mint")]
+    /// #[account(4, writable, name = "staker")]
+    /// #[account(5, writable, name = "staker_token_account")]
+    /// #[account(6, writable, name = "vault_staker_withdrawal_ticket")]
+    /// #[account(7, writable, name = "vault_staker_withdrawal_ticket_token_account")]
+    /// #[account(8, writable, name = "vault_fee_token_account")]
+    /// #[account(9, writable, name = "program_fee_token_account")]
+    /// #[account(10, name = "token_program")]
+    /// #[account(11, name = "system_program")]
+    pub fn parse_burn_withdrawal_ticket_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Self {
+        let mut account_metas = [
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+
+        for (index, account) in instruction.accounts().iter().enumerate() {
+            if let Some(account_meta) = account_metas.get_mut(index) {
+                if let Some(account) = account_keys.get(*account as usize) {
+                    account_meta.pubkey = *account;
+                }
+            }
+        }
+
+        let ix = Instruction {
+            program_id: Self::program_id(),
+            accounts: account_metas.to_vec(),
+            data: instruction.data().to_vec(),
+        };
+
+        Self::BurnWithdrawalTicket { ix }
+    }
+
+    /// AddDelegation and CooldownDelegation share their accounts
+    ///
+    /// #[account(0, name = "config")]
+    /// #[account(1, writable, name = "vault")]
+    /// #[account(2, name = "operator")]
+    /// #[account(3, writable, name = "vault_operator_delegation")]
+    /// #[account(4, signer, name = "admin")]
+    pub fn parse_delegation_ix<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+        amount: u64,
+        cooldown: bool,
+    ) -> Self {
+        let mut account_metas = [
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+
+        for (index, account) in instruction.accounts().iter().enumerate() {
+            if let Some(account_meta) = account_metas.get_mut(index) {
+                if let Some(account) = account_keys.get(*account as usize) {
+                    account_meta.pubkey = *account;
+                }
+            }
+        }
+
+        let ix = Instruction {
+            program_id: Self::program_id(),
+            accounts: account_metas.to_vec(),
+            data: instruction.data().to_vec(),
+        };
+
+        if cooldown {
+            Self::CooldownDelegation { ix, amount }
+        } else {
+            Self::AddDelegation { ix, amount }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -352,4 +505,49 @@ mod tests {
             panic!("Expected MintTo variant");
         }
     }
+
+    #[test]
+    fn test_burn_withdrawal_ticket() {
+        let ix_number = 14;
+        let num_account = 12;
+
+        let account_keys = create_test_pubkeys(num_account);
+        let accounts = (0..num_account).map(|i| i as u8).collect();
+        let instruction = create_compiled_instruction(1, accounts, vec![ix_number]);
+
+        let parsed = JitoVaultProgram::parse_jito_vault_program(&instruction, &account_keys);
+
+        if let Some(JitoVaultProgram::BurnWithdrawalTicket { ix }) = parsed {
+            assert_eq!(ix.accounts[3].pubkey, account_keys[3]);
+            assert_eq!(ix.accounts[7].pubkey, account_keys[7]);
+        } else {
+            panic!("Expected BurnWithdrawalTicket variant");
+        }
+    }
+
+    #[test]
+    fn test_delegation() {
+        let num_account = 5;
+        let amount: u64 = 7_000_000_000;
+
+        let account_keys = create_test_pubkeys(num_account);
+
+        for (ix_number, cooldown) in [(23, false), (24, true)] {
+            let mut data = vec![ix_number];
+            data.extend_from_slice(&amount.to_le_bytes());
+            let accounts = (0..num_account).map(|i| i as u8).collect();
+            let instruction = create_compiled_instruction(1, accounts, data);
+
+            let parsed = JitoVaultProgram::parse_jito_vault_program(&instruction, &account_keys);
+
+            match (parsed, cooldown) {
+                (Some(JitoVaultProgram::AddDelegation { ix, amount }), false)
+                | (Some(JitoVaultProgram::CooldownDelegation { ix, amount }), true) => {
+                    assert_eq!(amount, 7_000_000_000);
+                    assert_eq!(ix.accounts[2].pubkey, account_keys[2]);
+                }
+                (parsed, _) => panic!("Unexpected variant: {parsed:?}"),
+            }
+        }
+    }
 }
@@ -0,0 +1,105 @@
+use std::str::FromStr;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::instruction::ParsableInstruction;
+
+/// Vote program `UpdateCommission` discriminator (bincode u32 enum tag)
+const UPDATE_COMMISSION: u32 = 5;
+
+/// Vote Program
+#[derive(Debug)]
+pub enum VoteProgram {
+    /// #[account(0, writable, name = "vote_account")]
+    /// #[account(1, signer, name = "withdraw_authority")]
+    UpdateCommission { ix: Instruction, commission: u8 },
+}
+
+impl std::fmt::Display for VoteProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VoteProgram::UpdateCommission { .. } => write!(f, "update_commission"),
+        }
+    }
+}
+
+impl VoteProgram {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("Vote111111111111111111111111111111111111111").unwrap()
+    }
+
+    /// Parse Vote Program, only commission updates are parsed
+    pub fn parse_vote_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<VoteProgram> {
+        let data = instruction.data();
+        let discriminator = u32::from_le_bytes(data.get(..4)?.try_into().ok()?);
+        if discriminator != UPDATE_COMMISSION {
+            return None;
+        }
+        let commission = *data.get(4)?;
+
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        let ix = Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: data.to_vec(),
+        };
+
+        Some(Self::UpdateCommission { ix, commission })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::vote::VoteProgram;
+
+    #[test]
+    fn test_update_commission() {
+        let account_keys = vec![Keypair::new().pubkey(), Keypair::new().pubkey()];
+
+        let mut data = 5_u32.to_le_bytes().to_vec();
+        data.push(8);
+        let instruction = CompiledInstruction {
+            program_id_index: 2,
+            accounts: vec![0, 1],
+            data,
+        };
+
+        let parsed = VoteProgram::parse_vote_program(&instruction, &account_keys);
+
+        if let Some(VoteProgram::UpdateCommission { ix, commission }) = parsed {
+            assert_eq!(commission, 8);
+            assert_eq!(ix.accounts[0].pubkey, account_keys[0]);
+        } else {
+            panic!("Expected UpdateCommission variant");
+        }
+    }
+
+    #[test]
+    fn test_vote() {
+        let account_keys = vec![Keypair::new().pubkey()];
+
+        // Vote
+        let instruction = CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: vec![2, 0, 0, 0, 1],
+        };
+
+        assert!(VoteProgram::parse_vote_program(&instruction, &account_keys).is_none());
+    }
+}
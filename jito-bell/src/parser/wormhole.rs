@@ -0,0 +1,177 @@
+use std::str::FromStr;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use super::instruction::ParsableInstruction;
+
+/// Token Bridge instruction discriminators (borsh u8 enum tag)
+const TRANSFER_NATIVE: u8 = 5;
+const TRANSFER_NATIVE_WITH_PAYLOAD: u8 = 12;
+
+/// Wormhole Token Bridge Program
+///
+/// - Only transfers of Solana-native mints (e.g. JitoSOL) leaving Solana are parsed
+/// - Arguments start with `(nonce: u32, amount: u64)`, followed by the target address and chain
+#[derive(Debug)]
+pub enum WormholeTokenBridgeProgram {
+    /// transfer_native(_with_payload)
+    ///
+    /// #[account(0, writable, signer, name = "payer")]
+    /// #[account(1, name = "config")]
+    /// #[account(2, writable, name = "from")]
+    /// #[account(3, writable, name = "mint")]
+    /// #[account(4, writable, name = "custody")]
+    TransferNative {
+        ix: Instruction,
+        amount: u64,
+        target_chain: u16,
+    },
+}
+
+impl std::fmt::Display for WormholeTokenBridgeProgram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WormholeTokenBridgeProgram::TransferNative { .. } => write!(f, "transfer_native"),
+        }
+    }
+}
+
+impl WormholeTokenBridgeProgram {
+    pub fn program_id() -> Pubkey {
+        Pubkey::from_str("wormDTUJ6AWPNvk59vGQbDvGJmqbDTdgWgAqcLBCgUb").unwrap()
+    }
+
+    /// Parse Wormhole Token Bridge Program
+    pub fn parse_wormhole_token_bridge_program<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<WormholeTokenBridgeProgram> {
+        let data = instruction.data();
+        let amount = u64::from_le_bytes(data.get(5..13)?.try_into().ok()?);
+
+        // transfer_native carries a relayer fee before the target address
+        let target_chain_offset = match *data.first()? {
+            TRANSFER_NATIVE => 53,
+            TRANSFER_NATIVE_WITH_PAYLOAD => 45,
+            _ => return None,
+        };
+        let target_chain = u16::from_le_bytes(
+            data.get(target_chain_offset..target_chain_offset + 2)?
+                .try_into()
+                .ok()?,
+        );
+
+        let ix = Self::build_ix(instruction, account_keys);
+        if ix.accounts.len() < 4 {
+            return None;
+        }
+
+        Some(Self::TransferNative {
+            ix,
+            amount,
+            target_chain,
+        })
+    }
+
+    /// Build instruction with the accounts in their original order
+    fn build_ix<T: ParsableInstruction>(instruction: &T, account_keys: &[Pubkey]) -> Instruction {
+        let accounts = instruction
+            .accounts()
+            .iter()
+            .filter_map(|account| account_keys.get(*account as usize))
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect();
+
+        Instruction {
+            program_id: Self::program_id(),
+            accounts,
+            data: instruction.data().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::wormhole::{
+        WormholeTokenBridgeProgram, TRANSFER_NATIVE, TRANSFER_NATIVE_WITH_PAYLOAD,
+    };
+
+    fn create_test_pubkeys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Keypair::new().pubkey()).collect()
+    }
+
+    #[test]
+    fn test_transfer_native() {
+        let account_keys = create_test_pubkeys(17);
+
+        let mut data = vec![TRANSFER_NATIVE];
+        data.extend_from_slice(&1_u32.to_le_bytes());
+        data.extend_from_slice(&5_000_000_000_u64.to_le_bytes());
+        data.extend_from_slice(&0_u64.to_le_bytes());
+        data.extend_from_slice(&[7; 32]);
+        data.extend_from_slice(&2_u16.to_le_bytes());
+
+        let instruction = CompiledInstruction {
+            program_id_index: 17,
+            accounts: (0..17).collect(),
+            data,
+        };
+
+        let parsed = WormholeTokenBridgeProgram::parse_wormhole_token_bridge_program(
+            &instruction,
+            &account_keys,
+        );
+
+        if let Some(WormholeTokenBridgeProgram::TransferNative {
+            ix,
+            amount,
+            target_chain,
+        }) = parsed
+        {
+            assert_eq!(amount, 5_000_000_000);
+            assert_eq!(target_chain, 2);
+            assert_eq!(ix.accounts[3].pubkey, account_keys[3]);
+        } else {
+            panic!("Expected TransferNative variant");
+        }
+    }
+
+    #[test]
+    fn test_transfer_native_with_payload() {
+        let account_keys = create_test_pubkeys(18);
+
+        let mut data = vec![TRANSFER_NATIVE_WITH_PAYLOAD];
+        data.extend_from_slice(&1_u32.to_le_bytes());
+        data.extend_from_slice(&42_u64.to_le_bytes());
+        data.extend_from_slice(&[7; 32]);
+        data.extend_from_slice(&30_u16.to_le_bytes());
+        data.extend_from_slice(&0_u32.to_le_bytes());
+        data.push(0);
+
+        let instruction = CompiledInstruction {
+            program_id_index: 18,
+            accounts: (0..18).collect(),
+            data,
+        };
+
+        let parsed = WormholeTokenBridgeProgram::parse_wormhole_token_bridge_program(
+            &instruction,
+            &account_keys,
+        );
+
+        assert!(matches!(
+            parsed,
+            Some(WormholeTokenBridgeProgram::TransferNative {
+                amount: 42,
+                target_chain: 30,
+                ..
+            })
+        ));
+    }
+}
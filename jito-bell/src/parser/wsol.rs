@@ -0,0 +1,137 @@
+use solana_sdk::pubkey::Pubkey;
+
+use super::instruction::ParsableInstruction;
+
+/// SPL Token instruction discriminators, shared by Token-2022
+const CLOSE_ACCOUNT: u8 = 9;
+const SYNC_NATIVE: u8 = 17;
+
+/// Wrap or unwrap of a token account, only kept for wSOL accounts once balances are known
+#[derive(Debug, Clone, PartialEq)]
+pub enum WsolInstruction {
+    /// #[account(0, writable, name = "account")]
+    ///
+    /// Lamports sent to the account are wrapped
+    SyncNative { account: Pubkey },
+
+    /// #[account(0, writable, name = "account")]
+    /// #[account(1, writable, name = "destination")]
+    /// #[account(2, signer, name = "owner")]
+    ///
+    /// Remaining lamports, the unwrapped SOL for wSOL accounts, go to `destination`
+    CloseAccount {
+        account: Pubkey,
+        destination: Pubkey,
+    },
+}
+
+impl WsolInstruction {
+    /// Native mints of SPL Token and Token-2022
+    pub fn native_mints() -> [Pubkey; 2] {
+        [
+            spl_token::native_mint::id(),
+            spl_token_2022::native_mint::id(),
+        ]
+    }
+
+    /// Parse `SyncNative` and `CloseAccount` of SPL Token and Token-2022 Programs
+    pub fn parse<T: ParsableInstruction>(
+        instruction: &T,
+        account_keys: &[Pubkey],
+    ) -> Option<WsolInstruction> {
+        let program_id = account_keys.get(instruction.program_id_index() as usize)?;
+        if !program_id.eq(&spl_token::id()) && !program_id.eq(&spl_token_2022::id()) {
+            return None;
+        }
+
+        let account = |index: usize| {
+            instruction
+                .accounts()
+                .get(index)
+                .and_then(|account| account_keys.get(*account as usize))
+                .copied()
+        };
+
+        match instruction.data() {
+            [SYNC_NATIVE] => Some(Self::SyncNative {
+                account: account(0)?,
+            }),
+            [CLOSE_ACCOUNT] => Some(Self::CloseAccount {
+                account: account(0)?,
+                destination: account(1)?,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Token account wrapped or closed
+    pub fn account(&self) -> &Pubkey {
+        match self {
+            WsolInstruction::SyncNative { account }
+            | WsolInstruction::CloseAccount { account, .. } => account,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+    use yellowstone_grpc_proto::prelude::CompiledInstruction;
+
+    use crate::parser::wsol::{WsolInstruction, CLOSE_ACCOUNT, SYNC_NATIVE};
+
+    fn create_test_pubkeys(count: usize, program_id: Pubkey) -> Vec<Pubkey> {
+        let mut account_keys: Vec<Pubkey> = (0..count).map(|_| Keypair::new().pubkey()).collect();
+        account_keys.push(program_id);
+        account_keys
+    }
+
+    #[test]
+    fn test_sync_native() {
+        let account_keys = create_test_pubkeys(1, spl_token::id());
+        let instruction = CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: vec![SYNC_NATIVE],
+        };
+
+        assert_eq!(
+            WsolInstruction::parse(&instruction, &account_keys),
+            Some(WsolInstruction::SyncNative {
+                account: account_keys[0]
+            })
+        );
+    }
+
+    #[test]
+    fn test_close_account() {
+        let account_keys = create_test_pubkeys(3, spl_token_2022::id());
+        let instruction = CompiledInstruction {
+            program_id_index: 3,
+            accounts: vec![0, 1, 2],
+            data: vec![CLOSE_ACCOUNT],
+        };
+
+        let parsed = WsolInstruction::parse(&instruction, &account_keys).unwrap();
+        assert_eq!(
+            parsed,
+            WsolInstruction::CloseAccount {
+                account: account_keys[0],
+                destination: account_keys[1],
+            }
+        );
+        assert_eq!(parsed.account(), &account_keys[0]);
+    }
+
+    #[test]
+    fn test_other_program() {
+        let account_keys = create_test_pubkeys(1, Keypair::new().pubkey());
+        let instruction = CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![0],
+            data: vec![SYNC_NATIVE],
+        };
+
+        assert!(WsolInstruction::parse(&instruction, &account_keys).is_none());
+    }
+}
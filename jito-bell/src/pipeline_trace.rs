@@ -0,0 +1,176 @@
+use std::{fmt::Write, time::Instant};
+
+use log::info;
+use serde::{Deserialize, Serialize};
+use solana_metrics::datapoint_info;
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Subscriber,
+};
+use tracing_subscriber::{
+    layer::{Context, SubscriberExt},
+    registry::LookupSpan,
+    Layer, Registry,
+};
+
+use crate::{error::JitoBellError, otlp::OtlpLayer};
+
+/// Target prefix of the pipeline spans, spans of dependencies (tonic, hyper, ...) are ignored
+const PIPELINE_TARGET: &str = "jito_bell";
+
+/// Tracing of the event pipeline
+///
+/// - Each transaction is traced through `transaction` → `parse` → `match` → `enrich` →
+///   `dispatch` → `send` (per destination) spans
+/// - Every closed span is reported in the `jito-bell-span` datapoint with its wall-clock
+///   duration, so latency can be broken down by stage
+/// - `dispatch` also reports the time since the transaction's block time in the
+///   `jito-bell-dispatch-latency` datapoint
+/// - With `otlp_endpoint`, the spans are also exported to an OpenTelemetry collector
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TracingConfig {
+    /// Also log every closed span with its fields and duration
+    #[serde(default)]
+    pub log_spans: bool,
+
+    /// OTLP/HTTP endpoint of an OpenTelemetry collector (e.g. "http://localhost:4318"), spans
+    /// are posted to `<otlp_endpoint>/v1/traces`
+    pub otlp_endpoint: Option<String>,
+}
+
+/// Install the pipeline tracing as the global `tracing` subscriber
+///
+/// - Logging is unchanged, log records don't go through `tracing`
+/// - Spans are exported with `http_client` when `otlp_endpoint` is set
+pub fn init(config: &TracingConfig, http_client: reqwest::Client) -> Result<(), JitoBellError> {
+    let subscriber = Registry::default()
+        .with(layer(config))
+        .with(otlp_layer(config, http_client));
+
+    tracing::subscriber::set_global_default(subscriber)
+        .map_err(|e| JitoBellError::Config(format!("Failed to install tracing: {e}")))
+}
+
+/// Layer timing the pipeline spans, for a subscriber installed elsewhere
+pub fn layer(config: &TracingConfig) -> PipelineLayer {
+    PipelineLayer {
+        log_spans: config.log_spans,
+    }
+}
+
+/// Layer exporting the pipeline spans, None without `otlp_endpoint`
+pub fn otlp_layer(config: &TracingConfig, http_client: reqwest::Client) -> Option<OtlpLayer> {
+    config
+        .otlp_endpoint
+        .as_deref()
+        .map(|endpoint| OtlpLayer::new(endpoint, http_client))
+}
+
+/// Layer timing the pipeline spans
+pub struct PipelineLayer {
+    /// Log every closed span
+    log_spans: bool,
+}
+
+/// Start and fields of a pipeline span
+struct SpanTiming {
+    /// Creation time
+    started: Instant,
+
+    /// Fields as "name=value" pairs
+    fields: String,
+}
+
+impl<S> Layer<S> for PipelineLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if !is_pipeline_target(attrs.metadata().target()) {
+            return;
+        }
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let mut fields = String::new();
+        attrs.record(&mut FieldsVisitor(&mut fields));
+        span.extensions_mut().insert(SpanTiming {
+            started: Instant::now(),
+            fields,
+        });
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let mut extensions = span.extensions_mut();
+        if let Some(timing) = extensions.get_mut::<SpanTiming>() {
+            values.record(&mut FieldsVisitor(&mut timing.fields));
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let extensions = span.extensions();
+        let Some(timing) = extensions.get::<SpanTiming>() else {
+            return;
+        };
+
+        let duration = timing.started.elapsed();
+        datapoint_info!(
+            "jito-bell-span",
+            ("span", span.name(), String),
+            ("duration_us", duration.as_micros() as i64, i64)
+        );
+        if self.log_spans {
+            info!(
+                "Span {}{{{}}} took {:.3}ms",
+                span.name(),
+                timing.fields,
+                duration.as_secs_f64() * 1_000.0
+            );
+        }
+    }
+}
+
+/// Appends the visited fields to a string as "name=value" pairs
+struct FieldsVisitor<'a>(&'a mut String);
+
+impl Visit for FieldsVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record_debug(field, &format_args!("{value}"));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        let _ = write!(self.0, "{}={value:?}", field.name());
+    }
+}
+
+/// Whether a span target belongs to the pipeline
+pub(crate) fn is_pipeline_target(target: &str) -> bool {
+    target
+        .strip_prefix(PIPELINE_TARGET)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pipeline_trace::is_pipeline_target;
+
+    #[test]
+    fn test_is_pipeline_target() {
+        assert!(is_pipeline_target("jito_bell"));
+        assert!(is_pipeline_target("jito_bell::enrichment"));
+        assert!(!is_pipeline_target("jito_bell_cli"));
+        assert!(!is_pipeline_target("h2::proto"));
+    }
+}
@@ -1,14 +1,22 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, path::PathBuf};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::instruction::Instruction;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 pub struct Program {
     /// Program ID
     pub program_id: String,
 
+    /// Additional program IDs sharing the instruction layout (e.g. SPL stake pool forks)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_program_ids: Vec<String>,
+
+    /// Anchor IDL (JSON) to decode the program with, for programs without a built-in parser
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idl: Option<PathBuf>,
+
     /// Instructions
     pub instructions: HashMap<String, Instruction>,
 }
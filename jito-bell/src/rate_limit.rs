@@ -0,0 +1,239 @@
+use std::{collections::HashMap, time::Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{notification_event::NotificationEvent, schedule::HeldAlerts};
+
+/// Rate limit of a destination, a token bucket of `messages` refilled over `period_secs`
+///
+/// - Bursts of up to `messages` are sent immediately
+/// - Alerts beyond the limit are held and coalesced into a single digest, sent once a message
+///   is allowed again
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct RateLimitConfig {
+    /// Messages allowed per period, also the burst size
+    pub messages: u32,
+
+    /// Period in seconds
+    #[serde(default = "default_period_secs")]
+    pub period_secs: u64,
+}
+
+fn default_period_secs() -> u64 {
+    60
+}
+
+impl RateLimitConfig {
+    /// Tokens refilled per second
+    fn refill_rate(&self) -> f64 {
+        self.messages as f64 / self.period_secs.max(1) as f64
+    }
+}
+
+/// Token bucket of a destination
+#[derive(Debug)]
+struct TokenBucket {
+    /// Tokens available, up to `messages`
+    tokens: f64,
+
+    /// Last refill
+    refilled: Instant,
+}
+
+impl TokenBucket {
+    /// Full bucket
+    fn new(config: &RateLimitConfig, now: Instant) -> Self {
+        Self {
+            tokens: config.messages as f64,
+            refilled: now,
+        }
+    }
+
+    /// Refill the tokens accrued since the last refill
+    fn refill(&mut self, config: &RateLimitConfig, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.refilled);
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * config.refill_rate())
+            .min(config.messages as f64);
+        self.refilled = now;
+    }
+
+    /// Whether a token is available
+    fn is_available(&mut self, config: &RateLimitConfig, now: Instant) -> bool {
+        self.refill(config, now);
+        self.tokens >= 1.0
+    }
+
+    /// Take a token if available
+    fn try_take(&mut self, config: &RateLimitConfig, now: Instant) -> bool {
+        if !self.is_available(config, now) {
+            return false;
+        }
+        self.tokens -= 1.0;
+
+        true
+    }
+}
+
+/// Rate limiters and alerts held by them, per destination
+#[derive(Debug, Default)]
+pub struct RateLimiters {
+    buckets: HashMap<String, TokenBucket>,
+    held: HashMap<String, HeldAlerts>,
+}
+
+impl RateLimiters {
+    /// Whether an alert to `destination` is sent now, taking a token, otherwise it is held
+    ///
+    /// - Alerts are held while older ones wait, so the digest keeps them in order
+    pub fn admit(
+        &mut self,
+        destination: &str,
+        config: &RateLimitConfig,
+        event: &NotificationEvent,
+        now: Instant,
+    ) -> bool {
+        if let Some(held) = self.held.get_mut(destination) {
+            held.hold(event);
+            return false;
+        }
+
+        let bucket = self
+            .buckets
+            .entry(destination.to_string())
+            .or_insert_with(|| TokenBucket::new(config, now));
+        if bucket.try_take(config, now) {
+            return true;
+        }
+
+        self.held
+            .entry(destination.to_string())
+            .or_default()
+            .hold(event);
+
+        false
+    }
+
+    /// Take the digests of the destinations allowed to send again
+    ///
+    /// - The token is left for the digest itself, taken when it is admitted
+    /// - Destinations whose rate limit was removed are always due
+    pub fn take_due(
+        &mut self,
+        config: impl Fn(&str) -> Option<RateLimitConfig>,
+        now: Instant,
+    ) -> Vec<(String, String)> {
+        let due: Vec<String> = self
+            .held
+            .keys()
+            .filter(|destination| match config(destination) {
+                Some(config) => self
+                    .buckets
+                    .get_mut(destination.as_str())
+                    .is_none_or(|bucket| bucket.is_available(&config, now)),
+                None => true,
+            })
+            .cloned()
+            .collect();
+
+        due.into_iter()
+            .filter_map(|destination| {
+                let held = self.held.remove(&destination)?;
+                Some((destination, held.digest("over the rate limit")))
+            })
+            .collect()
+    }
+
+    /// Take the alerts held for every destination, e.g. to keep them across a restart
+    pub(crate) fn take_all(&mut self) -> HashMap<String, HeldAlerts> {
+        std::mem::take(&mut self.held)
+    }
+
+    /// Hold alerts taken with `take_all` again
+    ///
+    /// - Their digest is due once the destination's bucket allows a message
+    pub(crate) fn restore(&mut self, destination: &str, held: HeldAlerts) {
+        self.held
+            .entry(destination.to_string())
+            .or_default()
+            .extend(held);
+    }
+
+    /// Number of alerts held
+    pub fn len(&self) -> usize {
+        self.held.values().map(HeldAlerts::count).sum()
+    }
+
+    /// Whether no alert is held
+    pub fn is_empty(&self) -> bool {
+        self.held.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::{
+        notification_event::NotificationEvent,
+        notification_info::NotificationInfo,
+        rate_limit::{RateLimitConfig, RateLimiters},
+    };
+
+    fn event(description: &str) -> NotificationEvent {
+        NotificationEvent::scheduled(
+            &NotificationInfo::default(),
+            description.to_string(),
+            "pool",
+            1500.0,
+            "SOL",
+        )
+    }
+
+    #[test]
+    fn test_admit() {
+        let config = RateLimitConfig {
+            messages: 2,
+            period_secs: 60,
+        };
+        let now = Instant::now();
+        let mut limiters = RateLimiters::default();
+
+        assert!(limiters.admit("telegram", &config, &event("first"), now));
+        assert!(limiters.admit("telegram", &config, &event("second"), now));
+        assert!(!limiters.admit("telegram", &config, &event("third"), now));
+        assert!(limiters.admit("slack", &config, &event("first"), now));
+
+        // Held alerts keep later ones waiting even once a token is available
+        let later = now + Duration::from_secs(30);
+        assert!(!limiters.admit("telegram", &config, &event("fourth"), later));
+        assert_eq!(limiters.len(), 2);
+    }
+
+    #[test]
+    fn test_take_due() {
+        let config = RateLimitConfig {
+            messages: 1,
+            period_secs: 60,
+        };
+        let now = Instant::now();
+        let mut limiters = RateLimiters::default();
+
+        assert!(limiters.admit("telegram", &config, &event("first"), now));
+        assert!(!limiters.admit("telegram", &config, &event("second"), now));
+        assert!(!limiters.admit("telegram", &config, &event("third"), now));
+
+        assert!(limiters.take_due(|_| Some(config), now).is_empty());
+
+        let later = now + Duration::from_secs(60);
+        let due = limiters.take_due(|_| Some(config), later);
+        assert_eq!(due.len(), 1);
+        let (destination, digest) = &due[0];
+        assert_eq!(destination, "telegram");
+        assert!(digest.starts_with("2 alerts over the rate limit:"));
+        assert!(digest.contains("1500.00 SOL - second"));
+        assert!(limiters.is_empty());
+
+        // The token is left for the digest
+        assert!(limiters.admit("telegram", &config, &event("digest"), later));
+    }
+}
@@ -0,0 +1,95 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Delivery status of a notification to a destination
+///
+/// - A receipt is opened `pending` before the first attempt, so a notification lost by a crash
+///   stays listed as undelivered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReceiptStatus {
+    /// Not attempted yet, or the attempt did not finish
+    Pending,
+
+    /// Accepted by the destination
+    Delivered,
+
+    /// Failed, kept in the outbox to be retried
+    Retrying,
+
+    /// Given up (dead letter)
+    DeadLettered,
+
+    /// Not sent, the destination's circuit was open and the outbox is disabled
+    Skipped,
+}
+
+impl ReceiptStatus {
+    /// Status after a delivery attempt, `retried` when a failed notification stays in the outbox
+    pub fn after_attempt(delivered: bool, retried: bool) -> Self {
+        match (delivered, retried) {
+            (true, _) => Self::Delivered,
+            (false, true) => Self::Retrying,
+            (false, false) => Self::DeadLettered,
+        }
+    }
+
+    /// Name stored in the event store
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Delivered => "delivered",
+            Self::Retrying => "retrying",
+            Self::DeadLettered => "dead_lettered",
+            Self::Skipped => "skipped",
+        }
+    }
+}
+
+impl fmt::Display for ReceiptStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::receipt::ReceiptStatus;
+
+    #[test]
+    fn test_after_attempt() {
+        assert_eq!(
+            ReceiptStatus::after_attempt(true, true),
+            ReceiptStatus::Delivered
+        );
+        assert_eq!(
+            ReceiptStatus::after_attempt(true, false),
+            ReceiptStatus::Delivered
+        );
+        assert_eq!(
+            ReceiptStatus::after_attempt(false, true),
+            ReceiptStatus::Retrying
+        );
+        assert_eq!(
+            ReceiptStatus::after_attempt(false, false),
+            ReceiptStatus::DeadLettered
+        );
+    }
+
+    #[test]
+    fn test_as_str_matches_serde() {
+        for status in [
+            ReceiptStatus::Pending,
+            ReceiptStatus::Delivered,
+            ReceiptStatus::Retrying,
+            ReceiptStatus::DeadLettered,
+            ReceiptStatus::Skipped,
+        ] {
+            assert_eq!(
+                serde_json::to_string(&status).unwrap(),
+                format!("\"{status}\"")
+            );
+        }
+    }
+}
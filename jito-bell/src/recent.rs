@@ -0,0 +1,73 @@
+use std::{collections::VecDeque, sync::Mutex};
+
+use serde_json::Value;
+
+use crate::{
+    notification_event::NotificationEvent,
+    notifier::file::{JsonLineRecord, TransactionRecord},
+    schema::SchemaVersion,
+};
+
+/// Recent event
+#[derive(Debug, Clone)]
+pub enum RecentEvent {
+    Notification(Box<NotificationEvent>),
+    Transaction(TransactionRecord),
+}
+
+impl RecentEvent {
+    /// Serialize in the given schema version, tagged with its kind
+    pub fn to_payload(&self, version: SchemaVersion) -> Result<Value, serde_json::Error> {
+        match self {
+            RecentEvent::Notification(event) => {
+                JsonLineRecord::Notification(event).to_value(version)
+            }
+            RecentEvent::Transaction(record) => {
+                JsonLineRecord::Transaction(record).to_value(version)
+            }
+        }
+    }
+}
+
+/// Recent Events
+///
+/// - In-memory ring buffer of the last `capacity` events, available without the event store
+pub struct RecentEvents {
+    /// Maximum number of events kept
+    capacity: usize,
+
+    /// Events, oldest first
+    events: Mutex<VecDeque<RecentEvent>>,
+}
+
+impl RecentEvents {
+    /// Initialize Recent Events
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            events: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Push event, evicting the oldest one when full
+    pub fn push(&self, event: RecentEvent) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if let Ok(mut events) = self.events.lock() {
+            if events.len() == self.capacity {
+                events.pop_front();
+            }
+            events.push_back(event);
+        }
+    }
+
+    /// List up to `limit` events, newest first
+    pub fn list(&self, limit: usize) -> Vec<RecentEvent> {
+        match self.events.lock() {
+            Ok(events) => events.iter().rev().take(limit).cloned().collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+}
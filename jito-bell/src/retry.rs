@@ -0,0 +1,254 @@
+use std::time::Duration;
+
+use log::warn;
+use rand::Rng;
+use reqwest::{header::RETRY_AFTER, RequestBuilder, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+
+/// Retry policy of notifier HTTP calls
+///
+/// - Transport errors (connect, timeout), 5xx and 429 responses are retried, other responses
+///   are returned as is
+/// - The delay doubles from `initial_backoff_ms` up to `max_backoff_ms`, randomized by
+///   `jitter` (0.2 = ±20%), and honours `Retry-After` of 429 responses
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct RetryPolicy {
+    /// Attempts per request, including the first one (1 disables retries)
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+
+    /// Delay before the first retry in milliseconds
+    #[serde(default = "default_initial_backoff_ms")]
+    pub initial_backoff_ms: u64,
+
+    /// Maximum delay between attempts in milliseconds
+    #[serde(default = "default_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+
+    /// Random fraction of the delay added or removed (0.0 - 1.0)
+    #[serde(default = "default_jitter")]
+    pub jitter: f64,
+}
+
+fn default_max_attempts() -> u32 {
+    3
+}
+
+fn default_initial_backoff_ms() -> u64 {
+    500
+}
+
+fn default_max_backoff_ms() -> u64 {
+    10_000
+}
+
+fn default_jitter() -> f64 {
+    0.2
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: default_max_attempts(),
+            initial_backoff_ms: default_initial_backoff_ms(),
+            max_backoff_ms: default_max_backoff_ms(),
+            jitter: default_jitter(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before retry number `retry` (1-based), without jitter
+    pub fn backoff(&self, retry: u32) -> Duration {
+        backoff(self.initial_backoff_ms, self.max_backoff_ms, retry)
+    }
+
+    /// Randomize a delay by `jitter`
+    fn jittered(&self, delay: Duration) -> Duration {
+        jittered(delay, self.jitter)
+    }
+
+    /// Send a request, retrying transient failures
+    ///
+    /// - Requests whose body cannot be cloned (streams) are sent once
+    pub async fn send(&self, request: RequestBuilder) -> reqwest::Result<Response> {
+        let mut retry = 0;
+        loop {
+            let Some(attempt) = request.try_clone() else {
+                return request.send().await;
+            };
+
+            let response = attempt.send().await;
+            retry += 1;
+            if retry >= self.max_attempts || !is_transient(&response) {
+                return response;
+            }
+
+            let retry_after = response
+                .as_ref()
+                .ok()
+                .and_then(|response| response.headers().get(RETRY_AFTER))
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse().ok())
+                .map(Duration::from_secs);
+            let delay = self
+                .jittered(self.backoff(retry))
+                .max(retry_after.unwrap_or_default())
+                .min(Duration::from_millis(self.max_backoff_ms));
+
+            match &response {
+                Ok(response) => warn!(
+                    "Request to {} failed with {}, retrying in {}ms",
+                    response.url(),
+                    response.status(),
+                    delay.as_millis()
+                ),
+                Err(e) => warn!("Request failed: {e}, retrying in {}ms", delay.as_millis()),
+            }
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+/// Reconnection policy of the Geyser subscription
+///
+/// - The subscription is re-established when the stream fails or ends, the delay doubles from
+///   `initial_backoff_ms` up to `max_backoff_ms`, randomized by `jitter`
+/// - The attempt count resets once a new stream delivers updates
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct ReconnectPolicy {
+    /// Consecutive failed attempts before giving up, reconnects forever when missing
+    #[serde(default)]
+    pub max_attempts: Option<u32>,
+
+    /// Delay before the first reconnection in milliseconds
+    #[serde(default = "default_reconnect_initial_backoff_ms")]
+    pub initial_backoff_ms: u64,
+
+    /// Maximum delay between reconnections in milliseconds
+    #[serde(default = "default_reconnect_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+
+    /// Random fraction of the delay added or removed (0.0 - 1.0)
+    #[serde(default = "default_jitter")]
+    pub jitter: f64,
+}
+
+fn default_reconnect_initial_backoff_ms() -> u64 {
+    1_000
+}
+
+fn default_reconnect_max_backoff_ms() -> u64 {
+    60_000
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: None,
+            initial_backoff_ms: default_reconnect_initial_backoff_ms(),
+            max_backoff_ms: default_reconnect_max_backoff_ms(),
+            jitter: default_jitter(),
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Whether `attempt` (1-based) consecutive failures exhaust the policy
+    pub fn gives_up(&self, attempt: u32) -> bool {
+        self.max_attempts
+            .is_some_and(|max_attempts| attempt > max_attempts)
+    }
+
+    /// Delay before reconnection number `attempt` (1-based), with jitter
+    pub fn delay(&self, attempt: u32) -> Duration {
+        jittered(
+            backoff(self.initial_backoff_ms, self.max_backoff_ms, attempt),
+            self.jitter,
+        )
+    }
+}
+
+/// Delay before retry number `retry` (1-based), doubling from `initial_ms` up to `max_ms`
+fn backoff(initial_ms: u64, max_ms: u64, retry: u32) -> Duration {
+    let backoff = initial_ms.saturating_mul(
+        1_u64
+            .checked_shl(retry.saturating_sub(1))
+            .unwrap_or(u64::MAX),
+    );
+
+    Duration::from_millis(backoff.min(max_ms))
+}
+
+/// Randomize a delay by `jitter`
+fn jittered(delay: Duration, jitter: f64) -> Duration {
+    let jitter = jitter.clamp(0.0, 1.0);
+    if jitter == 0.0 {
+        return delay;
+    }
+
+    delay.mul_f64(rand::thread_rng().gen_range(1.0 - jitter..=1.0 + jitter))
+}
+
+/// Whether a response or error is worth retrying
+fn is_transient(response: &reqwest::Result<Response>) -> bool {
+    match response {
+        Ok(response) => {
+            response.status().is_server_error()
+                || response.status() == StatusCode::TOO_MANY_REQUESTS
+        }
+        Err(e) => e.is_timeout() || e.is_connect() || e.is_request(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::retry::{ReconnectPolicy, RetryPolicy};
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy {
+            max_attempts: 5,
+            initial_backoff_ms: 500,
+            max_backoff_ms: 3_000,
+            jitter: 0.0,
+        };
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(500));
+        assert_eq!(policy.backoff(2), Duration::from_millis(1_000));
+        assert_eq!(policy.backoff(3), Duration::from_millis(2_000));
+        assert_eq!(policy.backoff(4), Duration::from_millis(3_000));
+        assert_eq!(policy.backoff(100), Duration::from_millis(3_000));
+    }
+
+    #[test]
+    fn test_jitter() {
+        let policy = RetryPolicy {
+            jitter: 0.2,
+            ..RetryPolicy::default()
+        };
+
+        for _ in 0..100 {
+            let delay = policy.jittered(Duration::from_millis(1_000));
+            assert!(delay >= Duration::from_millis(800) && delay <= Duration::from_millis(1_200));
+        }
+    }
+
+    #[test]
+    fn test_reconnect_policy() {
+        let policy = ReconnectPolicy {
+            max_attempts: Some(3),
+            jitter: 0.0,
+            ..ReconnectPolicy::default()
+        };
+
+        assert_eq!(policy.delay(1), Duration::from_secs(1));
+        assert_eq!(policy.delay(3), Duration::from_secs(4));
+        assert_eq!(policy.delay(10), Duration::from_secs(60));
+        assert!(!policy.gives_up(3));
+        assert!(policy.gives_up(4));
+        assert!(!ReconnectPolicy::default().gives_up(u32::MAX));
+    }
+}
@@ -0,0 +1,329 @@
+use std::{collections::HashMap, str::FromStr};
+
+use chrono::{DateTime, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    notification_event::NotificationEvent, notification_info::Severity,
+    rate_limit::RateLimitConfig, timezone::Timezone,
+};
+
+/// Alerts listed in a digest, the others are only counted
+const MAX_DIGEST_ALERTS: usize = 20;
+
+/// Time of day, written "HH:MM"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TimeOfDay {
+    /// Minutes since midnight
+    minutes: u32,
+}
+
+impl TimeOfDay {
+    /// Time of day of `time` in `timezone`
+    pub fn at(time: DateTime<Utc>, timezone: &Timezone) -> Self {
+        let local = timezone.naive_local(time);
+
+        Self {
+            minutes: local.hour() * 60 + local.minute(),
+        }
+    }
+}
+
+impl FromStr for TimeOfDay {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid time of day {s}, expected HH:MM");
+
+        let (hour, minute) = s.split_once(':').ok_or_else(invalid)?;
+        let hour: u32 = hour.parse().map_err(|_| invalid())?;
+        let minute: u32 = minute.parse().map_err(|_| invalid())?;
+        if hour > 24 || minute > 59 || (hour == 24 && minute > 0) {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            minutes: hour * 60 + minute,
+        })
+    }
+}
+
+impl TryFrom<String> for TimeOfDay {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<TimeOfDay> for String {
+    fn from(time: TimeOfDay) -> Self {
+        format!("{:02}:{:02}", time.minutes / 60, time.minutes % 60)
+    }
+}
+
+/// Delivery schedule of a destination
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ScheduleConfig {
+    /// Quiet hours, alerts are held and sent as a digest when the window ends
+    pub quiet_hours: Option<QuietHoursConfig>,
+
+    /// Rate limit, alerts beyond it are held and sent as a digest once it allows
+    pub rate_limit: Option<RateLimitConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct QuietHoursConfig {
+    /// Start of the window, in `timezone`
+    pub start: TimeOfDay,
+
+    /// End of the window, in `timezone` (before `start` for windows spanning midnight)
+    pub end: TimeOfDay,
+
+    /// Alerts of this severity or above are still delivered during the window
+    #[serde(default = "default_bypass_severity")]
+    pub bypass_severity: Severity,
+}
+
+fn default_bypass_severity() -> Severity {
+    Severity::Critical
+}
+
+impl QuietHoursConfig {
+    /// Whether `time` falls in the window
+    pub fn is_quiet(&self, time: TimeOfDay) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            self.start <= time || time < self.end
+        }
+    }
+
+    /// Whether an alert of `severity` is held at `time`
+    pub fn holds(&self, severity: Option<Severity>, time: TimeOfDay) -> bool {
+        self.is_quiet(time) && severity.is_none_or(|severity| severity < self.bypass_severity)
+    }
+}
+
+/// Alerts held for a destination
+#[derive(Debug, Default, PartialEq, Deserialize, Serialize)]
+pub(crate) struct HeldAlerts {
+    /// Number of alerts held
+    count: usize,
+
+    /// Summary lines of the first alerts
+    lines: Vec<String>,
+}
+
+impl HeldAlerts {
+    /// Hold an alert
+    pub(crate) fn hold(&mut self, event: &NotificationEvent) {
+        self.count += 1;
+
+        if self.lines.len() < MAX_DIGEST_ALERTS {
+            let line = format!(
+                "{} - {:.2} {} - {} {}",
+                event.local_time(),
+                event.amount,
+                event.currency_unit,
+                event.description,
+                event.explorer_url
+            );
+            self.lines.push(line.trim_end().to_string());
+        }
+    }
+
+    /// Hold the alerts of `other` after these
+    pub(crate) fn extend(&mut self, other: HeldAlerts) {
+        self.count += other.count;
+
+        let listed = MAX_DIGEST_ALERTS.saturating_sub(self.lines.len());
+        self.lines.extend(other.lines.into_iter().take(listed));
+    }
+
+    /// Number of alerts held
+    pub(crate) fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Digest text, "{count} alerts {reason}:" followed by the first alerts
+    pub(crate) fn digest(&self, reason: &str) -> String {
+        let mut digest = format!("{} alerts {reason}:", self.count);
+        for line in self.lines.iter() {
+            digest.push_str("\n- ");
+            digest.push_str(line);
+        }
+        if self.count > self.lines.len() {
+            digest.push_str(&format!("\n... and {} more", self.count - self.lines.len()));
+        }
+
+        digest
+    }
+}
+
+/// Alerts held during quiet hours, per destination
+#[derive(Debug, Default)]
+pub struct QuietHoursDigests {
+    held: HashMap<String, HeldAlerts>,
+}
+
+impl QuietHoursDigests {
+    /// Hold an alert for the digest of `destination`
+    pub fn hold(&mut self, destination: &str, event: &NotificationEvent) {
+        self.held
+            .entry(destination.to_string())
+            .or_default()
+            .hold(event);
+    }
+
+    /// Take the digests of the destinations no longer in quiet hours
+    pub fn take_due(&mut self, is_quiet: impl Fn(&str) -> bool) -> Vec<(String, String)> {
+        let due: Vec<String> = self
+            .held
+            .keys()
+            .filter(|destination| !is_quiet(destination))
+            .cloned()
+            .collect();
+
+        due.into_iter()
+            .filter_map(|destination| {
+                let held = self.held.remove(&destination)?;
+                Some((destination, held.digest("during quiet hours")))
+            })
+            .collect()
+    }
+
+    /// Take the alerts held for every destination, e.g. to keep them across a restart
+    pub(crate) fn take_all(&mut self) -> HashMap<String, HeldAlerts> {
+        std::mem::take(&mut self.held)
+    }
+
+    /// Hold alerts taken with `take_all` again
+    pub(crate) fn restore(&mut self, destination: &str, held: HeldAlerts) {
+        self.held
+            .entry(destination.to_string())
+            .or_default()
+            .extend(held);
+    }
+
+    /// Number of alerts held
+    pub fn len(&self) -> usize {
+        self.held.values().map(HeldAlerts::count).sum()
+    }
+
+    /// Whether no alert is held
+    pub fn is_empty(&self) -> bool {
+        self.held.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        notification_event::NotificationEvent,
+        notification_info::{NotificationInfo, Severity},
+        schedule::{HeldAlerts, QuietHoursConfig, QuietHoursDigests, TimeOfDay},
+    };
+
+    fn time(s: &str) -> TimeOfDay {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_time_of_day() {
+        assert_eq!(String::from(time("7:05")), "07:05");
+        assert!("25:00".parse::<TimeOfDay>().is_err());
+        assert!("07:60".parse::<TimeOfDay>().is_err());
+        assert!("0700".parse::<TimeOfDay>().is_err());
+    }
+
+    #[test]
+    fn test_quiet_hours() {
+        let overnight = QuietHoursConfig {
+            start: time("23:00"),
+            end: time("07:00"),
+            bypass_severity: Severity::Critical,
+        };
+        assert!(overnight.is_quiet(time("23:30")));
+        assert!(overnight.is_quiet(time("01:00")));
+        assert!(!overnight.is_quiet(time("07:00")));
+        assert!(!overnight.is_quiet(time("12:00")));
+
+        let night = QuietHoursConfig {
+            start: time("01:00"),
+            end: time("07:00"),
+            bypass_severity: Severity::Warning,
+        };
+        assert!(night.holds(None, time("03:00")));
+        assert!(night.holds(Some(Severity::Info), time("03:00")));
+        assert!(!night.holds(Some(Severity::Warning), time("03:00")));
+        assert!(!night.holds(Some(Severity::Critical), time("03:00")));
+        assert!(!night.holds(None, time("08:00")));
+    }
+
+    #[test]
+    fn test_digests() {
+        let event = NotificationEvent::scheduled(
+            &NotificationInfo::default(),
+            "Large withdrawal".to_string(),
+            "pool",
+            1500.0,
+            "SOL",
+        );
+
+        let mut digests = QuietHoursDigests::default();
+        for _ in 0..25 {
+            digests.hold("telegram", &event);
+        }
+        digests.hold("slack", &event);
+        assert_eq!(digests.len(), 26);
+
+        assert!(digests.take_due(|_| true).is_empty());
+
+        let due = digests.take_due(|destination| destination == "slack");
+        assert_eq!(due.len(), 1);
+        let (destination, digest) = &due[0];
+        assert_eq!(destination, "telegram");
+        assert!(digest.starts_with("25 alerts during quiet hours:"));
+        assert!(digest.contains("1500.00 SOL - Large withdrawal"));
+        assert!(digest.ends_with("... and 5 more"));
+        assert_eq!(digests.len(), 1);
+    }
+
+    #[test]
+    fn test_restore_digests() {
+        let event = NotificationEvent::scheduled(
+            &NotificationInfo::default(),
+            "Large withdrawal".to_string(),
+            "pool",
+            1500.0,
+            "SOL",
+        );
+
+        let mut digests = QuietHoursDigests::default();
+        for _ in 0..3 {
+            digests.hold("telegram", &event);
+        }
+
+        // Kept as JSON across a restart
+        let kept: Vec<(String, String)> = digests
+            .take_all()
+            .into_iter()
+            .map(|(destination, held)| (destination, serde_json::to_string(&held).unwrap()))
+            .collect();
+        assert!(digests.is_empty());
+
+        let mut restored = QuietHoursDigests::default();
+        restored.hold("telegram", &event);
+        for (destination, payload) in kept {
+            let held: HeldAlerts = serde_json::from_str(&payload).unwrap();
+            restored.restore(&destination, held);
+        }
+        assert_eq!(restored.len(), 4);
+
+        let due = restored.take_due(|_| false);
+        assert!(due[0].1.starts_with("4 alerts during quiet hours:"));
+        assert_eq!(due[0].1.matches("Large withdrawal").count(), 4);
+    }
+}
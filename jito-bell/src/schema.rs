@@ -0,0 +1,429 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    notification_event::{NotificationEvent, SolFlow},
+    notification_info::Severity,
+    notifier::file::TransactionRecord,
+    stake_pool_fee::FeeChange,
+};
+
+/// Version of the machine-readable event schema
+///
+/// - V1: flat notification event, fields as they were before versioning
+/// - V2: amount and transaction details grouped into objects
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "u32", into = "u32")]
+pub enum SchemaVersion {
+    V1,
+
+    #[default]
+    V2,
+}
+
+impl SchemaVersion {
+    /// Version number written as `schema_version`
+    pub fn number(&self) -> u32 {
+        match self {
+            SchemaVersion::V1 => 1,
+            SchemaVersion::V2 => 2,
+        }
+    }
+}
+
+impl From<SchemaVersion> for u32 {
+    fn from(version: SchemaVersion) -> Self {
+        version.number()
+    }
+}
+
+impl TryFrom<u32> for SchemaVersion {
+    type Error = String;
+
+    fn try_from(version: u32) -> Result<Self, Self::Error> {
+        match version {
+            1 => Ok(SchemaVersion::V1),
+            2 => Ok(SchemaVersion::V2),
+            _ => Err(format!(
+                "Unsupported schema_version {version}, supported versions are 1 and 2"
+            )),
+        }
+    }
+}
+
+/// V1 notification, the flat event with the fields it had before versioning
+#[derive(Serialize)]
+struct NotificationV1<'a> {
+    schema_version: u32,
+    description: &'a str,
+    pool: &'a str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    validator: Option<&'a str>,
+
+    amount: f64,
+    currency_unit: &'a str,
+    transaction_signature: &'a str,
+    slot: u64,
+    transaction_index: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sequence: Option<u64>,
+
+    explorer_url: &'a str,
+    timestamp: &'a str,
+}
+
+/// V2 notification
+#[derive(Serialize)]
+struct NotificationV2<'a> {
+    schema_version: u32,
+    description: &'a str,
+    pool: &'a str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pool_name: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    program: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instruction: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sender: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    receiver: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    validator: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    claimant: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proposal: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    memo: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fee_change: Option<&'a FeeChange>,
+
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    first_time_depositor: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sol_flow: Option<SolFlow>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    severity: Option<Severity>,
+
+    amount: AmountV2<'a>,
+    transaction: TransactionV2<'a>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sequence: Option<u64>,
+
+    timestamp: &'a str,
+}
+
+#[derive(Serialize)]
+struct AmountV2<'a> {
+    value: f64,
+    unit: &'a str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    usd: Option<f64>,
+}
+
+#[derive(Serialize)]
+struct TransactionV2<'a> {
+    signature: &'a str,
+    slot: u64,
+    index: u64,
+    explorer_url: &'a str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    block_time: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priority_fee: Option<u64>,
+
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    failed: bool,
+}
+
+/// Parsed transaction, identical in every version
+#[derive(Serialize)]
+struct VersionedTransaction<'a> {
+    schema_version: u32,
+
+    #[serde(flatten)]
+    record: &'a TransactionRecord,
+}
+
+/// Serialize a notification event in the given schema version
+pub fn notification_payload(
+    event: &NotificationEvent,
+    version: SchemaVersion,
+) -> Result<Value, serde_json::Error> {
+    match version {
+        SchemaVersion::V1 => serde_json::to_value(NotificationV1 {
+            schema_version: version.number(),
+            description: &event.description,
+            pool: &event.pool,
+            validator: event.validator.as_deref(),
+            amount: event.amount,
+            currency_unit: &event.currency_unit,
+            transaction_signature: &event.transaction_signature,
+            slot: event.slot,
+            transaction_index: event.transaction_index,
+            sequence: event.sequence,
+            explorer_url: &event.explorer_url,
+            timestamp: &event.timestamp,
+        }),
+        SchemaVersion::V2 => serde_json::to_value(NotificationV2 {
+            schema_version: version.number(),
+            description: &event.description,
+            pool: &event.pool,
+            pool_name: event.pool_name.as_deref(),
+            program: event.program.as_deref(),
+            instruction: event.instruction.as_deref(),
+            sender: event.sender.as_deref(),
+            receiver: event.receiver.as_deref(),
+            validator: event.validator.as_deref(),
+            claimant: event.claimant.as_deref(),
+            proposal: event.proposal.as_deref(),
+            memo: event.memo.as_deref(),
+            fee_change: event.fee_change.as_ref(),
+            first_time_depositor: event.first_time_depositor,
+            sol_flow: event.sol_flow,
+            severity: event.severity,
+            amount: AmountV2 {
+                value: event.amount,
+                unit: &event.currency_unit,
+                usd: event.amount_usd,
+            },
+            transaction: TransactionV2 {
+                signature: &event.transaction_signature,
+                slot: event.slot,
+                index: event.transaction_index,
+                explorer_url: &event.explorer_url,
+                block_time: event.block_time,
+                priority_fee: event.priority_fee,
+                failed: event.failed,
+            },
+            sequence: event.sequence,
+            timestamp: &event.timestamp,
+        }),
+    }
+}
+
+/// Serialize a parsed transaction in the given schema version
+pub fn transaction_payload(
+    record: &TransactionRecord,
+    version: SchemaVersion,
+) -> Result<Value, serde_json::Error> {
+    serde_json::to_value(VersionedTransaction {
+        schema_version: version.number(),
+        record,
+    })
+}
+
+/// Convert a V2 notification payload to V1, for consumers still reading V1
+///
+/// - Payloads that are not V2 are returned unchanged
+/// - Fields added in V2 are dropped
+pub fn downgrade_v2_to_v1(payload: Value) -> Value {
+    if payload.get("schema_version").and_then(Value::as_u64) != Some(2) {
+        return payload;
+    }
+
+    let field = |object: &str, key: &str| {
+        payload
+            .get(object)
+            .and_then(|object| object.get(key))
+            .cloned()
+            .unwrap_or(Value::Null)
+    };
+
+    let mut v1 = serde_json::Map::new();
+    v1.insert("schema_version".to_string(), Value::from(1));
+    for key in [
+        "kind",
+        "description",
+        "pool",
+        "validator",
+        "sequence",
+        "timestamp",
+    ] {
+        if let Some(value) = payload.get(key) {
+            v1.insert(key.to_string(), value.clone());
+        }
+    }
+    v1.insert("amount".to_string(), field("amount", "value"));
+    v1.insert("currency_unit".to_string(), field("amount", "unit"));
+    v1.insert(
+        "transaction_signature".to_string(),
+        field("transaction", "signature"),
+    );
+    v1.insert("slot".to_string(), field("transaction", "slot"));
+    v1.insert(
+        "transaction_index".to_string(),
+        field("transaction", "index"),
+    );
+    v1.insert(
+        "explorer_url".to_string(),
+        field("transaction", "explorer_url"),
+    );
+
+    Value::Object(v1)
+}
+
+/// Convert a V1 (or unversioned) notification payload to V2
+///
+/// - Payloads that are already V2 are returned unchanged
+pub fn upgrade_v1_to_v2(payload: Value) -> Value {
+    if payload.get("schema_version").and_then(Value::as_u64) == Some(2) {
+        return payload;
+    }
+
+    let field = |key: &str| payload.get(key).cloned().unwrap_or(Value::Null);
+
+    let mut v2 = serde_json::Map::new();
+    v2.insert("schema_version".to_string(), Value::from(2));
+    for key in [
+        "kind",
+        "description",
+        "pool",
+        "validator",
+        "sequence",
+        "timestamp",
+    ] {
+        if let Some(value) = payload.get(key) {
+            v2.insert(key.to_string(), value.clone());
+        }
+    }
+    v2.insert(
+        "amount".to_string(),
+        serde_json::json!({ "value": field("amount"), "unit": field("currency_unit") }),
+    );
+    v2.insert(
+        "transaction".to_string(),
+        serde_json::json!({
+            "signature": field("transaction_signature"),
+            "slot": field("slot"),
+            "index": field("transaction_index"),
+            "explorer_url": field("explorer_url"),
+        }),
+    );
+
+    Value::Object(v2)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::{
+        notification_event::{NotificationEvent, SolFlow},
+        notification_info::{NotificationInfo, Severity},
+        schema::{downgrade_v2_to_v1, notification_payload, upgrade_v1_to_v2, SchemaVersion},
+        stake_pool_fee::FeeChange,
+    };
+
+    fn event() -> NotificationEvent {
+        let mut event = NotificationEvent::scheduled(
+            &NotificationInfo::default(),
+            "Deposit".to_string(),
+            "pool",
+            1.5,
+            "SOL",
+        );
+        event.transaction_signature = "signature".to_string();
+        event.slot = 10;
+        event.transaction_index = 2;
+        event.sequence = Some(3);
+        event.explorer_url = "https://explorer.solana.com/tx/signature".to_string();
+        event
+    }
+
+    #[test]
+    fn test_notification_payload() {
+        let v1 = notification_payload(&event(), SchemaVersion::V1).unwrap();
+        assert_eq!(v1["schema_version"], 1);
+        assert_eq!(v1["amount"], 1.5);
+        assert_eq!(v1["transaction_signature"], "signature");
+
+        let v2 = notification_payload(&event(), SchemaVersion::V2).unwrap();
+        assert_eq!(v2["schema_version"], 2);
+        assert_eq!(v2["amount"]["unit"], "SOL");
+        assert_eq!(v2["transaction"]["slot"], 10);
+    }
+
+    #[test]
+    fn test_notification_payload_v1_fields() {
+        let mut event = event();
+        event.pool_name = Some("JitoSOL".to_string());
+        event.program = Some("spl_stake_pool".to_string());
+        event.instruction = Some("deposit_sol".to_string());
+        event.sender = Some("sender".to_string());
+        event.receiver = Some("receiver".to_string());
+        event.validator = Some("validator".to_string());
+        event.claimant = Some("claimant".to_string());
+        event.proposal = Some("proposal".to_string());
+        event.memo = Some("memo".to_string());
+        event.priority_fee = Some(5000);
+        event.fee_change = Some(FeeChange {
+            fee_type: "epoch".to_string(),
+            before: Some("1%".to_string()),
+            after: "2%".to_string(),
+        });
+        event.first_time_depositor = true;
+        event.sol_flow = Some(SolFlow::FreshSol);
+        event.severity = Some(Severity::Critical);
+        event.amount_usd = Some(300.0);
+        event.failed = true;
+        event.block_time = Some(1_735_689_600);
+
+        let v1 = notification_payload(&event, SchemaVersion::V1).unwrap();
+        let keys: BTreeSet<&str> = v1.as_object().unwrap().keys().map(String::as_str).collect();
+
+        assert_eq!(
+            keys,
+            BTreeSet::from([
+                "schema_version",
+                "description",
+                "pool",
+                "validator",
+                "amount",
+                "currency_unit",
+                "transaction_signature",
+                "slot",
+                "transaction_index",
+                "sequence",
+                "explorer_url",
+                "timestamp",
+            ])
+        );
+    }
+
+    #[test]
+    fn test_converters_round_trip() {
+        let mut event = event();
+        event.validator = Some("validator".to_string());
+        let v1 = notification_payload(&event, SchemaVersion::V1).unwrap();
+        let v2 = notification_payload(&event, SchemaVersion::V2).unwrap();
+
+        assert_eq!(downgrade_v2_to_v1(v2.clone()), v1);
+        assert_eq!(upgrade_v1_to_v2(v1), v2);
+    }
+
+    #[test]
+    fn test_schema_version() {
+        assert_eq!(SchemaVersion::try_from(1), Ok(SchemaVersion::V1));
+        assert!(SchemaVersion::try_from(3).is_err());
+    }
+}
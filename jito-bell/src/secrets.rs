@@ -0,0 +1,421 @@
+use std::collections::{hash_map::Entry, HashMap};
+
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::error::JitoBellError;
+
+const AWS_SECRETS_MANAGER_SCHEME: &str = "aws-sm://";
+const VAULT_SCHEME: &str = "vault://";
+
+/// Reference to a secret held by a secrets backend, written as a config string value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecretReference {
+    /// `aws-sm://<secret-id>[#<key>]`, a key selects a field of a JSON secret
+    AwsSecretsManager {
+        secret_id: String,
+        key: Option<String>,
+    },
+
+    /// `vault://<mount>/<path>#<key>`, a field of a Vault KV v2 secret
+    Vault {
+        mount: String,
+        path: String,
+        key: String,
+    },
+}
+
+impl SecretReference {
+    /// Parse a config value, None for values that are not a secret reference
+    pub fn parse(value: &str) -> Result<Option<Self>, JitoBellError> {
+        if let Some(reference) = value.strip_prefix(AWS_SECRETS_MANAGER_SCHEME) {
+            let (secret_id, key) = match reference.split_once('#') {
+                Some((secret_id, key)) => (secret_id, Some(key.to_string())),
+                None => (reference, None),
+            };
+            if secret_id.is_empty() {
+                return Err(JitoBellError::Config(format!(
+                    "Invalid secret reference {value}, expected aws-sm://<secret-id>[#<key>]"
+                )));
+            }
+
+            return Ok(Some(SecretReference::AwsSecretsManager {
+                secret_id: secret_id.to_string(),
+                key,
+            }));
+        }
+
+        if let Some(reference) = value.strip_prefix(VAULT_SCHEME) {
+            let parsed = reference.split_once('#').and_then(|(path, key)| {
+                let (mount, path) = path.split_once('/')?;
+                (!mount.is_empty() && !path.is_empty() && !key.is_empty())
+                    .then_some((mount, path, key))
+            });
+            let Some((mount, path, key)) = parsed else {
+                return Err(JitoBellError::Config(format!(
+                    "Invalid secret reference {value}, expected vault://<mount>/<path>#<key>"
+                )));
+            };
+
+            return Ok(Some(SecretReference::Vault {
+                mount: mount.to_string(),
+                path: path.to_string(),
+                key: key.to_string(),
+            }));
+        }
+
+        Ok(None)
+    }
+}
+
+impl std::fmt::Display for SecretReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SecretReference::AwsSecretsManager { secret_id, key } => match key {
+                Some(key) => write!(f, "{AWS_SECRETS_MANAGER_SCHEME}{secret_id}#{key}"),
+                None => write!(f, "{AWS_SECRETS_MANAGER_SCHEME}{secret_id}"),
+            },
+            SecretReference::Vault { mount, path, key } => {
+                write!(f, "{VAULT_SCHEME}{mount}/{path}#{key}")
+            }
+        }
+    }
+}
+
+/// Resolver of secret references in config values
+///
+/// - AWS Secrets Manager: credentials and region from `AWS_ACCESS_KEY_ID`,
+///   `AWS_SECRET_ACCESS_KEY`, `AWS_SESSION_TOKEN` (optional) and `AWS_REGION`
+///   (or `AWS_DEFAULT_REGION`)
+/// - Vault: `VAULT_ADDR`, `VAULT_TOKEN` and `VAULT_NAMESPACE` (optional)
+pub struct SecretResolver<'a> {
+    client: &'a reqwest::Client,
+}
+
+impl<'a> SecretResolver<'a> {
+    /// Initialize a resolver fetching with `client`
+    pub fn new(client: &'a reqwest::Client) -> Self {
+        Self { client }
+    }
+
+    /// Replace every string value that is a secret reference with the secret
+    ///
+    /// - Each reference is fetched once
+    pub async fn resolve(&self, value: &mut Value) -> Result<(), JitoBellError> {
+        let mut references = Vec::new();
+        collect_references(value, &mut references)?;
+
+        let mut secrets = HashMap::new();
+        for (raw, reference) in references {
+            if let Entry::Vacant(entry) = secrets.entry(raw) {
+                entry.insert(self.fetch(&reference).await?);
+            }
+        }
+        replace_references(value, &secrets);
+
+        Ok(())
+    }
+
+    /// Fetch a secret
+    async fn fetch(&self, reference: &SecretReference) -> Result<String, JitoBellError> {
+        match reference {
+            SecretReference::AwsSecretsManager { secret_id, key } => {
+                let secret = self.fetch_aws_secret(secret_id).await?;
+                match key {
+                    Some(key) => json_field(&secret, key, reference),
+                    None => Ok(secret),
+                }
+            }
+            SecretReference::Vault { mount, path, key } => {
+                self.fetch_vault_secret(mount, path, key, reference).await
+            }
+        }
+    }
+
+    /// `GetSecretValue` of AWS Secrets Manager, signed with Signature Version 4
+    async fn fetch_aws_secret(&self, secret_id: &str) -> Result<String, JitoBellError> {
+        let access_key = required_env("AWS_ACCESS_KEY_ID")?;
+        let secret_key = required_env("AWS_SECRET_ACCESS_KEY")?;
+        let session_token = std::env::var("AWS_SESSION_TOKEN").ok();
+        let region = std::env::var("AWS_REGION")
+            .or_else(|_| std::env::var("AWS_DEFAULT_REGION"))
+            .map_err(|_| {
+                JitoBellError::Config(
+                    "AWS_REGION must be set to resolve aws-sm:// secrets".to_string(),
+                )
+            })?;
+
+        let host = format!("secretsmanager.{region}.amazonaws.com");
+        let target = "secretsmanager.GetSecretValue";
+        let content_type = "application/x-amz-json-1.1";
+        let payload = serde_json::json!({ "SecretId": secret_id }).to_string();
+        let now = Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+
+        let mut headers = vec![
+            ("content-type", content_type.to_string()),
+            ("host", host.clone()),
+            ("x-amz-date", amz_date.clone()),
+        ];
+        if let Some(session_token) = &session_token {
+            headers.push(("x-amz-security-token", session_token.clone()));
+        }
+        headers.push(("x-amz-target", target.to_string()));
+
+        let canonical_headers: String = headers
+            .iter()
+            .map(|(name, value)| format!("{name}:{value}\n"))
+            .collect();
+        let signed_headers = headers
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+        let canonical_request = format!(
+            "POST\n/\n\n{canonical_headers}\n{signed_headers}\n{}",
+            hex(&Sha256::digest(payload.as_bytes()))
+        );
+        let scope = format!("{date}/{region}/secretsmanager/aws4_request");
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+            hex(&Sha256::digest(canonical_request.as_bytes()))
+        );
+        let signing_key = aws_signing_key(&secret_key, &date, &region, "secretsmanager");
+        let signature = hex(&hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+        let authorization = format!(
+            "AWS4-HMAC-SHA256 Credential={access_key}/{scope}, SignedHeaders={signed_headers}, Signature={signature}"
+        );
+
+        let mut request = self
+            .client
+            .post(format!("https://{host}/"))
+            .header("Content-Type", content_type)
+            .header("X-Amz-Date", amz_date)
+            .header("X-Amz-Target", target)
+            .header("Authorization", authorization)
+            .body(payload);
+        if let Some(session_token) = session_token {
+            request = request.header("X-Amz-Security-Token", session_token);
+        }
+
+        let response: Value = request.send().await?.error_for_status()?.json().await?;
+
+        response
+            .get("SecretString")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| {
+                JitoBellError::Config(format!(
+                    "Secret {secret_id} of AWS Secrets Manager has no SecretString"
+                ))
+            })
+    }
+
+    /// Field of a Vault KV v2 secret
+    async fn fetch_vault_secret(
+        &self,
+        mount: &str,
+        path: &str,
+        key: &str,
+        reference: &SecretReference,
+    ) -> Result<String, JitoBellError> {
+        let address = required_env("VAULT_ADDR")?;
+        let token = required_env("VAULT_TOKEN")?;
+
+        let url = format!("{}/v1/{mount}/data/{path}", address.trim_end_matches('/'));
+        let mut request = self.client.get(url).header("X-Vault-Token", token);
+        if let Ok(namespace) = std::env::var("VAULT_NAMESPACE") {
+            request = request.header("X-Vault-Namespace", namespace);
+        }
+
+        let response: Value = request.send().await?.error_for_status()?.json().await?;
+
+        match response
+            .pointer("/data/data")
+            .and_then(|data| data.get(key))
+        {
+            Some(Value::String(secret)) => Ok(secret.clone()),
+            Some(secret) => Ok(secret.to_string()),
+            None => Err(JitoBellError::Config(format!(
+                "Secret {reference} has no field {key}"
+            ))),
+        }
+    }
+}
+
+/// Collect the secret references of every string value
+fn collect_references(
+    value: &Value,
+    references: &mut Vec<(String, SecretReference)>,
+) -> Result<(), JitoBellError> {
+    match value {
+        Value::String(s) => {
+            if let Some(reference) = SecretReference::parse(s)? {
+                references.push((s.clone(), reference));
+            }
+        }
+        Value::Array(values) => {
+            for value in values.iter() {
+                collect_references(value, references)?;
+            }
+        }
+        Value::Object(map) => {
+            for value in map.values() {
+                collect_references(value, references)?;
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+
+    Ok(())
+}
+
+/// Replace string values that are a resolved secret reference
+fn replace_references(value: &mut Value, secrets: &HashMap<String, String>) {
+    match value {
+        Value::String(s) => {
+            if let Some(secret) = secrets.get(s.as_str()) {
+                *s = secret.clone();
+            }
+        }
+        Value::Array(values) => {
+            for value in values.iter_mut() {
+                replace_references(value, secrets);
+            }
+        }
+        Value::Object(map) => {
+            for value in map.values_mut() {
+                replace_references(value, secrets);
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+/// Field of a secret stored as a JSON object
+fn json_field(
+    secret: &str,
+    key: &str,
+    reference: &SecretReference,
+) -> Result<String, JitoBellError> {
+    let secret: Value = serde_json::from_str(secret).map_err(|e| {
+        JitoBellError::Config(format!("Secret {reference} is not a JSON object: {e}"))
+    })?;
+
+    match secret.get(key) {
+        Some(Value::String(field)) => Ok(field.clone()),
+        Some(field) => Ok(field.to_string()),
+        None => Err(JitoBellError::Config(format!(
+            "Secret {reference} has no field {key}"
+        ))),
+    }
+}
+
+fn required_env(name: &str) -> Result<String, JitoBellError> {
+    std::env::var(name).map_err(|_| {
+        JitoBellError::Config(format!("{name} must be set to resolve secret references"))
+    })
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Signature Version 4 signing key
+fn aws_signing_key(secret_key: &str, date: &str, region: &str, service: &str) -> Vec<u8> {
+    let key = hmac_sha256(format!("AWS4{secret_key}").as_bytes(), date.as_bytes());
+    let key = hmac_sha256(&key, region.as_bytes());
+    let key = hmac_sha256(&key, service.as_bytes());
+    hmac_sha256(&key, b"aws4_request")
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use crate::secrets::{
+        aws_signing_key, collect_references, hex, replace_references, SecretReference,
+    };
+
+    #[test]
+    fn test_parse_reference() {
+        assert_eq!(
+            SecretReference::parse("aws-sm://jito-bell/telegram").unwrap(),
+            Some(SecretReference::AwsSecretsManager {
+                secret_id: "jito-bell/telegram".to_string(),
+                key: None,
+            })
+        );
+        assert_eq!(
+            SecretReference::parse("vault://kv/jito-bell#slack_webhook").unwrap(),
+            Some(SecretReference::Vault {
+                mount: "kv".to_string(),
+                path: "jito-bell".to_string(),
+                key: "slack_webhook".to_string(),
+            })
+        );
+        assert_eq!(
+            SecretReference::parse("https://hooks.slack.com").unwrap(),
+            None
+        );
+        assert!(SecretReference::parse("vault://kv/jito-bell").is_err());
+    }
+
+    #[test]
+    fn test_replace_references() {
+        let mut value = json!({
+            "telegram": { "bot_token": "aws-sm://jito-bell/telegram#bot_token", "chat_id": "-100" },
+            "slack": { "webhook_url": "vault://kv/jito-bell#slack_webhook" },
+        });
+
+        let mut references = Vec::new();
+        collect_references(&value, &mut references).unwrap();
+        assert_eq!(references.len(), 2);
+
+        let secrets = HashMap::from([
+            (
+                "aws-sm://jito-bell/telegram#bot_token".to_string(),
+                "123:abc".to_string(),
+            ),
+            (
+                "vault://kv/jito-bell#slack_webhook".to_string(),
+                "https://hooks.slack.com/services/1".to_string(),
+            ),
+        ]);
+        replace_references(&mut value, &secrets);
+
+        assert_eq!(value["telegram"]["bot_token"], "123:abc");
+        assert_eq!(value["telegram"]["chat_id"], "-100");
+        assert_eq!(
+            value["slack"]["webhook_url"],
+            "https://hooks.slack.com/services/1"
+        );
+    }
+
+    #[test]
+    fn test_aws_signing_key() {
+        // Example of the AWS Signature Version 4 documentation
+        let key = aws_signing_key(
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            "20120215",
+            "us-east-1",
+            "iam",
+        );
+
+        assert_eq!(
+            hex(&key),
+            "f4780e2d9f65fa895f9c67b32ce1baf0b0d8a43505a000a1a9e090d414db404d"
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{config::ShardingConfig, error::JitoBellError};
+
+/// Shard
+///
+/// - Assign each transaction to exactly one of `count` instances by its signature, so instances
+///   sharing the same filters never handle (and notify) the same transaction twice
+#[derive(Debug, Clone, Copy)]
+pub struct Shard {
+    /// Index of this instance
+    index: u64,
+
+    /// Number of instances
+    count: u64,
+}
+
+impl Shard {
+    /// Initialize Shard
+    pub fn new(config: &ShardingConfig) -> Result<Self, JitoBellError> {
+        if config.count == 0 || config.index >= config.count {
+            return Err(JitoBellError::Config(format!(
+                "Invalid sharding: index {} of count {}",
+                config.index, config.count
+            )));
+        }
+
+        Ok(Self {
+            index: config.index,
+            count: config.count,
+        })
+    }
+
+    /// Whether this instance is the first shard, which runs instance-wide jobs
+    pub fn is_primary(&self) -> bool {
+        self.index == 0
+    }
+
+    /// Whether this instance handles the transaction
+    ///
+    /// - Signatures are uniformly distributed, so the first 8 bytes serve as the hash
+    pub fn owns(&self, signature: &[u8]) -> bool {
+        let mut bytes = [0; 8];
+        let len = signature.len().min(8);
+        bytes[..len].copy_from_slice(&signature[..len]);
+
+        u64::from_le_bytes(bytes) % self.count == self.index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{config::ShardingConfig, sharding::Shard};
+
+    #[test]
+    fn test_owns_exactly_one_shard() {
+        let count = 4;
+        let shards: Vec<Shard> = (0..count)
+            .map(|index| Shard::new(&ShardingConfig { index, count }).unwrap())
+            .collect();
+
+        for seed in 0..=255u8 {
+            let signature = [seed; 64];
+            let owners = shards.iter().filter(|shard| shard.owns(&signature)).count();
+            assert_eq!(owners, 1);
+        }
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert!(Shard::new(&ShardingConfig { index: 0, count: 0 }).is_err());
+        assert!(Shard::new(&ShardingConfig { index: 2, count: 2 }).is_err());
+    }
+}
@@ -0,0 +1,29 @@
+use tokio::signal::unix::{signal, Signal, SignalKind};
+
+use crate::error::JitoBellError;
+
+/// Listener of SIGINT and SIGTERM
+///
+/// - Registered once, so a signal arriving between two waits is not lost
+pub struct ShutdownSignal {
+    interrupt: Signal,
+    terminate: Signal,
+}
+
+impl ShutdownSignal {
+    /// Start listening
+    pub fn listen() -> Result<Self, JitoBellError> {
+        Ok(Self {
+            interrupt: signal(SignalKind::interrupt())?,
+            terminate: signal(SignalKind::terminate())?,
+        })
+    }
+
+    /// Wait for SIGINT or SIGTERM, returning its name
+    pub async fn recv(&mut self) -> &'static str {
+        tokio::select! {
+            _ = self.interrupt.recv() => "SIGINT",
+            _ = self.terminate.recv() => "SIGTERM",
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use yellowstone_grpc_proto::prelude::{subscribe_update::UpdateOneof, SubscribeUpdate};
+
+use crate::notification_info::NotificationInfo;
+
+/// Detection of data dropped by the Geyser stream
+///
+/// - A gap is a jump of more than `max_gap_slots` between consecutive slots of the updates
+///   (skipped leader slots make small gaps normal)
+/// - A stall is no update for `stall_secs`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SlotGapConfig {
+    /// Largest jump between consecutive slots not alerted
+    #[serde(default = "default_max_gap_slots")]
+    pub max_gap_slots: u64,
+
+    /// Seconds without an update before a stall is alerted
+    #[serde(default = "default_stall_secs")]
+    pub stall_secs: u64,
+
+    /// Alert sent for gaps and stalls
+    pub notification: Option<NotificationInfo>,
+}
+
+fn default_max_gap_slots() -> u64 {
+    100
+}
+
+fn default_stall_secs() -> u64 {
+    30
+}
+
+/// Slot of an update, None for updates without one
+pub fn update_slot(update: &SubscribeUpdate) -> Option<u64> {
+    match update.update_oneof.as_ref()? {
+        UpdateOneof::Slot(slot) => Some(slot.slot),
+        UpdateOneof::Transaction(transaction) => Some(transaction.slot),
+        UpdateOneof::BlockMeta(block_meta) => Some(block_meta.slot),
+        _ => None,
+    }
+}
+
+/// Slots the stream skipped, reported by [`SlotMonitor::observe`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlotGap {
+    /// Last slot before the gap
+    pub from: u64,
+
+    /// First slot after the gap
+    pub to: u64,
+}
+
+impl SlotGap {
+    /// Number of slots missing
+    pub fn missed(&self) -> u64 {
+        self.to - self.from - 1
+    }
+}
+
+/// Latest slot and update time of the stream
+#[derive(Debug, Default)]
+pub struct SlotMonitor {
+    /// Highest slot received
+    last_slot: Option<u64>,
+
+    /// Time of the latest update
+    last_update: Option<Instant>,
+
+    /// Whether the current stall was reported
+    stalled: bool,
+}
+
+impl SlotMonitor {
+    /// Observe the slot of an update, returning the gap before it if it exceeds `max_gap_slots`
+    ///
+    /// - Slots older than the highest one (e.g. transactions of earlier slots) are ignored
+    /// - Only updates with a slot count, so pings don't hide a stall
+    pub fn observe(&mut self, slot: u64, config: &SlotGapConfig, now: Instant) -> Option<SlotGap> {
+        self.last_update = Some(now);
+        self.stalled = false;
+
+        let last_slot = self.last_slot;
+        self.last_slot = Some(last_slot.map_or(slot, |last_slot| last_slot.max(slot)));
+        match last_slot {
+            Some(from) if slot > from.saturating_add(config.max_gap_slots).saturating_add(1) => {
+                Some(SlotGap { from, to: slot })
+            }
+            _ => None,
+        }
+    }
+
+    /// Time since the latest update once it exceeds `stall_secs`, reported once per stall
+    pub fn check_stall(&mut self, config: &SlotGapConfig, now: Instant) -> Option<Duration> {
+        let elapsed = now.saturating_duration_since(self.last_update?);
+        if self.stalled || elapsed < Duration::from_secs(config.stall_secs) {
+            return None;
+        }
+        self.stalled = true;
+
+        Some(elapsed)
+    }
+
+    /// Highest slot received
+    pub fn last_slot(&self) -> Option<u64> {
+        self.last_slot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::slot_gap::{SlotGap, SlotGapConfig, SlotMonitor};
+
+    fn config() -> SlotGapConfig {
+        SlotGapConfig {
+            max_gap_slots: 10,
+            stall_secs: 30,
+            notification: None,
+        }
+    }
+
+    #[test]
+    fn test_slot_gap() {
+        let config = config();
+        let now = Instant::now();
+        let mut monitor = SlotMonitor::default();
+
+        assert_eq!(monitor.observe(100, &config, now), None);
+        assert_eq!(monitor.observe(111, &config, now), None);
+        assert_eq!(monitor.observe(105, &config, now), None);
+
+        let gap = monitor.observe(123, &config, now).unwrap();
+        assert_eq!(gap, SlotGap { from: 111, to: 123 });
+        assert_eq!(gap.missed(), 11);
+        assert_eq!(monitor.last_slot(), Some(123));
+    }
+
+    #[test]
+    fn test_stall() {
+        let config = config();
+        let now = Instant::now();
+        let mut monitor = SlotMonitor::default();
+        assert_eq!(monitor.check_stall(&config, now), None);
+
+        monitor.observe(100, &config, now);
+        assert_eq!(
+            monitor.check_stall(&config, now + Duration::from_secs(29)),
+            None
+        );
+        assert_eq!(
+            monitor.check_stall(&config, now + Duration::from_secs(30)),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            monitor.check_stall(&config, now + Duration::from_secs(60)),
+            None
+        );
+
+        let later = now + Duration::from_secs(61);
+        monitor.observe(101, &config, later);
+        assert_eq!(
+            monitor.check_stall(&config, later + Duration::from_secs(30)),
+            Some(Duration::from_secs(30))
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use spl_stake_pool::{
+    solana_program::borsh1::try_from_slice_unchecked,
+    state::{Fee, FeeType, StakePool},
+};
+
+use crate::error::JitoBellError;
+
+/// Fee change set by a `SetFee` instruction
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct FeeChange {
+    /// Fee type (e.g. `epoch`, `sol_deposit`)
+    pub fee_type: String,
+
+    /// Fee before the change, unknown if the pool was not fetched before an immediate change
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+
+    /// Fee after the change
+    pub after: String,
+}
+
+/// Last known fees of stake pools, keyed by fee type
+#[derive(Default)]
+pub struct StakePoolFees {
+    pools: HashMap<Pubkey, HashMap<&'static str, String>>,
+}
+
+impl StakePoolFees {
+    /// Fetch the stake pool and remember its current fees
+    pub async fn refresh(
+        &mut self,
+        rpc_client: &RpcClient,
+        stake_pool: &Pubkey,
+    ) -> Result<(), JitoBellError> {
+        let fees = fetch_fees(rpc_client, stake_pool).await?;
+        self.pools.insert(*stake_pool, fees);
+
+        Ok(())
+    }
+
+    /// Fee change of a `SetFee` instruction
+    ///
+    /// - Epoch and withdrawal fees only apply two epochs later, the fetched pool still holds the old fee
+    /// - Deposit and referral fees apply immediately, the old fee is the last one remembered
+    pub async fn change(
+        &mut self,
+        rpc_client: &RpcClient,
+        stake_pool: &Pubkey,
+        fee: &FeeType,
+    ) -> FeeChange {
+        let fee_type = fee_type_name(fee);
+        let remembered = self
+            .pools
+            .get(stake_pool)
+            .and_then(|fees| fees.get(fee_type))
+            .cloned();
+
+        let before = match fetch_fees(rpc_client, stake_pool).await {
+            Ok(mut fees) => {
+                let current = fees.get(fee_type).cloned();
+                fees.insert(fee_type, format_fee_type(fee));
+                self.pools.insert(*stake_pool, fees);
+
+                if is_delayed(fee) {
+                    current
+                } else {
+                    remembered
+                }
+            }
+            Err(e) => {
+                warn!("Failed to fetch stake pool {stake_pool}: {e}");
+                remembered
+            }
+        };
+
+        FeeChange {
+            fee_type: fee_type.to_string(),
+            before,
+            after: format_fee_type(fee),
+        }
+    }
+}
+
+/// Fetch the current fees of a stake pool
+async fn fetch_fees(
+    rpc_client: &RpcClient,
+    stake_pool: &Pubkey,
+) -> Result<HashMap<&'static str, String>, JitoBellError> {
+    let stake_pool_acc = rpc_client.get_account(stake_pool).await?;
+    let stake_pool = try_from_slice_unchecked::<StakePool>(&stake_pool_acc.data)
+        .map_err(|e| JitoBellError::TransactionParse(format!("Invalid stake pool: {e}")))?;
+
+    Ok(current_fees(&stake_pool))
+}
+
+/// Fees currently applied by the stake pool
+fn current_fees(stake_pool: &StakePool) -> HashMap<&'static str, String> {
+    [
+        FeeType::Epoch(stake_pool.epoch_fee),
+        FeeType::StakeWithdrawal(stake_pool.stake_withdrawal_fee),
+        FeeType::SolWithdrawal(stake_pool.sol_withdrawal_fee),
+        FeeType::StakeDeposit(stake_pool.stake_deposit_fee),
+        FeeType::SolDeposit(stake_pool.sol_deposit_fee),
+        FeeType::StakeReferral(stake_pool.stake_referral_fee),
+        FeeType::SolReferral(stake_pool.sol_referral_fee),
+    ]
+    .iter()
+    .map(|fee| (fee_type_name(fee), format_fee_type(fee)))
+    .collect()
+}
+
+/// Name of the fee type
+fn fee_type_name(fee: &FeeType) -> &'static str {
+    match fee {
+        FeeType::Epoch(_) => "epoch",
+        FeeType::StakeWithdrawal(_) => "stake_withdrawal",
+        FeeType::SolWithdrawal(_) => "sol_withdrawal",
+        FeeType::StakeDeposit(_) => "stake_deposit",
+        FeeType::SolDeposit(_) => "sol_deposit",
+        FeeType::StakeReferral(_) => "stake_referral",
+        FeeType::SolReferral(_) => "sol_referral",
+    }
+}
+
+/// Whether the fee only applies from a future epoch
+fn is_delayed(fee: &FeeType) -> bool {
+    matches!(
+        fee,
+        FeeType::Epoch(_) | FeeType::StakeWithdrawal(_) | FeeType::SolWithdrawal(_)
+    )
+}
+
+/// Fee as a percentage, referral fees are a percentage of the deposit fee
+fn format_fee_type(fee: &FeeType) -> String {
+    match fee {
+        FeeType::StakeReferral(percent) | FeeType::SolReferral(percent) => format!("{percent}%"),
+        FeeType::Epoch(fee)
+        | FeeType::StakeWithdrawal(fee)
+        | FeeType::SolWithdrawal(fee)
+        | FeeType::StakeDeposit(fee)
+        | FeeType::SolDeposit(fee) => format_fee(fee),
+    }
+}
+
+fn format_fee(fee: &Fee) -> String {
+    if fee.denominator == 0 {
+        return "0%".to_string();
+    }
+    let percent = fee.numerator as f64 * 100.0 / fee.denominator as f64;
+
+    format!("{}%", (percent * 10_000.0).round() / 10_000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use spl_stake_pool::state::{Fee, FeeType, StakePool};
+
+    use crate::stake_pool_fee::{current_fees, format_fee_type, is_delayed};
+
+    #[test]
+    fn test_format_fee_type() {
+        let fee = Fee {
+            numerator: 1,
+            denominator: 1_000,
+        };
+        assert_eq!(format_fee_type(&FeeType::SolDeposit(fee)), "0.1%");
+
+        let fee = Fee {
+            numerator: 1,
+            denominator: 3,
+        };
+        assert_eq!(format_fee_type(&FeeType::Epoch(fee)), "33.3333%");
+
+        assert_eq!(format_fee_type(&FeeType::SolReferral(50)), "50%");
+        assert_eq!(format_fee_type(&FeeType::Epoch(Fee::default())), "0%");
+    }
+
+    #[test]
+    fn test_current_fees() {
+        let stake_pool = StakePool {
+            epoch_fee: Fee {
+                numerator: 4,
+                denominator: 100,
+            },
+            sol_referral_fee: 100,
+            ..StakePool::default()
+        };
+
+        let fees = current_fees(&stake_pool);
+        assert_eq!(fees.len(), 7);
+        assert_eq!(fees["epoch"], "4%");
+        assert_eq!(fees["sol_referral"], "100%");
+        assert_eq!(fees["sol_deposit"], "0%");
+    }
+
+    #[test]
+    fn test_is_delayed() {
+        assert!(is_delayed(&FeeType::Epoch(Fee::default())));
+        assert!(!is_delayed(&FeeType::SolDeposit(Fee::default())));
+    }
+}
@@ -0,0 +1,165 @@
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{error::JitoBellError, notification_config::NotificationConfig};
+
+/// Timeout of each probe
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Discord API base URL
+const DISCORD_API_URL: &str = "https://discord.com/api/v10";
+
+/// Slack Web API base URL
+const SLACK_API_URL: &str = "https://slack.com/api";
+
+/// Startup check of the configured destinations
+///
+/// - Telegram (`getMe`), Slack (`auth.test` or the webhook), Discord and Discord bot credentials
+///   are verified, and the generic webhook URL is checked for reachability (`HEAD`)
+/// - With `test_message`, a test notification is also sent to every configured destination
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct StartupCheckConfig {
+    /// Exit when a destination fails the check, only warn otherwise
+    #[serde(default)]
+    pub fail_fast: bool,
+
+    /// Send a test notification to every configured destination
+    #[serde(default)]
+    pub test_message: bool,
+}
+
+/// Verify a destination's credentials or reachability without posting a message
+///
+/// - Probes go through the shared `client`, each within `PROBE_TIMEOUT`
+/// - Returns what was verified, None for destinations without a probe
+pub async fn probe(
+    client: &reqwest::Client,
+    notifications: &NotificationConfig,
+    destination: &str,
+) -> Result<Option<String>, JitoBellError> {
+    match destination {
+        "telegram" => {
+            let Some(telegram) = &notifications.telegram else {
+                return Ok(None);
+            };
+            let url = format!("https://api.telegram.org/bot{}/getMe", telegram.bot_token);
+            let body = get_json(client.get(url)).await?;
+            let username = body["result"]["username"].as_str().unwrap_or_default();
+            Ok(Some(format!("bot @{username}")))
+        }
+        "slack" => {
+            let Some(slack) = &notifications.slack else {
+                return Ok(None);
+            };
+            if let Some(bot_token) = &slack.bot_token {
+                let body = get_json(
+                    client
+                        .post(format!("{SLACK_API_URL}/auth.test"))
+                        .bearer_auth(bot_token),
+                )
+                .await?;
+                if body["ok"].as_bool() != Some(true) {
+                    return Err(JitoBellError::Notification(format!(
+                        "Slack auth.test failed: {}",
+                        body["error"].as_str().unwrap_or("unknown error")
+                    )));
+                }
+                let user = body["user"].as_str().unwrap_or_default();
+                return Ok(Some(format!("bot {user}")));
+            }
+
+            // An empty payload is rejected without posting, by a valid webhook with 400
+            let Some(webhook_url) = &slack.webhook_url else {
+                return Ok(None);
+            };
+            check_reachable(client.post(webhook_url).json(&serde_json::json!({}))).await?;
+            Ok(Some("webhook reachable".to_string()))
+        }
+        "discord" => {
+            let Some(discord) = &notifications.discord else {
+                return Ok(None);
+            };
+            let body = get_json(client.get(&discord.webhook_url)).await?;
+            let name = body["name"].as_str().unwrap_or_default();
+            Ok(Some(format!("webhook {name}")))
+        }
+        "discord_bot" => {
+            let Some(discord_bot) = &notifications.discord_bot else {
+                return Ok(None);
+            };
+            let body = get_json(
+                client
+                    .get(format!("{DISCORD_API_URL}/users/@me"))
+                    .header("Authorization", format!("Bot {}", discord_bot.bot_token)),
+            )
+            .await?;
+            let username = body["username"].as_str().unwrap_or_default();
+            Ok(Some(format!("bot {username}")))
+        }
+        "webhook" => {
+            let Some(webhook) = &notifications.webhook else {
+                return Ok(None);
+            };
+            check_reachable(client.head(&webhook.url)).await?;
+            Ok(Some("reachable".to_string()))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Send a request expecting a successful JSON response
+async fn get_json(request: reqwest::RequestBuilder) -> Result<Value, JitoBellError> {
+    let response = request.timeout(PROBE_TIMEOUT).send().await?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(JitoBellError::Notification(format!(
+            "Unexpected response: {status}"
+        )));
+    }
+
+    Ok(response.json().await?)
+}
+
+/// Send a request expecting the endpoint to exist and accept the credentials in its URL
+async fn check_reachable(request: reqwest::RequestBuilder) -> Result<(), JitoBellError> {
+    let status = request.timeout(PROBE_TIMEOUT).send().await?.status();
+    if !is_reachable(status) {
+        return Err(JitoBellError::Notification(format!(
+            "Unexpected response: {status}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Whether a response shows the endpoint exists, regardless of the probe request being accepted
+fn is_reachable(status: StatusCode) -> bool {
+    !status.is_server_error()
+        && !matches!(
+            status,
+            StatusCode::UNAUTHORIZED
+                | StatusCode::FORBIDDEN
+                | StatusCode::NOT_FOUND
+                | StatusCode::GONE
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+
+    use crate::startup_check::is_reachable;
+
+    #[test]
+    fn test_is_reachable() {
+        assert!(is_reachable(StatusCode::OK));
+        assert!(is_reachable(StatusCode::BAD_REQUEST));
+        assert!(is_reachable(StatusCode::METHOD_NOT_ALLOWED));
+        assert!(!is_reachable(StatusCode::NOT_FOUND));
+        assert!(!is_reachable(StatusCode::FORBIDDEN));
+        assert!(!is_reachable(StatusCode::BAD_GATEWAY));
+    }
+}
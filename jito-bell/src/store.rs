@@ -0,0 +1,561 @@
+use std::{
+    path::Path,
+    sync::{Mutex, PoisonError},
+};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+
+use crate::{error::JitoBellError, receipt::ReceiptStatus};
+
+/// Default number of records returned by list queries
+const DEFAULT_LIST_LIMIT: u32 = 100;
+
+/// Notification audit record
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditRecord {
+    /// Record ID
+    pub id: i64,
+
+    /// Time the delivery was attempted (RFC 3339)
+    pub created_at: String,
+
+    /// Destination (telegram, slack, discord, ...)
+    pub destination: String,
+
+    /// Transaction signature
+    pub transaction_signature: String,
+
+    /// Rendered text or payload sent to the destination
+    pub rendered: String,
+
+    /// Response status
+    pub status: String,
+}
+
+/// Notification waiting in the outbox
+#[derive(Debug, Clone)]
+pub struct OutboxRecord {
+    /// Record ID
+    pub id: i64,
+
+    /// Destination (telegram, slack, discord, ...)
+    pub destination: String,
+
+    /// Serialized notification and event
+    pub payload: String,
+
+    /// Failed delivery attempts
+    pub attempts: u32,
+}
+
+/// Alerts held for a destination, kept across a restart
+#[derive(Debug, Clone)]
+pub struct HeldAlertRecord {
+    /// Why the alerts are held (quiet_hours, rate_limit)
+    pub reason: String,
+
+    /// Destination (telegram, slack, discord, ...)
+    pub destination: String,
+
+    /// Serialized held alerts
+    pub payload: String,
+}
+
+/// Delivery receipt of a notification to a destination
+#[derive(Debug, Clone, Serialize)]
+pub struct ReceiptRecord {
+    /// Receipt ID
+    pub id: i64,
+
+    /// Time the notification was dispatched (RFC 3339)
+    pub created_at: String,
+
+    /// Time of the last status change (RFC 3339)
+    pub updated_at: String,
+
+    /// Destination (telegram, slack, discord, ...)
+    pub destination: String,
+
+    /// Transaction signature
+    pub transaction_signature: String,
+
+    /// Description of the notification
+    pub description: String,
+
+    /// Delivery status (pending, delivered, retrying, dead_lettered)
+    pub status: String,
+
+    /// Delivery attempts
+    pub attempts: u32,
+
+    /// Error of the last failed attempt
+    pub last_error: Option<String>,
+}
+
+/// Filter for listing audit records
+#[derive(Debug, Default, Deserialize)]
+pub struct AuditFilter {
+    /// Destination
+    pub destination: Option<String>,
+
+    /// Transaction signature
+    pub signature: Option<String>,
+
+    /// Only include records created at or after this time
+    pub since: Option<DateTime<Utc>>,
+
+    /// Maximum number of records
+    pub limit: Option<u32>,
+}
+
+/// Filter for listing delivery receipts
+#[derive(Debug, Default, Deserialize)]
+pub struct ReceiptFilter {
+    /// Destination
+    pub destination: Option<String>,
+
+    /// Only include notifications not delivered (yet)
+    #[serde(default)]
+    pub undelivered: bool,
+
+    /// Only include notifications dispatched at or after this time
+    pub since: Option<DateTime<Utc>>,
+
+    /// Only include notifications dispatched before this time
+    pub until: Option<DateTime<Utc>>,
+
+    /// Maximum number of records
+    pub limit: Option<u32>,
+}
+
+/// SQLite backed event store
+pub struct EventStore {
+    /// Database connection
+    conn: Mutex<Connection>,
+}
+
+impl EventStore {
+    /// Open (or create) the event store at `path`
+    pub fn open(path: &Path) -> Result<Self, JitoBellError> {
+        let conn = Connection::open(path)?;
+
+        // WAL lets the CLI read while the service is writing
+        conn.query_row("PRAGMA journal_mode = WAL", [], |_| Ok(()))?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS notification_audit (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                created_at TEXT NOT NULL,
+                destination TEXT NOT NULL,
+                transaction_signature TEXT NOT NULL,
+                rendered TEXT NOT NULL,
+                status TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS notification_audit_created_at
+                ON notification_audit (created_at);
+            CREATE INDEX IF NOT EXISTS notification_audit_transaction_signature
+                ON notification_audit (transaction_signature);
+            CREATE TABLE IF NOT EXISTS observed_amount (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                created_at TEXT NOT NULL,
+                instruction TEXT NOT NULL,
+                address TEXT NOT NULL,
+                amount REAL NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS observed_amount_instruction_created_at
+                ON observed_amount (instruction, address, created_at);
+            CREATE TABLE IF NOT EXISTS notification_outbox (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                created_at TEXT NOT NULL,
+                destination TEXT NOT NULL,
+                payload TEXT NOT NULL,
+                attempts INTEGER NOT NULL DEFAULT 0,
+                last_error TEXT
+            );
+            CREATE TABLE IF NOT EXISTS notification_dead_letter (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                created_at TEXT NOT NULL,
+                destination TEXT NOT NULL,
+                payload TEXT NOT NULL,
+                error TEXT NOT NULL,
+                attempts INTEGER NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS notification_receipt (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL,
+                destination TEXT NOT NULL,
+                transaction_signature TEXT NOT NULL,
+                description TEXT NOT NULL,
+                status TEXT NOT NULL,
+                attempts INTEGER NOT NULL DEFAULT 0,
+                last_error TEXT
+            );
+            CREATE INDEX IF NOT EXISTS notification_receipt_created_at
+                ON notification_receipt (created_at);
+            CREATE TABLE IF NOT EXISTS notification_held_alert (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                created_at TEXT NOT NULL,
+                reason TEXT NOT NULL,
+                destination TEXT NOT NULL,
+                payload TEXT NOT NULL
+            );",
+        )?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Record a notification delivery attempt
+    pub fn record_notification(
+        &self,
+        destination: &str,
+        transaction_signature: &str,
+        rendered: &str,
+        status: &str,
+    ) -> Result<(), JitoBellError> {
+        let conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        conn.execute(
+            "INSERT INTO notification_audit
+                (created_at, destination, transaction_signature, rendered, status)
+                VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                format_timestamp(Utc::now()),
+                destination,
+                transaction_signature,
+                rendered,
+                status
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// List notification delivery attempts, newest first
+    pub fn list_notifications(
+        &self,
+        filter: &AuditFilter,
+    ) -> Result<Vec<AuditRecord>, JitoBellError> {
+        let conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut stmt = conn.prepare(
+            "SELECT id, created_at, destination, transaction_signature, rendered, status
+                FROM notification_audit
+                WHERE (?1 IS NULL OR destination = ?1)
+                    AND (?2 IS NULL OR transaction_signature = ?2)
+                    AND (?3 IS NULL OR created_at >= ?3)
+                ORDER BY id DESC
+                LIMIT ?4",
+        )?;
+
+        let since = filter.since.map(format_timestamp);
+        let records = stmt
+            .query_map(
+                params![
+                    filter.destination,
+                    filter.signature,
+                    since,
+                    filter.limit.unwrap_or(DEFAULT_LIST_LIMIT)
+                ],
+                |row| {
+                    Ok(AuditRecord {
+                        id: row.get(0)?,
+                        created_at: row.get(1)?,
+                        destination: row.get(2)?,
+                        transaction_signature: row.get(3)?,
+                        rendered: row.get(4)?,
+                        status: row.get(5)?,
+                    })
+                },
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(records)
+    }
+
+    /// Add a notification to the outbox before it is sent, returning its ID
+    pub fn enqueue_outbox(&self, destination: &str, payload: &str) -> Result<i64, JitoBellError> {
+        let conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        conn.execute(
+            "INSERT INTO notification_outbox (created_at, destination, payload)
+                VALUES (?1, ?2, ?3)",
+            params![format_timestamp(Utc::now()), destination, payload],
+        )?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Remove a notification from the outbox once it was sent
+    pub fn complete_outbox(&self, id: i64) -> Result<(), JitoBellError> {
+        let conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        conn.execute("DELETE FROM notification_outbox WHERE id = ?1", params![id])?;
+
+        Ok(())
+    }
+
+    /// Record a failed delivery of a notification kept in the outbox
+    pub fn fail_outbox(&self, id: i64, error: &str) -> Result<(), JitoBellError> {
+        let conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        conn.execute(
+            "UPDATE notification_outbox SET attempts = attempts + 1, last_error = ?2
+                WHERE id = ?1",
+            params![id, error],
+        )?;
+
+        Ok(())
+    }
+
+    /// Notifications waiting in the outbox, oldest first
+    pub fn pending_outbox(&self, limit: u32) -> Result<Vec<OutboxRecord>, JitoBellError> {
+        let conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut stmt = conn.prepare(
+            "SELECT id, destination, payload, attempts
+                FROM notification_outbox
+                ORDER BY id
+                LIMIT ?1",
+        )?;
+
+        let records = stmt
+            .query_map(params![limit], |row| {
+                Ok(OutboxRecord {
+                    id: row.get(0)?,
+                    destination: row.get(1)?,
+                    payload: row.get(2)?,
+                    attempts: row.get(3)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(records)
+    }
+
+    /// Record a notification that could not be delivered
+    pub fn record_dead_letter(
+        &self,
+        destination: &str,
+        payload: &str,
+        error: &str,
+        attempts: u32,
+    ) -> Result<(), JitoBellError> {
+        let conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        conn.execute(
+            "INSERT INTO notification_dead_letter
+                (created_at, destination, payload, error, attempts)
+                VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                format_timestamp(Utc::now()),
+                destination,
+                payload,
+                error,
+                attempts
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Keep alerts still held on shutdown for the next run
+    pub fn keep_held_alerts(
+        &self,
+        reason: &str,
+        destination: &str,
+        payload: &str,
+    ) -> Result<(), JitoBellError> {
+        let conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        conn.execute(
+            "INSERT INTO notification_held_alert (created_at, reason, destination, payload)
+                VALUES (?1, ?2, ?3, ?4)",
+            params![format_timestamp(Utc::now()), reason, destination, payload],
+        )?;
+
+        Ok(())
+    }
+
+    /// Take the alerts kept by the previous run, oldest first
+    pub fn take_held_alerts(&self) -> Result<Vec<HeldAlertRecord>, JitoBellError> {
+        let mut conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        let tx = conn.transaction()?;
+
+        let records = {
+            let mut stmt = tx.prepare(
+                "SELECT reason, destination, payload
+                    FROM notification_held_alert
+                    ORDER BY id",
+            )?;
+            let records = stmt
+                .query_map([], |row| {
+                    Ok(HeldAlertRecord {
+                        reason: row.get(0)?,
+                        destination: row.get(1)?,
+                        payload: row.get(2)?,
+                    })
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+            records
+        };
+        tx.execute("DELETE FROM notification_held_alert", [])?;
+        tx.commit()?;
+
+        Ok(records)
+    }
+
+    /// Open a pending delivery receipt, returning its ID
+    pub fn open_receipt(
+        &self,
+        destination: &str,
+        transaction_signature: &str,
+        description: &str,
+    ) -> Result<i64, JitoBellError> {
+        let conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = format_timestamp(Utc::now());
+        conn.execute(
+            "INSERT INTO notification_receipt
+                (created_at, updated_at, destination, transaction_signature, description, status)
+                VALUES (?1, ?1, ?2, ?3, ?4, ?5)",
+            params![
+                now,
+                destination,
+                transaction_signature,
+                description,
+                ReceiptStatus::Pending.as_str()
+            ],
+        )?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Record the status of a delivery receipt after `attempts` attempts
+    pub fn update_receipt(
+        &self,
+        id: i64,
+        status: ReceiptStatus,
+        attempts: u32,
+        error: Option<&str>,
+    ) -> Result<(), JitoBellError> {
+        let conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        conn.execute(
+            "UPDATE notification_receipt
+                SET updated_at = ?2, status = ?3, attempts = ?4,
+                    last_error = COALESCE(?5, last_error)
+                WHERE id = ?1",
+            params![
+                id,
+                format_timestamp(Utc::now()),
+                status.as_str(),
+                attempts,
+                error
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// List delivery receipts, newest first
+    pub fn list_receipts(
+        &self,
+        filter: &ReceiptFilter,
+    ) -> Result<Vec<ReceiptRecord>, JitoBellError> {
+        let conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut stmt = conn.prepare(
+            "SELECT id, created_at, updated_at, destination, transaction_signature, description,
+                    status, attempts, last_error
+                FROM notification_receipt
+                WHERE (?1 IS NULL OR destination = ?1)
+                    AND (?2 = 0 OR status != ?3)
+                    AND (?4 IS NULL OR created_at >= ?4)
+                    AND (?5 IS NULL OR created_at < ?5)
+                ORDER BY id DESC
+                LIMIT ?6",
+        )?;
+
+        let since = filter.since.map(format_timestamp);
+        let until = filter.until.map(format_timestamp);
+        let records = stmt
+            .query_map(
+                params![
+                    filter.destination,
+                    filter.undelivered,
+                    ReceiptStatus::Delivered.as_str(),
+                    since,
+                    until,
+                    filter.limit.unwrap_or(DEFAULT_LIST_LIMIT)
+                ],
+                |row| {
+                    Ok(ReceiptRecord {
+                        id: row.get(0)?,
+                        created_at: row.get(1)?,
+                        updated_at: row.get(2)?,
+                        destination: row.get(3)?,
+                        transaction_signature: row.get(4)?,
+                        description: row.get(5)?,
+                        status: row.get(6)?,
+                        attempts: row.get(7)?,
+                        last_error: row.get(8)?,
+                    })
+                },
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(records)
+    }
+
+    /// Record an amount observed for an instruction
+    pub fn record_amount(
+        &self,
+        instruction: &str,
+        address: &str,
+        amount: f64,
+    ) -> Result<(), JitoBellError> {
+        let conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        conn.execute(
+            "INSERT INTO observed_amount (created_at, instruction, address, amount)
+                VALUES (?1, ?2, ?3, ?4)",
+            params![format_timestamp(Utc::now()), instruction, address, amount],
+        )?;
+
+        Ok(())
+    }
+
+    /// Median of amounts observed for an instruction since `since`
+    pub fn median_amount(
+        &self,
+        instruction: &str,
+        address: &str,
+        since: DateTime<Utc>,
+    ) -> Result<Option<f64>, JitoBellError> {
+        let conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        let since = format_timestamp(since);
+
+        let count: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM observed_amount
+                WHERE instruction = ?1 AND address = ?2 AND created_at >= ?3",
+            params![instruction, address, since],
+            |row| row.get(0),
+        )?;
+        if count == 0 {
+            return Ok(None);
+        }
+
+        // Lower and upper middle, equal for odd counts
+        let mut stmt = conn.prepare(
+            "SELECT amount FROM observed_amount
+                WHERE instruction = ?1 AND address = ?2 AND created_at >= ?3
+                ORDER BY amount
+                LIMIT ?4 OFFSET ?5",
+        )?;
+        let middle = stmt
+            .query_map(
+                params![instruction, address, since, 2 - count % 2, (count - 1) / 2],
+                |row| row.get::<_, f64>(0),
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Some(middle.iter().sum::<f64>() / middle.len() as f64))
+    }
+}
+
+/// Format timestamps with a fixed layout so they compare lexicographically
+fn format_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
@@ -0,0 +1,232 @@
+use std::{
+    any::Any,
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use axum::Router;
+use futures::future::BoxFuture;
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use tokio::{net::TcpListener, sync::mpsc, task::JoinHandle};
+
+use crate::{error::JitoBellError, notification_info::NotificationInfo, retry::ReconnectPolicy};
+
+/// A task running this long before exiting restarts from the first backoff step
+const STABLE_RUN_SECS: u64 = 60;
+
+/// Supervision of the background tasks
+///
+/// - The REST and admin API servers are restarted with backoff (`restart`) when they exit or
+///   panic
+/// - A panic of the subscription and dispatch loop is reconnected like a failed stream
+///   (`reconnect`)
+/// - Restarts are logged, reported in the `jito-bell-task-restart` datapoint and alerted with
+///   `notification`
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SupervisorConfig {
+    /// Restart policy of the supervised tasks, `max_attempts` consecutive restarts before the
+    /// task is left stopped
+    #[serde(default)]
+    pub restart: ReconnectPolicy,
+
+    /// Alert sent for restarts
+    pub notification: Option<NotificationInfo>,
+}
+
+/// Restart of a supervised task
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskRestart {
+    /// Task name
+    pub task: &'static str,
+
+    /// Why the task stopped
+    pub reason: String,
+
+    /// Consecutive restarts (1-based)
+    pub attempt: u32,
+
+    /// Delay before the restart
+    pub delay: Duration,
+}
+
+/// Spawns supervised tasks and reports their restarts
+#[derive(Debug, Clone)]
+pub struct Supervisor {
+    /// Restart policy
+    policy: ReconnectPolicy,
+
+    /// Restarts reported to the handler
+    restarts: mpsc::UnboundedSender<TaskRestart>,
+}
+
+impl Supervisor {
+    /// Create a supervisor, with the receiver of the restarts it reports
+    pub fn new(policy: ReconnectPolicy) -> (Self, mpsc::UnboundedReceiver<TaskRestart>) {
+        let (restarts, receiver) = mpsc::unbounded_channel();
+        (Self { policy, restarts }, receiver)
+    }
+
+    /// Run the task created by `task` in the background, creating and running it again after a
+    /// backoff whenever it exits or panics
+    pub fn spawn<F, Fut>(&self, name: &'static str, mut task: F) -> JoinHandle<()>
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(), JitoBellError>> + Send + 'static,
+    {
+        let policy = self.policy;
+        let restarts = self.restarts.clone();
+
+        tokio::spawn(async move {
+            let mut attempt = 0;
+            loop {
+                let started = Instant::now();
+                let reason = match tokio::spawn(task()).await {
+                    Ok(Ok(())) => "exited".to_string(),
+                    Ok(Err(e)) => format!("failed: {e}"),
+                    Err(e) if e.is_panic() => {
+                        format!("panicked: {}", panic_message(&*e.into_panic()))
+                    }
+                    Err(e) => format!("failed: {e}"),
+                };
+
+                attempt = next_attempt(attempt, started.elapsed());
+                if policy.gives_up(attempt) {
+                    error!(
+                        "Task {name} {reason}, giving up after {} restarts",
+                        attempt - 1
+                    );
+                    return;
+                }
+
+                let delay = policy.delay(attempt);
+                error!(
+                    "Task {name} {reason}, restarting in {}ms (attempt {attempt})",
+                    delay.as_millis()
+                );
+                let _ = restarts.send(TaskRestart {
+                    task: name,
+                    reason,
+                    attempt,
+                    delay,
+                });
+
+                tokio::time::sleep(delay).await;
+                info!("Restarting task {name}");
+            }
+        })
+    }
+}
+
+/// Task serving `router` on `listener`, binding `bind_address` again when restarted
+pub fn serve_task(
+    bind_address: String,
+    listener: TcpListener,
+    router: Router,
+) -> impl FnMut() -> BoxFuture<'static, Result<(), JitoBellError>> + Send + 'static {
+    let mut listener = Some(listener);
+    move || {
+        let listener = listener.take();
+        let bind_address = bind_address.clone();
+        let router = router.clone();
+        Box::pin(async move {
+            let listener = match listener {
+                Some(listener) => listener,
+                None => {
+                    let listener = TcpListener::bind(&bind_address).await?;
+                    info!("Listening on {bind_address} again");
+                    listener
+                }
+            };
+            axum::serve(listener, router).await?;
+            Ok(())
+        })
+    }
+}
+
+/// Consecutive restarts after a task stopped having run for `ran`
+///
+/// - The count resets when the task ran for `STABLE_RUN_SECS`
+pub fn next_attempt(attempt: u32, ran: Duration) -> u32 {
+    if ran >= Duration::from_secs(STABLE_RUN_SECS) {
+        1
+    } else {
+        attempt.saturating_add(1)
+    }
+}
+
+/// Message of a panic payload
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use crate::{
+        retry::ReconnectPolicy,
+        supervisor::{next_attempt, panic_message, Supervisor},
+    };
+
+    #[test]
+    fn test_next_attempt() {
+        assert_eq!(next_attempt(0, Duration::from_secs(1)), 1);
+        assert_eq!(next_attempt(3, Duration::from_secs(1)), 4);
+        assert_eq!(next_attempt(3, Duration::from_secs(60)), 1);
+    }
+
+    #[test]
+    fn test_panic_message() {
+        assert_eq!(panic_message(&"boom"), "boom");
+        assert_eq!(panic_message(&"boom".to_string()), "boom");
+        assert_eq!(panic_message(&1), "unknown panic");
+    }
+
+    #[tokio::test]
+    async fn test_restart() {
+        let policy = ReconnectPolicy {
+            max_attempts: Some(2),
+            initial_backoff_ms: 1,
+            max_backoff_ms: 1,
+            jitter: 0.0,
+        };
+        let (supervisor, mut restarts) = Supervisor::new(policy);
+
+        let runs = Arc::new(AtomicU32::new(0));
+        let task_runs = runs.clone();
+        let task = supervisor.spawn("test", move || {
+            let run = task_runs.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if run == 0 {
+                    panic!("first run");
+                }
+                Ok(())
+            }
+        });
+        task.await.unwrap();
+
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+
+        let restart = restarts.recv().await.unwrap();
+        assert_eq!(restart.task, "test");
+        assert_eq!(restart.reason, "panicked: first run");
+        assert_eq!(restart.attempt, 1);
+
+        let restart = restarts.recv().await.unwrap();
+        assert_eq!(restart.reason, "exited");
+        assert_eq!(restart.attempt, 2);
+    }
+}
@@ -0,0 +1,126 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// Suppression list
+///
+/// - Notifications of a muted pool are dropped until the mute expires
+/// - Updated from Discord bot interactions ("Mute 1h")
+/// - Notifications of a muted instruction are dropped until unmuted or the mute expires, updated
+///   from the admin API
+#[derive(Debug, Default)]
+pub struct Suppressions {
+    /// Mute expiry keyed by pool
+    muted: Mutex<HashMap<String, Instant>>,
+
+    /// Mute expiry keyed by (program, instruction), None until unmuted
+    muted_instructions: Mutex<HashMap<(String, String), Option<Instant>>>,
+}
+
+impl Suppressions {
+    /// Mute notifications of `pool` for `duration`
+    pub fn mute(&self, pool: &str, duration: Duration) {
+        let mut muted = self.muted.lock().unwrap_or_else(PoisonError::into_inner);
+        muted.insert(pool.to_string(), Instant::now() + duration);
+    }
+
+    /// Whether notifications of `pool` are muted, expired mutes are removed
+    pub fn is_muted(&self, pool: &str) -> bool {
+        let mut muted = self.muted.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+        muted.retain(|_, until| *until > now);
+
+        muted.contains_key(pool)
+    }
+
+    /// Mute notifications of an instruction for `duration`, until unmuted when None
+    pub fn mute_instruction(&self, program: &str, instruction: &str, duration: Option<Duration>) {
+        let mut muted = self
+            .muted_instructions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        muted.insert(
+            (program.to_string(), instruction.to_string()),
+            duration.map(|duration| Instant::now() + duration),
+        );
+    }
+
+    /// Unmute notifications of an instruction
+    pub fn unmute_instruction(&self, program: &str, instruction: &str) {
+        let mut muted = self
+            .muted_instructions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        muted.remove(&(program.to_string(), instruction.to_string()));
+    }
+
+    /// Whether notifications of an instruction are muted, expired mutes are removed
+    pub fn is_instruction_muted(&self, program: &str, instruction: &str) -> bool {
+        let mut muted = self
+            .muted_instructions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+        muted.retain(|_, until| until.is_none_or(|until| until > now));
+
+        muted.contains_key(&(program.to_string(), instruction.to_string()))
+    }
+
+    /// Muted instructions as "program.instruction", sorted
+    pub fn muted_instructions(&self) -> Vec<String> {
+        let mut muted = self
+            .muted_instructions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+        muted.retain(|_, until| until.is_none_or(|until| until > now));
+
+        let mut instructions: Vec<String> = muted
+            .keys()
+            .map(|(program, instruction)| format!("{program}.{instruction}"))
+            .collect();
+        instructions.sort();
+
+        instructions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::suppression::Suppressions;
+
+    #[test]
+    fn test_mute() {
+        let suppressions = Suppressions::default();
+        assert!(!suppressions.is_muted("pool"));
+
+        suppressions.mute("pool", Duration::from_secs(3600));
+        assert!(suppressions.is_muted("pool"));
+        assert!(!suppressions.is_muted("other"));
+
+        suppressions.mute("pool", Duration::ZERO);
+        assert!(!suppressions.is_muted("pool"));
+    }
+
+    #[test]
+    fn test_mute_instruction() {
+        let suppressions = Suppressions::default();
+
+        suppressions.mute_instruction("spl_stake_pool", "deposit_sol", None);
+        suppressions.mute_instruction("jito_vault", "mint_to", Some(Duration::ZERO));
+        assert!(suppressions.is_instruction_muted("spl_stake_pool", "deposit_sol"));
+        assert!(!suppressions.is_instruction_muted("spl_stake_pool", "withdraw_sol"));
+        assert!(!suppressions.is_instruction_muted("jito_vault", "mint_to"));
+        assert_eq!(
+            suppressions.muted_instructions(),
+            vec!["spl_stake_pool.deposit_sol"]
+        );
+
+        suppressions.unmute_instruction("spl_stake_pool", "deposit_sol");
+        assert!(!suppressions.is_instruction_muted("spl_stake_pool", "deposit_sol"));
+    }
+}
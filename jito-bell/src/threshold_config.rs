@@ -1,17 +1,36 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::notification_info::NotificationInfo;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ThresholdConfig {
     /// Threshold value in SOL
+    ///
+    /// - Fallback when `median_multiple` is set but no history is available
     pub value: f64,
 
+    /// Threshold as a multiple of the trailing 7-day median amount of the instruction
+    ///
+    /// - Requires `store`
+    #[serde(default)]
+    pub median_multiple: Option<f64>,
+
     /// Notification configuration for this threshold
     pub notification: NotificationInfo,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+impl ThresholdConfig {
+    /// Highest threshold reached by `amount`
+    ///
+    /// - `thresholds` are sorted from high to low (e.g. resolved thresholds)
+    pub fn reached(thresholds: &[ThresholdConfig], amount: f64) -> Option<&ThresholdConfig> {
+        thresholds
+            .iter()
+            .find(|threshold| amount >= threshold.value)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct UsdThresholdConfig {
     /// Threshold value in USD
     pub value: u64,
@@ -19,3 +38,12 @@ pub struct UsdThresholdConfig {
     /// Notification configuration for this threshold
     pub notification: NotificationInfo,
 }
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LamportThresholdConfig {
+    /// Threshold value in lamports
+    pub value: u64,
+
+    /// Notification configuration for this threshold
+    pub notification: NotificationInfo,
+}
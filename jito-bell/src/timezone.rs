@@ -0,0 +1,105 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Format of local timestamps in notifications
+const LOCAL_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Timezone of the human-readable timestamps in notifications
+///
+/// - `UTC` (default)
+/// - `local`: timezone of the host, which honours `TZ` (e.g. `TZ=Asia/Tokyo`)
+/// - A fixed UTC offset (e.g. `+09:00`, `-05:00`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Timezone {
+    #[default]
+    Utc,
+
+    Local,
+
+    Fixed(FixedOffset),
+}
+
+impl Timezone {
+    /// Wall clock date and time of `time` in this timezone
+    pub fn naive_local(&self, time: DateTime<Utc>) -> NaiveDateTime {
+        match self {
+            Timezone::Utc => time.naive_utc(),
+            Timezone::Local => time.with_timezone(&Local).naive_local(),
+            Timezone::Fixed(offset) => time.with_timezone(offset).naive_local(),
+        }
+    }
+
+    /// Format a time in this timezone (e.g. "2025-01-01 09:00:00 +09:00")
+    pub fn format(&self, time: DateTime<Utc>) -> String {
+        match self {
+            Timezone::Utc => format!("{} UTC", time.format(LOCAL_TIME_FORMAT)),
+            Timezone::Local => time
+                .with_timezone(&Local)
+                .format(&format!("{LOCAL_TIME_FORMAT} %:z"))
+                .to_string(),
+            Timezone::Fixed(offset) => time
+                .with_timezone(offset)
+                .format(&format!("{LOCAL_TIME_FORMAT} %:z"))
+                .to_string(),
+        }
+    }
+}
+
+impl FromStr for Timezone {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "UTC" | "utc" | "Z" => Ok(Timezone::Utc),
+            "local" => Ok(Timezone::Local),
+            _ => FixedOffset::from_str(s).map(Timezone::Fixed).map_err(|_| {
+                format!("Invalid timezone {s}, expected UTC, local or an offset such as +09:00")
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for Timezone {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<Timezone> for String {
+    fn from(timezone: Timezone) -> Self {
+        match timezone {
+            Timezone::Utc => "UTC".to_string(),
+            Timezone::Local => "local".to_string(),
+            Timezone::Fixed(offset) => offset.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+
+    use crate::timezone::Timezone;
+
+    #[test]
+    fn test_timezone() {
+        let time = DateTime::from_timestamp(1_735_689_600, 0).unwrap();
+
+        assert_eq!(Timezone::Utc.format(time), "2025-01-01 00:00:00 UTC");
+
+        let tokyo: Timezone = "+09:00".parse().unwrap();
+        assert_eq!(tokyo.format(time), "2025-01-01 09:00:00 +09:00");
+        assert_eq!(String::from(tokyo), "+09:00");
+
+        let new_york: Timezone = "-05:00".parse().unwrap();
+        assert_eq!(new_york.format(time), "2024-12-31 19:00:00 -05:00");
+
+        assert_eq!("local".parse(), Ok(Timezone::Local));
+        assert!("Asia/Tokyo".parse::<Timezone>().is_err());
+    }
+}
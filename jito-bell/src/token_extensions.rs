@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{epoch_schedule::EpochSchedule, pubkey::Pubkey};
+use spl_token_2022::{
+    extension::{
+        transfer_fee::TransferFeeConfig, transfer_hook::TransferHook, BaseStateWithExtensions,
+        StateWithExtensions,
+    },
+    state::Mint,
+};
+
+use crate::error::JitoBellError;
+
+/// Token-2022 mint extensions affecting transfers
+#[derive(Debug, Clone, Default)]
+pub struct TransferExtensions {
+    /// TransferFee extension
+    pub transfer_fee: Option<TransferFeeConfig>,
+
+    /// Program of the TransferHook extension
+    pub transfer_hook_program: Option<Pubkey>,
+}
+
+impl TransferExtensions {
+    /// Decode the extensions of a mint account, SPL Token mints have none
+    pub fn unpack(data: &[u8]) -> Result<Self, JitoBellError> {
+        let mint = StateWithExtensions::<Mint>::unpack(data)?;
+
+        Ok(Self {
+            transfer_fee: mint.get_extension::<TransferFeeConfig>().ok().copied(),
+            transfer_hook_program: mint
+                .get_extension::<TransferHook>()
+                .ok()
+                .and_then(|hook| Option::<Pubkey>::from(hook.program_id)),
+        })
+    }
+
+    /// Fee withheld from a transfer of `amount` in `epoch`
+    pub fn transfer_fee(&self, epoch: u64, amount: u64) -> u64 {
+        self.transfer_fee
+            .as_ref()
+            .and_then(|config| config.calculate_epoch_fee(epoch, amount))
+            .unwrap_or_default()
+    }
+}
+
+/// Transfer extensions of Token-2022 mints
+///
+/// - Refetched once per epoch, transfer fee updates only take effect at an epoch boundary
+#[derive(Default)]
+pub struct Token2022Mints {
+    epoch_schedule: Option<EpochSchedule>,
+    mints: HashMap<Pubkey, (u64, TransferExtensions)>,
+}
+
+impl Token2022Mints {
+    /// Epoch of `slot` and the transfer extensions of `mint` in that epoch
+    pub async fn extensions(
+        &mut self,
+        rpc_client: &RpcClient,
+        mint: &Pubkey,
+        slot: u64,
+    ) -> Result<(u64, TransferExtensions), JitoBellError> {
+        let epoch_schedule = match self.epoch_schedule.clone() {
+            Some(epoch_schedule) => epoch_schedule,
+            None => {
+                let epoch_schedule = rpc_client.get_epoch_schedule().await?;
+                self.epoch_schedule = Some(epoch_schedule.clone());
+                epoch_schedule
+            }
+        };
+        let epoch = epoch_schedule.get_epoch(slot);
+
+        if let Some((fetched_epoch, extensions)) = self.mints.get(mint) {
+            if *fetched_epoch == epoch {
+                return Ok((epoch, extensions.clone()));
+            }
+        }
+
+        let mint_acc = rpc_client.get_account(mint).await?;
+        let extensions = TransferExtensions::unpack(&mint_acc.data)?;
+        self.mints.insert(*mint, (epoch, extensions.clone()));
+
+        Ok((epoch, extensions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::program_pack::Pack;
+    use spl_token_2022::{
+        extension::transfer_fee::{TransferFee, TransferFeeConfig},
+        state::Mint,
+    };
+
+    use crate::token_extensions::TransferExtensions;
+
+    fn transfer_fee(epoch: u64, basis_points: u16, maximum_fee: u64) -> TransferFee {
+        TransferFee {
+            epoch: epoch.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: basis_points.into(),
+        }
+    }
+
+    #[test]
+    fn test_transfer_fee() {
+        let extensions = TransferExtensions {
+            transfer_fee: Some(TransferFeeConfig {
+                older_transfer_fee: transfer_fee(0, 100, u64::MAX),
+                newer_transfer_fee: transfer_fee(10, 200, 150),
+                ..TransferFeeConfig::default()
+            }),
+            transfer_hook_program: None,
+        };
+
+        assert_eq!(extensions.transfer_fee(9, 10_000), 100);
+        // Newer fee, capped at its maximum
+        assert_eq!(extensions.transfer_fee(10, 10_000), 150);
+        assert_eq!(TransferExtensions::default().transfer_fee(10, 10_000), 0);
+    }
+
+    #[test]
+    fn test_unpack_without_extensions() {
+        let mint = Mint {
+            decimals: 9,
+            is_initialized: true,
+            ..Mint::default()
+        };
+        let mut data = vec![0; Mint::LEN];
+        Mint::pack(mint, &mut data).unwrap();
+
+        let extensions = TransferExtensions::unpack(&data).unwrap();
+        assert!(extensions.transfer_fee.is_none());
+        assert!(extensions.transfer_hook_program.is_none());
+    }
+}
@@ -0,0 +1,280 @@
+use std::fmt::Display;
+
+use futures::{Sink, SinkExt, Stream, StreamExt};
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{
+    broadcast::{self, error::RecvError},
+    mpsc,
+};
+use yellowstone_grpc_proto::{
+    prelude::{subscribe_update::UpdateOneof, SubscribeRequest, SubscribeUpdate},
+    tonic::Status,
+};
+
+use crate::keepalive::{self, KeepaliveConfig};
+
+/// What the stream reader does when the update queue is full
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverflowPolicy {
+    /// Wait for the dispatcher, slowing down stream consumption
+    #[default]
+    Block,
+
+    /// Drop the oldest queued update, counted in `dropped`
+    DropOldest,
+}
+
+/// Queue of Geyser updates between the stream reader and the dispatcher
+///
+/// - The stream is read by its own task, so slow destinations don't stall stream consumption
+///   until the queue is full
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct UpdateQueueConfig {
+    /// Maximum number of queued updates
+    #[serde(default = "default_capacity")]
+    pub capacity: usize,
+
+    /// Policy when the queue is full
+    #[serde(default)]
+    pub overflow: OverflowPolicy,
+}
+
+fn default_capacity() -> usize {
+    4096
+}
+
+impl Default for UpdateQueueConfig {
+    fn default() -> Self {
+        Self {
+            capacity: default_capacity(),
+            overflow: OverflowPolicy::default(),
+        }
+    }
+}
+
+/// Item read from the Geyser stream
+#[derive(Debug, Clone)]
+pub enum StreamItem {
+    /// Update to dispatch
+    Update(Box<SubscribeUpdate>),
+
+    /// The stream failed, no item follows
+    Failed(String),
+}
+
+/// Sending half of the update queue
+pub enum UpdateSender<T> {
+    /// Waits while the queue is full
+    Block(mpsc::Sender<T>),
+
+    /// Overwrites the oldest item while the queue is full
+    DropOldest(broadcast::Sender<T>),
+}
+
+/// Receiving half of the update queue
+pub struct UpdateReceiver<T> {
+    inner: UpdateReceiverInner<T>,
+
+    /// Maximum number of queued items
+    capacity: usize,
+
+    /// Items dropped by `DropOldest`
+    dropped: u64,
+}
+
+enum UpdateReceiverInner<T> {
+    Block(mpsc::Receiver<T>),
+    DropOldest(broadcast::Receiver<T>),
+}
+
+/// Create an update queue
+pub fn channel<T: Clone>(config: &UpdateQueueConfig) -> (UpdateSender<T>, UpdateReceiver<T>) {
+    let capacity = config.capacity.max(1);
+    let (sender, inner) = match config.overflow {
+        OverflowPolicy::Block => {
+            let (sender, receiver) = mpsc::channel(capacity);
+            (
+                UpdateSender::Block(sender),
+                UpdateReceiverInner::Block(receiver),
+            )
+        }
+        OverflowPolicy::DropOldest => {
+            let (sender, receiver) = broadcast::channel(capacity);
+            (
+                UpdateSender::DropOldest(sender),
+                UpdateReceiverInner::DropOldest(receiver),
+            )
+        }
+    };
+
+    (
+        sender,
+        UpdateReceiver {
+            inner,
+            capacity,
+            dropped: 0,
+        },
+    )
+}
+
+impl<T> UpdateSender<T> {
+    /// Queue an item, false once the receiver is gone
+    pub async fn send(&self, item: T) -> bool {
+        match self {
+            UpdateSender::Block(sender) => sender.send(item).await.is_ok(),
+            UpdateSender::DropOldest(sender) => sender.send(item).is_ok(),
+        }
+    }
+}
+
+impl<T: Clone> UpdateReceiver<T> {
+    /// Next item, None once the sender is gone and the queue is empty
+    pub async fn recv(&mut self) -> Option<T> {
+        match &mut self.inner {
+            UpdateReceiverInner::Block(receiver) => receiver.recv().await,
+            UpdateReceiverInner::DropOldest(receiver) => loop {
+                match receiver.recv().await {
+                    Ok(item) => return Some(item),
+                    Err(RecvError::Lagged(dropped)) => {
+                        warn!("Update queue is full, dropped the {dropped} oldest updates");
+                        self.dropped += dropped;
+                    }
+                    Err(RecvError::Closed) => return None,
+                }
+            },
+        }
+    }
+}
+
+impl<T> UpdateReceiver<T> {
+    /// Number of queued items
+    pub fn len(&self) -> usize {
+        match &self.inner {
+            UpdateReceiverInner::Block(receiver) => receiver.len(),
+            UpdateReceiverInner::DropOldest(receiver) => receiver.len(),
+        }
+    }
+
+    /// Whether no item is queued
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Maximum number of queued items
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Items dropped because the queue was full
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+}
+
+/// Read the Geyser stream into the update queue until it fails or ends, or the queue is closed
+///
+/// - Pings are answered here, and sent every `keepalive.ping_interval_secs`, so keepalive doesn't
+///   wait for the dispatcher
+pub async fn read_stream<S, U>(
+    mut subscribe_tx: S,
+    mut stream: U,
+    keepalive: KeepaliveConfig,
+    updates: UpdateSender<StreamItem>,
+) where
+    S: Sink<SubscribeRequest> + Unpin,
+    S::Error: Display,
+    U: Stream<Item = Result<SubscribeUpdate, Status>> + Unpin,
+{
+    let mut ping_interval = keepalive.ping_interval().map(tokio::time::interval);
+    let mut ping_id: i32 = 0;
+
+    loop {
+        let action = tokio::select! {
+            message = stream.next() => match message {
+                Some(Ok(update)) => {
+                    if let Some(UpdateOneof::Pong(pong)) = &update.update_oneof {
+                        debug!("Received pong {}", pong.id);
+                        continue;
+                    }
+
+                    let is_ping = matches!(update.update_oneof, Some(UpdateOneof::Ping(_)));
+                    if !updates.send(StreamItem::Update(Box::new(update))).await {
+                        return;
+                    }
+                    if !is_ping {
+                        continue;
+                    }
+                    "answer"
+                }
+                Some(Err(error)) => {
+                    updates
+                        .send(StreamItem::Failed(format!("Stream error: {error:?}")))
+                        .await;
+                    return;
+                }
+                None => return,
+            },
+            _ = async {
+                match ping_interval.as_mut() {
+                    Some(ping_interval) => ping_interval.tick().await,
+                    None => std::future::pending().await,
+                }
+            } => "send",
+        };
+
+        ping_id = ping_id.wrapping_add(1);
+        if let Err(e) = subscribe_tx.send(keepalive::ping_request(ping_id)).await {
+            updates
+                .send(StreamItem::Failed(format!("Failed to {action} ping: {e}")))
+                .await;
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::update_queue::{channel, OverflowPolicy, UpdateQueueConfig};
+
+    #[tokio::test]
+    async fn test_drop_oldest() {
+        let config = UpdateQueueConfig {
+            capacity: 2,
+            overflow: OverflowPolicy::DropOldest,
+        };
+        let (sender, mut receiver) = channel(&config);
+        for item in 1..=4 {
+            assert!(sender.send(item).await);
+        }
+        assert_eq!(receiver.len(), 4);
+
+        assert_eq!(receiver.recv().await, Some(3));
+        assert_eq!(receiver.dropped(), 2);
+        assert_eq!(receiver.recv().await, Some(4));
+
+        drop(sender);
+        assert_eq!(receiver.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_block() {
+        let config = UpdateQueueConfig {
+            capacity: 2,
+            overflow: OverflowPolicy::Block,
+        };
+        let (sender, mut receiver) = channel(&config);
+        assert!(sender.send(1).await);
+        assert!(sender.send(2).await);
+        assert_eq!(receiver.len(), 2);
+
+        let blocked = tokio::spawn(async move { sender.send(3).await });
+        assert_eq!(receiver.recv().await, Some(1));
+        assert!(blocked.await.unwrap());
+        assert_eq!(receiver.recv().await, Some(2));
+        assert_eq!(receiver.recv().await, Some(3));
+        assert_eq!(receiver.recv().await, None);
+        assert_eq!(receiver.dropped(), 0);
+    }
+}
@@ -0,0 +1,283 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
+use spl_stake_pool::{
+    solana_program::borsh1::try_from_slice_unchecked,
+    state::{StakePool, ValidatorList},
+};
+
+use crate::{config::ValidatorListReportConfig, error::JitoBellError};
+
+/// Total stake (active + transient) per vote account in lamports
+pub type ValidatorSnapshot = BTreeMap<Pubkey, u64>;
+
+/// Difference between two validator list snapshots
+#[derive(Debug, Default, PartialEq)]
+pub struct ValidatorListDiff {
+    /// Validators added with their stake
+    pub added: Vec<(Pubkey, u64)>,
+
+    /// Validators removed with their last known stake
+    pub removed: Vec<(Pubkey, u64)>,
+
+    /// Stake change of validators present in both snapshots
+    pub changed: Vec<(Pubkey, i128)>,
+}
+
+impl ValidatorListDiff {
+    /// Diff two snapshots, ignoring stake changes smaller than `min_delta` lamports
+    pub fn new(previous: &ValidatorSnapshot, current: &ValidatorSnapshot, min_delta: u64) -> Self {
+        let mut diff = Self::default();
+
+        for (vote_account, stake) in current.iter() {
+            match previous.get(vote_account) {
+                Some(previous_stake) => {
+                    let delta = *stake as i128 - *previous_stake as i128;
+                    if delta != 0 && delta.unsigned_abs() >= min_delta as u128 {
+                        diff.changed.push((*vote_account, delta));
+                    }
+                }
+                None => diff.added.push((*vote_account, *stake)),
+            }
+        }
+
+        for (vote_account, stake) in previous.iter() {
+            if !current.contains_key(vote_account) {
+                diff.removed.push((*vote_account, *stake));
+            }
+        }
+
+        diff
+    }
+
+    /// Whether nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Net stake change in lamports
+    pub fn net_delta(&self) -> i128 {
+        let added: i128 = self.added.iter().map(|(_, stake)| *stake as i128).sum();
+        let removed: i128 = self.removed.iter().map(|(_, stake)| *stake as i128).sum();
+        let changed: i128 = self.changed.iter().map(|(_, delta)| *delta).sum();
+
+        added - removed + changed
+    }
+
+    /// Render report body
+    pub fn render(&self, description: &str) -> String {
+        let mut lines = vec![description.to_string()];
+
+        for (vote_account, stake) in self.added.iter() {
+            lines.push(format!(
+                "+ {vote_account} ({:.2} SOL)",
+                lamports_to_sol(*stake)
+            ));
+        }
+        for (vote_account, stake) in self.removed.iter() {
+            lines.push(format!(
+                "- {vote_account} ({:.2} SOL)",
+                lamports_to_sol(*stake)
+            ));
+        }
+        for (vote_account, delta) in self.changed.iter() {
+            lines.push(format!(
+                "~ {vote_account} ({:+.2} SOL)",
+                *delta as f64 / LAMPORTS_PER_SOL as f64
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Schedule and last snapshot of a report
+struct ReportState {
+    /// Stake pool address
+    stake_pool: Pubkey,
+
+    /// Report interval
+    interval: Duration,
+
+    /// Last time the report ran
+    last_run: Option<Instant>,
+
+    /// Last snapshot
+    snapshot: Option<ValidatorSnapshot>,
+}
+
+/// Validator List Monitor
+///
+/// - Snapshot the validator list of each configured pool on its schedule and diff it against the
+///   previous snapshot
+pub struct ValidatorListMonitor {
+    /// Report states, in the order of the configuration
+    reports: Vec<ReportState>,
+}
+
+impl ValidatorListMonitor {
+    /// Initialize Validator List Monitor
+    pub fn new(configs: &[ValidatorListReportConfig]) -> Result<Self, JitoBellError> {
+        let reports = configs
+            .iter()
+            .map(|config| {
+                let stake_pool = Pubkey::from_str(&config.stake_pool).map_err(|e| {
+                    JitoBellError::Config(format!("Invalid stake pool {}: {e}", config.stake_pool))
+                })?;
+
+                Ok(ReportState {
+                    stake_pool,
+                    interval: Duration::from_secs(config.interval_secs),
+                    last_run: None,
+                    snapshot: None,
+                })
+            })
+            .collect::<Result<Vec<_>, JitoBellError>>()?;
+
+        Ok(Self { reports })
+    }
+
+    /// Indexes of reports due now
+    pub fn due_reports(&self) -> Vec<usize> {
+        self.reports
+            .iter()
+            .enumerate()
+            .filter(|(_, report)| {
+                report
+                    .last_run
+                    .is_none_or(|last_run| last_run.elapsed() >= report.interval)
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Take a new snapshot for the report
+    ///
+    /// - Return the diff against the previous snapshot, None on the first snapshot
+    pub async fn run_report(
+        &mut self,
+        rpc_client: &RpcClient,
+        index: usize,
+        min_delta: u64,
+    ) -> Result<Option<ValidatorListDiff>, JitoBellError> {
+        let report = &mut self.reports[index];
+        report.last_run = Some(Instant::now());
+
+        let current = fetch_snapshot(rpc_client, &report.stake_pool).await?;
+        let diff = report
+            .snapshot
+            .as_ref()
+            .map(|previous| ValidatorListDiff::new(previous, &current, min_delta));
+        report.snapshot = Some(current);
+
+        Ok(diff)
+    }
+}
+
+/// Vote accounts of stake pools' validator lists, refreshed after `ttl`
+pub struct PoolValidators {
+    /// Time to live of a fetched validator list
+    ttl: Duration,
+
+    /// Vote accounts and fetch time per stake pool
+    pools: HashMap<Pubkey, (Instant, HashSet<Pubkey>)>,
+}
+
+impl PoolValidators {
+    /// Initialize Pool Validators
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            pools: HashMap::new(),
+        }
+    }
+
+    /// Whether the vote account is in the stake pool's validator list
+    pub async fn contains(
+        &mut self,
+        rpc_client: &RpcClient,
+        stake_pool: &Pubkey,
+        vote_account: &Pubkey,
+    ) -> Result<bool, JitoBellError> {
+        let fresh = self
+            .pools
+            .get(stake_pool)
+            .is_some_and(|(fetched_at, _)| fetched_at.elapsed() < self.ttl);
+
+        if !fresh {
+            let vote_accounts = fetch_snapshot(rpc_client, stake_pool)
+                .await?
+                .into_keys()
+                .collect();
+            self.pools
+                .insert(*stake_pool, (Instant::now(), vote_accounts));
+        }
+
+        Ok(self
+            .pools
+            .get(stake_pool)
+            .is_some_and(|(_, vote_accounts)| vote_accounts.contains(vote_account)))
+    }
+}
+
+/// Fetch stake pool's validator list and build a snapshot
+async fn fetch_snapshot(
+    rpc_client: &RpcClient,
+    stake_pool: &Pubkey,
+) -> Result<ValidatorSnapshot, JitoBellError> {
+    let stake_pool_acc = rpc_client.get_account(stake_pool).await?;
+    let stake_pool = try_from_slice_unchecked::<StakePool>(&stake_pool_acc.data)
+        .map_err(|e| JitoBellError::TransactionParse(format!("Invalid stake pool: {e}")))?;
+
+    let validator_list_acc = rpc_client.get_account(&stake_pool.validator_list).await?;
+    let validator_list = try_from_slice_unchecked::<ValidatorList>(&validator_list_acc.data)
+        .map_err(|e| JitoBellError::TransactionParse(format!("Invalid validator list: {e}")))?;
+
+    Ok(validator_list
+        .validators
+        .iter()
+        .map(|validator| {
+            let stake = u64::from(validator.active_stake_lamports)
+                .saturating_add(u64::from(validator.transient_stake_lamports));
+            (validator.vote_account_address, stake)
+        })
+        .collect())
+}
+
+fn lamports_to_sol(lamports: u64) -> f64 {
+    lamports as f64 / LAMPORTS_PER_SOL as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::pubkey::Pubkey;
+
+    use crate::validator_list::{ValidatorListDiff, ValidatorSnapshot};
+
+    #[test]
+    fn test_validator_list_diff() {
+        let kept = Pubkey::new_unique();
+        let unchanged = Pubkey::new_unique();
+        let removed = Pubkey::new_unique();
+        let added = Pubkey::new_unique();
+
+        let previous: ValidatorSnapshot = [(kept, 100), (unchanged, 50), (removed, 30)]
+            .into_iter()
+            .collect();
+        let current: ValidatorSnapshot = [(kept, 160), (unchanged, 51), (added, 20)]
+            .into_iter()
+            .collect();
+
+        let diff = ValidatorListDiff::new(&previous, &current, 10);
+
+        assert_eq!(diff.added, vec![(added, 20)]);
+        assert_eq!(diff.removed, vec![(removed, 30)]);
+        assert_eq!(diff.changed, vec![(kept, 60)]);
+        assert_eq!(diff.net_delta(), 20 - 30 + 60);
+    }
+}